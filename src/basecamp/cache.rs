@@ -0,0 +1,101 @@
+use crate::basecamp::daemon;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+const CACHE_VERSION: u8 = 1;
+const RESPONSES_DIR: &str = "responses";
+
+/// On-disk ETag cache for GET responses. Caching is best effort: read and
+/// write failures fall back to a normal request instead of failing it.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    #[serde(default)]
+    version: u8,
+    pub etag: String,
+    #[serde(default)]
+    pub next_url: Option<String>,
+    pub body: String,
+}
+
+impl CachedResponse {
+    pub fn new(etag: String, next_url: Option<String>, body: String) -> Self {
+        Self {
+            version: CACHE_VERSION,
+            etag,
+            next_url,
+            body,
+        }
+    }
+}
+
+impl ResponseCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Where `basecamp-cli daemon` listens; private like the cache itself.
+    pub fn daemon_socket_path(&self) -> PathBuf {
+        self.dir.join(daemon::SOCKET_FILE)
+    }
+
+    pub fn load(&self, url: &str) -> Option<CachedResponse> {
+        let contents = fs::read(self.entry_path(url)).ok()?;
+        let entry: CachedResponse = serde_json::from_slice(&contents).ok()?;
+        (entry.version == CACHE_VERSION).then_some(entry)
+    }
+
+    pub fn clear(&self) {
+        let _ = fs::remove_dir_all(self.dir.join(RESPONSES_DIR));
+    }
+
+    pub fn store(&self, url: &str, entry: &CachedResponse) {
+        let _ = self.try_store(url, entry);
+    }
+
+    fn try_store(&self, url: &str, entry: &CachedResponse) -> std::io::Result<()> {
+        let dir = self.dir.join(RESPONSES_DIR);
+        fs::create_dir_all(&dir)?;
+        set_private_permissions(&self.dir, 0o700)?;
+        set_private_permissions(&dir, 0o700)?;
+
+        let path = self.entry_path(url);
+        let tmp_path = path.with_extension(format!("tmp-{}", std::process::id()));
+        let payload = serde_json::to_vec(entry)?;
+        {
+            let mut file = fs::File::create(&tmp_path)?;
+            set_private_permissions(&tmp_path, 0o600)?;
+            file.write_all(&payload)?;
+        }
+        fs::rename(&tmp_path, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp_path);
+        })
+    }
+
+    // URLs are hashed so query strings never leak into file names.
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let digest = Sha256::digest(url.as_bytes());
+        self.dir
+            .join(RESPONSES_DIR)
+            .join(format!("{digest:x}.json"))
+    }
+}
+
+fn set_private_permissions(path: &std::path::Path, mode: u32) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+    }
+    #[cfg(not(unix))]
+    let _ = (path, mode);
+
+    Ok(())
+}
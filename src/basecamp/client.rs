@@ -1,14 +1,30 @@
+use crate::basecamp::cache::{CachedResponse, ResponseCache};
+use crate::basecamp::daemon;
+use crate::basecamp::dry_run;
 use crate::basecamp::models::{
-    CreateTodoPayload, CreatedTodo, PersonProfile, Project, ProjectPerson, Todo, TodoSearchResult,
-    Todolist, UpdateTodoPayload,
+    Attachment, Campfire, CampfireLine, Card, CardStep, CardStepCompletionPayload, Comment,
+    CreateCampfireLinePayload, CreateCardStepPayload, CreateCommentPayload, CreateMessagePayload,
+    CreateQuestionAnswerPayload, CreateScheduleEntryPayload, CreateTodoPayload,
+    CreateTodolistPayload, CreateUploadPayload, CreateWebhookPayload, CreatedTodo, Document,
+    DocumentPayload, Message, MyAssignments, OverdueTodosReport, PersonProfile, PositionPayload,
+    Project, ProjectAccessChange, ProjectAccessPayload, ProjectConstruction,
+    ProjectConstructionPayload, ProjectPayload, ProjectPerson, Question, QuestionAnswer,
+    RecordingEvent, ScheduleEntry, TimelineEvent, Todo, TodoSearchResult, Todolist,
+    UpcomingScheduleReport, UpdateTodoPayload, UpdateTodolistPayload, Upload, Vault, Webhook,
 };
+use crate::basecamp::reauth;
 use crate::error::{
     AppError, AppResult, OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE, OAuthStatusMessages,
     oauth_error_from_status,
 };
-use reqwest::{Client, Response, StatusCode};
+use crate::telemetry;
+use reqwest::header::{
+    CONTENT_LENGTH, CONTENT_TYPE, ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH, LINK,
+};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::cell::Cell;
 
 const USER_AGENT: &str = concat!(
     env!("CARGO_PKG_NAME"),
@@ -17,11 +33,24 @@ const USER_AGENT: &str = concat!(
     " (+https://github.com/basecamp/bc3-api)"
 );
 const TODO_SEARCH_TYPE: &str = "Todo";
+const MAX_LINKED_PAGES: u32 = 50;
 
+#[derive(Clone)]
 pub struct BasecampClient {
     http: Client,
     account_id: u64,
     access_token: String,
+    cache: Option<ResponseCache>,
+    daemon_reads: bool,
+    session_recovery: bool,
+}
+
+struct FetchedResponse {
+    /// The request URL with its query, which is also the cache key.
+    url: String,
+    status: StatusCode,
+    next_url: Option<String>,
+    body: Vec<u8>,
 }
 
 impl BasecampClient {
@@ -35,9 +64,38 @@ impl BasecampClient {
             http,
             account_id,
             access_token,
+            cache: None,
+            daemon_reads: true,
+            session_recovery: true,
         })
     }
 
+    /// The Basecamp account every request goes to.
+    pub fn account_id(&self) -> u64 {
+        self.account_id
+    }
+
+    /// Enables conditional GETs backed by `cache`; `None` leaves caching off.
+    pub fn with_cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Whether cached responses the daemon vouches for skip revalidation.
+    /// The daemon itself turns this off so it always reaches Basecamp.
+    pub fn with_daemon_reads(mut self, daemon_reads: bool) -> Self {
+        self.daemon_reads = daemon_reads;
+        self
+    }
+
+    /// Whether a 401 restores the stored session and retries the request.
+    /// `login` turns this off: its token is brand new, and recovering would
+    /// run `login` again.
+    pub fn with_session_recovery(mut self, session_recovery: bool) -> Self {
+        self.session_recovery = session_recovery;
+        self
+    }
+
     pub async fn fetch_my_profile(&self) -> AppResult<PersonProfile> {
         self.get_json(
             "my/profile.json",
@@ -50,60 +108,1080 @@ impl BasecampClient {
         .await
     }
 
-    pub async fn list_projects(&self) -> AppResult<Vec<Project>> {
-        self.get_json(
-            "projects.json",
-            Vec::new(),
-            "projects",
-            "Basecamp denied access to projects (403 Forbidden).",
-            Some("Basecamp projects endpoint was not found or is not accessible.".to_string()),
-            "Basecamp projects request failed with status",
+    pub async fn list_my_assignments(&self) -> AppResult<MyAssignments> {
+        self.get_json(
+            "my/assignments.json",
+            Vec::new(),
+            "assignments",
+            "Basecamp denied access to assignments (403 Forbidden).",
+            Some("Basecamp assignments endpoint was not found or is not accessible.".to_string()),
+            "Basecamp assignments request failed with status",
+        )
+        .await
+    }
+
+    pub async fn overdue_todos_report(&self) -> AppResult<OverdueTodosReport> {
+        self.get_json(
+            "reports/todos/overdue.json",
+            Vec::new(),
+            "overdue to-dos report",
+            "Basecamp denied access to the overdue to-dos report (403 Forbidden).",
+            Some("Basecamp overdue to-dos report was not found or is not accessible.".to_string()),
+            "Basecamp overdue to-dos report request failed with status",
+        )
+        .await
+    }
+
+    pub async fn upcoming_schedule_report(
+        &self,
+        starts_on: &str,
+        ends_on: &str,
+    ) -> AppResult<UpcomingScheduleReport> {
+        self.get_json(
+            "reports/schedules/upcoming.json",
+            vec![
+                ("window_starts_on", starts_on.to_string()),
+                ("window_ends_on", ends_on.to_string()),
+            ],
+            "upcoming schedule report",
+            "Basecamp denied access to the upcoming schedule report (403 Forbidden).",
+            Some(
+                "Basecamp upcoming schedule report was not found or is not accessible.".to_string(),
+            ),
+            "Basecamp upcoming schedule report request failed with status",
+        )
+        .await
+    }
+
+    /// Newest first.
+    pub async fn list_messages(
+        &self,
+        project_id: u64,
+        message_board_id: u64,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<Message>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/message_boards/{message_board_id}/messages.json"),
+            Vec::new(),
+            "messages",
+            "Basecamp denied access to messages (403 Forbidden).",
+            Some("Target project/message board was not found or is not accessible.".to_string()),
+            "Basecamp messages request failed with status",
+            |messages: &[Message]| limit.is_some_and(|limit| messages.len() >= limit),
+        )
+        .await
+    }
+
+    pub async fn get_message(&self, project_id: u64, message_id: u64) -> AppResult<Message> {
+        self.get_json(
+            &format!("buckets/{project_id}/messages/{message_id}.json"),
+            Vec::new(),
+            "message",
+            "Basecamp denied access to the message (403 Forbidden).",
+            Some(format!(
+                "Message {message_id} was not found or is not accessible."
+            )),
+            "Basecamp message request failed with status",
+        )
+        .await
+    }
+
+    pub async fn create_message(
+        &self,
+        project_id: u64,
+        message_board_id: u64,
+        payload: &CreateMessagePayload,
+    ) -> AppResult<Message> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/message_boards/{message_board_id}/messages.json"),
+                payload,
+                "message creation",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied posting the message (403 Forbidden).",
+            ),
+            Some("Target project/message board was not found or is not accessible."),
+            "Basecamp message creation failed with status",
+        )?;
+
+        response.json::<Message>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode created message response: {err}"))
+        })
+    }
+
+    pub async fn list_vaults(&self, project_id: u64, vault_id: u64) -> AppResult<Vec<Vault>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/vaults/{vault_id}/vaults.json"),
+            Vec::new(),
+            "folders",
+            "Basecamp denied access to folders (403 Forbidden).",
+            Some("Target project/folder was not found or is not accessible.".to_string()),
+            "Basecamp folders request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn list_documents(&self, project_id: u64, vault_id: u64) -> AppResult<Vec<Document>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/vaults/{vault_id}/documents.json"),
+            Vec::new(),
+            "documents",
+            "Basecamp denied access to documents (403 Forbidden).",
+            Some("Target project/folder was not found or is not accessible.".to_string()),
+            "Basecamp documents request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn get_vault(&self, project_id: u64, vault_id: u64) -> AppResult<Vault> {
+        self.get_json(
+            &format!("buckets/{project_id}/vaults/{vault_id}.json"),
+            Vec::new(),
+            "folder",
+            "Basecamp denied access to the folder (403 Forbidden).",
+            Some(format!(
+                "Folder {vault_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp folder request failed with status",
+        )
+        .await
+    }
+
+    pub async fn get_document(&self, project_id: u64, document_id: u64) -> AppResult<Document> {
+        self.get_json(
+            &format!("buckets/{project_id}/documents/{document_id}.json"),
+            Vec::new(),
+            "document",
+            "Basecamp denied access to the document (403 Forbidden).",
+            Some(format!(
+                "Document {document_id} was not found or is not accessible."
+            )),
+            "Basecamp document request failed with status",
+        )
+        .await
+    }
+
+    pub async fn create_document(
+        &self,
+        project_id: u64,
+        vault_id: u64,
+        payload: &DocumentPayload,
+    ) -> AppResult<Document> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/vaults/{vault_id}/documents.json"),
+                payload,
+                "document",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied document creation (403 Forbidden).",
+            ),
+            Some("Target project/folder was not found or is not accessible."),
+            "Basecamp document creation failed with status",
+        )?;
+
+        response.json::<Document>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode created document response: {err}"))
+        })
+    }
+
+    pub async fn update_document(
+        &self,
+        project_id: u64,
+        document_id: u64,
+        payload: &DocumentPayload,
+    ) -> AppResult<Document> {
+        let Some(response) = self
+            .send_put_json(
+                &format!("buckets/{project_id}/documents/{document_id}.json"),
+                payload,
+                "document update",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied document update (403 Forbidden).",
+            ),
+            Some("Target document was not found or is not accessible."),
+            "Basecamp document update failed with status",
+        )?;
+
+        response.json::<Document>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode updated document response: {err}"))
+        })
+    }
+
+    /// Uploads a file's bytes; the returned sgid turns them into an upload
+    /// or a rich-text attachment. A dry run returns a placeholder sgid.
+    pub async fn create_attachment(
+        &self,
+        file_name: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> AppResult<Attachment> {
+        let url = self.account_url("attachments.json");
+        if dry_run::enabled() {
+            dry_run::print_request(
+                "POST",
+                &format!("{url}?name={file_name}"),
+                Some(&serde_json::json!({
+                    "content_type": content_type,
+                    "byte_size": bytes.len(),
+                })),
+            );
+            return Ok(Attachment {
+                attachable_sgid: "dry-run".to_string(),
+            });
+        }
+
+        self.invalidate_daemon().await;
+        let response = self
+            .send_authorized(
+                |token| {
+                    self.http
+                        .post(&url)
+                        .bearer_auth(token)
+                        .query(&[("name", file_name)])
+                        .header(CONTENT_TYPE, content_type)
+                        .header(CONTENT_LENGTH, bytes.len())
+                        .body(bytes.clone())
+                },
+                "attachment",
+            )
+            .await?;
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied the file upload (403 Forbidden).",
+            ),
+            None,
+            "Basecamp file upload failed with status",
+        )?;
+
+        response.json::<Attachment>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode attachment response: {err}"))
+        })
+    }
+
+    pub async fn create_upload(
+        &self,
+        project_id: u64,
+        vault_id: u64,
+        payload: &CreateUploadPayload,
+    ) -> AppResult<Upload> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/vaults/{vault_id}/uploads.json"),
+                payload,
+                "upload",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied upload creation (403 Forbidden).",
+            ),
+            Some("Target project/folder was not found or is not accessible."),
+            "Basecamp upload creation failed with status",
+        )?;
+
+        response
+            .json::<Upload>()
+            .await
+            .map_err(|err| AppError::generic(format!("Failed to decode upload response: {err}")))
+    }
+
+    pub async fn list_schedule_entries(
+        &self,
+        project_id: u64,
+        schedule_id: u64,
+    ) -> AppResult<Vec<ScheduleEntry>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/schedules/{schedule_id}/entries.json"),
+            Vec::new(),
+            "schedule entries",
+            "Basecamp denied access to the schedule (403 Forbidden).",
+            Some("Target project/schedule was not found or is not accessible.".to_string()),
+            "Basecamp schedule request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn create_schedule_entry(
+        &self,
+        project_id: u64,
+        schedule_id: u64,
+        payload: &CreateScheduleEntryPayload,
+    ) -> AppResult<ScheduleEntry> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/schedules/{schedule_id}/entries.json"),
+                payload,
+                "schedule entry",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied schedule entry creation (403 Forbidden).",
+            ),
+            Some("Target project/schedule was not found or is not accessible."),
+            "Basecamp schedule entry creation failed with status",
+        )?;
+
+        response.json::<ScheduleEntry>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode schedule entry response: {err}"))
+        })
+    }
+
+    pub async fn list_webhooks(&self, project_id: u64) -> AppResult<Vec<Webhook>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/webhooks.json"),
+            Vec::new(),
+            "webhooks",
+            "Basecamp denied access to webhooks (403 Forbidden); only project admins can manage them.",
+            Some(format!(
+                "Project {project_id} was not found or is not accessible."
+            )),
+            "Basecamp webhooks request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn create_webhook(
+        &self,
+        project_id: u64,
+        payload: &CreateWebhookPayload,
+    ) -> AppResult<Webhook> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/webhooks.json"),
+                payload,
+                "webhook",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied webhook creation (403 Forbidden); only project admins can manage webhooks.",
+            ),
+            Some("Target project was not found or is not accessible."),
+            "Basecamp webhook creation failed with status",
+        )?;
+
+        response
+            .json::<Webhook>()
+            .await
+            .map_err(|err| AppError::generic(format!("Failed to decode webhook response: {err}")))
+    }
+
+    pub async fn delete_webhook(&self, project_id: u64, webhook_id: u64) -> AppResult<()> {
+        let Some(response) = self
+            .send_delete(
+                &format!("buckets/{project_id}/webhooks/{webhook_id}.json"),
+                "webhook delete",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied webhook deletion (403 Forbidden); only project admins can manage webhooks.",
+            ),
+            Some(&format!(
+                "Webhook {webhook_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp webhook deletion failed with status",
+        )
+    }
+
+    pub async fn get_card(&self, project_id: u64, card_id: u64) -> AppResult<Card> {
+        self.get_json(
+            &format!("buckets/{project_id}/card_tables/cards/{card_id}.json"),
+            Vec::new(),
+            "card",
+            "Basecamp denied access to the card (403 Forbidden).",
+            Some(format!(
+                "Card {card_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp card request failed with status",
+        )
+        .await
+    }
+
+    pub async fn create_card_step(
+        &self,
+        project_id: u64,
+        card_id: u64,
+        payload: &CreateCardStepPayload,
+    ) -> AppResult<CardStep> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/card_tables/cards/{card_id}/steps.json"),
+                payload,
+                "card step",
+            )
+            .await?
+        else {
+            // The payload's `assignees` is comma-joined ids, which `echo`
+            // cannot decode as people; the caller fills them in.
+            return Ok(CardStep {
+                id: 0,
+                title: payload.title.clone(),
+                completed: false,
+                due_on: payload.due_on.clone(),
+                assignees: Vec::new(),
+            });
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied card step creation (403 Forbidden).",
+            ),
+            Some("Target project/card was not found or is not accessible."),
+            "Basecamp card step creation failed with status",
+        )?;
+
+        response
+            .json::<CardStep>()
+            .await
+            .map_err(|err| AppError::generic(format!("Failed to decode card step response: {err}")))
+    }
+
+    /// Completes a step, or re-opens it when `completed` is false.
+    pub async fn set_card_step_completion(
+        &self,
+        project_id: u64,
+        step_id: u64,
+        completed: bool,
+    ) -> AppResult<()> {
+        let payload = CardStepCompletionPayload {
+            completion: if completed { "on" } else { "off" }.to_string(),
+        };
+        let Some(response) = self
+            .send_put_json(
+                &format!("buckets/{project_id}/card_tables/steps/{step_id}/completions.json"),
+                &payload,
+                "card step completion",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied card step completion (403 Forbidden).",
+            ),
+            Some("Target project/card step was not found or is not accessible."),
+            "Basecamp card step completion failed with status",
+        )
+    }
+
+    pub async fn list_questions(
+        &self,
+        project_id: u64,
+        questionnaire_id: u64,
+    ) -> AppResult<Vec<Question>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/questionnaires/{questionnaire_id}/questions.json"),
+            Vec::new(),
+            "check-in questions",
+            "Basecamp denied access to check-ins (403 Forbidden).",
+            Some("Target project/check-ins were not found or are not accessible.".to_string()),
+            "Basecamp check-in questions request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    /// Answers come newest first; paging stops once a page reaches answers
+    /// filed before `since_date` (`YYYY-MM-DD`).
+    pub async fn list_question_answers(
+        &self,
+        project_id: u64,
+        question_id: u64,
+        since_date: &str,
+    ) -> AppResult<Vec<QuestionAnswer>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/questions/{question_id}/answers.json"),
+            Vec::new(),
+            "check-in answers",
+            "Basecamp denied access to check-in answers (403 Forbidden).",
+            Some(format!(
+                "Check-in question {question_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp check-in answers request failed with status",
+            |page: &[QuestionAnswer]| {
+                page.last().is_some_and(|answer| {
+                    answer.group_on.as_deref().unwrap_or_default() < since_date
+                })
+            },
+        )
+        .await
+    }
+
+    pub async fn create_question_answer(
+        &self,
+        project_id: u64,
+        question_id: u64,
+        payload: &CreateQuestionAnswerPayload,
+    ) -> AppResult<QuestionAnswer> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/questions/{question_id}/answers.json"),
+                payload,
+                "check-in answer",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied answering the check-in (403 Forbidden).",
+            ),
+            Some("Target project/check-in question was not found or is not accessible."),
+            "Basecamp check-in answer failed with status",
+        )?;
+
+        response.json::<QuestionAnswer>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode check-in answer response: {err}"))
+        })
+    }
+
+    /// Every campfire the signed-in person can see, across projects.
+    pub async fn list_campfires(&self) -> AppResult<Vec<Campfire>> {
+        self.get_paginated_json(
+            "chats.json",
+            Vec::new(),
+            "campfires",
+            "Basecamp denied access to campfires (403 Forbidden).",
+            Some("Basecamp campfires endpoint was not found or is not accessible.".to_string()),
+            "Basecamp campfires request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    /// The most recent page of a campfire's lines, newest first.
+    pub async fn list_campfire_lines(
+        &self,
+        project_id: u64,
+        campfire_id: u64,
+    ) -> AppResult<Vec<CampfireLine>> {
+        self.get_json(
+            &format!("buckets/{project_id}/chats/{campfire_id}/lines.json"),
+            Vec::new(),
+            "campfire lines",
+            "Basecamp denied access to campfire lines (403 Forbidden).",
+            Some(format!(
+                "Campfire {campfire_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp campfire lines request failed with status",
+        )
+        .await
+    }
+
+    pub async fn create_campfire_line(
+        &self,
+        project_id: u64,
+        campfire_id: u64,
+        payload: &CreateCampfireLinePayload,
+    ) -> AppResult<CampfireLine> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/chats/{campfire_id}/lines.json"),
+                payload,
+                "campfire line",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied posting to the campfire (403 Forbidden).",
+            ),
+            Some("Target project/campfire was not found or is not accessible."),
+            "Basecamp campfire line failed with status",
+        )?;
+
+        response.json::<CampfireLine>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode campfire line response: {err}"))
+        })
+    }
+
+    pub async fn list_projects(&self) -> AppResult<Vec<Project>> {
+        self.get_json(
+            "projects.json",
+            Vec::new(),
+            "projects",
+            "Basecamp denied access to projects (403 Forbidden).",
+            Some("Basecamp projects endpoint was not found or is not accessible.".to_string()),
+            "Basecamp projects request failed with status",
+        )
+        .await
+    }
+
+    /// How many projects the first page of `projects.json` lists, without
+    /// decoding them or following later pages: a cheap access check.
+    pub async fn probe_projects(&self) -> AppResult<usize> {
+        self.get_json::<Vec<serde::de::IgnoredAny>>(
+            "projects.json",
+            Vec::new(),
+            "projects",
+            "Basecamp denied access to projects (403 Forbidden).",
+            Some("Basecamp projects endpoint was not found or is not accessible.".to_string()),
+            "Basecamp projects request failed with status",
+        )
+        .await
+        .map(|projects| projects.len())
+    }
+
+    /// Archived or trashed projects; `projects.json` lists only active ones.
+    pub async fn list_projects_with_status(&self, status: &str) -> AppResult<Vec<Project>> {
+        self.get_paginated_json(
+            "projects.json",
+            vec![("status", status.to_string())],
+            "projects",
+            "Basecamp denied access to projects (403 Forbidden).",
+            Some("Basecamp projects endpoint was not found or is not accessible.".to_string()),
+            "Basecamp projects request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn get_project(&self, project_id: u64) -> AppResult<Project> {
+        self.get_json(
+            &format!("projects/{project_id}.json"),
+            Vec::new(),
+            "project",
+            "Basecamp denied access to project (403 Forbidden).",
+            Some(format!(
+                "Project {project_id} was not found or is not accessible."
+            )),
+            "Basecamp project request failed with status",
+        )
+        .await
+    }
+
+    pub async fn list_todolists(
+        &self,
+        project_id: u64,
+        todoset_id: u64,
+    ) -> AppResult<Vec<Todolist>> {
+        self.get_json(
+            &format!("buckets/{project_id}/todosets/{todoset_id}/todolists.json"),
+            Vec::new(),
+            "to-do lists",
+            "Basecamp denied access to to-do lists (403 Forbidden).",
+            Some("Basecamp to-do lists endpoint was not found or is not accessible.".to_string()),
+            "Basecamp to-do lists request failed with status",
+        )
+        .await
+    }
+
+    /// Works for top-level lists and groups alike; both are `Todolist` records.
+    pub async fn get_todolist(&self, project_id: u64, todolist_id: u64) -> AppResult<Todolist> {
+        self.get_json(
+            &format!("buckets/{project_id}/todolists/{todolist_id}.json"),
+            Vec::new(),
+            "to-do list",
+            "Basecamp denied access to to-do list (403 Forbidden).",
+            Some(format!(
+                "To-do list {todolist_id} was not found in project {project_id} or is not accessible."
+            )),
+            "Basecamp to-do list request failed with status",
+        )
+        .await
+    }
+
+    pub async fn list_todolist_groups(
+        &self,
+        project_id: u64,
+        todolist_id: u64,
+    ) -> AppResult<Vec<Todolist>> {
+        self.get_json(
+            &format!("buckets/{project_id}/todolists/{todolist_id}/groups.json"),
+            Vec::new(),
+            "to-do groups",
+            "Basecamp denied access to to-do groups (403 Forbidden).",
+            Some("Basecamp to-do groups endpoint was not found or is not accessible.".to_string()),
+            "Basecamp to-do groups request failed with status",
+        )
+        .await
+    }
+
+    pub async fn create_project(&self, payload: &ProjectPayload) -> AppResult<Project> {
+        let Some(response) = self
+            .send_post_json("projects.json", payload, "project creation")
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied project creation (403 Forbidden).",
+            ),
+            None,
+            "Basecamp project creation failed with status",
+        )?;
+
+        response.json::<Project>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode created project response: {err}"))
+        })
+    }
+
+    pub async fn update_project(
+        &self,
+        project_id: u64,
+        payload: &ProjectPayload,
+    ) -> AppResult<Project> {
+        let Some(response) = self
+            .send_put_json(
+                &format!("projects/{project_id}.json"),
+                payload,
+                "project update",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied project update (403 Forbidden).",
+            ),
+            Some("Target project was not found or is not accessible."),
+            "Basecamp project update failed with status",
+        )?;
+
+        response.json::<Project>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode updated project response: {err}"))
+        })
+    }
+
+    /// Starts building a project from a template; Basecamp finishes it in
+    /// the background. A dry run reports a construction already completed,
+    /// without a project.
+    pub async fn construct_project(
+        &self,
+        template_id: u64,
+        payload: &ProjectConstructionPayload,
+    ) -> AppResult<ProjectConstruction> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("templates/{template_id}/project_constructions.json"),
+                payload,
+                "project construction",
+            )
+            .await?
+        else {
+            return Ok(ProjectConstruction {
+                id: 0,
+                status: "completed".to_string(),
+                project: None,
+            });
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied project creation from template (403 Forbidden).",
+            ),
+            Some(&format!(
+                "Template {template_id} was not found or is not accessible."
+            )),
+            "Basecamp project construction failed with status",
+        )?;
+
+        response.json::<ProjectConstruction>().await.map_err(|err| {
+            AppError::generic(format!(
+                "Failed to decode project construction response: {err}"
+            ))
+        })
+    }
+
+    pub async fn get_project_construction(
+        &self,
+        template_id: u64,
+        construction_id: u64,
+    ) -> AppResult<ProjectConstruction> {
+        self.get_json(
+            &format!("templates/{template_id}/project_constructions/{construction_id}.json"),
+            Vec::new(),
+            "project construction",
+            "Basecamp denied access to the project construction (403 Forbidden).",
+            Some(format!(
+                "Project construction {construction_id} was not found or is not accessible."
+            )),
+            "Basecamp project construction request failed with status",
+        )
+        .await
+    }
+
+    /// `archived` or `active`; `active` also restores a trashed project.
+    pub async fn set_project_status(&self, project_id: u64, status: &str) -> AppResult<()> {
+        let Some(response) = self
+            .send_put_empty(
+                &format!("projects/{project_id}/status/{status}.json"),
+                "project status",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied changing the project status (403 Forbidden).",
+            ),
+            Some(&format!(
+                "Project {project_id} was not found or is not accessible."
+            )),
+            "Basecamp project status request failed with status",
+        )
+    }
+
+    pub async fn trash_project(&self, project_id: u64) -> AppResult<()> {
+        let Some(response) = self
+            .send_delete(&format!("projects/{project_id}.json"), "project trash")
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied moving the project to trash (403 Forbidden).",
+            ),
+            Some(&format!(
+                "Project {project_id} was not found or is not accessible."
+            )),
+            "Basecamp project trash request failed with status",
+        )
+    }
+
+    pub async fn list_project_people(&self, project_id: u64) -> AppResult<Vec<ProjectPerson>> {
+        self.get_paginated_json(
+            &format!("projects/{project_id}/people.json"),
+            Vec::new(),
+            "project people",
+            "Basecamp denied access to project people (403 Forbidden).",
+            Some(
+                "Basecamp project people endpoint was not found or is not accessible.".to_string(),
+            ),
+            "Basecamp project people request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    /// Everyone the signed-in person can see in the account.
+    pub async fn list_people(&self) -> AppResult<Vec<ProjectPerson>> {
+        self.get_paginated_json(
+            "people.json",
+            Vec::new(),
+            "people",
+            "Basecamp denied access to people (403 Forbidden).",
+            Some("Basecamp people endpoint was not found or is not accessible.".to_string()),
+            "Basecamp people request failed with status",
+            |_| false,
+        )
+        .await
+    }
+
+    pub async fn get_person(&self, person_id: u64) -> AppResult<ProjectPerson> {
+        self.get_json(
+            &format!("people/{person_id}.json"),
+            Vec::new(),
+            "person",
+            "Basecamp denied access to people (403 Forbidden).",
+            Some(format!(
+                "Person {person_id} was not found in this account or is not visible to you."
+            )),
+            "Basecamp person request failed with status",
+        )
+        .await
+    }
+
+    /// A dry run reports no changes.
+    pub async fn update_project_access(
+        &self,
+        project_id: u64,
+        payload: &ProjectAccessPayload,
+    ) -> AppResult<ProjectAccessChange> {
+        let Some(response) = self
+            .send_put_json(
+                &format!("projects/{project_id}/people/users.json"),
+                payload,
+                "project access update",
+            )
+            .await?
+        else {
+            return Ok(ProjectAccessChange::default());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied changing project access (403 Forbidden).",
+            ),
+            Some("Target project was not found or is not accessible."),
+            "Basecamp project access update failed with status",
+        )?;
+
+        response.json::<ProjectAccessChange>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode project access response: {err}"))
+        })
+    }
+
+    pub async fn list_todos(
+        &self,
+        project_id: u64,
+        todolist_id: u64,
+        completed: bool,
+    ) -> AppResult<Vec<Todo>> {
+        let mut params = Vec::new();
+        if completed {
+            params.push(("completed", "true".to_string()));
+        }
+
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/todolists/{todolist_id}/todos.json"),
+            params,
+            "to-dos",
+            "Basecamp denied access to to-dos (403 Forbidden).",
+            Some("Target project/list was not found or is not accessible.".to_string()),
+            "Basecamp to-dos request failed with status",
+            |_| false,
         )
         .await
     }
 
-    pub async fn list_todolists(
+    /// The change log of any recording.
+    pub async fn list_recording_events(
         &self,
         project_id: u64,
-        todoset_id: u64,
-    ) -> AppResult<Vec<Todolist>> {
-        self.get_json(
-            &format!("buckets/{project_id}/todosets/{todoset_id}/todolists.json"),
+        recording_id: u64,
+    ) -> AppResult<Vec<RecordingEvent>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/recordings/{recording_id}/events.json"),
             Vec::new(),
-            "to-do lists",
-            "Basecamp denied access to to-do lists (403 Forbidden).",
-            Some("Basecamp to-do lists endpoint was not found or is not accessible.".to_string()),
-            "Basecamp to-do lists request failed with status",
+            "events",
+            "Basecamp denied access to events (403 Forbidden).",
+            Some("Target project/item was not found or is not accessible.".to_string()),
+            "Basecamp events request failed with status",
+            |_| false,
         )
         .await
     }
 
-    pub async fn list_todolist_groups(
+    /// Comments on any recording, oldest first.
+    pub async fn list_comments(
         &self,
         project_id: u64,
-        todolist_id: u64,
-    ) -> AppResult<Vec<Todolist>> {
-        self.get_json(
-            &format!("buckets/{project_id}/todolists/{todolist_id}/groups.json"),
+        recording_id: u64,
+    ) -> AppResult<Vec<Comment>> {
+        self.get_paginated_json(
+            &format!("buckets/{project_id}/recordings/{recording_id}/comments.json"),
             Vec::new(),
-            "to-do groups",
-            "Basecamp denied access to to-do groups (403 Forbidden).",
-            Some("Basecamp to-do groups endpoint was not found or is not accessible.".to_string()),
-            "Basecamp to-do groups request failed with status",
+            "comments",
+            "Basecamp denied access to comments (403 Forbidden).",
+            Some("Target project/item was not found or is not accessible.".to_string()),
+            "Basecamp comments request failed with status",
+            |_| false,
         )
         .await
     }
 
-    pub async fn list_project_people(&self, project_id: u64) -> AppResult<Vec<ProjectPerson>> {
-        self.get_json(
-            &format!("projects/{project_id}/people.json"),
+    /// Events are returned newest first; paging stops once a page reaches
+    /// events created before `since_date` (`YYYY-MM-DD`).
+    pub async fn list_progress_events(&self, since_date: &str) -> AppResult<Vec<TimelineEvent>> {
+        self.get_paginated_json(
+            "reports/progress.json",
             Vec::new(),
-            "project people",
-            "Basecamp denied access to project people (403 Forbidden).",
+            "progress report",
+            "Basecamp denied access to the progress report (403 Forbidden).",
             Some(
-                "Basecamp project people endpoint was not found or is not accessible.".to_string(),
+                "Basecamp progress report endpoint was not found or is not accessible.".to_string(),
             ),
-            "Basecamp project people request failed with status",
+            "Basecamp progress report request failed with status",
+            |page: &[TimelineEvent]| {
+                page.last()
+                    .is_some_and(|event| event.created_at.get(..10).unwrap_or("") < since_date)
+            },
         )
         .await
     }
@@ -114,13 +1192,16 @@ impl BasecampClient {
         target_todolist_id: u64,
         payload: &CreateTodoPayload,
     ) -> AppResult<CreatedTodo> {
-        let response = self
+        let Some(response) = self
             .send_post_json(
                 &format!("buckets/{project_id}/todolists/{target_todolist_id}/todos.json"),
                 payload,
                 "todo creation",
             )
-            .await?;
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
 
         self.ensure_success_status(
             response.status(),
@@ -137,6 +1218,74 @@ impl BasecampClient {
         })
     }
 
+    pub async fn create_todolist(
+        &self,
+        project_id: u64,
+        todoset_id: u64,
+        payload: &CreateTodolistPayload,
+    ) -> AppResult<Todolist> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/todosets/{todoset_id}/todolists.json"),
+                payload,
+                "to-do list creation",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied to-do list creation (403 Forbidden).",
+            ),
+            Some("Target project was not found or is not accessible."),
+            "Basecamp to-do list creation failed with status",
+        )?;
+
+        response.json::<Todolist>().await.map_err(|err| {
+            AppError::generic(format!(
+                "Failed to decode created to-do list response: {err}"
+            ))
+        })
+    }
+
+    pub async fn create_todolist_group(
+        &self,
+        project_id: u64,
+        todolist_id: u64,
+        payload: &CreateTodolistPayload,
+    ) -> AppResult<Todolist> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/todolists/{todolist_id}/groups.json"),
+                payload,
+                "to-do group creation",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied to-do group creation (403 Forbidden).",
+            ),
+            Some("Target project/list was not found or is not accessible."),
+            "Basecamp to-do group creation failed with status",
+        )?;
+
+        response.json::<Todolist>().await.map_err(|err| {
+            AppError::generic(format!(
+                "Failed to decode created to-do group response: {err}"
+            ))
+        })
+    }
+
     pub async fn get_todo(&self, project_id: u64, todo_id: u64) -> AppResult<Todo> {
         self.get_json(
             &format!("buckets/{project_id}/todos/{todo_id}.json"),
@@ -155,13 +1304,16 @@ impl BasecampClient {
         todo_id: u64,
         payload: &UpdateTodoPayload,
     ) -> AppResult<Todo> {
-        let response = self
+        let Some(response) = self
             .send_put_json(
                 &format!("buckets/{project_id}/todos/{todo_id}.json"),
                 payload,
                 "todo update",
             )
-            .await?;
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
 
         self.ensure_success_status(
             response.status(),
@@ -178,6 +1330,40 @@ impl BasecampClient {
         })
     }
 
+    pub async fn update_todolist(
+        &self,
+        project_id: u64,
+        todolist_id: u64,
+        payload: &UpdateTodolistPayload,
+    ) -> AppResult<Todolist> {
+        let Some(response) = self
+            .send_put_json(
+                &format!("buckets/{project_id}/todolists/{todolist_id}.json"),
+                payload,
+                "to-do list update",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied to-do list update (403 Forbidden).",
+            ),
+            Some("Target project/list was not found or is not accessible."),
+            "Basecamp to-do list update failed with status",
+        )?;
+
+        response.json::<Todolist>().await.map_err(|err| {
+            AppError::generic(format!(
+                "Failed to decode updated to-do list response: {err}"
+            ))
+        })
+    }
+
     pub async fn search_todos(
         &self,
         query: &str,
@@ -230,13 +1416,51 @@ impl BasecampClient {
         Ok(matches)
     }
 
+    /// The project of `todo_id`, looked up among the most recently updated
+    /// to-dos of every project; `None` when it is not in the first
+    /// `max_pages` pages.
+    pub async fn find_recent_todo_project(
+        &self,
+        todo_id: u64,
+        max_pages: u32,
+    ) -> AppResult<Option<u64>> {
+        let pages = Cell::new(0_u32);
+        let recordings: Vec<TodoSearchResult> = self
+            .get_paginated_json(
+                "projects/recordings.json",
+                vec![
+                    ("type", TODO_SEARCH_TYPE.to_string()),
+                    ("sort", "updated_at".to_string()),
+                    ("direction", "desc".to_string()),
+                ],
+                "recent to-dos",
+                "Basecamp denied access to recordings (403 Forbidden).",
+                None,
+                "Basecamp recordings request failed with status",
+                |page: &[TodoSearchResult]| {
+                    pages.set(pages.get() + 1);
+                    pages.get() >= max_pages || page.iter().any(|recording| recording.id == todo_id)
+                },
+            )
+            .await?;
+
+        Ok(recordings
+            .into_iter()
+            .find(|recording| recording.id == todo_id)
+            .and_then(|recording| recording.bucket)
+            .map(|bucket| bucket.id))
+    }
+
     pub async fn complete_todo(&self, project_id: u64, todo_id: u64) -> AppResult<()> {
-        let response = self
+        let Some(response) = self
             .send_post_empty(
                 &format!("buckets/{project_id}/todos/{todo_id}/completion.json"),
                 "todo completion",
             )
-            .await?;
+            .await?
+        else {
+            return Ok(());
+        };
 
         self.ensure_success_status(
             response.status(),
@@ -250,12 +1474,15 @@ impl BasecampClient {
     }
 
     pub async fn re_open_todo(&self, project_id: u64, todo_id: u64) -> AppResult<()> {
-        let response = self
+        let Some(response) = self
             .send_delete(
                 &format!("buckets/{project_id}/todos/{todo_id}/completion.json"),
                 "todo re-open",
             )
-            .await?;
+            .await?
+        else {
+            return Ok(());
+        };
 
         self.ensure_success_status(
             response.status(),
@@ -268,6 +1495,113 @@ impl BasecampClient {
         )
     }
 
+    pub async fn create_comment(
+        &self,
+        project_id: u64,
+        recording_id: u64,
+        payload: &CreateCommentPayload,
+    ) -> AppResult<Comment> {
+        let Some(response) = self
+            .send_post_json(
+                &format!("buckets/{project_id}/recordings/{recording_id}/comments.json"),
+                payload,
+                "comment creation",
+            )
+            .await?
+        else {
+            return dry_run::echo(payload);
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied comment creation (403 Forbidden).",
+            ),
+            Some("Target project/item was not found or is not accessible."),
+            "Basecamp comment creation failed with status",
+        )?;
+
+        response.json::<Comment>().await.map_err(|err| {
+            AppError::generic(format!("Failed to decode created comment response: {err}"))
+        })
+    }
+
+    /// Moves any recording (to-do, message, comment, ...) to the project trash,
+    /// where Basecamp keeps it restorable for 30 days.
+    pub async fn trash_recording(&self, project_id: u64, recording_id: u64) -> AppResult<()> {
+        let Some(response) = self
+            .send_put_empty(
+                &format!("buckets/{project_id}/recordings/{recording_id}/status/trashed.json"),
+                "recording trash",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied moving the item to trash (403 Forbidden).",
+            ),
+            Some("Target project/item was not found or is not accessible."),
+            "Basecamp trash request failed with status",
+        )
+    }
+
+    /// Moves a to-do (or list) within its parent.
+    pub async fn reposition_recording(
+        &self,
+        project_id: u64,
+        recording_id: u64,
+        position: u32,
+    ) -> AppResult<()> {
+        let Some(response) = self
+            .send_put_json(
+                &format!("buckets/{project_id}/recordings/{recording_id}/position.json"),
+                &PositionPayload { position },
+                "recording position",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied moving the item (403 Forbidden).",
+            ),
+            Some("Target project/item was not found or is not accessible."),
+            "Basecamp position request failed with status",
+        )
+    }
+
+    pub async fn archive_recording(&self, project_id: u64, recording_id: u64) -> AppResult<()> {
+        let Some(response) = self
+            .send_put_empty(
+                &format!("buckets/{project_id}/recordings/{recording_id}/status/archived.json"),
+                "recording archive",
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.ensure_success_status(
+            response.status(),
+            OAuthStatusMessages::new(
+                OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                "Basecamp denied archiving the item (403 Forbidden).",
+            ),
+            Some("Target project/item was not found or is not accessible."),
+            "Basecamp archive request failed with status",
+        )
+    }
+
     async fn get_json<T>(
         &self,
         path: &str,
@@ -280,43 +1614,219 @@ impl BasecampClient {
     where
         T: DeserializeOwned,
     {
-        let response = self.send_get(path, query, response_context).await?;
+        let response = self
+            .fetch_get(self.account_url(path), &query, response_context)
+            .await?;
         self.ensure_success_status(
-            response.status(),
+            response.status,
             OAuthStatusMessages::new(OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE, forbidden_message),
             not_found_message.as_deref(),
             status_error_prefix,
         )?;
 
-        response.json::<T>().await.map_err(|err| {
+        serde_json::from_slice::<T>(&response.body).map_err(|err| {
             AppError::generic(format!(
                 "Failed to decode {response_context} response: {err}"
             ))
         })
     }
 
-    async fn send_get(
+    #[allow(clippy::too_many_arguments)]
+    async fn get_paginated_json<T>(
         &self,
         path: &str,
         query: Vec<(&str, String)>,
+        response_context: &str,
+        forbidden_message: &str,
+        not_found_message: Option<String>,
+        status_error_prefix: &str,
+        stop_after_page: impl Fn(&[T]) -> bool,
+    ) -> AppResult<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut response = self
+            .fetch_get(self.account_url(path), &query, response_context)
+            .await?;
+        let mut page = 1_u32;
+
+        loop {
+            self.ensure_success_status(
+                response.status,
+                OAuthStatusMessages::new(OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE, forbidden_message),
+                not_found_message.as_deref(),
+                status_error_prefix,
+            )?;
+
+            let page_items = serde_json::from_slice::<Vec<T>>(&response.body).map_err(|err| {
+                AppError::generic(format!(
+                    "Failed to decode {response_context} response: {err}"
+                ))
+            })?;
+            let stop = stop_after_page(&page_items);
+            items.extend(page_items);
+
+            let Some(next_url) = response.next_url.take() else {
+                break;
+            };
+            if stop {
+                break;
+            }
+            if page >= MAX_LINKED_PAGES {
+                break;
+            }
+
+            page += 1;
+            response = self.fetch_get(next_url, &[], response_context).await?;
+        }
+
+        Ok(items)
+    }
+
+    // Sends `If-None-Match` when a cached ETag exists and serves the cached
+    // body on `304 Not Modified`.
+    async fn fetch_get(
+        &self,
+        url: String,
+        query: &[(&str, String)],
         request_context: &str,
-    ) -> AppResult<Response> {
-        self.http
-            .get(self.account_url(path))
-            .bearer_auth(&self.access_token)
-            .query(&query)
-            .send()
+    ) -> AppResult<FetchedResponse> {
+        let request_error = |err: reqwest::Error| {
+            AppError::generic(format!("Failed to request {request_context}: {err}"))
+        };
+        let cache_key = self
+            .http
+            .get(&url)
+            .query(query)
+            .build()
+            .map_err(request_error)?
+            .url()
+            .to_string();
+        let cached = self.cache.as_ref().and_then(|cache| cache.load(&cache_key));
+        if self.daemon_reads
+            && let (Some(cache), Some(entry)) = (self.cache.as_ref(), cached.as_ref())
+            && daemon::is_fresh(
+                &cache.daemon_socket_path(),
+                &self.daemon_session(),
+                &cache_key,
+            )
             .await
-            .map_err(|err| AppError::generic(format!("Failed to request {request_context}: {err}")))
+        {
+            return Ok(FetchedResponse {
+                url: cache_key,
+                status: StatusCode::OK,
+                next_url: entry.next_url.clone(),
+                body: entry.body.clone().into_bytes(),
+            });
+        }
+        let etag = cached
+            .as_ref()
+            .and_then(|entry| HeaderValue::from_str(&entry.etag).ok());
+
+        let response = self
+            .send_authorized(
+                |token| {
+                    let request = self.http.get(&url).bearer_auth(token).query(query);
+                    match etag.as_ref() {
+                        Some(etag) => request.header(IF_NONE_MATCH, etag.clone()),
+                        None => request,
+                    }
+                },
+                request_context,
+            )
+            .await?;
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED
+            && let Some(entry) = cached
+        {
+            return Ok(FetchedResponse {
+                url: cache_key,
+                status: StatusCode::OK,
+                next_url: entry.next_url,
+                body: entry.body.into_bytes(),
+            });
+        }
+
+        let next_url = next_page_url(response.headers());
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await.map_err(request_error)?.to_vec();
+
+        if status.is_success()
+            && let (Some(cache), Some(etag)) = (self.cache.as_ref(), etag)
+            && let Ok(text) = std::str::from_utf8(&body)
+        {
+            cache.store(
+                &cache_key,
+                &CachedResponse::new(etag, next_url.clone(), text.to_string()),
+            );
+        }
+
+        Ok(FetchedResponse {
+            url: cache_key,
+            status,
+            next_url,
+            body,
+        })
     }
 
-    async fn send_post_empty(&self, path: &str, request_context: &str) -> AppResult<Response> {
-        self.http
-            .post(self.account_url(path))
-            .bearer_auth(&self.access_token)
-            .send()
-            .await
-            .map_err(|err| AppError::generic(format!("Failed to request {request_context}: {err}")))
+    /// Identifies this client's account and token to the daemon.
+    pub fn daemon_session(&self) -> String {
+        daemon::session_key(self.account_id, &reauth::current_token(&self.access_token))
+    }
+
+    /// Fetches `path` and every page linked after it into the response
+    /// cache, returning each page's cache key; the daemon marks them fresh.
+    pub async fn warm_pages(&self, path: &str) -> AppResult<Vec<String>> {
+        let mut urls = Vec::new();
+        let mut next_url = Some(self.account_url(path));
+        while let Some(url) = next_url.take() {
+            let response = self.fetch_get(url, &[], path).await?;
+            self.ensure_success_status(
+                response.status,
+                OAuthStatusMessages::new(
+                    OAUTH_UNAUTHORIZED_RELOGIN_MESSAGE,
+                    "Basecamp denied access while warming the cache (403 Forbidden).",
+                ),
+                None,
+                "Basecamp cache warm-up request failed with status",
+            )?;
+            urls.push(response.url);
+            if urls.len() < MAX_LINKED_PAGES as usize {
+                next_url = response.next_url;
+            }
+        }
+        Ok(urls)
+    }
+
+    // Writes can change anything the daemon has marked fresh.
+    async fn invalidate_daemon(&self) {
+        if let Some(cache) = self.cache.as_ref() {
+            daemon::invalidate(&cache.daemon_socket_path()).await;
+        }
+    }
+
+    async fn send_post_empty(
+        &self,
+        path: &str,
+        request_context: &str,
+    ) -> AppResult<Option<Response>> {
+        if dry_run::enabled() {
+            dry_run::print_request("POST", &self.account_url(path), None::<&()>);
+            return Ok(None);
+        }
+        self.invalidate_daemon().await;
+        let url = self.account_url(path);
+        self.send_authorized(
+            |token| self.http.post(&url).bearer_auth(token),
+            request_context,
+        )
+        .await
+        .map(Some)
     }
 
     async fn send_post_json<P>(
@@ -324,17 +1834,22 @@ impl BasecampClient {
         path: &str,
         payload: &P,
         request_context: &str,
-    ) -> AppResult<Response>
+    ) -> AppResult<Option<Response>>
     where
         P: Serialize,
     {
-        self.http
-            .post(self.account_url(path))
-            .bearer_auth(&self.access_token)
-            .json(payload)
-            .send()
-            .await
-            .map_err(|err| AppError::generic(format!("Failed to request {request_context}: {err}")))
+        if dry_run::enabled() {
+            dry_run::print_request("POST", &self.account_url(path), Some(payload));
+            return Ok(None);
+        }
+        self.invalidate_daemon().await;
+        let url = self.account_url(path);
+        self.send_authorized(
+            |token| self.http.post(&url).bearer_auth(token).json(payload),
+            request_context,
+        )
+        .await
+        .map(Some)
     }
 
     async fn send_put_json<P>(
@@ -342,26 +1857,85 @@ impl BasecampClient {
         path: &str,
         payload: &P,
         request_context: &str,
-    ) -> AppResult<Response>
+    ) -> AppResult<Option<Response>>
     where
         P: Serialize,
     {
-        self.http
-            .put(self.account_url(path))
-            .bearer_auth(&self.access_token)
-            .json(payload)
-            .send()
-            .await
-            .map_err(|err| AppError::generic(format!("Failed to request {request_context}: {err}")))
+        if dry_run::enabled() {
+            dry_run::print_request("PUT", &self.account_url(path), Some(payload));
+            return Ok(None);
+        }
+        self.invalidate_daemon().await;
+        let url = self.account_url(path);
+        self.send_authorized(
+            |token| self.http.put(&url).bearer_auth(token).json(payload),
+            request_context,
+        )
+        .await
+        .map(Some)
     }
 
-    async fn send_delete(&self, path: &str, request_context: &str) -> AppResult<Response> {
-        self.http
-            .delete(self.account_url(path))
-            .bearer_auth(&self.access_token)
-            .send()
-            .await
-            .map_err(|err| AppError::generic(format!("Failed to request {request_context}: {err}")))
+    async fn send_put_empty(
+        &self,
+        path: &str,
+        request_context: &str,
+    ) -> AppResult<Option<Response>> {
+        if dry_run::enabled() {
+            dry_run::print_request("PUT", &self.account_url(path), None::<&()>);
+            return Ok(None);
+        }
+        self.invalidate_daemon().await;
+        let url = self.account_url(path);
+        self.send_authorized(
+            |token| self.http.put(&url).bearer_auth(token),
+            request_context,
+        )
+        .await
+        .map(Some)
+    }
+
+    async fn send_delete(&self, path: &str, request_context: &str) -> AppResult<Option<Response>> {
+        if dry_run::enabled() {
+            dry_run::print_request("DELETE", &self.account_url(path), None::<&()>);
+            return Ok(None);
+        }
+        self.invalidate_daemon().await;
+        let url = self.account_url(path);
+        self.send_authorized(
+            |token| self.http.delete(&url).bearer_auth(token),
+            request_context,
+        )
+        .await
+        .map(Some)
+    }
+
+    // Sends with the session's current token. A 401 restores the session
+    // once and retries this request alone, so earlier writes in the same
+    // command are never repeated.
+    async fn send_authorized(
+        &self,
+        request: impl Fn(&str) -> RequestBuilder,
+        request_context: &str,
+    ) -> AppResult<Response> {
+        let send = |token: String| {
+            telemetry::record_api_call();
+            let pending = request(&token).send();
+            async move {
+                pending.await.map_err(|err| {
+                    AppError::generic(format!("Failed to request {request_context}: {err}"))
+                })
+            }
+        };
+
+        let token = reauth::current_token(&self.access_token);
+        let response = send(token.clone()).await?;
+        if response.status() != StatusCode::UNAUTHORIZED || !self.session_recovery {
+            return Ok(response);
+        }
+        match reauth::recover_after_rejection(&token).await? {
+            Some(replacement) => send(replacement).await,
+            None => Ok(response),
+        }
     }
 
     fn ensure_success_status(
@@ -390,8 +1964,25 @@ impl BasecampClient {
         Ok(())
     }
 
-    fn account_url(&self, path: &str) -> String {
+    pub fn account_url(&self, path: &str) -> String {
         let trimmed = path.trim_start_matches('/');
         format!("https://3.basecampapi.com/{}/{}", self.account_id, trimmed)
     }
 }
+
+// Basecamp paginates with RFC 5988 headers: `Link: <https://...?page=2>; rel="next"`.
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (target, params) = part.split_once(';')?;
+        let is_next = params
+            .split(';')
+            .any(|param| param.trim().eq_ignore_ascii_case("rel=\"next\""));
+        if !is_next {
+            return None;
+        }
+
+        let url = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+        Some(url.to_string())
+    })
+}
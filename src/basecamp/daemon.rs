@@ -0,0 +1,108 @@
+//! Newline-delimited JSON protocol between CLI runs and `basecamp-cli daemon`
+//! over a Unix socket in the response cache directory.
+//!
+//! Every client call is best effort: a missing, slow, or confused daemon
+//! reads as "not fresh" and the caller falls back to a normal request.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::Duration;
+
+pub const SOCKET_FILE: &str = "daemon.sock";
+// Local round trips take well under a millisecond; anything slower is a
+// stuck daemon and must not delay the command.
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(150);
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum DaemonRequest {
+    /// Whether the cached response for `url` was refreshed recently enough,
+    /// by the same `session` (see [`session_key`]), to be served without
+    /// revalidating.
+    Fresh {
+        session: String,
+        url: String,
+    },
+    /// Forget freshness after a mutation so the next reads revalidate.
+    Invalidate,
+    Status,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FreshResponse {
+    pub fresh: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    pub pid: u32,
+    pub started_at: u64,
+    pub interval_seconds: u64,
+    pub account_id: Option<u64>,
+    pub last_warm_at: Option<u64>,
+    pub warm_responses: usize,
+    pub last_token_refresh_at: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+/// The account plus a fingerprint of the token. Cached bodies skip
+/// revalidation only for the session that warmed them, so another profile
+/// or token sharing the cache directory still has Basecamp check its access.
+pub fn session_key(account_id: u64, access_token: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(access_token.as_bytes()));
+    let short = digest.get(..16).unwrap_or(digest.as_str());
+    format!("{account_id}:{short}")
+}
+
+pub async fn is_fresh(socket: &Path, session: &str, url: &str) -> bool {
+    request::<FreshResponse>(
+        socket,
+        &DaemonRequest::Fresh {
+            session: session.to_string(),
+            url: url.to_string(),
+        },
+    )
+    .await
+    .is_some_and(|response| response.fresh)
+}
+
+pub async fn invalidate(socket: &Path) {
+    let _ = request::<FreshResponse>(socket, &DaemonRequest::Invalidate).await;
+}
+
+pub async fn status(socket: &Path) -> Option<DaemonStatus> {
+    request(socket, &DaemonRequest::Status).await
+}
+
+#[cfg(unix)]
+async fn request<T: DeserializeOwned>(socket: &Path, request: &DaemonRequest) -> Option<T> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixStream;
+
+    if !socket.exists() {
+        return None;
+    }
+
+    let mut line = serde_json::to_string(request).ok()?;
+    line.push('\n');
+    let exchange = async {
+        let mut stream = UnixStream::connect(socket).await.ok()?;
+        stream.write_all(line.as_bytes()).await.ok()?;
+        let mut reader = BufReader::new(stream);
+        let mut response = String::new();
+        reader.read_line(&mut response).await.ok()?;
+        serde_json::from_str(&response).ok()
+    };
+
+    tokio::time::timeout(CLIENT_TIMEOUT, exchange)
+        .await
+        .ok()
+        .flatten()
+}
+
+#[cfg(not(unix))]
+async fn request<T: DeserializeOwned>(_socket: &Path, _request: &DaemonRequest) -> Option<T> {
+    None
+}
@@ -0,0 +1,42 @@
+//! `--dry-run`: writes are printed instead of sent. Reads still reach
+//! Basecamp, so targets resolve exactly as they would for real.
+
+use crate::error::{AppError, AppResult};
+use colored::Colorize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Prints the request to stderr, so `--json` output on stdout stays parseable.
+pub(super) fn print_request<P: Serialize>(method: &str, url: &str, payload: Option<&P>) {
+    eprintln!("{}", format!("Dry run: {method} {url}").yellow());
+    let Some(rendered) = payload.and_then(|payload| serde_json::to_string_pretty(payload).ok())
+    else {
+        return;
+    };
+    for line in rendered.lines() {
+        eprintln!("  {line}");
+    }
+}
+
+/// Stands in for the response to a create or update: the payload itself,
+/// with id 0, decoded as the response type.
+pub(super) fn echo<P: Serialize, T: DeserializeOwned>(payload: &P) -> AppResult<T> {
+    let mut value = serde_json::to_value(payload)
+        .map_err(|err| AppError::generic(format!("Failed to build dry-run response: {err}")))?;
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("id".to_string(), 0.into());
+    }
+    serde_json::from_value(value)
+        .map_err(|err| AppError::generic(format!("Failed to build dry-run response: {err}")))
+}
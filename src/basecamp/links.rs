@@ -0,0 +1,86 @@
+//! Basecamp web URLs pasted from the browser, e.g.
+//! `https://3.basecamp.com/<account>/buckets/<project>/todos/<id>`.
+
+use crate::error::{AppError, AppResult};
+use url::Url;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasecampLink {
+    pub account_id: u64,
+    pub project_id: u64,
+    /// The recording the URL points at, e.g. (`todos`, id); `None` for the
+    /// project itself.
+    pub recording: Option<(String, u64)>,
+}
+
+impl BasecampLink {
+    /// Parses project and recording URLs; other pages and hosts give `None`.
+    /// Query strings and `#` fragments are ignored.
+    pub fn parse(value: &str) -> Option<Self> {
+        let url = Url::parse(value.trim()).ok()?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+        let segments: Vec<&str> = url
+            .path_segments()?
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let (account, project, rest) = match segments.as_slice() {
+            [account, "buckets" | "projects", project, rest @ ..] => (*account, *project, rest),
+            _ => return None,
+        };
+        let recording = match rest {
+            [] => None,
+            // Cards, columns, and steps live under `card_tables/<kind>/<id>`.
+            ["card_tables", kind, id, ..] if parse_id(kind).is_none() => {
+                Some((kind.to_string(), parse_id(id)?))
+            }
+            [kind, id, ..] => Some((kind.to_string(), parse_id(id)?)),
+            [_] => return None,
+        };
+
+        Some(Self {
+            account_id: parse_id(account)?,
+            project_id: parse_id(project)?,
+            recording,
+        })
+    }
+
+    /// Rejects URLs pasted from another account, which would otherwise hit
+    /// the same ids in the current one.
+    pub fn ensure_account(&self, account_id: u64) -> AppResult<&Self> {
+        if self.account_id != account_id {
+            return Err(AppError::invalid_input(format!(
+                "URL belongs to account {}, not the current account {account_id}.",
+                self.account_id
+            )));
+        }
+        Ok(self)
+    }
+
+    /// The to-do id when the URL points at a to-do.
+    pub fn todo_id(&self) -> Option<u64> {
+        match &self.recording {
+            Some((kind, id)) if kind == "todos" => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// The list id when the URL points at a to-do list.
+    pub fn todolist_id(&self) -> Option<u64> {
+        match &self.recording {
+            Some((kind, id)) if kind == "todolists" => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+/// The web URL of a to-do, for when Basecamp did not return `app_url`.
+pub fn todo_url(account_id: u64, project_id: u64, todo_id: u64) -> String {
+    format!("https://3.basecamp.com/{account_id}/buckets/{project_id}/todos/{todo_id}")
+}
+
+fn parse_id(segment: &str) -> Option<u64> {
+    segment.trim_end_matches(".json").parse().ok()
+}
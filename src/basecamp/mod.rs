@@ -1,2 +1,7 @@
+pub mod cache;
 pub mod client;
+pub mod daemon;
+pub mod dry_run;
+pub mod links;
 pub mod models;
+pub mod reauth;
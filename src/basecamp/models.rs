@@ -1,58 +1,173 @@
+use crate::rich_text;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Project {
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
     pub name: String,
     #[serde(default)]
+    pub description: Option<String>,
+    /// `active`, `archived`, or `trashed`.
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+    #[serde(default)]
     pub dock: Vec<ProjectDock>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ProjectDock {
+    /// Tool kind, e.g. `todoset` or `message_board`.
     pub name: String,
+    /// What the project calls the tool, e.g. "To-dos".
+    #[serde(default)]
+    pub title: String,
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
     #[serde(default = "default_true")]
     pub enabled: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Todolist {
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
     pub title: String,
     #[serde(default)]
     pub name: String,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Done/total to-dos, e.g. `"2/5"`.
+    #[serde(default)]
+    pub completed_ratio: Option<String>,
+    #[serde(default)]
+    pub app_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ProjectPerson {
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
     pub name: String,
     pub email_address: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub admin: bool,
+    #[serde(default)]
+    pub owner: bool,
+    /// Someone from a client company, who sees only what is shared with
+    /// clients.
+    #[serde(default)]
+    pub client: bool,
+}
+
+/// `PUT /projects/{id}/people/users.json`: grant or revoke access by person
+/// id, and invite new people by email.
+#[derive(Debug, Serialize)]
+pub struct ProjectAccessPayload {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub grant: Vec<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub revoke: Vec<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub create: Vec<NewPerson>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NewPerson {
+    pub name: String,
+    pub email_address: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ProjectAccessChange {
+    #[serde(default)]
+    pub granted: Vec<ProjectPerson>,
+    #[serde(default)]
+    pub revoked: Vec<ProjectPerson>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoAssignee {
+    #[serde(default, deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default)]
+    pub name: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreatedTodo {
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
+    #[serde(deserialize_with = "deserialize_plain_text")]
     pub content: String,
+    #[serde(default)]
+    pub starts_on: Option<String>,
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub assignees: Vec<TodoAssignee>,
+    #[serde(default)]
+    pub app_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Todo {
     #[serde(deserialize_with = "deserialize_id")]
     pub id: u64,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
     pub content: String,
     #[serde(default)]
     pub description: Option<String>,
     #[serde(default)]
+    pub starts_on: Option<String>,
+    #[serde(default)]
     pub due_on: Option<String>,
+    #[serde(default)]
+    pub completed: Option<bool>,
+    #[serde(default)]
+    pub position: Option<u32>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    /// The list or group holding the to-do.
+    #[serde(default)]
+    pub parent: Option<TodoParent>,
+    /// Present on completed to-dos.
+    #[serde(default)]
+    pub completion: Option<TodoCompletion>,
+    #[serde(default)]
+    pub assignees: Vec<TodoAssignee>,
+    /// People notified when the to-do is completed.
+    #[serde(default)]
+    pub completion_subscribers: Vec<TodoAssignee>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoParent {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoCompletion {
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Who completed the to-do.
+    #[serde(default)]
+    pub creator: Option<Creator>,
 }
 
 #[derive(Debug, Serialize)]
@@ -66,9 +181,58 @@ pub struct CreateTodoPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_subscriber_ids: Option<Vec<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due_on: Option<String>,
 }
 
+/// `POST /projects.json` and `PUT /projects/{id}.json`. Descriptions are
+/// plain text.
+#[derive(Debug, Serialize)]
+pub struct ProjectPayload {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `POST /templates/{id}/project_constructions.json`.
+#[derive(Debug, Serialize)]
+pub struct ProjectConstructionPayload {
+    pub project: ProjectPayload,
+}
+
+/// A project being built from a template; `project` is set once `status`
+/// is `completed`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectConstruction {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub project: Option<Project>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateTodolistPayload {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `PUT .../recordings/{id}/position.json`; 1 is the top of the list.
+#[derive(Debug, Serialize)]
+pub struct PositionPayload {
+    pub position: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateTodolistPayload {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UpdateTodoPayload {
     pub content: String,
@@ -76,7 +240,430 @@ pub struct UpdateTodoPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_subscriber_ids: Option<Vec<u64>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Creator {
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Comment {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+/// One change to a recording, from its events feed.
+#[derive(Debug, Deserialize)]
+pub struct RecordingEvent {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    /// e.g. `created`, `completed`, `assignment_changed`.
+    #[serde(default)]
+    pub action: String,
+    /// Action-specific fields, such as `added_person_ids`.
+    #[serde(default)]
+    pub details: serde_json::Value,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+}
+
+/// A message board post.
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub subject: String,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+/// A "Docs & Files" folder; the project's top folder is its `vault` dock
+/// tool.
+#[derive(Debug, Deserialize)]
+pub struct Vault {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub documents_count: u32,
+    #[serde(default)]
+    pub vaults_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Document {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub comments_count: u32,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+/// `POST .../vaults/{id}/documents.json` and `PUT .../documents/{id}.json`.
+/// Without `status: active` a new document is saved as a draft.
+#[derive(Debug, Serialize)]
+pub struct DocumentPayload {
+    pub title: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// `POST /attachments.json`: an uploaded blob, not yet part of any
+/// recording until its sgid is used.
+#[derive(Debug, Deserialize)]
+pub struct Attachment {
+    pub attachable_sgid: String,
+}
+
+/// A file in Docs & Files.
+#[derive(Debug, Deserialize)]
+pub struct Upload {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default)]
+    pub filename: String,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateUploadPayload {
+    pub attachable_sgid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// File name without its extension; Basecamp keeps the extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleEntry {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub summary: String,
+    /// ISO 8601 with offset.
+    #[serde(default)]
+    pub starts_at: String,
+    #[serde(default)]
+    pub ends_at: String,
+    #[serde(default)]
+    pub all_day: bool,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub participants: Vec<TodoAssignee>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateScheduleEntryPayload {
+    pub summary: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub all_day: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub participant_ids: Vec<u64>,
+    /// Tell the participants about the new entry.
+    pub notify: bool,
+}
+
+/// A project's chat room.
+#[derive(Debug, Deserialize)]
+pub struct Campfire {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub bucket: Option<SearchBucket>,
+}
+
+/// One chat line.
+#[derive(Debug, Deserialize)]
+pub struct CampfireLine {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    /// Plain text.
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateCampfireLinePayload {
+    pub content: String,
+}
+
+/// `POST .../message_boards/{id}/messages.json`; `status: active` publishes
+/// right away instead of saving a draft.
+#[derive(Debug, Serialize)]
+pub struct CreateMessagePayload {
+    pub subject: String,
+    /// Rich text (HTML).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateCommentPayload {
+    /// Rich text (HTML).
+    pub content: String,
+}
+
+/// A project webhook: Basecamp POSTs recording changes of `types` to
+/// `payload_url`.
+#[derive(Debug, Deserialize)]
+pub struct Webhook {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default)]
+    pub payload_url: String,
+    #[serde(default)]
+    pub types: Vec<String>,
+    #[serde(default = "default_true")]
+    pub active: bool,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateWebhookPayload {
+    pub payload_url: String,
+    /// Empty means every type.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<String>,
+}
+
+/// A card in a card table, with the checklist steps inside it.
+#[derive(Debug, Deserialize)]
+pub struct Card {
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub steps: Vec<CardStep>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CardStep {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub assignees: Vec<TodoAssignee>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateCardStepPayload {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    /// Person ids, comma-separated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<String>,
+}
+
+/// `on` completes a step, `off` re-opens it.
+#[derive(Debug, Serialize)]
+pub struct CardStepCompletionPayload {
+    pub completion: String,
+}
+
+/// An automatic check-in question, asked on its schedule.
+#[derive(Debug, Deserialize)]
+pub struct Question {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub schedule: Option<QuestionSchedule>,
+    #[serde(default)]
+    pub answers_count: u32,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionSchedule {
+    /// e.g. `every_day`, `every_week`, `every_other_week`, `every_month`.
+    #[serde(default)]
+    pub frequency: String,
+    /// 0 = Sunday through 6 = Saturday.
+    #[serde(default)]
+    pub days: Vec<u32>,
+    #[serde(default)]
+    pub hour: u32,
+    #[serde(default)]
+    pub minute: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionAnswer {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    /// Rich text (HTML).
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub creator: Option<Creator>,
+    /// The day the answer is filed under (`YYYY-MM-DD`).
+    #[serde(default)]
+    pub group_on: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateQuestionAnswerPayload {
+    /// Rich text (HTML).
+    pub content: String,
+    pub group_on: String,
+}
+
+/// `GET /my/assignments.json`: open assignments split the way the
+/// "My Assignments" page shows them.
+#[derive(Debug, Deserialize)]
+pub struct MyAssignments {
+    #[serde(default)]
+    pub priorities: Vec<Assignment>,
+    #[serde(default)]
+    pub non_priorities: Vec<Assignment>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Assignment {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(rename = "type", default)]
+    pub recording_type: String,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub content: String,
+    #[serde(default)]
+    pub starts_on: Option<String>,
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(default)]
+    pub bucket: Option<SearchBucket>,
+    #[serde(default)]
+    pub parent: Option<AssignmentParent>,
+    #[serde(default)]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignmentParent {
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+}
+
+/// `GET /reports/todos/overdue.json`: open to-dos past due, bucketed by
+/// how late they are.
+#[derive(Debug, Deserialize)]
+pub struct OverdueTodosReport {
+    #[serde(default)]
+    pub under_a_week_late: Vec<ReportTodo>,
+    #[serde(default)]
+    pub over_a_week_late: Vec<ReportTodo>,
+    #[serde(default)]
+    pub over_a_month_late: Vec<ReportTodo>,
+    #[serde(default)]
+    pub over_three_months_late: Vec<ReportTodo>,
+}
+
+/// `GET /reports/schedules/upcoming.json`; only the dated assignables
+/// (to-dos, card steps) are read.
+#[derive(Debug, Deserialize)]
+pub struct UpcomingScheduleReport {
+    #[serde(default)]
+    pub assignables: Vec<ReportTodo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportTodo {
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: u64,
+    #[serde(rename = "type", default)]
+    pub recording_type: String,
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub content: String,
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(default)]
+    pub bucket: Option<SearchBucket>,
+    #[serde(default)]
+    pub parent: Option<AssignmentParent>,
+    #[serde(default)]
+    pub assignees: Vec<TodoAssignee>,
+    #[serde(default)]
+    pub app_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -92,7 +679,16 @@ pub struct TodoSearchResult {
     #[serde(default)]
     pub completed: Option<bool>,
     #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
     pub bucket: Option<SearchBucket>,
+    /// The fields below come with results that carry the full to-do.
+    #[serde(default)]
+    pub due_on: Option<String>,
+    #[serde(default)]
+    pub assignees: Option<Vec<TodoAssignee>>,
+    #[serde(default)]
+    pub parent: Option<TodoParent>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -103,6 +699,21 @@ pub struct SearchBucket {
     pub name: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TimelineEvent {
+    #[serde(default)]
+    pub kind: String,
+    /// The recording's title, e.g. the to-do for `todo_completed`.
+    #[serde(default, deserialize_with = "deserialize_plain_text")]
+    pub title: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub creator: Option<TodoAssignee>,
+    #[serde(default)]
+    pub bucket: Option<SearchBucket>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PersonProfile {
     pub id: u64,
@@ -120,6 +731,15 @@ fn default_true() -> bool {
     true
 }
 
+/// Titles as plain text: some responses carry HTML entities or tags in
+/// them (`&amp;`, `<strong>`).
+fn deserialize_plain_text<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(rich_text::plain_title(&String::deserialize(deserializer)?))
+}
+
 fn deserialize_id<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -0,0 +1,88 @@
+//! Recovering from a rejected access token in the middle of a command. The
+//! client retries only the request that got the 401, so work already done
+//! (created to-dos, answered prompts) is never repeated.
+//!
+//! How a new token is obtained (refresh, or an inline `login`) lives with
+//! the auth feature; `main` registers it with [`set_recovery`].
+
+use crate::error::AppResult;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+/// Restores the session and returns its new access token, or `None` when
+/// it could not be restored.
+pub type Recover = fn() -> Pin<Box<dyn Future<Output = AppResult<Option<String>>> + Send>>;
+
+const WAIT_INTERVAL: Duration = Duration::from_millis(50);
+
+static RECOVER: OnceLock<Recover> = OnceLock::new();
+static STATE: Mutex<Recovery> = Mutex::new(Recovery::Idle);
+
+enum Recovery {
+    Idle,
+    Running,
+    Finished {
+        rejected: String,
+        replacement: Option<String>,
+    },
+}
+
+pub fn set_recovery(recover: Recover) {
+    let _ = RECOVER.set(recover);
+}
+
+/// The token to send for a client built with `initial`: its replacement
+/// once `initial` was rejected and recovered from.
+pub(super) fn current_token(initial: &str) -> String {
+    match &*STATE.lock().unwrap_or_else(PoisonError::into_inner) {
+        Recovery::Finished {
+            rejected,
+            replacement: Some(replacement),
+        } if rejected == initial => replacement.clone(),
+        _ => initial.to_string(),
+    }
+}
+
+/// Called after Basecamp answered 401 to a request sent with `sent`.
+/// Returns the token to retry that request with, or `None` to give up.
+///
+/// Concurrent requests rejected together share one recovery, so the user
+/// is asked to log in at most once; a replacement that is itself rejected
+/// is not recovered again.
+pub(super) async fn recover_after_rejection(sent: &str) -> AppResult<Option<String>> {
+    let Some(recover) = RECOVER.get() else {
+        return Ok(None);
+    };
+
+    loop {
+        {
+            let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+            match &*state {
+                Recovery::Running => {}
+                Recovery::Finished {
+                    rejected,
+                    replacement,
+                } if rejected == sent => return Ok(replacement.clone()),
+                Recovery::Finished {
+                    replacement: Some(replacement),
+                    ..
+                } if replacement == sent => return Ok(None),
+                Recovery::Idle | Recovery::Finished { .. } => {
+                    *state = Recovery::Running;
+                    break;
+                }
+            }
+        }
+        tokio::time::sleep(WAIT_INTERVAL).await;
+    }
+
+    let recovered = recover().await;
+    let replacement = recovered.as_ref().ok().cloned().flatten();
+    *STATE.lock().unwrap_or_else(PoisonError::into_inner) = Recovery::Finished {
+        rejected: sent.to_string(),
+        replacement,
+    };
+    recovered
+}
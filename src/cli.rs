@@ -1,4 +1,8 @@
-use clap::{Args, Parser, Subcommand};
+use crate::basecamp::links::BasecampLink;
+use crate::features::projects::lookup;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use std::str::FromStr;
 
 #[derive(Debug, Parser)]
 #[command(
@@ -11,6 +15,18 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub verbose: bool,
 
+    /// Print a boxed receipt after to-do changes instead of a single line.
+    #[arg(long, global = true)]
+    pub verbose_result: bool,
+
+    /// Never truncate titles and names in listings.
+    #[arg(long, global = true)]
+    pub full_width: bool,
+
+    /// Print the Basecamp writes a command would make instead of sending them.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
     #[command(subcommand)]
     pub command: Command,
 }
@@ -23,10 +39,71 @@ pub enum Command {
     Login(LoginArgs),
     /// Logout from current Basecamp session.
     Logout(LogoutArgs),
+    /// Inspect the current OAuth token and session.
+    Auth(AuthArgs),
+    /// Inspect the stored login session.
+    Session(SessionArgs),
+    /// Inspect where secrets are stored.
+    Secrets(SecretsArgs),
     /// Show the current authenticated Basecamp user.
     Whoami(WhoamiArgs),
     /// Manage Basecamp to-dos.
     Todo(TodoArgs),
+    /// Manage pinned Basecamp projects.
+    Project(ProjectArgs),
+    /// Name projects and to-do lists for use as `@alias`.
+    Alias(AliasArgs),
+    /// Summarize recent account activity.
+    Stats(StatsArgs),
+    /// Manage reusable to-do templates.
+    Template(TemplateArgs),
+    /// Work with to-do lists.
+    #[command(visible_alias = "todolist")]
+    List(ListArgs),
+    /// Keep caches warm and tokens fresh in the background.
+    Daemon(DaemonArgs),
+    /// Read and post message board messages.
+    Message(MessageArgs),
+    /// Read and add comments on any to-do, message, or document.
+    Comment(CommentArgs),
+    /// List campfires and post chat lines.
+    Campfire(CampfireArgs),
+    /// Read and write documents in a project's Docs & Files.
+    Doc(DocArgs),
+    /// Upload a file to a project's Docs & Files.
+    Upload(UploadArgs),
+    /// List, add, and export project schedule entries.
+    Schedule(ScheduleArgs),
+    /// Read and answer automatic check-in questions.
+    Checkin(CheckinArgs),
+    /// Work with card table cards.
+    Card(CardArgs),
+    /// Look up people in the account or on a project.
+    People(PeopleArgs),
+    /// Manage a project's webhooks.
+    Webhook(WebhookArgs),
+}
+
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct DaemonArgs {
+    #[command(subcommand)]
+    pub command: Option<DaemonCommand>,
+    /// Seconds between cache refreshes.
+    #[arg(long, default_value_t = 300, value_parser = clap::value_parser!(u64).range(30..))]
+    pub interval: u64,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DaemonCommand {
+    /// Show whether a daemon is running and what it last did.
+    Status(DaemonStatusArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct DaemonStatusArgs {
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(Debug, Args)]
@@ -43,10 +120,18 @@ pub enum IntegrationCommand {
     Show,
     /// Clear integration configuration.
     Clear(IntegrationClearArgs),
+    /// Export integration credentials and session to a file.
+    Export(IntegrationExportArgs),
+    /// Import integration credentials and session from an export file.
+    Import(IntegrationImportArgs),
 }
 
 #[derive(Debug, Args)]
 pub struct IntegrationSetArgs {
+    /// Read `client_id`/`client_secret`/`redirect_uri` from a JSON file, or `-` for stdin.
+    /// Flags given alongside override its fields.
+    #[arg(long, value_name = "PATH")]
+    pub from_json: Option<PathBuf>,
     #[arg(long)]
     pub client_id: Option<String>,
     #[arg(long)]
@@ -61,12 +146,53 @@ pub struct IntegrationClearArgs {
     pub force: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct IntegrationExportArgs {
+    /// Destination file path.
+    pub path: PathBuf,
+    /// Encrypt the export with a passphrase.
+    #[arg(long)]
+    pub encrypt: bool,
+    /// Export only integration credentials, without the login session.
+    #[arg(long)]
+    pub no_session: bool,
+    /// Overwrite the destination file if it already exists.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct IntegrationImportArgs {
+    /// Export file produced by `integration export`.
+    pub path: PathBuf,
+    /// Replace existing integration credentials without confirmation.
+    #[arg(long)]
+    pub force: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct LoginArgs {
     #[arg(long)]
     pub account_id: Option<u64>,
+    /// Select the account by name (case-insensitive, fuzzy).
+    #[arg(long, conflicts_with = "account_id")]
+    pub account_name: Option<String>,
     #[arg(long)]
     pub no_browser: bool,
+    /// Paste the redirect URL or code instead of running a local callback server.
+    #[arg(long, conflicts_with = "no_browser")]
+    pub manual: bool,
+    /// Seconds to wait for the OAuth callback.
+    #[arg(
+        long,
+        default_value_t = 180,
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with = "manual"
+    )]
+    pub timeout: u64,
+    /// Callback port, overriding the one in redirect_uri (`0` picks a free port).
+    #[arg(long)]
+    pub callback_port: Option<u16>,
     #[arg(long)]
     pub json: bool,
     #[arg(long)]
@@ -77,6 +203,60 @@ pub struct LoginArgs {
     pub redirect_uri: Option<String>,
 }
 
+#[derive(Debug, Args)]
+pub struct AuthArgs {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AuthCommand {
+    /// Show token expiry, identity, and reachable accounts.
+    Inspect(AuthInspectArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct AuthInspectArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct SessionArgs {
+    #[command(subcommand)]
+    pub command: SessionCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SessionCommand {
+    /// Show the active account and token fingerprints.
+    Show(SessionShowArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SessionShowArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct SecretsArgs {
+    #[command(subcommand)]
+    pub command: SecretsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SecretsCommand {
+    /// Show the profile, keychain entry, and secrets file in use.
+    Where(SecretsWhereArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SecretsWhereArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct LogoutArgs {
     #[arg(long)]
@@ -87,83 +267,1703 @@ pub struct LogoutArgs {
 
 #[derive(Debug, Args)]
 pub struct WhoamiArgs {
+    /// Also list every account the current token can access.
+    #[arg(long)]
+    pub all_accounts: bool,
     #[arg(long)]
     pub json: bool,
 }
 
 #[derive(Debug, Args)]
-pub struct TodoArgs {
+pub struct ProjectArgs {
+    /// Run against another account the stored login can reach.
+    #[arg(long, global = true)]
+    pub account_id: Option<u64>,
+
     #[command(subcommand)]
-    pub command: TodoCommand,
+    pub command: ProjectCommand,
 }
 
 #[derive(Debug, Subcommand)]
-pub enum TodoCommand {
-    /// Add a new to-do interactively.
-    Add(TodoAddArgs),
-    /// Edit a to-do by search or direct id.
-    Edit(TodoEditArgs),
-    /// Complete to-dos by search or direct id.
-    Complete(TodoCompleteArgs),
-    /// Re-open completed to-dos by search or direct id.
-    ReOpen(TodoReOpenArgs),
+pub enum ProjectCommand {
+    /// Pin a project so it is listed first and can be referenced by alias.
+    Pin(ProjectPinArgs),
+    /// Remove a pinned project.
+    Unpin(ProjectUnpinArgs),
+    /// List pinned projects and aliases.
+    Pins(ProjectPinsArgs),
+    /// Show a project's description, people, and tool ids.
+    Show(ProjectShowArgs),
+    /// Create a project, optionally from a Basecamp template.
+    Create(ProjectCreateArgs),
+    /// Rename a project or change its description.
+    Update(ProjectUpdateArgs),
+    /// Archive projects.
+    Archive(ProjectLifecycleArgs),
+    /// Move projects to trash.
+    Trash(ProjectLifecycleArgs),
+    /// Bring archived or trashed projects back.
+    Restore(ProjectLifecycleArgs),
+    /// List, add, or remove the people on a project.
+    People(ProjectPeopleArgs),
 }
 
 #[derive(Debug, Args)]
-pub struct TodoAddArgs {
-    /// To-do title/content. If omitted, prompt interactively.
-    pub content: Option<String>,
-    /// Optional notes/description for the to-do.
+pub struct ProjectPeopleArgs {
+    #[command(subcommand)]
+    pub command: ProjectPeopleCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProjectPeopleCommand {
+    /// Show who is on a project.
+    List(ProjectPeopleListArgs),
+    /// Give people access to a project, inviting new email addresses.
+    Add(ProjectPeopleChangeArgs),
+    /// Take people off a project.
+    Remove(ProjectPeopleChangeArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectPeopleListArgs {
+    /// Project id, name, or `@alias`.
     #[arg(long)]
-    pub notes: Option<String>,
-    /// Optional due date in YYYY-MM-DD format.
+    pub project: String,
     #[arg(long)]
-    pub due_on: Option<String>,
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectPeopleChangeArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Person id, email, name, or `@team` (comma-separated). `add` invites
+    /// unknown emails; write `"Name <email>"` to set the invitee's name.
+    #[arg(required = true, value_delimiter = ',')]
+    pub people: Vec<String>,
     #[arg(long)]
     pub json: bool,
 }
 
 #[derive(Debug, Args)]
-pub struct TodoCompleteArgs {
-    /// To-do search text. If omitted in search mode, prompt interactively.
-    pub query: Option<String>,
-    #[arg(long, conflicts_with = "query", requires = "project_id")]
-    pub id: Option<u64>,
+pub struct AliasArgs {
+    #[command(subcommand)]
+    pub command: AliasCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AliasCommand {
+    /// Create or replace an alias for a project or to-do list.
+    Set(AliasSetArgs),
+    /// Show stored aliases.
+    List(AliasListArgs),
+    /// Remove an alias.
+    Remove(AliasRemoveArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct AliasSetArgs {
+    /// Alias name, used as `@name`.
+    pub name: String,
+    /// Project id, name, or `@alias`, or a Basecamp project or to-do list URL.
+    pub target: String,
+    /// To-do list name or id inside the project; makes this a list alias.
     #[arg(long)]
-    pub project_id: Option<u64>,
+    pub list: Option<String>,
     #[arg(long)]
     pub json: bool,
 }
 
 #[derive(Debug, Args)]
-pub struct TodoEditArgs {
-    /// To-do search text. If omitted in search mode, prompt interactively.
-    pub query: Option<String>,
-    #[arg(long, conflicts_with = "query", requires = "project_id")]
-    pub id: Option<u64>,
+pub struct AliasListArgs {
     #[arg(long)]
-    pub project_id: Option<u64>,
-    /// Updated to-do title/content.
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct AliasRemoveArgs {
+    /// Alias name, with or without `@`.
+    pub name: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectPinArgs {
+    /// Project id, name, or `@alias`.
+    pub project: String,
+    /// Short alias to reference the project as `@alias`.
+    #[arg(long)]
+    pub alias: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectUnpinArgs {
+    /// Pinned project id, name, or `@alias`.
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectPinsArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectCreateArgs {
+    /// Name of the new project.
+    #[arg(long)]
+    pub name: String,
+    /// Optional description (plain text).
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Build the project from this Basecamp template id.
+    #[arg(long)]
+    pub from_template: Option<u64>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectUpdateArgs {
+    /// Project id, name, or `@alias`.
+    pub project: String,
+    /// New name.
+    #[arg(long)]
+    pub name: Option<String>,
+    /// New description (plain text); an empty value clears it.
+    #[arg(long)]
+    pub description: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectLifecycleArgs {
+    /// Project ids, names, or `@alias`es.
+    #[arg(required = true)]
+    pub projects: Vec<String>,
+    /// Skip the confirmation prompt.
+    #[arg(long)]
+    pub force: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectShowArgs {
+    /// Project id, name, or `@alias`.
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MessageArgs {
+    #[command(subcommand)]
+    pub command: MessageCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MessageCommand {
+    /// Show the latest messages on a project's message board.
+    List(MessageListArgs),
+    /// Show one message.
+    Show(MessageShowArgs),
+    /// Post a message to a project's message board.
+    Post(MessagePostArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct MessageListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Show at most this many messages.
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MessageShowArgs {
+    /// Message id, or a message URL (which also sets the project).
+    pub message: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MessagePostArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Message title.
+    #[arg(long)]
+    pub subject: String,
+    /// Message body (markdown).
     #[arg(long)]
     pub content: Option<String>,
-    /// Updated to-do notes/description.
+    /// Read the body (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+    /// Open the posted message in the browser.
     #[arg(long)]
-    pub notes: Option<String>,
-    /// Updated due date in YYYY-MM-DD format.
+    pub open: bool,
     #[arg(long)]
-    pub due_on: Option<String>,
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CampfireArgs {
+    #[command(subcommand)]
+    pub command: CampfireCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CampfireCommand {
+    /// List campfires, across projects or in one.
+    List(CampfireListArgs),
+    /// Post a line to a project's campfire.
+    Send(CampfireSendArgs),
+    /// Print a campfire's recent lines, and with `--follow` new ones as they arrive.
+    Tail(CampfireTailArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CampfireListArgs {
+    /// Only this project's campfires: id, name, or `@alias`.
+    #[arg(long)]
+    pub project: Option<String>,
     #[arg(long)]
     pub json: bool,
 }
 
 #[derive(Debug, Args)]
-pub struct TodoReOpenArgs {
-    /// To-do search text. If omitted in search mode, prompt interactively.
-    pub query: Option<String>,
-    #[arg(long, conflicts_with = "query", requires = "project_id")]
-    pub id: Option<u64>,
+pub struct CampfireSendArgs {
+    /// Project id, name, or `@alias`.
     #[arg(long)]
-    pub project_id: Option<u64>,
+    pub project: String,
+    /// Campfire id, when the project has more than one.
+    #[arg(long)]
+    pub campfire: Option<u64>,
+    /// The line to post; `-` reads it from stdin.
+    #[arg(required = true, num_args = 1..)]
+    pub text: Vec<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CampfireTailArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Campfire id, when the project has more than one.
+    #[arg(long)]
+    pub campfire: Option<u64>,
+    /// Recent lines to print first.
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub lines: usize,
+    /// Keep polling and print new lines until Ctrl-C.
+    #[arg(short = 'f', long)]
+    pub follow: bool,
+    /// Seconds between polls with `--follow`.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(2..))]
+    pub interval: u64,
+    /// One JSON object per line, for piping.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct DocArgs {
+    #[command(subcommand)]
+    pub command: DocCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DocCommand {
+    /// List the folders and documents in a Docs & Files folder.
+    List(DocListArgs),
+    /// Print a document as markdown.
+    Show(DocShowArgs),
+    /// Create a document from markdown.
+    Create(DocCreateArgs),
+    /// Change a document's title or body.
+    Edit(DocEditArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct DocListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Folder path from the top (`Meetings/2026`) or folder id; the top folder by default.
+    #[arg(long)]
+    pub folder: Option<String>,
     #[arg(long)]
     pub json: bool,
 }
+
+#[derive(Debug, Args)]
+pub struct DocShowArgs {
+    /// Document id, or a document URL (which also sets the project).
+    pub document: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct DocCreateArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Folder path from the top (`Meetings/2026`) or folder id; the top folder by default.
+    #[arg(long)]
+    pub folder: Option<String>,
+    #[arg(long)]
+    pub title: String,
+    /// Document body (markdown). Without it or `--content-file`, opens `$EDITOR`.
+    #[arg(long)]
+    pub content: Option<String>,
+    /// Read the body (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+    /// Open the new document in the browser.
+    #[arg(long)]
+    pub open: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct DocEditArgs {
+    /// Document id, or a document URL (which also sets the project).
+    pub document: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// New title.
+    #[arg(long)]
+    pub title: Option<String>,
+    /// New body (markdown). Without it, `--content-file`, or `--title`, opens the body in `$EDITOR`.
+    #[arg(long)]
+    pub content: Option<String>,
+    /// Read the new body (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct UploadArgs {
+    /// File to upload.
+    pub file: PathBuf,
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Folder id or path from the top (`Specs/2026`); the top folder by default.
+    #[arg(long)]
+    pub vault: Option<String>,
+    /// Name to show instead of the file's own, without the extension.
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Notes shown with the file (markdown).
+    #[arg(long)]
+    pub description: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ScheduleArgs {
+    #[command(subcommand)]
+    pub command: ScheduleCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ScheduleCommand {
+    /// List a project's schedule entries, upcoming by default.
+    List(ScheduleListArgs),
+    /// Add an entry, such as a milestone, to a project's schedule.
+    Add(ScheduleAddArgs),
+    /// Export a project's schedule entries as an iCalendar (.ics) file.
+    Export(ScheduleExportArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ScheduleListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Entries ending on or after this date (YYYY-MM-DD, today, +3d, fri, ...).
+    #[arg(long, default_value = "today")]
+    pub from: String,
+    /// Entries starting on or before this date.
+    #[arg(long)]
+    pub to: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ScheduleAddArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// What the entry is called.
+    #[arg(long)]
+    pub summary: String,
+    /// Start: a date (YYYY-MM-DD, tomorrow, fri, ...), optionally followed by `HH:MM`.
+    #[arg(long)]
+    pub starts_at: String,
+    /// End, in the same forms. Dates alone on both ends make an all-day entry.
+    #[arg(long)]
+    pub ends_at: String,
+    /// Notes (markdown).
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Person taking part: id, email, or name (repeatable, comma-separated).
+    #[arg(long = "participant", value_delimiter = ',')]
+    pub participants: Vec<String>,
+    /// Notify the participants.
+    #[arg(long)]
+    pub notify: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ScheduleExportArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Only entries ending on or after this date (YYYY-MM-DD, today, +3d, fri, ...).
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Only entries starting on or before this date.
+    #[arg(long)]
+    pub to: Option<String>,
+    /// Write the calendar to this file instead of stdout.
+    #[arg(long)]
+    pub ical: Option<PathBuf>,
+    /// Overwrite the `--ical` file if it already exists.
+    #[arg(long, requires = "ical")]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WebhookArgs {
+    #[command(subcommand)]
+    pub command: WebhookCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WebhookCommand {
+    /// List a project's webhooks.
+    List(WebhookListArgs),
+    /// Add a webhook to a project; an identical one is left as is.
+    Create(WebhookCreateArgs),
+    /// Delete webhooks from a project by id or payload URL.
+    Delete(WebhookDeleteArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct WebhookListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WebhookCreateArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// HTTPS URL Basecamp will POST events to.
+    #[arg(long)]
+    pub payload_url: String,
+    /// Recording types to send, e.g. `Todo,Comment` (comma-separated); every type by default.
+    #[arg(long, value_delimiter = ',')]
+    pub types: Vec<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct WebhookDeleteArgs {
+    /// Webhook ids.
+    #[arg(required_unless_present = "payload_url")]
+    pub webhooks: Vec<u64>,
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Delete every webhook posting to this URL.
+    #[arg(long, conflicts_with = "webhooks")]
+    pub payload_url: Option<String>,
+    /// Skip the confirmation prompt.
+    #[arg(long)]
+    pub force: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct PeopleArgs {
+    #[command(subcommand)]
+    pub command: PeopleCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PeopleCommand {
+    /// List everyone in the account, or on one project.
+    List(PeopleListArgs),
+    /// Show one person.
+    Show(PeopleShowArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct PeopleListArgs {
+    /// Only people on this project: id, name, or `@alias`.
+    #[arg(long)]
+    pub project: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct PeopleShowArgs {
+    /// Person id, email address, or name.
+    pub person: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CardArgs {
+    #[command(subcommand)]
+    pub command: CardCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CardCommand {
+    /// Manage the checklist steps inside a card.
+    #[command(subcommand)]
+    Step(CardStepCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CardStepCommand {
+    /// Add a step to a card.
+    Add(CardStepAddArgs),
+    /// Complete steps on a card, chosen by reference or from a prompt.
+    Complete(CardStepCompleteArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CardStepAddArgs {
+    /// Card id, or a card URL (which also sets the project).
+    pub card: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// What the step is.
+    #[arg(long)]
+    pub title: String,
+    /// Due date (same forms as `todo add --due-on`).
+    #[arg(long)]
+    pub due_on: Option<String>,
+    /// Person to assign: id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CardStepCompleteArgs {
+    /// Card id, or a card URL (which also sets the project).
+    pub card: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Step id or part of its title (repeatable). Without it, prompt.
+    #[arg(long = "step")]
+    pub steps: Vec<String>,
+    /// Every open step on the card (completed, with `--reopen`), without prompting.
+    #[arg(long, conflicts_with = "steps")]
+    pub all: bool,
+    /// Re-open completed steps instead.
+    #[arg(long)]
+    pub reopen: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckinArgs {
+    #[command(subcommand)]
+    pub command: CheckinCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CheckinCommand {
+    /// List a project's check-in questions and when they are asked.
+    List(CheckinListArgs),
+    /// Show recent answers to a check-in question.
+    Answers(CheckinAnswersArgs),
+    /// Answer a check-in question.
+    Answer(CheckinAnswerArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CheckinListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckinAnswersArgs {
+    /// Question id, or a question URL (which also sets the project).
+    pub question: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Answers filed since this long ago (`7d`, `2w`) or this date (`YYYY-MM-DD`).
+    #[arg(long, default_value = "7d")]
+    pub since: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckinAnswerArgs {
+    /// Question id, or a question URL (which also sets the project).
+    pub question: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Answer text (markdown). Without it or `--content-file`, opens $EDITOR.
+    #[arg(long)]
+    pub content: Option<String>,
+    /// Read the answer (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+    /// Add a list of the to-dos I completed that day.
+    #[arg(long)]
+    pub from_completed: bool,
+    /// The day being answered: `YYYY-MM-DD` or days ago (`1d`); today by default.
+    #[arg(long)]
+    pub date: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CommentArgs {
+    #[command(subcommand)]
+    pub command: CommentCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CommentCommand {
+    /// Show the comments on a recording, oldest first.
+    List(CommentListArgs),
+    /// Comment on a recording.
+    Add(CommentAddArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CommentListArgs {
+    /// Recording id (to-do, message, document, ...), or its URL (which also sets the project).
+    #[arg(long)]
+    pub recording: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CommentAddArgs {
+    /// Recording id (to-do, message, document, ...), or its URL (which also sets the project).
+    #[arg(long)]
+    pub recording: IdOrUrl,
+    /// Project id, name, or `@alias`; needed with a bare id.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Comment text (markdown).
+    #[arg(long, required_unless_present_any = ["content_file", "attachments"])]
+    pub content: Option<String>,
+    /// Read the comment (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+    /// Upload a file and embed it in the comment (repeatable).
+    #[arg(long = "attach", value_name = "FILE")]
+    pub attachments: Vec<PathBuf>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// Time window as a duration (`30d`, `2w`) or a start date (`YYYY-MM-DD`).
+    #[arg(long, default_value = "30d")]
+    pub since: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoArgs {
+    /// Run against another account the stored login can reach.
+    #[arg(long, global = true)]
+    pub account_id: Option<u64>,
+
+    #[command(subcommand)]
+    pub command: TodoCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TodoCommand {
+    /// Add a new to-do interactively.
+    Add(Box<TodoAddArgs>),
+    /// Create a to-do from one line with inline #target, @person, and date tokens.
+    Quick(TodoQuickArgs),
+    /// Edit a to-do by search or direct id.
+    Edit(TodoEditArgs),
+    /// Add assignees to a to-do by search or direct id.
+    Assign(TodoAssignArgs),
+    /// Move an open to-do within its list by search or direct id.
+    Position(TodoPositionArgs),
+    /// Complete to-dos by search or direct id.
+    Complete(TodoCompleteArgs),
+    /// Re-open completed to-dos by search or direct id.
+    ReOpen(TodoReOpenArgs),
+    /// Reverse the last `complete` or `re-open` run.
+    Undo(TodoUndoArgs),
+    /// Manage recurring to-dos and create the ones that are due.
+    Recur(TodoRecurArgs),
+    /// Export every to-do in a project or list, open and completed, as CSV or JSON.
+    Export(TodoExportArgs),
+    /// Create lists and to-dos in a project from a Todoist or generic CSV export.
+    Import(TodoImportArgs),
+    /// Count open, completed, and overdue to-dos per list and assignee in a project.
+    Stats(TodoStatsArgs),
+    /// Move to-dos to the project trash by search or direct id.
+    Delete(TodoDeleteArgs),
+    /// Comment on a to-do by search or direct id.
+    Comment(TodoCommentArgs),
+    /// Show the comment thread on a to-do.
+    Comments(TodoCommentsArgs),
+    /// Show one to-do with who created it and who completed it.
+    Show(TodoShowArgs),
+    /// List the to-dos in one to-do list.
+    List(TodoListArgs),
+    /// Show a to-do's change log: assignments, due dates, completions.
+    History(TodoHistoryArgs),
+    /// List to-dos matching a search without prompting.
+    Search(TodoSearchArgs),
+    /// List my open assigned to-dos across projects, grouped by project.
+    Mine(TodoMineArgs),
+    /// List open to-dos past their due date across the account.
+    Overdue(TodoOverdueArgs),
+    /// List open to-dos due within the next N days across the account.
+    Due(TodoDueArgs),
+}
+
+#[derive(Debug, Default, Args)]
+pub struct TodoAddArgs {
+    /// To-do title/content. If omitted, prompt interactively.
+    pub content: Option<String>,
+    /// Optional notes/description for the to-do, in markdown; `-` reads stdin.
+    #[arg(long)]
+    pub notes: Option<String>,
+    /// Read the notes (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "notes")]
+    pub notes_file: Option<PathBuf>,
+    /// Optional due date: YYYY-MM-DD, today, tomorrow, +3d, 2w, fri, next monday, or eom.
+    #[arg(long)]
+    pub due_on: Option<String>,
+    /// Optional start date (same forms as `--due-on`); makes the to-do a date range.
+    #[arg(long)]
+    pub starts_on: Option<String>,
+    /// Project to create the to-do in (skips the project prompt).
+    #[arg(long, value_parser = project_id_arg)]
+    pub project_id: Option<u64>,
+    /// To-do list to create the to-do in (skips the list and group prompts):
+    /// an id with `--project-id`, or a list `@alias`.
+    #[arg(long, value_parser = todolist_id_arg)]
+    pub todolist_id: Option<TodolistId>,
+    /// Group inside `--todolist-id` to create the to-do in.
+    #[arg(long, requires = "todolist_id")]
+    pub group_id: Option<u64>,
+    /// Group name or id inside the chosen list; skips the group prompt.
+    #[arg(long, conflicts_with = "group_id")]
+    pub group: Option<String>,
+    /// Assignee: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    /// Assign the to-do to me (the signed-in person), with any `--assignee`.
+    #[arg(long)]
+    pub me: bool,
+    /// Ignore `defaults.project_id`/`defaults.todolist_id` and prompt for the target.
+    #[arg(long)]
+    pub no_defaults: bool,
+    /// Notify when done: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "notify", value_delimiter = ',')]
+    pub notify: Vec<String>,
+    /// Start from a saved template; flags and content override its fields.
+    #[arg(long)]
+    pub template: Option<String>,
+    /// Template placeholder value as `key=value` (repeatable).
+    #[arg(long = "var", value_name = "KEY=VALUE", requires = "template")]
+    pub vars: Vec<String>,
+    /// Create one to-do per stdin line (blank lines and `#` comments skipped).
+    #[arg(long, conflicts_with_all = ["content", "template", "from_file"])]
+    pub stdin: bool,
+    /// Create to-dos from a JSON array of `{content, notes, starts_on, due_on, assignees, notify}`.
+    #[arg(long, conflicts_with_all = ["content", "template"])]
+    pub from_file: Option<PathBuf>,
+    /// Place the new to-do: `top`, `bottom`, or a 1-based position in the list.
+    #[arg(long, conflicts_with_all = ["stdin", "from_file"])]
+    pub position: Option<TodoPosition>,
+    /// Open the new to-do in the browser.
+    #[arg(long, conflicts_with_all = ["stdin", "from_file"])]
+    pub open: bool,
+    /// Upload a file and embed it in the notes (repeatable).
+    #[arg(long = "attach", value_name = "FILE", conflicts_with_all = ["stdin", "from_file"])]
+    pub attachments: Vec<PathBuf>,
+    /// Retry the draft saved when an interactive add could not create its to-do.
+    #[arg(long, conflicts_with_all = [
+        "content", "notes", "notes_file", "due_on", "starts_on", "project_id", "todolist_id",
+        "group_id", "group", "assignees", "me", "no_defaults", "notify", "template", "stdin",
+        "from_file", "attachments",
+    ])]
+    pub resume: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoQuickArgs {
+    /// Title with inline tokens, e.g. "Ship changelog tomorrow #web @jane !notify:team".
+    #[arg(required = true, num_args = 1..)]
+    pub text: Vec<String>,
+    /// Open the new to-do in the browser.
+    #[arg(long)]
+    pub open: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoPositionArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Where to move it: `top`, `bottom`, or a 1-based position among open to-dos.
+    #[arg(long)]
+    pub to: TodoPosition,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoCompleteArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Project name, id, or `@alias` for `--list` targeting.
+    #[arg(long, conflicts_with = "project_id", requires = "position")]
+    pub project: Option<String>,
+    /// With `--position`, the to-do list (name or id) to complete from.
+    /// Otherwise only search matches in this list or group: id, list
+    /// `@alias`, or part of its name.
+    #[arg(long, conflicts_with = "id")]
+    pub list: Option<String>,
+    /// 1-based list positions to complete, e.g. `1`, `1..3`, or `1,3,5`.
+    #[arg(long, requires = "list", conflicts_with_all = ["query", "id"])]
+    pub position: Option<String>,
+    /// File with one to-do reference per line (`project_id todo_id`,
+    /// `project_id:todo_id`, or a to-do URL); `-` reads stdin.
+    #[arg(long, conflicts_with_all = ["query", "id", "project_id", "project", "list"])]
+    pub from_file: Option<PathBuf>,
+    /// Complete every open match of `query` without prompting (what
+    /// `todo search <query>` lists).
+    #[arg(long, requires = "query", conflicts_with_all = ["id", "position", "from_file"])]
+    pub all: bool,
+    /// Only to-dos assigned to this person: id, email, name, or `@team`
+    /// (repeatable, comma-separated; any of them matches).
+    #[arg(long = "assignee", value_delimiter = ',', conflicts_with_all = ["id", "position", "from_file"])]
+    pub assignees: Vec<String>,
+    /// Only to-dos due on or before this date (same forms as `todo add --due-on`).
+    #[arg(long, conflicts_with_all = ["id", "position", "from_file"])]
+    pub due_before: Option<String>,
+    /// Only to-dos due on or after this date (same forms as `todo add --due-on`).
+    #[arg(long, conflicts_with_all = ["id", "position", "from_file"])]
+    pub due_after: Option<String>,
+    /// Open the completed to-do in the browser (skipped when several were completed).
+    #[arg(long)]
+    pub open: bool,
+    /// Order of the matches: `relevance` to the search text, or `recent`
+    /// (most recently updated first).
+    #[arg(long, value_enum, default_value_t = SearchSort::Relevance)]
+    pub sort: SearchSort,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoRecurArgs {
+    #[command(subcommand)]
+    pub command: TodoRecurCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TodoRecurCommand {
+    /// Save a recurring to-do schedule.
+    Add(TodoRecurAddArgs),
+    /// List recurring to-do schedules with their next due date.
+    List(TodoRecurListArgs),
+    /// Delete a recurring to-do schedule.
+    Remove(TodoRecurRemoveArgs),
+    /// Create the to-dos that are due; safe to run from cron.
+    Run(TodoRecurRunArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct TodoRecurAddArgs {
+    /// To-do title/content for every instance.
+    pub content: String,
+    /// When it recurs: `day`, `weekday`, weekday names such as `monday` or
+    /// `mon,thu`, or a day of the month such as `1st` or `15th`.
+    #[arg(long)]
+    pub every: String,
+    /// Project name, id, or `@alias` (a list alias also sets the list).
+    #[arg(long)]
+    pub project: String,
+    /// To-do list name, id, or `@alias`; optional when the project has one list.
+    #[arg(long)]
+    pub list: Option<String>,
+    /// Notes/description for every instance, in markdown.
+    #[arg(long)]
+    pub notes: Option<String>,
+    /// Assignee: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    /// Notify when done: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "notify", value_delimiter = ',')]
+    pub notify: Vec<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoRecurListArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoRecurRemoveArgs {
+    /// Schedule id, as shown by `todo recur list`.
+    pub id: u32,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoRecurRunArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoMineArgs {
+    /// Only show assignments in one project.
+    #[arg(long, value_parser = project_id_arg)]
+    pub project_id: Option<u64>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoOverdueArgs {
+    /// Only show to-dos in one project.
+    #[arg(long, value_parser = project_id_arg)]
+    pub project_id: Option<u64>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoDueArgs {
+    /// Days ahead to include, from today through today + N: `7`, `3d`, or `2w`.
+    #[arg(long)]
+    pub within: String,
+    /// Only show to-dos in one project.
+    #[arg(long, value_parser = project_id_arg)]
+    pub project_id: Option<u64>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoSearchArgs {
+    /// To-do search text.
+    pub query: String,
+    /// Limit the search to one project.
+    #[arg(long, value_parser = project_id_arg)]
+    pub project_id: Option<u64>,
+    /// Match completed to-dos instead of open ones.
+    #[arg(long)]
+    pub completed: bool,
+    /// Only to-dos assigned to this person: id, email, name, or `@team`
+    /// (repeatable, comma-separated; any of them matches).
+    #[arg(long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    /// Only to-dos due on or before this date (same forms as `todo add --due-on`).
+    #[arg(long)]
+    pub due_before: Option<String>,
+    /// Only to-dos due on or after this date (same forms as `todo add --due-on`).
+    #[arg(long)]
+    pub due_after: Option<String>,
+    /// Only to-dos in this list or group: id, list `@alias`, or part of its name.
+    #[arg(long)]
+    pub list: Option<String>,
+    /// Print `project_id todo_id` per line, for `--from-file -` pipelines.
+    #[arg(long, conflicts_with = "json")]
+    pub id_only: bool,
+    /// Order of the matches: `relevance` to the search text, or `recent`
+    /// (most recently updated first).
+    #[arg(long, value_enum, default_value_t = SearchSort::Relevance)]
+    pub sort: SearchSort,
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// How search matches are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchSort {
+    Relevance,
+    Recent,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoEditArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Updated to-do title/content.
+    #[arg(long)]
+    pub content: Option<String>,
+    /// Updated to-do notes/description, in markdown; `-` reads stdin.
+    #[arg(long)]
+    pub notes: Option<String>,
+    /// Read the updated notes (markdown) from a file, or stdin with `-`.
+    #[arg(long, conflicts_with = "notes")]
+    pub notes_file: Option<PathBuf>,
+    /// Updated due date: YYYY-MM-DD or a relative form such as tomorrow, +3d, or fri.
+    #[arg(long)]
+    pub due_on: Option<String>,
+    /// Updated start date (same forms as `--due-on`; empty clears it).
+    #[arg(long)]
+    pub starts_on: Option<String>,
+    /// Replace who is notified when done: person id, email, name, or `@team`
+    /// (repeatable, comma-separated; empty clears the list).
+    #[arg(long = "notify", value_delimiter = ',')]
+    pub notify: Vec<String>,
+    /// Upload a file and add it to the end of the notes (repeatable).
+    #[arg(long = "attach", value_name = "FILE")]
+    pub attachments: Vec<PathBuf>,
+    /// Open the edited to-do in the browser.
+    #[arg(long)]
+    pub open: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoAssignArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Person to add: id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(
+        long = "assignee",
+        value_delimiter = ',',
+        required_unless_present = "me"
+    )]
+    pub assignees: Vec<String>,
+    /// Add me (the signed-in person) as an assignee.
+    #[arg(long)]
+    pub me: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoReOpenArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Pick completed to-dos from a to-do list instead of searching.
+    #[arg(long, conflicts_with_all = ["query", "id"])]
+    pub from_list: bool,
+    /// To-do list to read completed to-dos from (requires `--from-list`): an
+    /// id with `--project-id`, or a list `@alias`.
+    #[arg(long, requires = "from_list", value_parser = todolist_id_arg)]
+    pub list_id: Option<TodolistId>,
+    /// File with one to-do reference per line (`project_id todo_id`,
+    /// `project_id:todo_id`, or a to-do URL); `-` reads stdin.
+    #[arg(long, conflicts_with_all = ["query", "id", "project_id", "from_list"])]
+    pub from_file: Option<PathBuf>,
+    /// Only to-dos assigned to this person: id, email, name, or `@team`
+    /// (repeatable, comma-separated; any of them matches).
+    #[arg(long = "assignee", value_delimiter = ',', conflicts_with_all = ["id", "from_list", "from_file"])]
+    pub assignees: Vec<String>,
+    /// Only to-dos due on or before this date (same forms as `todo add --due-on`).
+    #[arg(long, conflicts_with_all = ["id", "from_list", "from_file"])]
+    pub due_before: Option<String>,
+    /// Only to-dos due on or after this date (same forms as `todo add --due-on`).
+    #[arg(long, conflicts_with_all = ["id", "from_list", "from_file"])]
+    pub due_after: Option<String>,
+    /// Only to-dos in this list or group: id, list `@alias`, or part of its name.
+    #[arg(long, conflicts_with_all = ["id", "from_list", "from_file"])]
+    pub list: Option<String>,
+    /// Order of the matches: `relevance` to the search text, or `recent`
+    /// (most recently updated first).
+    #[arg(long, value_enum, default_value_t = SearchSort::Relevance)]
+    pub sort: SearchSort,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoUndoArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoExportArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Export only this list and its groups: id, name, or list `@alias`.
+    #[arg(long)]
+    pub list: Option<String>,
+    #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+    pub format: ExportFormat,
+    /// Write to this file instead of stdout.
+    #[arg(long, group = "destination")]
+    pub output: Option<PathBuf>,
+    /// Write open to-dos with due dates to this iCalendar (.ics) file instead.
+    #[arg(long, group = "destination", conflicts_with = "format")]
+    pub ical: Option<PathBuf>,
+    /// Overwrite the `--output` or `--ical` file if it already exists.
+    #[arg(long, requires = "destination")]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoImportArgs {
+    /// CSV file to import, or `-` for stdin.
+    pub path: PathBuf,
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// List for to-dos the file does not place in a list or section.
+    /// Defaults to the file name, or "Imported" for stdin.
+    #[arg(long)]
+    pub list: Option<String>,
+    #[arg(long, value_enum, default_value_t = ImportFormat::Auto)]
+    pub format: ImportFormat,
+    /// Show what would be created without creating anything.
+    #[arg(long)]
+    pub dry_run: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `auto` picks `todoist` when the header has Todoist's `TYPE`, `CONTENT`,
+/// and `INDENT` columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    Auto,
+    Todoist,
+    Csv,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoStatsArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoDeleteArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    /// Order of the matches: `relevance` to the search text, or `recent`
+    /// (most recently updated first).
+    #[arg(long, value_enum, default_value_t = SearchSort::Relevance)]
+    pub sort: SearchSort,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoCommentArgs {
+    /// Comment text. If omitted, prompt interactively.
+    pub text: Option<String>,
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    #[arg(long, conflicts_with = "id")]
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long)]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoCommentsArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoShowArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoHistoryArgs {
+    /// To-do search text, or a to-do URL. If omitted in search mode, prompt interactively.
+    pub query: Option<String>,
+    /// To-do id, or a to-do URL (which also sets the project).
+    #[arg(long, conflicts_with = "query")]
+    pub id: Option<IdOrUrl>,
+    /// Project id, `@alias`, or any URL inside the project.
+    #[arg(long, value_parser = project_id_or_url_arg)]
+    pub project_id: Option<IdOrUrl>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TodoListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// To-do list id, name, or `@alias`.
+    #[arg(long)]
+    pub list: String,
+    /// Include completed to-dos after the open ones.
+    #[arg(long)]
+    pub completed: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateArgs {
+    #[command(subcommand)]
+    pub command: TemplateCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TemplateCommand {
+    /// Save a to-do template for `todo add --template`.
+    Save(TemplateSaveArgs),
+    /// List saved to-do templates.
+    List(TemplateListArgs),
+    /// Delete a saved to-do template.
+    Delete(TemplateDeleteArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateSaveArgs {
+    /// Template name (letters, digits, `-`, `_`).
+    pub name: String,
+    /// To-do content; `{date}`, `{due}`, and `{key}` placeholders are filled on use.
+    #[arg(long)]
+    pub content: String,
+    /// Notes/description; supports the same placeholders as `--content`.
+    #[arg(long)]
+    pub notes: Option<String>,
+    /// Assignee: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    /// Notify when done: person id, email, name, or `@team` (repeatable, comma-separated).
+    #[arg(long = "notify", value_delimiter = ',')]
+    pub notify: Vec<String>,
+    /// Due date relative to the day of use, e.g. `3d` or `1w`.
+    #[arg(long)]
+    pub due_in: Option<String>,
+    /// Replace an existing template with the same name.
+    #[arg(long)]
+    pub force: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateListArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateDeleteArgs {
+    /// Template name.
+    pub name: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    #[command(subcommand)]
+    pub command: ListCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ListCommand {
+    /// Show the to-do lists in a project.
+    List(ListListArgs),
+    /// Create a to-do list in a project.
+    Create(ListCreateArgs),
+    /// Rename a to-do list.
+    Rename(ListRenameArgs),
+    /// Archive a to-do list.
+    Archive(ListArchiveArgs),
+    /// Work with groups inside a to-do list.
+    Group(ListGroupArgs),
+    /// Save and re-create to-do list structures.
+    Template(ListTemplateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ListListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListCreateArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// Name of the new list.
+    #[arg(long)]
+    pub name: String,
+    /// Optional description (plain text).
+    #[arg(long)]
+    pub description: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListRenameArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// To-do list name or id.
+    #[arg(long)]
+    pub list: String,
+    /// New name.
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListGroupArgs {
+    #[command(subcommand)]
+    pub command: ListGroupCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ListGroupCommand {
+    /// Show the groups in a to-do list.
+    List(ListGroupListArgs),
+    /// Create a group in a to-do list.
+    Add(ListGroupAddArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ListGroupListArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// To-do list name or id.
+    #[arg(long)]
+    pub list: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListGroupAddArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// To-do list name or id.
+    #[arg(long)]
+    pub list: String,
+    /// Name of the new group.
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListArchiveArgs {
+    /// Project id, name, or `@alias`.
+    #[arg(long)]
+    pub project: String,
+    /// To-do list name or id.
+    #[arg(long)]
+    pub list: String,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListTemplateArgs {
+    #[command(subcommand)]
+    pub command: ListTemplateCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ListTemplateCommand {
+    /// Snapshot a to-do list's groups and to-dos as a template.
+    Save(ListTemplateSaveArgs),
+    /// Create a new to-do list from a template.
+    Apply(ListTemplateApplyArgs),
+    /// List saved to-do list templates.
+    List(ListTemplateListArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ListTemplateSaveArgs {
+    /// Template name (letters, digits, `-`, `_`).
+    pub name: String,
+    /// Project id, name, or `@alias` holding the source list.
+    #[arg(long)]
+    pub project: String,
+    /// Source to-do list name or id.
+    #[arg(long)]
+    pub list: String,
+    /// Replace an existing template with the same name.
+    #[arg(long)]
+    pub force: bool,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListTemplateApplyArgs {
+    /// Template name.
+    pub name: String,
+    /// Project id, name, or `@alias` to create the list in.
+    #[arg(long)]
+    pub project: String,
+    /// Name for the new list (defaults to the source list's name).
+    #[arg(long)]
+    pub title: Option<String>,
+    /// Date the earliest due date maps to: YYYY-MM-DD, or `3d`/`2w` from today (default today).
+    #[arg(long)]
+    pub start: Option<String>,
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ListTemplateListArgs {
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// A numeric id, or a Basecamp web URL pasted from the browser.
+#[derive(Debug, Clone)]
+pub enum IdOrUrl {
+    Id(u64),
+    Url(BasecampLink),
+}
+
+/// Where a to-do goes among the open to-dos of its list.
+#[derive(Debug, Clone, Copy)]
+pub enum TodoPosition {
+    Top,
+    Bottom,
+    /// 1-based.
+    At(u32),
+}
+
+/// A to-do list id, plus its project when given as a list `@alias`.
+#[derive(Debug, Clone, Copy)]
+pub struct TodolistId {
+    pub id: u64,
+    pub project_id: Option<u64>,
+}
+
+// Aliases are resolved while parsing, so every numeric id flag takes them
+// without the commands knowing.
+fn project_id_arg(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    match value.strip_prefix('@') {
+        Some(alias) => lookup::resolve_alias(alias)
+            .map(|target| target.project_id)
+            .map_err(|err| err.message),
+        None => value
+            .parse()
+            .map_err(|_| "expected a numeric id or an @alias".to_string()),
+    }
+}
+
+fn project_id_or_url_arg(value: &str) -> Result<IdOrUrl, String> {
+    if value.trim().starts_with('@') {
+        return project_id_arg(value).map(IdOrUrl::Id);
+    }
+    value.parse()
+}
+
+fn todolist_id_arg(value: &str) -> Result<TodolistId, String> {
+    let value = value.trim();
+    let Some(alias) = value.strip_prefix('@') else {
+        return value
+            .parse()
+            .map(|id| TodolistId {
+                id,
+                project_id: None,
+            })
+            .map_err(|_| "expected a numeric id or a list @alias".to_string());
+    };
+
+    let target = lookup::resolve_alias(alias).map_err(|err| err.message)?;
+    let id = target
+        .todolist_id
+        .ok_or_else(|| format!("@{alias} names a project, not a to-do list."))?;
+    Ok(TodolistId {
+        id,
+        project_id: Some(target.project_id),
+    })
+}
+
+impl FromStr for TodoPosition {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "top" => Ok(Self::Top),
+            "bottom" => Ok(Self::Bottom),
+            other => match other.parse::<u32>() {
+                Ok(position) if position >= 1 => Ok(Self::At(position)),
+                _ => Err("expected top, bottom, or a position of 1 or more".to_string()),
+            },
+        }
+    }
+}
+
+impl FromStr for IdOrUrl {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Ok(id) = value.trim().parse() {
+            return Ok(Self::Id(id));
+        }
+        BasecampLink::parse(value)
+            .map(Self::Url)
+            .ok_or_else(|| "expected a numeric id or a Basecamp URL".to_string())
+    }
+}
@@ -0,0 +1,92 @@
+/// One CSV record and the 1-based line it starts on; quoted fields can span
+/// several lines.
+#[derive(Debug)]
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// Parses RFC 4180 CSV: `,` separated, `"` quoted with `""` escapes, LF or
+/// CRLF line ends, and an optional UTF-8 byte order mark. Empty lines are
+/// skipped.
+pub fn parse(input: &str) -> Result<Vec<CsvRecord>, String> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push('\n');
+                }
+                _ => field.push(ch),
+            }
+            continue;
+        }
+
+        match ch {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push(CsvRecord {
+                        line: record_line,
+                        fields: std::mem::take(&mut fields),
+                    });
+                } else {
+                    fields.clear();
+                }
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(ch),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!(
+            "Unterminated quoted field starting on line {record_line}."
+        ));
+    }
+    fields.push(field);
+    if fields.len() > 1 || !fields[0].is_empty() {
+        records.push(CsvRecord {
+            line: record_line,
+            fields,
+        });
+    }
+
+    Ok(records)
+}
+
+/// Appends one row: fields with a comma, quote, or line break are quoted and
+/// their quotes doubled; the row ends with CRLF.
+pub fn push_row(document: &mut String, fields: impl IntoIterator<Item = String>) {
+    for (index, field) in fields.into_iter().enumerate() {
+        if index > 0 {
+            document.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            document.push('"');
+            document.push_str(&field.replace('"', "\"\""));
+            document.push('"');
+        } else {
+            document.push_str(&field);
+        }
+    }
+    document.push_str("\r\n");
+}
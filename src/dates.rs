@@ -0,0 +1,272 @@
+use crate::error::{AppError, AppResult};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Calendar date without a time zone, used for day-granular comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn today_utc() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self::from_days((seconds / SECONDS_PER_DAY) as i64)
+    }
+
+    /// Parses `YYYY-MM-DD`, also accepting a trailing time component.
+    pub fn parse_iso(value: &str) -> Option<Self> {
+        let date = value.get(..10)?;
+        let mut parts = date.split('-');
+        let year = parts.next()?.parse::<i64>().ok()?;
+        let month = parts.next()?.parse::<u32>().ok()?;
+        let day = parts.next()?.parse::<u32>().ok()?;
+        if parts.next().is_some() || !(1..=12).contains(&month) {
+            return None;
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        if value.len() > 10 && !value[10..].starts_with('T') {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// The calendar date at `seconds` since the Unix epoch, shifted by
+    /// `utc_offset` seconds.
+    pub fn from_unix(seconds: i64, utc_offset: i64) -> Self {
+        Self::from_days((seconds + utc_offset).div_euclid(SECONDS_PER_DAY as i64))
+    }
+
+    pub fn year(self) -> i64 {
+        self.year
+    }
+
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Days since 1970-01-01.
+    pub fn unix_days(self) -> i64 {
+        self.to_days()
+    }
+
+    /// 0 = Sunday through 6 = Saturday.
+    pub fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday.
+        (self.to_days() + 4).rem_euclid(7) as u32
+    }
+
+    pub fn end_of_month(self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Whole days from `earlier` to `self`; negative when `self` comes first.
+    pub fn days_since(self, earlier: Self) -> i64 {
+        self.to_days() - earlier.to_days()
+    }
+
+    // Days since 1970-01-01 (Howard Hinnant's civil calendar algorithms).
+    fn to_days(self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Renders Unix seconds as `YYYY-MM-DD HH:MM UTC`.
+pub fn format_unix_utc(seconds: u64) -> String {
+    let date = Date::from_days((seconds / SECONDS_PER_DAY) as i64);
+    let seconds_of_day = seconds % SECONDS_PER_DAY;
+    format!(
+        "{date} {:02}:{:02} UTC",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60
+    )
+}
+
+/// Shortens a Basecamp ISO 8601 timestamp to `YYYY-MM-DD HH:MM` in the
+/// offset Basecamp reported; anything unexpected is returned unchanged.
+pub fn format_timestamp(value: &str) -> String {
+    match (value.get(..10), value.get(11..16)) {
+        (Some(date), Some(time)) if Date::parse_iso(date).is_some() => format!("{date} {time}"),
+        _ => value.to_string(),
+    }
+}
+
+/// Resolves a `--since` style value: `30d`, `2w` before `today`, or an ISO
+/// date.
+pub fn parse_since(value: &str, flag: &str, today: Date) -> AppResult<Date> {
+    let trimmed = value.trim();
+    if let Some(date) = Date::parse_iso(trimmed) {
+        return Ok(date);
+    }
+
+    let days = parse_day_count(trimmed).ok_or_else(|| {
+        AppError::invalid_input(format!(
+            "Invalid {flag} value \"{trimmed}\". Use a duration like 30d or 2w, or a date like 2026-01-31."
+        ))
+    })?;
+
+    Ok(today.add_days(-days))
+}
+
+/// Resolves a forward date: an ISO date, or `3d`/`2w` from `today`.
+pub fn parse_start(value: &str, flag: &str, today: Date) -> AppResult<Date> {
+    let trimmed = value.trim();
+    if let Some(date) = Date::parse_iso(trimmed) {
+        return Ok(date);
+    }
+
+    let days = parse_day_count(trimmed).ok_or_else(|| {
+        AppError::invalid_input(format!(
+            "Invalid {flag} value \"{trimmed}\". Use a date like 2026-01-31 or an offset like 3d or 2w from today."
+        ))
+    })?;
+
+    Ok(today.add_days(days))
+}
+
+/// Resolves a to-do date: strict `YYYY-MM-DD`, or a relative form against
+/// `today`: `today`, `tomorrow`, `+3d`/`3d`/`+2w`, a weekday (`fri`,
+/// `next monday`) meaning its next occurrence after today, or `eom`.
+/// `label` names the field in errors, e.g. "due date".
+pub fn parse_todo_date(value: &str, today: Date, label: &str) -> AppResult<Date> {
+    let normalized = value.trim().to_ascii_lowercase();
+    if is_iso_date(&normalized) {
+        return Date::parse_iso(&normalized).ok_or_else(|| invalid_todo_date(value, label));
+    }
+
+    let date = match normalized.as_str() {
+        "today" => Some(today),
+        "tomorrow" => Some(today.add_days(1)),
+        "eom" => Some(today.end_of_month()),
+        other => parse_day_count(other.strip_prefix('+').unwrap_or(other))
+            .map(|days| today.add_days(days))
+            .or_else(|| {
+                let day = other.strip_prefix("next ").unwrap_or(other).trim();
+                let weekday = parse_weekday(day)?;
+                let ahead = (weekday + 7 - today.weekday()) % 7;
+                Some(today.add_days(if ahead == 0 { 7 } else { i64::from(ahead) }))
+            }),
+    };
+
+    date.ok_or_else(|| invalid_todo_date(value, label))
+}
+
+/// Whether `value` is shaped like `YYYY-MM-DD`, so it needs no "today".
+pub fn is_iso_date(value: &str) -> bool {
+    let bytes = value.trim().as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, byte)| match index {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        })
+}
+
+fn invalid_todo_date(value: &str, label: &str) -> AppError {
+    AppError::invalid_input(format!(
+        "Invalid {label} \"{}\". Use YYYY-MM-DD, today, tomorrow, +3d, 2w, fri, next monday, or eom.",
+        value.trim()
+    ))
+}
+
+/// A weekday name or a prefix of at least three letters (`mon`, `thurs`),
+/// as 0 = Sunday through 6 = Saturday.
+pub fn parse_weekday(value: &str) -> Option<u32> {
+    const NAMES: [&str; 7] = [
+        "sunday",
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+    ];
+    if value.len() < 3 {
+        return None;
+    }
+    NAMES
+        .iter()
+        .position(|name| name.starts_with(value))
+        .map(|index| index as u32)
+}
+
+/// Parses a day-granular duration such as `3d` or `2w` into days.
+pub fn parse_day_count(value: &str) -> Option<i64> {
+    let trimmed = value.trim();
+    let unit = trimmed.chars().last()?;
+    let amount = trimmed[..trimmed.len() - unit.len_utf8()]
+        .parse::<i64>()
+        .ok()?;
+    let days = match unit {
+        'd' | 'D' => amount,
+        'w' | 'W' => amount.checked_mul(7)?,
+        _ => return None,
+    };
+
+    (0..=36_500).contains(&days).then_some(days)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
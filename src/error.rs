@@ -1,3 +1,4 @@
+use crate::redact::redact;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -44,7 +45,7 @@ impl AppError {
     pub fn new(code: i32, message: impl Into<String>) -> Self {
         Self {
             code,
-            message: message.into(),
+            message: redact(&message.into()),
         }
     }
 
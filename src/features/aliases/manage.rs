@@ -0,0 +1,155 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::links::BasecampLink;
+use crate::basecamp::models::{Project, Todolist};
+use crate::cli::{AliasRemoveArgs, AliasSetArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::TargetAlias;
+use crate::features::projects::lookup;
+use crate::features::todos::picker::{resolve_todolist, todolist_display_name};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct AliasEntry {
+    pub alias: String,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AliasSetOutput {
+    pub ok: bool,
+    #[serde(flatten)]
+    pub entry: AliasEntry,
+    /// Whether an alias with this name was overwritten.
+    pub replaced: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AliasRemoveOutput {
+    pub ok: bool,
+    #[serde(flatten)]
+    pub entry: AliasEntry,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AliasListOutput {
+    pub ok: bool,
+    pub aliases: Vec<AliasEntry>,
+    pub count: usize,
+}
+
+pub async fn run_set(args: AliasSetArgs) -> AppResult<AliasSetOutput> {
+    let alias = lookup::normalize_alias(&args.name)?;
+
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+    let (project, todolist) = resolve_target(
+        &client,
+        &args.target,
+        args.list.as_deref(),
+        session.account_id,
+    )
+    .await?;
+
+    let target = TargetAlias {
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: todolist.as_ref().map(|todolist| todolist.id),
+        todolist_name: todolist.as_ref().map(todolist_display_name),
+    };
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let replaced = config
+        .aliases
+        .insert(alias.clone(), target.clone())
+        .is_some();
+    integration::save_config(&config)?;
+
+    Ok(AliasSetOutput {
+        ok: true,
+        entry: entry(alias, target),
+        replaced,
+    })
+}
+
+pub fn run_list() -> AppResult<AliasListOutput> {
+    let aliases: Vec<AliasEntry> = integration::load_config()?
+        .aliases
+        .into_iter()
+        .map(|(alias, target)| entry(alias, target))
+        .collect();
+    let count = aliases.len();
+
+    Ok(AliasListOutput {
+        ok: true,
+        aliases,
+        count,
+    })
+}
+
+pub fn run_remove(args: AliasRemoveArgs) -> AppResult<AliasRemoveOutput> {
+    let alias = lookup::normalize_alias(&args.name)?;
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let target = config.aliases.remove(&alias).ok_or_else(|| {
+        AppError::no_account(format!(
+            "No alias @{alias}. Run `basecamp-cli alias list` to list aliases."
+        ))
+    })?;
+    integration::save_config(&config)?;
+
+    Ok(AliasRemoveOutput {
+        ok: true,
+        entry: entry(alias, target),
+    })
+}
+
+// A pasted list URL names both the project and the list; `--list` picks a
+// list inside any project reference.
+async fn resolve_target(
+    client: &BasecampClient,
+    reference: &str,
+    list: Option<&str>,
+    account_id: u64,
+) -> AppResult<(Project, Option<Todolist>)> {
+    let (project, linked_todolist_id) = match BasecampLink::parse(reference) {
+        Some(link) => {
+            if link.account_id != account_id {
+                return Err(AppError::invalid_input(format!(
+                    "URL belongs to account {}, not the current account {account_id}.",
+                    link.account_id
+                )));
+            }
+            (
+                client.get_project(link.project_id).await?,
+                link.todolist_id(),
+            )
+        }
+        None => (lookup::resolve_project(client, reference).await?, None),
+    };
+
+    let todolist = match (list, linked_todolist_id) {
+        (Some(list), _) => Some(resolve_todolist(client, &project, list).await?),
+        (None, Some(todolist_id)) => Some(client.get_todolist(project.id, todolist_id).await?),
+        (None, None) => None,
+    };
+    Ok((project, todolist))
+}
+
+fn entry(alias: String, target: TargetAlias) -> AliasEntry {
+    AliasEntry {
+        alias,
+        project_id: target.project_id,
+        project_name: target.project_name,
+        todolist_id: target.todolist_id,
+        todolist_name: target.todolist_name,
+    }
+}
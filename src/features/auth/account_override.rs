@@ -0,0 +1,40 @@
+//! `--account-id` on todo and project commands: runs one command against
+//! another account the stored login can reach, without logging in again.
+//! Basecamp tokens belong to the person, so the same token works for every
+//! account listed in its `authorization.json`.
+
+use crate::error::{AppError, AppResult};
+use crate::features::auth::{integration, oauth};
+
+/// Checks that the stored token reaches `account_id` and makes it the
+/// account of this run. The logged-in account needs no check.
+pub async fn apply(account_id: Option<u64>) -> AppResult<()> {
+    let Some(account_id) = account_id else {
+        return Ok(());
+    };
+    let session = integration::resolve_session_context()?;
+    if session.account_id == account_id {
+        return Ok(());
+    }
+
+    let endpoints = integration::oauth_endpoints()?;
+    let authorization = oauth::fetch_authorization(&endpoints, &session.access_token).await?;
+    let accounts: Vec<oauth::Account> = authorization
+        .accounts
+        .into_iter()
+        .filter(|account| account.product == "bc3")
+        .collect();
+    let Some(account) = accounts.iter().find(|account| account.id == account_id) else {
+        let available = accounts
+            .iter()
+            .map(|account| format!("\"{}\" ({})", account.name, account.id))
+            .collect::<Vec<String>>()
+            .join(", ");
+        return Err(AppError::no_account(format!(
+            "Account {account_id} is not reachable with the stored login. Available: {available}."
+        )));
+    };
+
+    integration::set_account_override(account.id, account.name.clone());
+    Ok(())
+}
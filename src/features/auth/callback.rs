@@ -1,13 +1,19 @@
 use crate::error::{AppError, AppResult};
-use std::io::{Read, Write};
-use std::net::{TcpListener, TcpStream};
-use std::thread;
-use std::time::{Duration, Instant};
+use crate::features::auth::models::CallbackPageConfig;
+use crate::rich_text::escape_html;
+use std::fs;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
 use url::Url;
 
-const SUCCESS_BODY: &str =
-    "<html><body><h1>Basecamp login complete</h1><p>You can close this window.</p></body></html>";
+const DEFAULT_SUCCESS_TITLE: &str = "Basecamp login complete";
+const DEFAULT_SUCCESS_MESSAGE: &str = "You can close this window.";
 const FAILURE_BODY: &str = "<html><body><h1>Basecamp login failed</h1><p>You can return to the terminal and retry.</p></body></html>";
+const NOT_FOUND_BODY: &str = "<html><body><h1>Not found</h1></body></html>";
+const MAX_REQUEST_HEAD_BYTES: usize = 16 * 1024;
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Debug)]
 pub struct CallbackPayload {
@@ -15,15 +21,27 @@ pub struct CallbackPayload {
     pub state: String,
 }
 
+#[derive(Debug)]
+pub struct PastedCallback {
+    pub code: String,
+    pub state: Option<String>,
+}
+
 pub struct CallbackServer {
     listener: TcpListener,
+    redirect_uri: String,
     expected_path: String,
+    success_body: String,
     timeout: Duration,
 }
 
 impl CallbackServer {
-    pub fn bind(redirect_uri: &str, timeout: Duration) -> AppResult<Self> {
-        let parsed = Url::parse(redirect_uri)
+    pub async fn bind(
+        redirect_uri: &str,
+        success_body: String,
+        timeout: Duration,
+    ) -> AppResult<Self> {
+        let mut parsed = Url::parse(redirect_uri)
             .map_err(|err| AppError::invalid_input(format!("Invalid redirect_uri: {err}")))?;
 
         if parsed.scheme() != "http" {
@@ -55,113 +73,300 @@ impl CallbackServer {
         };
 
         let bind_addr = format!("127.0.0.1:{port}");
-        let listener = TcpListener::bind(&bind_addr).map_err(|err| {
+        let listener = TcpListener::bind(&bind_addr).await.map_err(|err| {
             AppError::oauth(format!(
                 "Failed to bind callback server on {bind_addr}: {err}"
             ))
         })?;
 
-        listener.set_nonblocking(true).map_err(|err| {
-            AppError::oauth(format!("Failed to configure callback server: {err}"))
-        })?;
+        // Port 0 asks the OS for a free port; the real one must be sent to
+        // Basecamp so the redirect lands on this listener.
+        if port == 0 {
+            let bound_port = listener
+                .local_addr()
+                .map_err(|err| {
+                    AppError::oauth(format!("Failed to read callback server address: {err}"))
+                })?
+                .port();
+            parsed
+                .set_port(Some(bound_port))
+                .map_err(|()| AppError::invalid_input("redirect_uri does not accept a port."))?;
+        }
 
         Ok(Self {
             listener,
+            redirect_uri: parsed.to_string(),
             expected_path,
+            success_body,
             timeout,
         })
     }
 
-    pub fn wait_for_code(self) -> AppResult<CallbackPayload> {
-        let deadline = Instant::now() + self.timeout;
+    /// Redirect URI with the bound port, to use for authorization and token exchange.
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    // Each connection is served on its own task so stray requests (favicon,
+    // preconnect sockets that close without a request) never block or abort
+    // the real callback; only the callback path itself can end the wait.
+    pub async fn wait_for_code(self) -> AppResult<CallbackPayload> {
+        let deadline = tokio::time::sleep(self.timeout);
+        tokio::pin!(deadline);
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
 
-        while Instant::now() < deadline {
-            match self.listener.accept() {
-                Ok((mut stream, _addr)) => {
-                    return parse_callback_request(&mut stream, &self.expected_path);
+        let mut connections: JoinSet<AppResult<Option<CallbackPayload>>> = JoinSet::new();
+
+        loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, _addr) = accepted.map_err(|err| {
+                        AppError::oauth(format!("Failed to receive callback request: {err}"))
+                    })?;
+                    let expected_path = self.expected_path.clone();
+                    let success_body = self.success_body.clone();
+                    connections.spawn(handle_connection(stream, expected_path, success_body));
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
-                    thread::sleep(Duration::from_millis(50));
+                Some(joined) = connections.join_next(), if !connections.is_empty() => {
+                    let handled = joined.map_err(|err| {
+                        AppError::oauth(format!("Callback request handler failed: {err}"))
+                    })?;
+                    if let Some(payload) = handled? {
+                        return Ok(payload);
+                    }
                 }
-                Err(err) => {
+                _ = &mut deadline => {
                     return Err(AppError::oauth(format!(
-                        "Failed to receive callback request: {err}"
+                        "Timed out after {}s waiting for OAuth callback. Try login again, or raise --timeout.",
+                        self.timeout.as_secs()
                     )));
                 }
+                _ = &mut ctrl_c => {
+                    return Err(AppError::oauth("Login cancelled while waiting for OAuth callback."));
+                }
             }
         }
-
-        Err(AppError::oauth(
-            "Timed out waiting for OAuth callback. Try login again.",
-        ))
     }
 }
 
-fn parse_callback_request(
-    stream: &mut TcpStream,
-    expected_path: &str,
-) -> AppResult<CallbackPayload> {
-    let mut buffer = [0_u8; 8192];
-    let bytes_read = stream
-        .read(&mut buffer)
-        .map_err(|err| AppError::oauth(format!("Failed to read callback request: {err}")))?;
+// Templates may use {{title}}, {{message}}, and {{auto_close_script}} placeholders.
+pub fn render_success_page(settings: &CallbackPageConfig) -> AppResult<String> {
+    let title = escape_html(settings.title.as_deref().unwrap_or(DEFAULT_SUCCESS_TITLE));
+    let message = escape_html(
+        settings
+            .message
+            .as_deref()
+            .unwrap_or(DEFAULT_SUCCESS_MESSAGE),
+    );
+    let auto_close_script = settings
+        .auto_close_seconds
+        .map(|seconds| {
+            format!(
+                "<script>setTimeout(function () {{ window.close(); }}, {});</script>",
+                u64::from(seconds) * 1000
+            )
+        })
+        .unwrap_or_default();
 
-    let request = String::from_utf8_lossy(&buffer[..bytes_read]);
-    let first_line = request
-        .lines()
-        .next()
-        .ok_or_else(|| AppError::oauth("Received malformed callback request."))?;
+    let template = match settings.template_path.as_deref() {
+        Some(path) => fs::read_to_string(path).map_err(|err| {
+            AppError::invalid_input(format!(
+                "Failed to read callback page template {path}: {err}"
+            ))
+        })?,
+        None => "<html><head><title>{{title}}</title></head><body><h1>{{title}}</h1><p>{{message}}</p>{{auto_close_script}}</body></html>".to_string(),
+    };
 
-    let mut parts = first_line.split_whitespace();
-    let method = parts.next().unwrap_or_default();
-    let target = parts.next().unwrap_or_default();
+    Ok(template
+        .replace("{{title}}", &title)
+        .replace("{{message}}", &message)
+        .replace("{{auto_close_script}}", &auto_close_script))
+}
 
-    if method != "GET" {
-        write_response(stream, "405 Method Not Allowed", FAILURE_BODY)?;
-        return Err(AppError::oauth(
-            "Callback request used unsupported HTTP method.",
+/// Replaces the port of a loopback redirect_uri, e.g. for `--callback-port`.
+pub fn with_port(redirect_uri: &str, port: u16) -> AppResult<String> {
+    let mut parsed = Url::parse(redirect_uri)
+        .map_err(|err| AppError::invalid_input(format!("Invalid redirect_uri: {err}")))?;
+    parsed
+        .set_port(Some(port))
+        .map_err(|()| AppError::invalid_input("redirect_uri does not accept a port."))?;
+    Ok(parsed.to_string())
+}
+
+pub fn uses_ephemeral_port(redirect_uri: &str) -> bool {
+    Url::parse(redirect_uri).is_ok_and(|parsed| parsed.port() == Some(0))
+}
+
+pub fn parse_pasted_callback(input: &str) -> AppResult<PastedCallback> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(AppError::invalid_input(
+            "Paste the redirect URL or authorization code to continue login.",
         ));
     }
 
-    let (path, query) = match target.split_once('?') {
-        Some((path, query)) => (path, query),
-        None => (target, ""),
+    let Ok(parsed) = Url::parse(trimmed) else {
+        return Ok(PastedCallback {
+            code: trimmed.to_string(),
+            state: None,
+        });
     };
 
-    if path != expected_path {
-        write_response(stream, "404 Not Found", FAILURE_BODY)?;
+    let mut code: Option<String> = None;
+    let mut state: Option<String> = None;
+    let mut error: Option<String> = None;
+
+    for (key, value) in parsed.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value.to_string()),
+            "state" => state = Some(value.to_string()),
+            "error" => error = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    if let Some(error) = error {
         return Err(AppError::oauth(format!(
-            "Callback path mismatch. Expected {expected_path}, got {path}."
+            "OAuth authorization was not granted ({error})."
         )));
     }
 
+    let code =
+        code.ok_or_else(|| AppError::oauth("Pasted redirect URL did not include code parameter."))?;
+    let state = state
+        .ok_or_else(|| AppError::oauth("Pasted redirect URL did not include state parameter."))?;
+
+    Ok(PastedCallback {
+        code,
+        state: Some(state),
+    })
+}
+
+struct RequestHead {
+    method: String,
+    target: String,
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    expected_path: String,
+    success_body: String,
+) -> AppResult<Option<CallbackPayload>> {
+    let head =
+        match tokio::time::timeout(REQUEST_READ_TIMEOUT, read_request_head(&mut stream)).await {
+            Ok(Ok(head)) => head,
+            // Empty or malformed requests, and clients that never finish their
+            // request line, are not the OAuth redirect.
+            Ok(Err(_)) => {
+                let _ = write_response(&mut stream, "400 Bad Request", FAILURE_BODY).await;
+                return Ok(None);
+            }
+            Err(_) => return Ok(None),
+        };
+
+    let (path, query) = match head.target.split_once('?') {
+        Some((path, query)) => (path, query),
+        None => (head.target.as_str(), ""),
+    };
+
+    if path != expected_path {
+        let _ = write_response(&mut stream, "404 Not Found", NOT_FOUND_BODY).await;
+        return Ok(None);
+    }
+
+    if head.method != "GET" {
+        let _ = write_response(&mut stream, "405 Method Not Allowed", FAILURE_BODY).await;
+        return Ok(None);
+    }
+
     let mut code: Option<String> = None;
     let mut state: Option<String> = None;
+    let mut error: Option<String> = None;
 
     for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
-        if key == "code" {
-            code = Some(value.to_string());
-        } else if key == "state" {
-            state = Some(value.to_string());
+        match key.as_ref() {
+            "code" => code = Some(value.to_string()),
+            "state" => state = Some(value.to_string()),
+            "error" => error = Some(value.to_string()),
+            _ => {}
         }
     }
 
-    let code = code.ok_or_else(|| {
-        let _ = write_response(stream, "400 Bad Request", FAILURE_BODY);
-        AppError::oauth("OAuth callback did not include code parameter.")
-    })?;
+    if let Some(error) = error {
+        write_response(&mut stream, "400 Bad Request", FAILURE_BODY).await?;
+        return Err(AppError::oauth(format!(
+            "OAuth authorization was not granted ({error})."
+        )));
+    }
 
-    let state = state.ok_or_else(|| {
-        let _ = write_response(stream, "400 Bad Request", FAILURE_BODY);
-        AppError::oauth("OAuth callback did not include state parameter.")
-    })?;
+    let (Some(code), Some(state)) = (code, state) else {
+        write_response(&mut stream, "400 Bad Request", FAILURE_BODY).await?;
+        return Err(AppError::oauth(
+            "OAuth callback did not include code and state parameters.",
+        ));
+    };
+
+    write_response(&mut stream, "200 OK", &success_body).await?;
+
+    Ok(Some(CallbackPayload { code, state }))
+}
 
-    write_response(stream, "200 OK", SUCCESS_BODY)?;
+async fn read_request_head(stream: &mut TcpStream) -> AppResult<RequestHead> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    read_head_line(&mut reader, &mut request_line).await?;
 
-    Ok(CallbackPayload { code, state })
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(AppError::oauth("Received malformed callback request."));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(AppError::oauth(format!(
+            "Callback request used unsupported protocol {version}."
+        )));
+    }
+
+    let head = RequestHead {
+        method: method.to_string(),
+        target: target.to_string(),
+    };
+
+    // Drain headers so the browser sees a clean response after the blank line.
+    let mut consumed = request_line.len();
+    loop {
+        let mut header_line = String::new();
+        read_head_line(&mut reader, &mut header_line).await?;
+        consumed += header_line.len();
+        if consumed > MAX_REQUEST_HEAD_BYTES {
+            return Err(AppError::oauth("Callback request headers are too large."));
+        }
+        if header_line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    Ok(head)
+}
+
+async fn read_head_line(
+    reader: &mut BufReader<&mut TcpStream>,
+    line: &mut String,
+) -> AppResult<()> {
+    let bytes_read = reader
+        .read_line(line)
+        .await
+        .map_err(|err| AppError::oauth(format!("Failed to read callback request: {err}")))?;
+    if bytes_read == 0 {
+        return Err(AppError::oauth(
+            "Callback connection closed before the request was complete.",
+        ));
+    }
+    Ok(())
 }
 
-fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> AppResult<()> {
+async fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> AppResult<()> {
     let response = format!(
         "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
         body.len(),
@@ -170,9 +375,11 @@ fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> AppResult
 
     stream
         .write_all(response.as_bytes())
+        .await
         .map_err(|err| AppError::oauth(format!("Failed to write callback response: {err}")))?;
     stream
         .flush()
+        .await
         .map_err(|err| AppError::oauth(format!("Failed to flush callback response: {err}")))?;
 
     Ok(())
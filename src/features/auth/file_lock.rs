@@ -0,0 +1,71 @@
+use crate::error::{AppError, AppResult};
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Exclusive advisory lock on a sidecar lock file, released on drop.
+///
+/// Locks are per open file, so a process must not acquire the same lock
+/// twice; keep guards around whole load -> modify -> save sequences instead
+/// of nesting them.
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+}
+
+impl FileLock {
+    pub fn acquire(path: &Path) -> AppResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)
+            .map_err(|err| {
+                AppError::generic(format!(
+                    "Failed to open lock file {}: {err}",
+                    path.display()
+                ))
+            })?;
+
+        let started = Instant::now();
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Self { file }),
+                Err(TryLockError::WouldBlock) if started.elapsed() < LOCK_TIMEOUT => {
+                    thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(AppError::generic(format!(
+                        "Timed out waiting for {} held by another basecamp-cli process.",
+                        path.display()
+                    )));
+                }
+                Err(TryLockError::Error(err)) => {
+                    return Err(AppError::generic(format!(
+                        "Failed to lock {}: {err}",
+                        path.display()
+                    )));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+pub fn lock_path(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".lock");
+    path.with_file_name(name)
+}
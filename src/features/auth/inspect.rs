@@ -0,0 +1,47 @@
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use crate::features::auth::models::{AuthInspectAccount, AuthInspectIdentity, AuthInspectOutput};
+use crate::features::auth::oauth;
+
+pub async fn run() -> AppResult<AuthInspectOutput> {
+    let session = integration::resolve_session_context()?;
+    let endpoints = integration::oauth_endpoints()?;
+    let authorization = oauth::fetch_authorization(&endpoints, &session.access_token).await?;
+
+    let identity = authorization.identity.map(|identity| {
+        let name = [identity.first_name, identity.last_name]
+            .into_iter()
+            .flatten()
+            .map(|part| part.trim().to_string())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        AuthInspectIdentity {
+            id: identity.id,
+            name: if name.is_empty() { None } else { Some(name) },
+            email_address: identity.email_address,
+        }
+    });
+
+    let accounts = authorization
+        .accounts
+        .into_iter()
+        .map(|account| AuthInspectAccount {
+            selected: account.id == session.account_id && account.product == "bc3",
+            id: account.id,
+            name: account.name,
+            product: account.product,
+            href: account.href,
+            app_href: account.app_href,
+        })
+        .collect();
+
+    Ok(AuthInspectOutput {
+        ok: true,
+        expires_at: authorization.expires_at,
+        identity,
+        selected_account_id: session.account_id,
+        accounts,
+    })
+}
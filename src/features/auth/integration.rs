@@ -1,20 +1,45 @@
+use crate::basecamp::cache::ResponseCache;
+use crate::basecamp::client::BasecampClient;
+use crate::dates;
 use crate::error::{AppError, AppResult};
+use crate::features::auth::file_lock::{self, FileLock};
 use crate::features::auth::models::{
-    AppConfig, IntegrationDefaults, IntegrationStatus, LoginOverrides, ResolvedIntegration,
-    SecretConfig, SessionConfig, SessionContext, SessionData,
+    AppConfig, AppState, BundleIntegration, BundleSession, CallbackPageConfig, IntegrationBundle,
+    IntegrationDefaults, IntegrationStatus, KeyringLocation, LoginOverrides, ResolvedIntegration,
+    SecretConfig, SecretsWhereOutput, SessionConfig, SessionContext, SessionData,
+    SessionShowOutput, TargetAlias,
 };
-use crate::features::auth::secret_store::SecretStore;
+use crate::features::auth::oauth::{OAuthEndpoints, TokenBundle};
+use crate::features::auth::secret_store::{SecretStore, SecretStoreMode};
+use crate::redact;
 use colored::Colorize;
 use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
 use std::env;
 use std::fs;
+use std::io::{self, IsTerminal};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 use url::Url;
 
 const APP_CONFIG_DIR_ENV: &str = "BASECAMP_CLI_CONFIG_DIR";
+const PROFILE_ENV: &str = "BASECAMP_CLI_PROFILE";
+const DEFAULT_PROFILE: &str = "default";
+const APP_CACHE_DIR_ENV: &str = "BASECAMP_CLI_CACHE_DIR";
+const ACCESS_TOKEN_ENV: &str = "BASECAMP_ACCESS_TOKEN";
+const LAUNCHPAD_URL_ENV: &str = "BASECAMP_LAUNCHPAD_URL";
+const OAUTH_AUTH_URL_ENV: &str = "BASECAMP_OAUTH_AUTH_URL";
+const OAUTH_TOKEN_URL_ENV: &str = "BASECAMP_OAUTH_TOKEN_URL";
+const OAUTH_AUTHORIZATION_URL_ENV: &str = "BASECAMP_OAUTH_AUTHORIZATION_URL";
+const ACCOUNT_ID_ENV: &str = "BASECAMP_ACCOUNT_ID";
 const APP_NAME: &str = "basecamp-cli";
 const CONFIG_FILE: &str = "config.json";
+const STATE_FILE: &str = "state.json";
+const BUNDLE_VERSION: u8 = 1;
+
+/// Account chosen with `--account-id` for this run, with its name.
+static ACCOUNT_OVERRIDE: OnceLock<(u64, String)> = OnceLock::new();
 
 pub fn set_integration(
     client_id: String,
@@ -25,6 +50,7 @@ pub fn set_integration(
     validate_non_empty("client_secret", &client_secret)?;
     validate_redirect_uri(&redirect_uri)?;
 
+    let _lock = lock_state()?;
     let mut secrets = load_secrets()?;
     secrets.client_secret = Some(client_secret);
     save_secrets(&secrets)?;
@@ -50,6 +76,57 @@ pub fn show_integration() -> AppResult<IntegrationStatus> {
     })
 }
 
+/// Session metadata with tokens reduced to fingerprints; never contacts Basecamp.
+pub fn show_session() -> AppResult<SessionShowOutput> {
+    let config = load_config()?;
+
+    if let Some(access_token) = env_value(ACCESS_TOKEN_ENV) {
+        let account_id = match env_value(ACCOUNT_ID_ENV) {
+            Some(raw) => Some(raw.trim().parse::<u64>().map_err(|_| {
+                AppError::invalid_input(format!(
+                    "{ACCOUNT_ID_ENV} must be a numeric Basecamp account id."
+                ))
+            })?),
+            None => config.session.account_id,
+        };
+        let stored_account = account_id.is_some() && account_id == config.session.account_id;
+
+        return Ok(SessionShowOutput {
+            ok: true,
+            source: "environment".to_string(),
+            account_id,
+            account_name: config.session.account_name.filter(|_| stored_account),
+            account_href: config.session.account_href.filter(|_| stored_account),
+            updated_at: None,
+            access_token_fingerprint: Some(token_fingerprint(access_token.trim())),
+            refresh_token_fingerprint: None,
+        });
+    }
+
+    let secrets = load_secrets()?;
+    if config.session.account_id.is_none() && secrets.access_token.is_none() {
+        return Err(AppError::invalid_input(
+            "No stored session. Run `basecamp-cli login` first.",
+        ));
+    }
+
+    Ok(SessionShowOutput {
+        ok: true,
+        source: "login".to_string(),
+        account_id: config.session.account_id,
+        account_name: config.session.account_name,
+        account_href: config.session.account_href,
+        updated_at: config
+            .session
+            .updated_at
+            .as_deref()
+            .and_then(|raw| raw.parse::<u64>().ok())
+            .map(dates::format_unix_utc),
+        access_token_fingerprint: secrets.access_token.as_deref().map(token_fingerprint),
+        refresh_token_fingerprint: secrets.refresh_token.as_deref().map(token_fingerprint),
+    })
+}
+
 pub fn integration_defaults() -> AppResult<IntegrationDefaults> {
     let config = load_config()?;
 
@@ -60,6 +137,7 @@ pub fn integration_defaults() -> AppResult<IntegrationDefaults> {
 }
 
 pub fn clear_integration_only() -> AppResult<()> {
+    let _lock = lock_state()?;
     let mut secrets = load_secrets()?;
     secrets.client_secret = None;
     save_secrets(&secrets)?;
@@ -73,6 +151,7 @@ pub fn clear_integration_only() -> AppResult<()> {
 }
 
 pub fn clear_session() -> AppResult<()> {
+    let _lock = lock_state()?;
     let mut secrets = load_secrets()?;
     secrets.access_token = None;
     secrets.refresh_token = None;
@@ -82,6 +161,11 @@ pub fn clear_session() -> AppResult<()> {
     config.session = SessionConfig::default();
     save_config(&config)?;
 
+    // Cached responses belong to the signed-out user.
+    if let Some(cache) = response_cache()? {
+        cache.clear();
+    }
+
     Ok(())
 }
 
@@ -92,6 +176,7 @@ pub fn clear_integration_and_session() -> AppResult<()> {
 }
 
 pub fn save_session(data: SessionData) -> AppResult<()> {
+    let _lock = lock_state()?;
     let mut secrets = load_secrets()?;
     secrets.access_token = Some(data.access_token);
     secrets.refresh_token = Some(data.refresh_token);
@@ -107,6 +192,98 @@ pub fn save_session(data: SessionData) -> AppResult<()> {
     Ok(())
 }
 
+/// Stores tokens from a refresh, keeping the selected account.
+pub fn save_session_tokens(tokens: TokenBundle) -> AppResult<()> {
+    let _lock = lock_state()?;
+    let mut secrets = load_secrets()?;
+    secrets.access_token = Some(tokens.access_token);
+    secrets.refresh_token = Some(tokens.refresh_token);
+    save_secrets(&secrets)?;
+
+    let mut config = load_config()?;
+    config.session.updated_at = Some(now_unix_timestamp());
+    save_config(&config)?;
+
+    Ok(())
+}
+
+/// Refresh token of the stored login. Environment tokens are never
+/// refreshed, so this is `None` while `BASECAMP_ACCESS_TOKEN` is set.
+pub fn stored_refresh_token() -> AppResult<Option<String>> {
+    if env_value(ACCESS_TOKEN_ENV).is_some() {
+        return Ok(None);
+    }
+    Ok(load_secrets()?.refresh_token)
+}
+
+pub fn export_bundle(include_session: bool) -> AppResult<IntegrationBundle> {
+    let config = load_config()?;
+    let secrets = load_secrets()?;
+
+    let missing = || {
+        AppError::invalid_input(
+            "Integration is not fully configured. Run `basecamp-cli integration set` first.",
+        )
+    };
+    let integration = BundleIntegration {
+        client_id: config.integration.client_id.ok_or_else(missing)?,
+        client_secret: secrets.client_secret.ok_or_else(missing)?,
+        redirect_uri: config.integration.redirect_uri.ok_or_else(missing)?,
+    };
+
+    let session = if include_session {
+        match (
+            config.session.account_id,
+            secrets.access_token,
+            secrets.refresh_token,
+        ) {
+            (Some(account_id), Some(access_token), Some(refresh_token)) => Some(BundleSession {
+                account_id,
+                account_name: config.session.account_name.unwrap_or_default(),
+                account_href: config.session.account_href.unwrap_or_default(),
+                access_token,
+                refresh_token,
+            }),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    Ok(IntegrationBundle {
+        version: BUNDLE_VERSION,
+        integration,
+        session,
+    })
+}
+
+pub fn import_bundle(bundle: IntegrationBundle) -> AppResult<()> {
+    if bundle.version > BUNDLE_VERSION {
+        return Err(AppError::invalid_input(format!(
+            "Export file version {} is newer than supported version {BUNDLE_VERSION}.",
+            bundle.version
+        )));
+    }
+
+    set_integration(
+        bundle.integration.client_id,
+        bundle.integration.client_secret,
+        bundle.integration.redirect_uri,
+    )?;
+
+    if let Some(session) = bundle.session {
+        save_session(SessionData {
+            access_token: session.access_token,
+            refresh_token: session.refresh_token,
+            account_id: session.account_id,
+            account_name: session.account_name,
+            account_href: session.account_href,
+        })?;
+    }
+
+    Ok(())
+}
+
 pub fn resolve_login_credentials(overrides: LoginOverrides) -> AppResult<ResolvedIntegration> {
     let config = load_config()?;
     let secrets = load_secrets()?;
@@ -145,6 +322,7 @@ pub fn resolve_login_credentials(overrides: LoginOverrides) -> AppResult<Resolve
     })?;
 
     validate_redirect_uri(&redirect_uri)?;
+    redact::register_secret(&client_secret);
 
     Ok(ResolvedIntegration {
         client_id,
@@ -153,8 +331,52 @@ pub fn resolve_login_credentials(overrides: LoginOverrides) -> AppResult<Resolve
     })
 }
 
+/// Points every session lookup of this run at another account the stored
+/// token can reach. Callers check that first; see `account_override`.
+pub fn set_account_override(account_id: u64, account_name: String) {
+    let _ = ACCOUNT_OVERRIDE.set((account_id, account_name));
+}
+
 pub fn resolve_session_context() -> AppResult<SessionContext> {
+    let mut session = stored_session_context()?;
+    if let Some((account_id, account_name)) = ACCOUNT_OVERRIDE.get() {
+        session.account_id = *account_id;
+        session.account_name = Some(account_name.clone());
+    }
+    Ok(session)
+}
+
+fn stored_session_context() -> AppResult<SessionContext> {
     let config = load_config()?;
+
+    // Environment tokens bypass the keyring so CI jobs can run without a login.
+    if let Some(access_token) = env_value(ACCESS_TOKEN_ENV) {
+        redact::register_secret(&access_token);
+        let account_id = match env_value(ACCOUNT_ID_ENV) {
+            Some(raw) => raw.trim().parse::<u64>().map_err(|_| {
+                AppError::invalid_input(format!(
+                    "{ACCOUNT_ID_ENV} must be a numeric Basecamp account id."
+                ))
+            })?,
+            None => config.session.account_id.ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "Missing account selection. Set {ACCOUNT_ID_ENV} alongside {ACCESS_TOKEN_ENV}."
+                ))
+            })?,
+        };
+        let account_name = if config.session.account_id == Some(account_id) {
+            config.session.account_name
+        } else {
+            None
+        };
+
+        return Ok(SessionContext {
+            access_token: access_token.trim().to_string(),
+            account_id,
+            account_name,
+        });
+    }
+
     let secrets = load_secrets()?;
 
     let access_token = secrets.access_token.ok_or_else(|| {
@@ -172,18 +394,46 @@ pub fn resolve_session_context() -> AppResult<SessionContext> {
     })
 }
 
+pub fn callback_page_settings() -> AppResult<CallbackPageConfig> {
+    let mut settings = load_config()?.callback_page;
+
+    if let Some(title) = env_value("BASECAMP_CLI_CALLBACK_TITLE") {
+        settings.title = Some(title);
+    }
+    if let Some(message) = env_value("BASECAMP_CLI_CALLBACK_MESSAGE") {
+        settings.message = Some(message);
+    }
+    if let Some(template_path) = env_value("BASECAMP_CLI_CALLBACK_TEMPLATE") {
+        settings.template_path = Some(template_path);
+    }
+    if let Some(raw) = env_value("BASECAMP_CLI_CALLBACK_AUTO_CLOSE") {
+        let seconds = raw.trim().parse::<u32>().map_err(|_| {
+            AppError::invalid_input("BASECAMP_CLI_CALLBACK_AUTO_CLOSE must be a number of seconds.")
+        })?;
+        settings.auto_close_seconds = Some(seconds);
+    }
+
+    Ok(settings)
+}
+
 pub fn print_secret_store_location() -> AppResult<()> {
     let store = secret_store()?;
-    let info = store.info();
-
-    eprintln!(
-        "{}",
-        format!(
-            "using secret store: keyring service={} account={}",
-            info.service, info.account
-        )
-        .bright_black()
-    );
+    let info = store.info()?;
+
+    let store_label = match info.mode {
+        SecretStoreMode::Keyring => format!(
+            "using secret store: keyring service={} account={} (profile {})",
+            info.service, info.account, info.profile
+        ),
+        SecretStoreMode::PassphraseEnv => {
+            "using secret store: passphrase from BASECAMP_CLI_PASSPHRASE".to_string()
+        }
+        SecretStoreMode::PassphrasePrompt => {
+            "using secret store: passphrase prompt (BASECAMP_CLI_SECRET_MODE=passphrase)"
+                .to_string()
+        }
+    };
+    eprintln!("{}", store_label.bright_black());
     eprintln!(
         "{}",
         format!("using secret file: {}", info.file_path.display()).bright_black()
@@ -192,6 +442,46 @@ pub fn print_secret_store_location() -> AppResult<()> {
     Ok(())
 }
 
+/// Locates the secret store for the active profile. Keyring entries are only
+/// probed in keyring mode; passphrase modes never touch the keyring.
+pub fn secrets_where() -> AppResult<SecretsWhereOutput> {
+    let config_dir = ensure_config_dir()?;
+    let store = secret_store()?;
+    let info = store.info()?;
+
+    let (mode, keyring) = match info.mode {
+        SecretStoreMode::Keyring => {
+            let exists = store.has_keyring_entry(&info.account)?;
+            let stale_account = if store.has_keyring_entry(&info.legacy_account)? {
+                Some(info.legacy_account)
+            } else {
+                None
+            };
+            (
+                "keyring",
+                Some(KeyringLocation {
+                    service: info.service,
+                    account: info.account,
+                    exists,
+                    stale_account,
+                }),
+            )
+        }
+        SecretStoreMode::PassphraseEnv => ("passphrase_env", None),
+        SecretStoreMode::PassphrasePrompt => ("passphrase_prompt", None),
+    };
+
+    Ok(SecretsWhereOutput {
+        ok: true,
+        profile: info.profile,
+        mode: mode.to_string(),
+        config_dir: config_dir.display().to_string(),
+        secrets_file_exists: info.file_path.exists(),
+        secrets_file: info.file_path.display().to_string(),
+        keyring,
+    })
+}
+
 fn pick_value(
     primary: Option<String>,
     secondary: Option<String>,
@@ -237,6 +527,13 @@ fn validate_redirect_uri(redirect_uri: &str) -> AppResult<()> {
     Ok(())
 }
 
+// Enough to tell two tokens apart, far too little to recover one.
+fn token_fingerprint(token: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(token.as_bytes()));
+    let short = digest.get(..8).unwrap_or(digest.as_str());
+    format!("sha256:{short}…")
+}
+
 fn redact_value(value: &str) -> String {
     let len = value.chars().count();
     if len <= 4 {
@@ -291,6 +588,141 @@ fn config_dir() -> AppResult<PathBuf> {
     ))
 }
 
+/// Launchpad endpoints, overridable for mock servers and corporate gateways.
+/// Per-endpoint values beat a base URL; env beats config.
+pub fn oauth_endpoints() -> AppResult<OAuthEndpoints> {
+    let config = load_config()?.oauth;
+
+    let base = pick_value(None, env_value(LAUNCHPAD_URL_ENV), config.launchpad_url);
+    let defaults = match base.as_deref() {
+        Some(base) => {
+            validate_endpoint_url("Launchpad URL", base)?;
+            OAuthEndpoints::from_base(base)
+        }
+        None => OAuthEndpoints::default(),
+    };
+
+    let resolve = |env_name: &str, configured: Option<String>, default: String, label: &str| {
+        match pick_value(None, env_value(env_name), configured) {
+            Some(value) => {
+                validate_endpoint_url(label, &value)?;
+                Ok(value)
+            }
+            None => Ok(default),
+        }
+    };
+
+    Ok(OAuthEndpoints {
+        auth_url: resolve(
+            OAUTH_AUTH_URL_ENV,
+            config.auth_url,
+            defaults.auth_url,
+            "OAuth authorization URL",
+        )?,
+        token_url: resolve(
+            OAUTH_TOKEN_URL_ENV,
+            config.token_url,
+            defaults.token_url,
+            "OAuth token URL",
+        )?,
+        authorization_url: resolve(
+            OAUTH_AUTHORIZATION_URL_ENV,
+            config.authorization_url,
+            defaults.authorization_url,
+            "authorization.json URL",
+        )?,
+    })
+}
+
+// Tokens and the client secret are sent to these URLs, so plain http is only
+// accepted for loopback mock servers.
+fn validate_endpoint_url(label: &str, value: &str) -> AppResult<()> {
+    let parsed = Url::parse(value)
+        .map_err(|err| AppError::invalid_input(format!("Invalid {label} \"{value}\": {err}")))?;
+    let loopback = matches!(parsed.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+
+    match parsed.scheme() {
+        "https" => Ok(()),
+        "http" if loopback => Ok(()),
+        _ => Err(AppError::invalid_input(format!(
+            "{label} must use https (http is allowed only for localhost): {value}"
+        ))),
+    }
+}
+
+/// Response cache for API clients. Cache data lives outside the config
+/// directory so backups and dotfile syncs skip it; an unresolvable cache
+/// directory disables caching rather than failing the command.
+/// Listing width: config `output.max_width` (`0` disables truncation), else
+/// `COLUMNS` when stdout is a terminal. `None` leaves listings untruncated.
+pub fn output_max_width() -> Option<usize> {
+    match load_config()
+        .ok()
+        .and_then(|config| config.output.max_width)
+    {
+        Some(0) => None,
+        Some(width) => Some(width),
+        None if io::stdout().is_terminal() => env_value("COLUMNS")?.parse().ok(),
+        None => None,
+    }
+}
+
+pub fn response_cache() -> AppResult<Option<ResponseCache>> {
+    if let Ok(path) = env::var(APP_CACHE_DIR_ENV)
+        && !path.trim().is_empty()
+    {
+        return Ok(Some(ResponseCache::new(PathBuf::from(path))));
+    }
+
+    if let Some(path) = load_config()?.cache.dir
+        && !path.trim().is_empty()
+    {
+        return Ok(Some(ResponseCache::new(PathBuf::from(path))));
+    }
+
+    Ok(platform_cache_dir().map(ResponseCache::new))
+}
+
+/// A Basecamp client for the current session, backed by the response cache.
+pub fn session_client() -> AppResult<BasecampClient> {
+    let session = resolve_session_context()?;
+    Ok(
+        BasecampClient::new(session.account_id, session.access_token)?
+            .with_cache(response_cache()?),
+    )
+}
+
+fn platform_cache_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(path) = env::var("LOCALAPPDATA") {
+            return Some(PathBuf::from(path).join(APP_NAME).join("cache"));
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(path) = env::var("HOME") {
+            return Some(
+                PathBuf::from(path)
+                    .join("Library")
+                    .join("Caches")
+                    .join(APP_NAME),
+            );
+        }
+    }
+
+    if let Ok(path) = env::var("XDG_CACHE_HOME")
+        && !path.is_empty()
+    {
+        return Some(PathBuf::from(path).join(APP_NAME));
+    }
+
+    env::var("HOME")
+        .ok()
+        .map(|path| PathBuf::from(path).join(".cache").join(APP_NAME))
+}
+
 fn ensure_config_dir() -> AppResult<PathBuf> {
     let dir = config_dir()?;
     fs::create_dir_all(&dir).map_err(|err| {
@@ -306,14 +738,34 @@ fn config_path() -> AppResult<PathBuf> {
     Ok(ensure_config_dir()?.join(CONFIG_FILE))
 }
 
-fn load_config() -> AppResult<AppConfig> {
+pub fn load_config() -> AppResult<AppConfig> {
     let path = config_path()?;
-    read_json_file(&path).map_err(|err| {
+    let mut config: AppConfig = read_json_file(&path).map_err(|err| {
         AppError::generic(format!("Failed to read config {}: {err}", path.display()))
-    })
+    })?;
+    migrate_pin_aliases(&mut config);
+    Ok(config)
 }
 
-fn save_config(config: &AppConfig) -> AppResult<()> {
+// Older configs kept `project pin --alias` names on the pin itself. They
+// resolved before `alias set` entries, so they win on a clash here too.
+fn migrate_pin_aliases(config: &mut AppConfig) {
+    for pin in &mut config.projects.pins {
+        if let Some(alias) = pin.legacy_alias.take() {
+            config.aliases.insert(
+                alias.to_ascii_lowercase(),
+                TargetAlias {
+                    project_id: pin.id,
+                    project_name: pin.name.clone(),
+                    todolist_id: None,
+                    todolist_name: None,
+                },
+            );
+        }
+    }
+}
+
+pub fn save_config(config: &AppConfig) -> AppResult<()> {
     let path = config_path()?;
     write_json_file(&path, config).map_err(|err| {
         AppError::generic(format!("Failed to write config {}: {err}", path.display()))
@@ -322,8 +774,40 @@ fn save_config(config: &AppConfig) -> AppResult<()> {
     Ok(())
 }
 
+pub fn load_state() -> AppResult<AppState> {
+    let path = ensure_config_dir()?.join(STATE_FILE);
+    read_json_file(&path)
+        .map_err(|err| AppError::generic(format!("Failed to read state {}: {err}", path.display())))
+}
+
+pub fn save_state(state: &AppState) -> AppResult<()> {
+    let path = ensure_config_dir()?.join(STATE_FILE);
+    write_json_file(&path, state).map_err(|err| {
+        AppError::generic(format!("Failed to write state {}: {err}", path.display()))
+    })?;
+    lock_down_permissions(&path, false)?;
+    Ok(())
+}
+
+/// Serializes load -> modify -> save of `config.json`, `state.json`, and the
+/// secrets file across concurrent CLI processes. Hold the guard for the whole sequence.
+pub fn lock_state() -> AppResult<FileLock> {
+    FileLock::acquire(&file_lock::lock_path(&config_path()?))
+}
+
 fn load_secrets() -> AppResult<SecretConfig> {
-    secret_store()?.load()
+    let secrets = secret_store()?.load()?;
+    for secret in [
+        &secrets.client_secret,
+        &secrets.access_token,
+        &secrets.refresh_token,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        redact::register_secret(secret);
+    }
+    Ok(secrets)
 }
 
 fn save_secrets(secrets: &SecretConfig) -> AppResult<()> {
@@ -331,7 +815,37 @@ fn save_secrets(secrets: &SecretConfig) -> AppResult<()> {
 }
 
 fn secret_store() -> AppResult<SecretStore> {
-    Ok(SecretStore::new(ensure_config_dir()?))
+    Ok(SecretStore::new(ensure_config_dir()?, profile_name()?)
+        .with_os_auth(load_config()?.security.require_os_auth))
+}
+
+// `BASECAMP_CLI_PROFILE` wins; a custom config dir is named after its last
+// path component; the platform default dir is the `default` profile.
+fn profile_name() -> AppResult<String> {
+    if let Some(profile) = env_value(PROFILE_ENV) {
+        let profile = profile.trim();
+        let valid = profile
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'));
+        if !valid {
+            return Err(AppError::invalid_input(format!(
+                "{PROFILE_ENV} must use letters, digits, `-`, `_`, or `.`."
+            )));
+        }
+        return Ok(profile.to_string());
+    }
+
+    if env_value(APP_CONFIG_DIR_ENV).is_some() {
+        let name = config_dir()?
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .filter(|name| !name.is_empty());
+        if let Some(name) = name {
+            return Ok(name);
+        }
+    }
+
+    Ok(DEFAULT_PROFILE.to_string())
 }
 
 fn read_json_file<T>(path: &Path) -> Result<T, String>
@@ -355,7 +869,16 @@ where
     T: serde::Serialize,
 {
     let serialized = serde_json::to_string_pretty(value).map_err(|err| err.to_string())?;
-    fs::write(path, format!("{serialized}\n")).map_err(|err| err.to_string())
+
+    // Write then rename so concurrent readers never see a partial file.
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(format!(".tmp-{}", std::process::id()));
+    let tmp_path = path.with_file_name(tmp_name);
+    fs::write(&tmp_path, format!("{serialized}\n")).map_err(|err| err.to_string())?;
+    fs::rename(&tmp_path, path).map_err(|err| {
+        let _ = fs::remove_file(&tmp_path);
+        err.to_string()
+    })
 }
 
 fn lock_down_permissions(path: &Path, _secure_storage: bool) -> AppResult<()> {
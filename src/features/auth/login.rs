@@ -1,11 +1,13 @@
+use crate::basecamp::client::BasecampClient;
 use crate::cli::LoginArgs;
 use crate::error::{AppError, AppResult};
-use crate::features::auth::callback::CallbackServer;
+use crate::features::auth::callback::{self, CallbackServer};
 use crate::features::auth::integration;
-use crate::features::auth::models::{LoginOutput, LoginOverrides, SessionData};
-use crate::features::auth::oauth::{self, Account};
-use std::io;
-use std::process::Command;
+use crate::features::auth::models::{LoginCheck, LoginOutput, LoginOverrides, SessionData};
+use crate::features::auth::oauth::{self, Account, OAuthEndpoints};
+use crate::ui::{SELECT_HELP_MESSAGE, can_prompt, open_browser, prompt_error};
+use colored::Colorize;
+use inquire::{Select, Text};
 use std::time::Duration;
 
 pub async fn run(args: LoginArgs) -> AppResult<LoginOutput> {
@@ -15,37 +17,98 @@ pub async fn run(args: LoginArgs) -> AppResult<LoginOutput> {
         redirect_uri: args.redirect_uri,
     };
 
-    let resolved = integration::resolve_login_credentials(overrides)?;
+    let mut resolved = integration::resolve_login_credentials(overrides)?;
+    if let Some(port) = args.callback_port {
+        resolved.redirect_uri = callback::with_port(&resolved.redirect_uri, port)?;
+    }
 
-    let callback_server = CallbackServer::bind(&resolved.redirect_uri, Duration::from_secs(180))?;
+    let callback_server = if args.manual {
+        if callback::uses_ephemeral_port(&resolved.redirect_uri) {
+            return Err(AppError::invalid_input(
+                "redirect_uri with port 0 needs the local callback server. Use a fixed port with --manual.",
+            ));
+        }
+        None
+    } else {
+        let success_body = callback::render_success_page(&integration::callback_page_settings()?)?;
+        Some(
+            CallbackServer::bind(
+                &resolved.redirect_uri,
+                success_body,
+                Duration::from_secs(args.timeout),
+            )
+            .await?,
+        )
+    };
 
+    let redirect_uri = match callback_server.as_ref() {
+        Some(server) => server.redirect_uri().to_string(),
+        None => resolved.redirect_uri,
+    };
+    let endpoints = integration::oauth_endpoints()?;
+    if endpoints != OAuthEndpoints::default() {
+        eprintln!(
+            "{}",
+            format!(
+                "using OAuth endpoints: authorize={} token={} authorization={}",
+                endpoints.auth_url, endpoints.token_url, endpoints.authorization_url
+            )
+            .bright_black()
+        );
+    }
     let oauth_client = oauth::build_client(
         resolved.client_id,
         resolved.client_secret,
-        resolved.redirect_uri,
+        redirect_uri,
+        &endpoints,
     )?;
 
     let (authorization_url, expected_state) = oauth::build_authorization_url(&oauth_client);
 
-    if args.no_browser {
-        println!("Open this URL to continue login:\n{authorization_url}");
-    } else if let Err(err) = open_browser(&authorization_url) {
-        eprintln!(
-            "Could not open browser automatically ({err}). Open this URL manually:\n{authorization_url}"
-        );
-    }
+    let (code, state) = match callback_server {
+        Some(callback_server) => {
+            if args.no_browser {
+                println!("Open this URL to continue login:\n{authorization_url}");
+            } else if let Err(err) = open_browser(&authorization_url) {
+                eprintln!(
+                    "Could not open browser automatically ({err}). Open this URL manually:\n{authorization_url}"
+                );
+            }
 
-    let callback = callback_server.wait_for_code()?;
+            let callback = callback_server.wait_for_code().await?;
+            (callback.code, Some(callback.state))
+        }
+        None => {
+            println!("Open this URL in any browser to continue login:\n{authorization_url}");
+            println!(
+                "After approving access, copy the URL your browser was redirected to (it may show a connection error) and paste it below."
+            );
 
-    if callback.state != expected_state {
+            let pasted = prompt_pasted_callback()?;
+            (pasted.code, pasted.state)
+        }
+    };
+
+    if let Some(state) = state
+        && state != expected_state
+    {
         return Err(AppError::oauth(
             "OAuth state mismatch. Aborting login for security.",
         ));
     }
 
-    let tokens = oauth::exchange_code(&oauth_client, callback.code).await?;
-    let authorization = oauth::fetch_authorization(&tokens.access_token).await?;
-    let account = select_account(authorization.accounts, args.account_id)?;
+    let tokens = oauth::exchange_code(&oauth_client, code).await?;
+    let authorization = oauth::fetch_authorization(&endpoints, &tokens.access_token).await?;
+    let account = select_account(
+        authorization.accounts,
+        args.account_id,
+        args.account_name.as_deref(),
+        args.no_browser || args.json,
+    )?;
+
+    let client = BasecampClient::new(account.id, tokens.access_token.clone())?
+        .with_cache(integration::response_cache()?)
+        .with_session_recovery(false);
 
     integration::save_session(SessionData {
         access_token: tokens.access_token,
@@ -55,14 +118,70 @@ pub async fn run(args: LoginArgs) -> AppResult<LoginOutput> {
         account_href: account.href,
     })?;
 
+    let checks = verify_api_access(&client).await;
+
     Ok(LoginOutput {
         ok: true,
         account_id: account.id,
         account_name: account.name,
+        checks,
     })
 }
 
-fn select_account(accounts: Vec<Account>, requested_account_id: Option<u64>) -> AppResult<Account> {
+// Failures are reported, not fatal: the session is already saved by now.
+async fn verify_api_access(client: &BasecampClient) -> Vec<LoginCheck> {
+    let profile_check = match client.fetch_my_profile().await {
+        Ok(profile) => LoginCheck {
+            name: "profile".to_string(),
+            ok: true,
+            message: format!(
+                "Profile readable as {} (person {}).",
+                profile.name, profile.id
+            ),
+        },
+        Err(err) => LoginCheck {
+            name: "profile".to_string(),
+            ok: false,
+            message: err.message,
+        },
+    };
+
+    let projects_check = match client.probe_projects().await {
+        Ok(0) => LoginCheck {
+            name: "projects".to_string(),
+            ok: false,
+            message: "No projects are visible to this account; to-do commands will have nothing to target.".to_string(),
+        },
+        Ok(_) => LoginCheck {
+            name: "projects".to_string(),
+            ok: true,
+            message: "Projects readable.".to_string(),
+        },
+        Err(err) => LoginCheck {
+            name: "projects".to_string(),
+            ok: false,
+            message: err.message,
+        },
+    };
+
+    vec![profile_check, projects_check]
+}
+
+fn prompt_pasted_callback() -> AppResult<callback::PastedCallback> {
+    let input = Text::new("Redirect URL or code")
+        .with_help_message("Paste the full redirect URL to verify the OAuth state.")
+        .prompt()
+        .map_err(|err| prompt_error("read redirect URL", err))?;
+
+    callback::parse_pasted_callback(&input)
+}
+
+fn select_account(
+    accounts: Vec<Account>,
+    requested_account_id: Option<u64>,
+    requested_account_name: Option<&str>,
+    non_interactive: bool,
+) -> AppResult<Account> {
     let mut bc3_accounts: Vec<Account> = accounts
         .into_iter()
         .filter(|account| account.product == "bc3")
@@ -85,84 +204,108 @@ fn select_account(accounts: Vec<Account>, requested_account_id: Option<u64>) ->
             });
     }
 
+    if let Some(account_name) = requested_account_name {
+        return select_account_by_name(bc3_accounts, account_name);
+    }
+
     if bc3_accounts.len() == 1 {
         return Ok(bc3_accounts.remove(0));
     }
 
-    prompt_for_account(bc3_accounts)
+    prompt_for_account(bc3_accounts, non_interactive)
 }
 
-fn prompt_for_account(accounts: Vec<Account>) -> AppResult<Account> {
-    println!("Multiple Basecamp accounts found. Select one:");
-    for (index, account) in accounts.iter().enumerate() {
-        println!("  {}. {} ({})", index + 1, account.name, account.id);
+// Tries exact, then substring, then in-order character matches on normalized
+// names; the first tier with any hits decides, and must have exactly one.
+fn select_account_by_name(mut accounts: Vec<Account>, requested: &str) -> AppResult<Account> {
+    let needle = normalize_account_name(requested);
+    if needle.is_empty() {
+        return Err(AppError::invalid_input("`--account-name` cannot be blank."));
     }
 
-    println!("Enter selection number:");
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .map_err(|err| AppError::generic(format!("Failed to read account selection: {err}")))?;
+    let names: Vec<String> = accounts
+        .iter()
+        .map(|account| normalize_account_name(&account.name))
+        .collect();
+    let tiers: [&dyn Fn(&str) -> bool; 3] = [
+        &|name| name == needle,
+        &|name| name.contains(&needle),
+        &|name| is_subsequence(&needle, name),
+    ];
 
-    let selection = input
-        .trim()
-        .parse::<usize>()
-        .map_err(|_| AppError::invalid_input("Invalid selection. Expected a number."))?;
+    for matches_tier in tiers {
+        let hits: Vec<usize> = (0..names.len())
+            .filter(|index| matches_tier(&names[*index]))
+            .collect();
 
-    if selection == 0 || selection > accounts.len() {
-        return Err(AppError::invalid_input("Selection out of range."));
+        match hits.as_slice() {
+            [] => continue,
+            [index] => return Ok(accounts.swap_remove(*index)),
+            _ => {
+                let candidates = hits
+                    .iter()
+                    .map(|index| format!("\"{}\" ({})", accounts[*index].name, accounts[*index].id))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                return Err(AppError::invalid_input(format!(
+                    "Account name \"{requested}\" is ambiguous: {candidates}. Use a more specific name or --account-id."
+                )));
+            }
+        }
     }
 
-    let index = selection - 1;
-    accounts
-        .into_iter()
-        .nth(index)
-        .ok_or_else(|| AppError::invalid_input("Selection out of range."))
+    let available = accounts
+        .iter()
+        .map(|account| format!("\"{}\" ({})", account.name, account.id))
+        .collect::<Vec<String>>()
+        .join(", ");
+    Err(AppError::no_account(format!(
+        "No accessible Basecamp account matched \"{requested}\". Available: {available}."
+    )))
 }
 
-fn open_browser(url: &str) -> Result<(), String> {
-    #[cfg(target_os = "macos")]
-    {
-        let status = Command::new("open")
-            .arg(url)
-            .status()
-            .map_err(|err| err.to_string())?;
-        if status.success() {
-            Ok(())
-        } else {
-            Err(format!("open exited with status {status}"))
-        }
-    }
+fn normalize_account_name(value: &str) -> String {
+    value
+        .chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        let status = Command::new("xdg-open")
-            .arg(url)
-            .status()
-            .map_err(|err| err.to_string())?;
-        if status.success() {
-            Ok(())
-        } else {
-            Err(format!("xdg-open exited with status {status}"))
-        }
-    }
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut remaining = haystack.chars();
+    needle
+        .chars()
+        .all(|expected| remaining.any(|candidate| candidate == expected))
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        let status = Command::new("cmd")
-            .args(["/C", "start", "", url])
-            .status()
-            .map_err(|err| err.to_string())?;
-        if status.success() {
-            Ok(())
-        } else {
-            Err(format!("cmd start exited with status {status}"))
-        }
+// `--json` and `--no-browser` runs are scripted, so they never block on a picker.
+fn prompt_for_account(mut accounts: Vec<Account>, non_interactive: bool) -> AppResult<Account> {
+    if non_interactive || !can_prompt() {
+        let available = accounts
+            .iter()
+            .map(|account| format!("{} ({})", account.id, account.name))
+            .collect::<Vec<String>>()
+            .join(", ");
+        return Err(AppError::invalid_input(format!(
+            "Multiple Basecamp accounts found. Re-run with --account-id or --account-name. Available: {available}."
+        )));
     }
 
-    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
-    {
-        let _ = url;
-        Err("Unsupported platform for automatic browser launch.".to_string())
+    let labels: Vec<String> = accounts
+        .iter()
+        .map(|account| format!("{} ({})", account.name, account.id))
+        .collect();
+
+    let selection = Select::new("Basecamp account", labels)
+        .with_help_message(SELECT_HELP_MESSAGE)
+        .with_starting_cursor(0)
+        .raw_prompt()
+        .map(|selection| selection.index)
+        .map_err(|err| prompt_error("select account", err))?;
+
+    if selection >= accounts.len() {
+        return Err(AppError::invalid_input("Account selection out of range."));
     }
+    Ok(accounts.swap_remove(selection))
 }
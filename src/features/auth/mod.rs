@@ -1,8 +1,14 @@
+pub mod account_override;
 pub mod callback;
+pub mod file_lock;
+pub mod inspect;
 pub mod integration;
 pub mod login;
 pub mod logout;
 pub mod models;
 pub mod oauth;
+pub mod os_auth;
 pub mod secret_store;
+pub mod session_recovery;
+pub mod transfer;
 pub mod whoami;
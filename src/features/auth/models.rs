@@ -1,9 +1,259 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct AppConfig {
     pub integration: IntegrationConfig,
     pub session: SessionConfig,
+    #[serde(default)]
+    pub projects: ProjectsConfig,
+    #[serde(default)]
+    pub callback_page: CallbackPageConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub oauth: OAuthEndpointsConfig,
+    /// Named person groups usable as `@team` in `--assignee`/`--notify`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub teams: BTreeMap<String, Vec<String>>,
+    /// Named projects and to-do lists usable as `@name` wherever an id is
+    /// expected; keys are stored lowercase.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, TargetAlias>,
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+    #[serde(default)]
+    pub templates: TemplatesConfig,
+    /// Schedules for `todo recur run`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recurring: Vec<RecurringTodo>,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub security: SecurityConfig,
+}
+
+/// Local command history kept in `state.json`, separate from `config.json`
+/// so that it never travels with `integration export`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AppState {
+    /// The most recent `todo complete`/`todo re-open` run, for `todo undo`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_batch: Option<TodoBatch>,
+    /// Answers from an interactive `todo add` whose create request failed,
+    /// for `todo add --resume`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub todo_draft: Option<TodoDraft>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoDraft {
+    pub account_id: u64,
+    /// Unix seconds.
+    pub saved_at: u64,
+    pub project_id: u64,
+    pub project_name: String,
+    /// The list or group the to-do goes in.
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub content: String,
+    /// Markdown, as typed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignee_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completion_subscriber_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoBatch {
+    pub action: TodoBatchAction,
+    pub account_id: u64,
+    /// Unix seconds.
+    pub recorded_at: u64,
+    pub todos: Vec<TodoBatchItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TodoBatchAction {
+    Complete,
+    ReOpen,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoBatchItem {
+    pub project_id: u64,
+    pub todo_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecurityConfig {
+    /// Ask the OS to re-authenticate the user before stored secrets are decrypted.
+    #[serde(default)]
+    pub require_os_auth: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputConfig {
+    /// Column limit for titles and names in listings; `0` disables truncation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<usize>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TemplatesConfig {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub todos: BTreeMap<String, TodoTemplate>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lists: BTreeMap<String, ListTemplate>,
+}
+
+/// Snapshot of a to-do list's structure for `list template apply`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub todos: Vec<ListTemplateTodo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<ListTemplateGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTemplateGroup {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub todos: Vec<ListTemplateTodo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTemplateTodo {
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Days after the earliest due date in the snapshot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_offset_days: Option<i64>,
+}
+
+/// A to-do that `todo recur run` creates on every matching day. The target
+/// is stored by id, with names kept for display.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTodo {
+    pub id: u32,
+    pub account_id: u64,
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Normalized `--every` value, e.g. `monday`, `mon,thu`, `weekday`, or `15th`.
+    pub every: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<String>,
+    /// First day an instance may be due (`YYYY-MM-DD`).
+    pub starts_on: String,
+    /// Due date of the last instance created (`YYYY-MM-DD`). Kept here rather
+    /// than in `state.json` so an exported config does not repeat instances.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_created_on: Option<String>,
+}
+
+/// Reusable `todo add` blueprint; `content` and `notes` may hold placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoTemplate {
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<String>,
+    /// Due date relative to the day the template is applied, e.g. `3d` or `1w`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_in: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DefaultsConfig {
+    /// People references notified on completion when `todo add` gets none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notify_on_complete: Vec<String>,
+    /// Project `todo add` targets when `--project-id` is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+    /// List inside `project_id` that `todo add` targets when
+    /// `--todolist-id` is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub todolist_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OAuthEndpointsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launchpad_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CallbackPageConfig {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub template_path: Option<String>,
+    #[serde(default)]
+    pub auto_close_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetAlias {
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub todolist_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub todolist_name: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectsConfig {
+    #[serde(default)]
+    pub pins: Vec<PinnedProject>,
+}
+
+/// A pinned project, listed first in pickers. Pins only order projects;
+/// their `@name`s live in `AppConfig::aliases`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedProject {
+    pub id: u64,
+    pub name: String,
+    /// Pin alias from older configs; moved into `aliases` when loaded.
+    #[serde(default, rename = "alias", skip_serializing)]
+    pub legacy_alias: Option<String>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -27,6 +277,30 @@ pub struct SecretConfig {
     pub refresh_token: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntegrationBundle {
+    pub version: u8,
+    pub integration: BundleIntegration,
+    #[serde(default)]
+    pub session: Option<BundleSession>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleIntegration {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleSession {
+    pub account_id: u64,
+    pub account_name: String,
+    pub account_href: String,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolvedIntegration {
     pub client_id: String,
@@ -34,7 +308,7 @@ pub struct ResolvedIntegration {
     pub redirect_uri: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct LoginOverrides {
     pub client_id: Option<String>,
     pub client_secret: Option<String>,
@@ -68,6 +342,14 @@ pub struct LoginOutput {
     pub ok: bool,
     pub account_id: u64,
     pub account_name: String,
+    pub checks: Vec<LoginCheck>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginCheck {
+    pub name: String,
+    pub ok: bool,
+    pub message: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -89,6 +371,95 @@ pub struct WhoamiOutput {
     pub client: Option<bool>,
     pub employee: Option<bool>,
     pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Vec<WhoamiAccount>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WhoamiAccount {
+    pub id: u64,
+    pub name: String,
+    pub product: String,
+    pub href: String,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthInspectOutput {
+    pub ok: bool,
+    pub expires_at: Option<String>,
+    pub identity: Option<AuthInspectIdentity>,
+    pub selected_account_id: u64,
+    pub accounts: Vec<AuthInspectAccount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthInspectIdentity {
+    pub id: u64,
+    pub name: Option<String>,
+    pub email_address: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthInspectAccount {
+    pub id: u64,
+    pub name: String,
+    pub product: String,
+    pub href: String,
+    pub app_href: Option<String>,
+    pub selected: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IntegrationExportOutput {
+    pub ok: bool,
+    pub path: String,
+    pub encrypted: bool,
+    pub includes_session: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SessionShowOutput {
+    pub ok: bool,
+    /// `login` for the stored session, `environment` for `BASECAMP_ACCESS_TOKEN`.
+    pub source: String,
+    pub account_id: Option<u64>,
+    pub account_name: Option<String>,
+    pub account_href: Option<String>,
+    pub updated_at: Option<String>,
+    pub access_token_fingerprint: Option<String>,
+    pub refresh_token_fingerprint: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SecretsWhereOutput {
+    pub ok: bool,
+    pub profile: String,
+    pub mode: String,
+    pub config_dir: String,
+    pub secrets_file: String,
+    pub secrets_file_exists: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyring: Option<KeyringLocation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KeyringLocation {
+    pub service: String,
+    pub account: String,
+    pub exists: bool,
+    /// Pre-profile entry for the same config dir, when it is still present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_account: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IntegrationImportOutput {
+    pub ok: bool,
+    pub path: String,
+    pub encrypted: bool,
+    pub account_id: Option<u64>,
+    pub account_name: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -2,16 +2,19 @@ use crate::error::{
     AppError, AppResult, OAUTH_FORBIDDEN_MESSAGE, OAUTH_UNAUTHORIZED_MESSAGE, OAuthStatusMessages,
     oauth_error_from_status,
 };
-use oauth2::basic::BasicClient;
+use crate::redact;
+use crate::telemetry;
+use oauth2::basic::{BasicClient, BasicErrorResponseType};
 use oauth2::{
     AuthType, AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, RedirectUrl,
-    RefreshToken, TokenResponse, TokenUrl,
+    RefreshToken, RequestTokenError, TokenResponse, TokenUrl,
 };
 use serde::Deserialize;
 
-const AUTH_URL: &str = "https://launchpad.37signals.com/authorization/new";
-const TOKEN_URL: &str = "https://launchpad.37signals.com/authorization/token";
-const AUTHORIZATION_JSON_URL: &str = "https://launchpad.37signals.com/authorization.json";
+pub const LAUNCHPAD_URL: &str = "https://launchpad.37signals.com";
+const AUTH_PATH: &str = "authorization/new";
+const TOKEN_PATH: &str = "authorization/token";
+const AUTHORIZATION_JSON_PATH: &str = "authorization.json";
 const USER_AGENT: &str = concat!(
     env!("CARGO_PKG_NAME"),
     "/",
@@ -19,6 +22,31 @@ const USER_AGENT: &str = concat!(
     " (+https://github.com/basecamp/bc3-api)"
 );
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthEndpoints {
+    pub auth_url: String,
+    pub token_url: String,
+    pub authorization_url: String,
+}
+
+impl OAuthEndpoints {
+    /// Endpoints under a Launchpad-compatible base URL (mock servers, gateways).
+    pub fn from_base(base_url: &str) -> Self {
+        let base = base_url.trim_end_matches('/');
+        Self {
+            auth_url: format!("{base}/{AUTH_PATH}"),
+            token_url: format!("{base}/{TOKEN_PATH}"),
+            authorization_url: format!("{base}/{AUTHORIZATION_JSON_PATH}"),
+        }
+    }
+}
+
+impl Default for OAuthEndpoints {
+    fn default() -> Self {
+        Self::from_base(LAUNCHPAD_URL)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TokenBundle {
     pub access_token: String,
@@ -27,9 +55,25 @@ pub struct TokenBundle {
 
 #[derive(Debug, Deserialize)]
 pub struct AuthorizationEnvelope {
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    #[serde(default)]
+    pub identity: Option<Identity>,
     pub accounts: Vec<Account>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct Identity {
+    #[serde(deserialize_with = "deserialize_account_id")]
+    pub id: u64,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub email_address: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Account {
     #[serde(deserialize_with = "deserialize_account_id")]
@@ -37,6 +81,8 @@ pub struct Account {
     pub name: String,
     pub href: String,
     pub product: String,
+    #[serde(default)]
+    pub app_href: Option<String>,
 }
 
 type OAuthClient = BasicClient<
@@ -51,11 +97,12 @@ pub fn build_client(
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    endpoints: &OAuthEndpoints,
 ) -> AppResult<OAuthClient> {
-    let auth_url = AuthUrl::new(AUTH_URL.to_string()).map_err(|err| {
+    let auth_url = AuthUrl::new(endpoints.auth_url.clone()).map_err(|err| {
         AppError::invalid_input(format!("Invalid OAuth authorization URL: {err}"))
     })?;
-    let token_url = TokenUrl::new(TOKEN_URL.to_string())
+    let token_url = TokenUrl::new(endpoints.token_url.clone())
         .map_err(|err| AppError::invalid_input(format!("Invalid OAuth token URL: {err}")))?;
     let redirect = RedirectUrl::new(redirect_uri)
         .map_err(|err| AppError::invalid_input(format!("Invalid redirect_uri: {err}")))?;
@@ -91,6 +138,8 @@ pub async fn exchange_code(client: &OAuthClient, code: String) -> AppResult<Toke
         .refresh_token()
         .map(|token| token.secret().to_string())
         .ok_or_else(|| AppError::oauth("OAuth token response did not include refresh_token."))?;
+    redact::register_secret(&access_token);
+    redact::register_secret(&refresh_token);
 
     Ok(TokenBundle {
         access_token,
@@ -98,42 +147,61 @@ pub async fn exchange_code(client: &OAuthClient, code: String) -> AppResult<Toke
     })
 }
 
-#[allow(dead_code)]
+/// Returns `Ok(None)` when Launchpad rejects the refresh token
+/// (`invalid_grant`), meaning only a new login can restore the session.
 pub async fn refresh_access_token(
     client: &OAuthClient,
     refresh_token: String,
-) -> AppResult<TokenBundle> {
+) -> AppResult<Option<TokenBundle>> {
     let http_client = reqwest::Client::builder()
         .redirect(reqwest::redirect::Policy::none())
         .build()
         .map_err(|err| AppError::oauth(format!("Failed to build OAuth HTTP client: {err}")))?;
 
-    let token_response = client
+    let token_response = match client
         .exchange_refresh_token(&RefreshToken::new(refresh_token))
         .request_async(&http_client)
         .await
-        .map_err(|err| AppError::oauth(format!("OAuth token refresh failed: {err}")))?;
+    {
+        Ok(token_response) => token_response,
+        Err(RequestTokenError::ServerResponse(response))
+            if *response.error() == BasicErrorResponseType::InvalidGrant =>
+        {
+            return Ok(None);
+        }
+        Err(err) => {
+            return Err(AppError::oauth(format!(
+                "OAuth token refresh failed: {err}"
+            )));
+        }
+    };
 
     let access_token = token_response.access_token().secret().to_string();
     let refresh_token = token_response
         .refresh_token()
         .map(|token| token.secret().to_string())
         .ok_or_else(|| AppError::oauth("OAuth refresh response did not include refresh_token."))?;
+    redact::register_secret(&access_token);
+    redact::register_secret(&refresh_token);
 
-    Ok(TokenBundle {
+    Ok(Some(TokenBundle {
         access_token,
         refresh_token,
-    })
+    }))
 }
 
-pub async fn fetch_authorization(access_token: &str) -> AppResult<AuthorizationEnvelope> {
+pub async fn fetch_authorization(
+    endpoints: &OAuthEndpoints,
+    access_token: &str,
+) -> AppResult<AuthorizationEnvelope> {
+    telemetry::record_api_call();
     let client = reqwest::Client::builder()
         .user_agent(USER_AGENT)
         .build()
         .map_err(|err| AppError::generic(format!("Failed to build HTTP client: {err}")))?;
 
     let response = client
-        .get(AUTHORIZATION_JSON_URL)
+        .get(&endpoints.authorization_url)
         .bearer_auth(access_token)
         .send()
         .await
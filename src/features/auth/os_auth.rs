@@ -0,0 +1,65 @@
+use crate::error::{AppError, AppResult};
+#[cfg(target_os = "linux")]
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// polkit action installed from `packaging/polkit/`; it asks for the user's
+/// own password (`auth_self`), not an administrator's.
+#[cfg(target_os = "linux")]
+const POLKIT_ACTION: &str = "com.github.mkv27.basecamp-cli.unlock-secrets";
+
+// One confirmation covers the whole command, including a session-recovery rerun.
+static CONFIRMED: AtomicBool = AtomicBool::new(false);
+
+/// Asks the OS to re-authenticate the user before stored tokens are decrypted.
+/// Fails closed: a dismissed prompt or an unsupported platform is an error.
+pub fn confirm() -> AppResult<()> {
+    if CONFIRMED.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    run_prompt()?;
+    CONFIRMED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+// An authorization check for this process only: `pkcheck` asks the session's
+// polkit agent to confirm the user, and nothing is run with elevated rights.
+#[cfg(target_os = "linux")]
+fn run_prompt() -> AppResult<()> {
+    let status = Command::new("pkcheck")
+        .args([
+            "--action-id",
+            POLKIT_ACTION,
+            "--process",
+            &std::process::id().to_string(),
+            "--allow-user-interaction",
+        ])
+        .status()
+        .map_err(|err| {
+            AppError::secure_storage(format!(
+                "security.require_os_auth is enabled, but pkcheck could not be started: {err}"
+            ))
+        })?;
+
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(2) => Err(AppError::secure_storage(
+            "security.require_os_auth is enabled, but no polkit authentication agent is running; stored secrets stay locked.",
+        )),
+        Some(3) => Err(AppError::secure_storage(
+            "OS authentication was not confirmed; stored secrets stay locked.",
+        )),
+        _ => Err(AppError::secure_storage(format!(
+            "OS authentication failed; stored secrets stay locked. Check that the polkit action {POLKIT_ACTION} is installed (see packaging/polkit/)."
+        ))),
+    }
+}
+
+// Touch ID needs LocalAuthentication, which waits on an approved dependency.
+#[cfg(not(target_os = "linux"))]
+fn run_prompt() -> AppResult<()> {
+    Err(AppError::secure_storage(
+        "security.require_os_auth is enabled, but OS re-authentication is only supported on Linux (polkit) so far.",
+    ))
+}
@@ -1,5 +1,8 @@
 use crate::error::{AppError, AppResult};
+use crate::features::auth::file_lock::{self, FileLock};
 use crate::features::auth::models::SecretConfig;
+use crate::features::auth::os_auth;
+use crate::ui::{can_prompt, prompt_error};
 use age::decrypt;
 use age::encrypt;
 use age::scrypt::Identity as ScryptIdentity;
@@ -8,12 +11,16 @@ use age::secrecy::ExposeSecret;
 use age::secrecy::SecretString;
 use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use inquire::Password;
+use inquire::validator::Validation;
 use keyring::Entry;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::env;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::sync::atomic::{Ordering, compiler_fence};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -21,19 +28,42 @@ const KEYRING_SERVICE: &str = "basecamp-cli";
 const SECRETS_DIR: &str = "secrets";
 const SECRETS_FILE: &str = "local.age";
 const SECRETS_VERSION: u8 = 1;
+// Upgrade steps applied in order to older secrets files; the step at index
+// `n` turns a version `n + 1` document into version `n + 2`. Append a step
+// whenever `SecretConfig` changes shape and bump `SECRETS_VERSION` with it.
+const SECRETS_MIGRATIONS: &[SecretsMigration] = &[];
+const PASSPHRASE_ENV: &str = "BASECAMP_CLI_PASSPHRASE";
+const SECRET_MODE_ENV: &str = "BASECAMP_CLI_SECRET_MODE";
+
+// Prompted passphrases are cached so one command asks at most once.
+static PROMPTED_PASSPHRASE: OnceLock<SecretString> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretStoreMode {
+    Keyring,
+    PassphraseEnv,
+    PassphrasePrompt,
+}
 
 #[derive(Debug, Clone)]
 pub struct SecretStoreInfo {
+    pub mode: SecretStoreMode,
+    pub profile: String,
     pub service: String,
     pub account: String,
+    pub legacy_account: String,
     pub file_path: PathBuf,
 }
 
 #[derive(Debug, Clone)]
 pub struct SecretStore {
     config_dir: PathBuf,
+    profile: String,
+    require_os_auth: bool,
 }
 
+type SecretsMigration = fn(&mut serde_json::Value) -> AppResult<()>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct EncryptedSecretsFile {
     version: u8,
@@ -41,15 +71,44 @@ struct EncryptedSecretsFile {
 }
 
 impl SecretStore {
-    pub fn new(config_dir: PathBuf) -> Self {
-        Self { config_dir }
+    pub fn new(config_dir: PathBuf, profile: String) -> Self {
+        Self {
+            config_dir,
+            profile,
+            require_os_auth: false,
+        }
     }
 
-    pub fn info(&self) -> SecretStoreInfo {
-        SecretStoreInfo {
+    pub fn with_os_auth(mut self, require_os_auth: bool) -> Self {
+        self.require_os_auth = require_os_auth;
+        self
+    }
+
+    pub fn info(&self) -> AppResult<SecretStoreInfo> {
+        Ok(SecretStoreInfo {
+            mode: resolve_mode()?,
+            profile: self.profile.clone(),
             service: KEYRING_SERVICE.to_string(),
             account: self.keyring_account(),
+            legacy_account: self.legacy_keyring_account(),
             file_path: self.secrets_path(),
+        })
+    }
+
+    /// Checks for a keyring entry without creating it or printing its value.
+    pub fn has_keyring_entry(&self, account: &str) -> AppResult<bool> {
+        let entry = Entry::new(KEYRING_SERVICE, account).map_err(|err| {
+            AppError::secure_storage(format!(
+                "Failed to initialize keyring entry (service={KEYRING_SERVICE}, account={account}): {err}"
+            ))
+        })?;
+
+        match entry.get_password() {
+            Ok(_) => Ok(true),
+            Err(keyring::Error::NoEntry) => Ok(false),
+            Err(err) => Err(AppError::secure_storage(format!(
+                "Failed to read keyring entry (service={KEYRING_SERVICE}, account={account}): {err}"
+            ))),
         }
     }
 
@@ -66,29 +125,82 @@ impl SecretStore {
             ))
         })?;
 
-        let passphrase = self.load_or_create_passphrase()?;
+        if self.require_os_auth {
+            os_auth::confirm()?;
+        }
+        let passphrase = self.resolve_passphrase(false)?;
         let plaintext = decrypt_with_passphrase(&ciphertext, &passphrase)?;
-        let parsed: EncryptedSecretsFile = serde_json::from_slice(&plaintext).map_err(|err| {
+        let mut document: serde_json::Value =
+            serde_json::from_slice(&plaintext).map_err(|err| {
+                AppError::secure_storage(format!(
+                    "Failed to decode decrypted secret file {}: {err}",
+                    path.display()
+                ))
+            })?;
+
+        let version = document
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|version| u8::try_from(version).ok())
+            .filter(|version| *version >= 1)
+            .ok_or_else(|| {
+                AppError::secure_storage(format!(
+                    "Secrets file {} has no valid version.",
+                    path.display()
+                ))
+            })?;
+        if version > SECRETS_VERSION {
+            return Err(AppError::secure_storage(format!(
+                "Secrets file version {version} is newer than supported version {SECRETS_VERSION}."
+            )));
+        }
+
+        if version < SECRETS_VERSION {
+            migrate_secrets_document(&mut document, version)?;
+        }
+
+        let parsed: EncryptedSecretsFile = serde_json::from_value(document).map_err(|err| {
             AppError::secure_storage(format!(
                 "Failed to decode decrypted secret file {}: {err}",
                 path.display()
             ))
         })?;
 
-        if parsed.version > SECRETS_VERSION {
-            return Err(AppError::secure_storage(format!(
-                "Secrets file version {} is newer than supported version {}.",
-                parsed.version, SECRETS_VERSION
-            )));
+        if version < SECRETS_VERSION {
+            self.backup_secrets_file(&ciphertext, version)?;
+            self.save(&parsed.secrets)?;
         }
 
         Ok(parsed.secrets)
     }
 
+    // Keeps the pre-migration ciphertext, still readable by older builds
+    // with the same passphrase, next to the upgraded file.
+    fn backup_secrets_file(&self, ciphertext: &[u8], version: u8) -> AppResult<()> {
+        let backup_path = self
+            .secrets_dir()
+            .join(format!("{SECRETS_FILE}.v{version}.bak"));
+        if backup_path.exists() {
+            return Ok(());
+        }
+
+        fs::write(&backup_path, ciphertext).map_err(|err| {
+            AppError::secure_storage(format!(
+                "Failed to back up secret file to {}: {err}",
+                backup_path.display()
+            ))
+        })?;
+        set_secure_file_permissions(&backup_path)
+    }
+
     pub fn save(&self, secrets: &SecretConfig) -> AppResult<()> {
         self.ensure_secrets_dir()?;
 
-        let passphrase = self.load_or_create_passphrase()?;
+        // Also covers first-run passphrase creation, so two processes cannot
+        // each generate a keyring passphrase and encrypt with different keys.
+        let _lock = FileLock::acquire(&file_lock::lock_path(&self.secrets_path()))?;
+        let creating = !self.secrets_path().exists();
+        let passphrase = self.resolve_passphrase(creating)?;
         let payload = EncryptedSecretsFile {
             version: SECRETS_VERSION,
             secrets: secrets.clone(),
@@ -106,6 +218,25 @@ impl SecretStore {
         Ok(())
     }
 
+    fn resolve_passphrase(&self, creating: bool) -> AppResult<SecretString> {
+        match resolve_mode()? {
+            SecretStoreMode::Keyring => self.load_or_create_passphrase(),
+            SecretStoreMode::PassphraseEnv => env::var(PASSPHRASE_ENV)
+                .map(SecretString::from)
+                .map_err(|err| {
+                    AppError::secure_storage(format!("Failed to read {PASSPHRASE_ENV}: {err}"))
+                }),
+            SecretStoreMode::PassphrasePrompt => {
+                if let Some(passphrase) = PROMPTED_PASSPHRASE.get() {
+                    return Ok(passphrase.clone());
+                }
+
+                let passphrase = prompt_passphrase(creating)?;
+                Ok(PROMPTED_PASSPHRASE.get_or_init(|| passphrase).clone())
+            }
+        }
+    }
+
     fn load_or_create_passphrase(&self) -> AppResult<SecretString> {
         let account = self.keyring_account();
         let entry = Entry::new(KEYRING_SERVICE, &account).map_err(|err| {
@@ -117,7 +248,11 @@ impl SecretStore {
         match entry.get_password() {
             Ok(password) => Ok(SecretString::from(password)),
             Err(keyring::Error::NoEntry) => {
-                let generated = generate_passphrase()?;
+                let legacy = self.load_legacy_passphrase()?;
+                let generated = match legacy.as_ref() {
+                    Some((_, passphrase)) => passphrase.clone(),
+                    None => generate_passphrase()?,
+                };
                 entry
                     .set_password(generated.expose_secret())
                     .map_err(|err| {
@@ -125,6 +260,9 @@ impl SecretStore {
                             "Failed to persist keyring secret (service={KEYRING_SERVICE}, account={account}): {err}"
                         ))
                     })?;
+                if let Some((legacy_entry, _)) = legacy {
+                    let _ = legacy_entry.delete_credential();
+                }
                 Ok(generated)
             }
             Err(err) => Err(AppError::secure_storage(format!(
@@ -133,6 +271,24 @@ impl SecretStore {
         }
     }
 
+    // Entries written before profile naming are keyed by a hash of the config
+    // dir. Their passphrase moves to the named entry; the old entry is removed
+    // once the new one is saved, and left for `secrets where` if that fails.
+    fn load_legacy_passphrase(&self) -> AppResult<Option<(Entry, SecretString)>> {
+        let account = self.legacy_keyring_account();
+        let Ok(entry) = Entry::new(KEYRING_SERVICE, &account) else {
+            return Ok(None);
+        };
+
+        match entry.get_password() {
+            Ok(password) => Ok(Some((entry, SecretString::from(password)))),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(err) => Err(AppError::secure_storage(format!(
+                "Failed to load keyring secret (service={KEYRING_SERVICE}, account={account}): {err}"
+            ))),
+        }
+    }
+
     fn ensure_secrets_dir(&self) -> AppResult<()> {
         let dir = self.secrets_dir();
         fs::create_dir_all(&dir).map_err(|err| {
@@ -153,30 +309,113 @@ impl SecretStore {
         self.secrets_dir().join(SECRETS_FILE)
     }
 
+    // `secrets|<profile>|<config dir>`: readable in keychain UIs, and still
+    // unique when two config dirs share a profile name.
     fn keyring_account(&self) -> String {
-        let canonical = self
-            .config_dir
-            .canonicalize()
-            .unwrap_or_else(|_| self.config_dir.clone())
-            .to_string_lossy()
-            .into_owned();
+        format!("secrets|{}|{}", self.profile, self.canonical_config_dir())
+    }
 
+    fn legacy_keyring_account(&self) -> String {
         let mut hasher = Sha256::new();
-        hasher.update(canonical.as_bytes());
+        hasher.update(self.canonical_config_dir().as_bytes());
         let digest = hasher.finalize();
         let hex = format!("{digest:x}");
         let short = hex.get(..16).unwrap_or(hex.as_str());
         format!("secrets|{short}")
     }
+
+    fn canonical_config_dir(&self) -> String {
+        self.config_dir
+            .canonicalize()
+            .unwrap_or_else(|_| self.config_dir.clone())
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+fn migrate_secrets_document(document: &mut serde_json::Value, from_version: u8) -> AppResult<()> {
+    for (index, migrate) in SECRETS_MIGRATIONS
+        .iter()
+        .enumerate()
+        .skip(usize::from(from_version) - 1)
+    {
+        migrate(document)?;
+        let upgraded_to = u8::try_from(index + 2).map_err(|_| {
+            AppError::secure_storage("Secrets migration table exceeds supported versions.")
+        })?;
+        if let Some(fields) = document.as_object_mut() {
+            fields.insert("version".to_string(), serde_json::Value::from(upgraded_to));
+        }
+    }
+
+    let reached = document.get("version").and_then(serde_json::Value::as_u64);
+    if reached != Some(u64::from(SECRETS_VERSION)) {
+        return Err(AppError::secure_storage(format!(
+            "No migration path from secrets file version {from_version} to {SECRETS_VERSION}."
+        )));
+    }
+
+    Ok(())
+}
+
+fn resolve_mode() -> AppResult<SecretStoreMode> {
+    if env::var(PASSPHRASE_ENV).is_ok_and(|value| !value.is_empty()) {
+        return Ok(SecretStoreMode::PassphraseEnv);
+    }
+
+    match env::var(SECRET_MODE_ENV).ok().as_deref().map(str::trim) {
+        None | Some("") | Some("keyring") => Ok(SecretStoreMode::Keyring),
+        Some("passphrase") => Ok(SecretStoreMode::PassphrasePrompt),
+        Some(other) => Err(AppError::invalid_input(format!(
+            "Unsupported {SECRET_MODE_ENV} value \"{other}\". Use keyring or passphrase."
+        ))),
+    }
+}
+
+fn prompt_passphrase(creating: bool) -> AppResult<SecretString> {
+    if !can_prompt() {
+        return Err(AppError::secure_storage(format!(
+            "Passphrase mode needs an interactive terminal. Set {PASSPHRASE_ENV} for non-interactive use."
+        )));
+    }
+
+    prompt_new_or_existing_passphrase("Secrets passphrase", creating)
+}
+
+/// Hidden, non-empty passphrase input labelled `label` (e.g. `Export
+/// passphrase`). A new passphrase (`confirm`) is typed twice.
+pub(crate) fn prompt_new_or_existing_passphrase(
+    label: &str,
+    confirm: bool,
+) -> AppResult<SecretString> {
+    let lowercase = label.to_lowercase();
+    let confirmation = format!("Confirm {lowercase}");
+    let prompt = Password::new(label).with_validator(|value: &str| {
+        if value.is_empty() {
+            Ok(Validation::Invalid("Passphrase is required.".into()))
+        } else {
+            Ok(Validation::Valid)
+        }
+    });
+    let prompt = if confirm {
+        prompt.with_custom_confirmation_message(&confirmation)
+    } else {
+        prompt.without_confirmation()
+    };
+
+    prompt
+        .prompt()
+        .map(SecretString::from)
+        .map_err(|err| prompt_error(&format!("read {lowercase}"), err))
 }
 
-fn encrypt_with_passphrase(plaintext: &[u8], passphrase: &SecretString) -> AppResult<Vec<u8>> {
+pub fn encrypt_with_passphrase(plaintext: &[u8], passphrase: &SecretString) -> AppResult<Vec<u8>> {
     let recipient = ScryptRecipient::new(passphrase.clone());
     encrypt(&recipient, plaintext)
         .map_err(|err| AppError::secure_storage(format!("Failed to encrypt secret data: {err}")))
 }
 
-fn decrypt_with_passphrase(ciphertext: &[u8], passphrase: &SecretString) -> AppResult<Vec<u8>> {
+pub fn decrypt_with_passphrase(ciphertext: &[u8], passphrase: &SecretString) -> AppResult<Vec<u8>> {
     let identity = ScryptIdentity::new(passphrase.clone());
     decrypt(&identity, ciphertext)
         .map_err(|err| AppError::secure_storage(format!("Failed to decrypt secret data: {err}")))
@@ -0,0 +1,104 @@
+use crate::cli::LoginArgs;
+use crate::error::AppResult;
+use crate::features::auth::models::LoginOverrides;
+use crate::features::auth::{integration, login, oauth};
+use crate::ui::{can_prompt, prompt_error};
+use colored::Colorize;
+use inquire::Confirm;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Registered with the Basecamp client, which calls it after a 401 and
+/// retries the rejected request with the token it returns.
+pub fn recover_token() -> Pin<Box<dyn Future<Output = AppResult<Option<String>>> + Send>> {
+    Box::pin(async {
+        if !recover().await? {
+            return Ok(None);
+        }
+        Ok(Some(integration::resolve_session_context()?.access_token))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshOutcome {
+    Refreshed,
+    /// Launchpad rejected the refresh token; only a new login helps.
+    Rejected,
+    /// Nothing to refresh with: env token, no login, or no integration credentials.
+    Unavailable,
+}
+
+/// Exchanges the stored refresh token for new tokens and saves them.
+pub async fn refresh_stored_tokens() -> AppResult<RefreshOutcome> {
+    let Some(refresh_token) = integration::stored_refresh_token()? else {
+        return Ok(RefreshOutcome::Unavailable);
+    };
+    let Ok(credentials) = integration::resolve_login_credentials(LoginOverrides::default()) else {
+        return Ok(RefreshOutcome::Unavailable);
+    };
+
+    let endpoints = integration::oauth_endpoints()?;
+    let client = oauth::build_client(
+        credentials.client_id,
+        credentials.client_secret,
+        credentials.redirect_uri,
+        &endpoints,
+    )?;
+
+    match oauth::refresh_access_token(&client, refresh_token).await? {
+        Some(tokens) => {
+            integration::save_session_tokens(tokens)?;
+            Ok(RefreshOutcome::Refreshed)
+        }
+        None => Ok(RefreshOutcome::Rejected),
+    }
+}
+
+/// Restores the stored session after a 401 so the request can be retried.
+///
+/// Refreshes the access token first. When Launchpad rejects the refresh
+/// token and a terminal is attached, offers to run `login` inline. Returns
+/// `false` when the session was left unchanged.
+async fn recover() -> AppResult<bool> {
+    match refresh_stored_tokens().await? {
+        RefreshOutcome::Refreshed => {
+            eprintln!("{}", "Access token refreshed.".bright_black());
+            return Ok(true);
+        }
+        RefreshOutcome::Unavailable => return Ok(false),
+        RefreshOutcome::Rejected => {}
+    }
+
+    if !can_prompt() {
+        return Ok(false);
+    }
+
+    let relogin = Confirm::new("Session expired — log in again now?")
+        .with_default(true)
+        .prompt()
+        .map_err(|err| prompt_error("confirm login", err))?;
+    if !relogin {
+        return Ok(false);
+    }
+
+    let output = login::run(LoginArgs {
+        account_id: integration::load_config()?.session.account_id,
+        account_name: None,
+        no_browser: false,
+        manual: false,
+        timeout: 180,
+        callback_port: None,
+        json: false,
+        client_id: None,
+        client_secret: None,
+        redirect_uri: None,
+    })
+    .await?;
+    eprintln!(
+        "{} {}",
+        "Logged in again.".green(),
+        format!("(account: {} {})", output.account_name, output.account_id).bright_black()
+    );
+
+    Ok(true)
+}
@@ -0,0 +1,139 @@
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::{
+    IntegrationBundle, IntegrationExportOutput, IntegrationImportOutput,
+};
+use crate::features::auth::secret_store::{
+    decrypt_with_passphrase, encrypt_with_passphrase, prompt_new_or_existing_passphrase,
+};
+use crate::ui::can_prompt;
+use age::secrecy::SecretString;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+const EXPORT_PASSPHRASE_ENV: &str = "BASECAMP_CLI_EXPORT_PASSPHRASE";
+const AGE_HEADER: &[u8] = b"age-encryption.org/v1";
+
+pub fn export(
+    path: &Path,
+    encrypt: bool,
+    include_session: bool,
+    force: bool,
+) -> AppResult<IntegrationExportOutput> {
+    let bundle = integration::export_bundle(include_session)?;
+    let includes_session = bundle.session.is_some();
+
+    let plaintext = serde_json::to_vec_pretty(&bundle)
+        .map_err(|err| AppError::generic(format!("Failed to serialize export: {err}")))?;
+    let contents = if encrypt {
+        let passphrase = resolve_passphrase(true)?;
+        encrypt_with_passphrase(&plaintext, &passphrase)?
+    } else {
+        plaintext
+    };
+
+    write_export_file(path, &contents, force)?;
+
+    Ok(IntegrationExportOutput {
+        ok: true,
+        path: path.display().to_string(),
+        encrypted: encrypt,
+        includes_session,
+    })
+}
+
+pub fn import(path: &Path) -> AppResult<IntegrationImportOutput> {
+    let contents = fs::read(path).map_err(|err| {
+        AppError::invalid_input(format!(
+            "Failed to read export file {}: {err}",
+            path.display()
+        ))
+    })?;
+
+    let encrypted = contents.starts_with(AGE_HEADER);
+    let plaintext = if encrypted {
+        let passphrase = resolve_passphrase(false)?;
+        decrypt_with_passphrase(&contents, &passphrase)?
+    } else {
+        contents
+    };
+
+    let bundle: IntegrationBundle = serde_json::from_slice(&plaintext).map_err(|err| {
+        AppError::invalid_input(format!(
+            "Failed to decode export file {}: {err}",
+            path.display()
+        ))
+    })?;
+
+    let account_id = bundle.session.as_ref().map(|session| session.account_id);
+    let account_name = bundle
+        .session
+        .as_ref()
+        .map(|session| session.account_name.clone());
+
+    integration::import_bundle(bundle)?;
+
+    Ok(IntegrationImportOutput {
+        ok: true,
+        path: path.display().to_string(),
+        encrypted,
+        account_id,
+        account_name,
+    })
+}
+
+fn resolve_passphrase(confirm: bool) -> AppResult<SecretString> {
+    if let Ok(value) = env::var(EXPORT_PASSPHRASE_ENV)
+        && !value.is_empty()
+    {
+        return Ok(SecretString::from(value));
+    }
+
+    if !can_prompt() {
+        return Err(AppError::invalid_input(format!(
+            "An export passphrase is required. Set {EXPORT_PASSPHRASE_ENV} for non-interactive use."
+        )));
+    }
+
+    prompt_new_or_existing_passphrase("Export passphrase", confirm)
+}
+
+pub(crate) fn write_export_file(path: &Path, contents: &[u8], force: bool) -> AppResult<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true);
+    if force {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path).map_err(|err| {
+        if err.kind() == io::ErrorKind::AlreadyExists {
+            AppError::invalid_input(format!(
+                "{} already exists. Pass --force to overwrite it.",
+                path.display()
+            ))
+        } else {
+            AppError::generic(format!(
+                "Failed to create export file {}: {err}",
+                path.display()
+            ))
+        }
+    })?;
+
+    file.write_all(contents).map_err(|err| {
+        AppError::generic(format!(
+            "Failed to write export file {}: {err}",
+            path.display()
+        ))
+    })
+}
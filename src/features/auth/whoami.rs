@@ -1,12 +1,36 @@
 use crate::basecamp::client::BasecampClient;
+use crate::cli::WhoamiArgs;
 use crate::error::AppResult;
 use crate::features::auth::integration;
-use crate::features::auth::models::WhoamiOutput;
+use crate::features::auth::models::{WhoamiAccount, WhoamiOutput};
+use crate::features::auth::oauth;
 
-pub async fn run() -> AppResult<WhoamiOutput> {
+pub async fn run(args: &WhoamiArgs) -> AppResult<WhoamiOutput> {
     let session = integration::resolve_session_context()?;
-    let client = BasecampClient::new(session.account_id, session.access_token.clone())?;
-    let profile = client.fetch_my_profile().await?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let (profile, accounts) = if args.all_accounts {
+        let endpoints = integration::oauth_endpoints()?;
+        let (profile, authorization) = tokio::try_join!(
+            client.fetch_my_profile(),
+            oauth::fetch_authorization(&endpoints, &session.access_token)
+        )?;
+        let accounts = authorization
+            .accounts
+            .into_iter()
+            .map(|account| WhoamiAccount {
+                active: account.id == session.account_id && account.product == "bc3",
+                id: account.id,
+                name: account.name,
+                product: account.product,
+                href: account.href,
+            })
+            .collect();
+        (profile, Some(accounts))
+    } else {
+        (client.fetch_my_profile().await?, None)
+    };
 
     Ok(WhoamiOutput {
         ok: true,
@@ -21,5 +45,6 @@ pub async fn run() -> AppResult<WhoamiOutput> {
         client: profile.client,
         employee: profile.employee,
         time_zone: profile.time_zone,
+        accounts,
     })
 }
@@ -0,0 +1,143 @@
+//! `campfire list/send`: a project's chat, mostly as a place for scripts to
+//! report to the team ("deploy done").
+
+use crate::basecamp::models::{CreateCampfireLinePayload, Project};
+use crate::cli::{CampfireListArgs, CampfireSendArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::notes::resolve_notes_input;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct CampfireListOutput {
+    pub ok: bool,
+    pub campfires: Vec<CampfireSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CampfireSummary {
+    pub campfire_id: u64,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CampfireSendOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub campfire_id: u64,
+    pub line_id: u64,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_list(args: CampfireListArgs) -> AppResult<CampfireListOutput> {
+    let client = integration::session_client()?;
+    let campfires: Vec<CampfireSummary> = match args.project.as_deref() {
+        Some(reference) => {
+            let project = lookup::resolve_project(&client, reference).await?;
+            project_campfires(&project)
+                .map(|(campfire_id, title)| CampfireSummary {
+                    campfire_id,
+                    title,
+                    project_id: Some(project.id),
+                    project_name: Some(project.name.clone()),
+                })
+                .collect()
+        }
+        None => client
+            .list_campfires()
+            .await?
+            .into_iter()
+            .map(|campfire| CampfireSummary {
+                campfire_id: campfire.id,
+                title: campfire.title,
+                project_id: campfire.bucket.as_ref().map(|bucket| bucket.id),
+                project_name: campfire.bucket.map(|bucket| bucket.name),
+            })
+            .collect(),
+    };
+    let count = campfires.len();
+
+    Ok(CampfireListOutput {
+        ok: true,
+        campfires,
+        count,
+    })
+}
+
+pub async fn run_send(args: CampfireSendArgs) -> AppResult<CampfireSendOutput> {
+    let text = args.text.join(" ");
+    let content = resolve_notes_input(Some(text), None)?
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    if content.is_empty() {
+        return Err(AppError::invalid_input("Campfire line cannot be blank."));
+    }
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let campfire_id = choose_campfire(&project, args.campfire)?;
+    let line = client
+        .create_campfire_line(
+            project.id,
+            campfire_id,
+            &CreateCampfireLinePayload { content },
+        )
+        .await?;
+
+    Ok(CampfireSendOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        campfire_id,
+        line_id: line.id,
+        content: line.content,
+        app_url: line.app_url,
+    })
+}
+
+/// The project's campfire, or the one `--campfire` names. A project with
+/// several needs `--campfire`, so a line never lands in the wrong room.
+pub(super) fn choose_campfire(project: &Project, requested: Option<u64>) -> AppResult<u64> {
+    let campfires: Vec<(u64, String)> = project_campfires(project).collect();
+    match (requested, campfires.as_slice()) {
+        (Some(id), _) if campfires.iter().any(|(campfire_id, _)| *campfire_id == id) => Ok(id),
+        (Some(id), _) => Err(AppError::no_account(format!(
+            "Project \"{}\" has no campfire {id}.",
+            project.name
+        ))),
+        (None, []) => Err(AppError::no_account(format!(
+            "Project \"{}\" has no Campfire turned on.",
+            project.name
+        ))),
+        (None, [(id, _)]) => Ok(*id),
+        (None, _) => {
+            let choices: Vec<String> = campfires
+                .iter()
+                .map(|(id, title)| format!("{id} ({title})"))
+                .collect();
+            Err(AppError::invalid_input(format!(
+                "Project \"{}\" has several campfires; pick one with --campfire: {}.",
+                project.name,
+                choices.join(", ")
+            )))
+        }
+    }
+}
+
+fn project_campfires(project: &Project) -> impl Iterator<Item = (u64, String)> + '_ {
+    project
+        .dock
+        .iter()
+        .filter(|item| item.name == "chat" && item.enabled)
+        .map(|item| (item.id, item.title.clone()))
+}
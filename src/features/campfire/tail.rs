@@ -0,0 +1,135 @@
+//! `campfire tail`: recent chat lines, then with `--follow` new ones as the
+//! campfire is polled. Polls revalidate by ETag, so a quiet room costs little.
+
+use super::chat::choose_campfire;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::CampfireLine;
+use crate::cli::CampfireTailArgs;
+use crate::dates;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use colored::Colorize;
+use serde::Serialize;
+use std::time::Duration;
+
+/// One line as printed by `--json`, one object per output line.
+#[derive(Debug, Serialize)]
+struct TailLine<'a> {
+    project_id: u64,
+    campfire_id: u64,
+    line_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_at: Option<&'a str>,
+    content: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_url: Option<&'a str>,
+}
+
+pub async fn run(args: CampfireTailArgs) -> AppResult<()> {
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let campfire_id = choose_campfire(&project, args.campfire)?;
+    let printer = Printer {
+        project_id: project.id,
+        campfire_id,
+        json: args.json,
+    };
+
+    let lines = recent_lines(&client, project.id, campfire_id).await?;
+    let mut last_seen = lines.last().map_or(0, |line| line.id);
+    for line in &lines[lines.len().saturating_sub(args.lines)..] {
+        printer.print(line)?;
+    }
+    if !args.follow {
+        return Ok(());
+    }
+
+    eprintln!(
+        "{}",
+        format!(
+            "Following the campfire in \"{}\" every {}s; Ctrl-C to stop.",
+            project.name, args.interval
+        )
+        .bright_black()
+    );
+    let mut ticker = tokio::time::interval(Duration::from_secs(args.interval));
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+
+        // A failed poll (network blip, rate limit) should not end an
+        // incident watch; the next tick tries again.
+        let lines = match recent_lines(&client, project.id, campfire_id).await {
+            Ok(lines) => lines,
+            Err(err) => {
+                eprintln!("{}", format!("Poll failed: {}", err.message).yellow());
+                continue;
+            }
+        };
+        let seen = last_seen;
+        for line in lines.iter().filter(|line| line.id > seen) {
+            printer.print(line)?;
+            last_seen = line.id;
+        }
+    }
+}
+
+// Oldest first, so new lines print at the bottom.
+async fn recent_lines(
+    client: &BasecampClient,
+    project_id: u64,
+    campfire_id: u64,
+) -> AppResult<Vec<CampfireLine>> {
+    let mut lines = client.list_campfire_lines(project_id, campfire_id).await?;
+    lines.sort_by_key(|line| line.id);
+    Ok(lines)
+}
+
+struct Printer {
+    project_id: u64,
+    campfire_id: u64,
+    json: bool,
+}
+
+impl Printer {
+    fn print(&self, line: &CampfireLine) -> AppResult<()> {
+        let author = line.creator.as_ref().map(|creator| creator.name.as_str());
+        if self.json {
+            let rendered = serde_json::to_string(&TailLine {
+                project_id: self.project_id,
+                campfire_id: self.campfire_id,
+                line_id: line.id,
+                author,
+                created_at: line.created_at.as_deref(),
+                content: &line.content,
+                app_url: line.app_url.as_deref(),
+            })
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+            println!("{rendered}");
+            return Ok(());
+        }
+
+        let created_at = line
+            .created_at
+            .as_deref()
+            .map(dates::format_timestamp)
+            .unwrap_or_default();
+        let mut content = line.content.trim().lines();
+        println!(
+            "{} {} {}",
+            created_at.bright_black(),
+            format!("{}:", author.unwrap_or("Someone")).bold(),
+            content.next().unwrap_or_default()
+        );
+        for rest in content {
+            println!("  {rest}");
+        }
+        Ok(())
+    }
+}
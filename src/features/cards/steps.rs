@@ -0,0 +1,260 @@
+//! `card step add/complete`: the checklist steps inside a card. Steps are
+//! read from the card itself, so completing them works like `todo complete`:
+//! by reference, `--all`, or a prompt over the card's open steps.
+
+use crate::basecamp::dry_run;
+use crate::basecamp::models::{CardStep, CreateCardStepPayload, TodoAssignee};
+use crate::cli::{CardStepAddArgs, CardStepCompleteArgs};
+use crate::dates;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::people::resolve_people_references;
+use crate::time_zone;
+use crate::ui::{MULTISELECT_HELP_MESSAGE, can_prompt, prompt_error};
+use inquire::MultiSelect;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct CardStepAddOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub card_id: u64,
+    pub card_title: String,
+    #[serde(flatten)]
+    pub step: StepSummary,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardStepCompleteOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub card_id: u64,
+    pub card_title: String,
+    /// `true` for `--reopen`.
+    pub reopened: bool,
+    pub steps: Vec<StepSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StepSummary {
+    pub step_id: u64,
+    pub title: String,
+    pub completed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+}
+
+impl From<CardStep> for StepSummary {
+    fn from(step: CardStep) -> Self {
+        Self {
+            step_id: step.id,
+            title: step.title,
+            completed: step.completed,
+            due_on: step.due_on,
+            assignees: step
+                .assignees
+                .into_iter()
+                .map(|assignee| assignee.name)
+                .collect(),
+        }
+    }
+}
+
+pub async fn run_add(args: CardStepAddArgs) -> AppResult<CardStepAddOutput> {
+    let title = args.title.trim().to_string();
+    if title.is_empty() {
+        return Err(AppError::invalid_input("Step title cannot be blank."));
+    }
+
+    let client = integration::session_client()?;
+    let (project_id, card_id) =
+        lookup::resolve_recording(&client, &args.card, args.project.as_deref(), lookup::CARD)
+            .await?;
+    let due_on = match args.due_on.as_deref() {
+        Some(due_on) => {
+            let today = time_zone::profile_today(&client).await?;
+            Some(dates::parse_todo_date(due_on, today, "due date")?.to_string())
+        }
+        None => None,
+    };
+    let assignees: Vec<TodoAssignee> = if args.assignees.is_empty() {
+        Vec::new()
+    } else {
+        let people = client.list_project_people(project_id).await?;
+        let ids = resolve_people_references(&args.assignees, &people, "--assignee")?;
+        people
+            .into_iter()
+            .filter(|person| ids.contains(&person.id))
+            .map(|person| TodoAssignee {
+                id: person.id,
+                name: person.name,
+            })
+            .collect()
+    };
+
+    let card = client.get_card(project_id, card_id).await?;
+    let payload = CreateCardStepPayload {
+        title,
+        due_on,
+        assignees: (!assignees.is_empty()).then(|| {
+            assignees
+                .iter()
+                .map(|assignee| assignee.id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        }),
+    };
+    let mut step = client
+        .create_card_step(project_id, card_id, &payload)
+        .await?;
+    // A dry run gets no response, so it reports who was asked for.
+    if dry_run::enabled() {
+        step.assignees = assignees;
+    }
+
+    Ok(CardStepAddOutput {
+        ok: true,
+        project_id,
+        card_id,
+        card_title: card.title,
+        step: StepSummary::from(step),
+    })
+}
+
+pub async fn run_complete(args: CardStepCompleteArgs) -> AppResult<CardStepCompleteOutput> {
+    let client = integration::session_client()?;
+    let (project_id, card_id) =
+        lookup::resolve_recording(&client, &args.card, args.project.as_deref(), lookup::CARD)
+            .await?;
+    let card = client.get_card(project_id, card_id).await?;
+
+    // Completing picks among open steps; `--reopen` among completed ones.
+    let candidates: Vec<CardStep> = card
+        .steps
+        .into_iter()
+        .filter(|step| step.completed == args.reopen)
+        .collect();
+    let state = if args.reopen { "completed" } else { "open" };
+    if candidates.is_empty() {
+        return Err(AppError::no_account(format!(
+            "Card \"{}\" has no {state} steps.",
+            card.title
+        )));
+    }
+
+    let selections = if args.all {
+        (0..candidates.len()).collect()
+    } else if args.steps.is_empty() {
+        if !can_prompt() {
+            return Err(AppError::invalid_input(
+                "`basecamp-cli card step complete` without --step or --all requires an interactive terminal for prompts.",
+            ));
+        }
+        let selections = prompt_select_steps(&candidates)?;
+        if selections.is_empty() {
+            return Err(AppError::invalid_input(
+                "Select at least one step to change.",
+            ));
+        }
+        selections
+    } else {
+        select_steps(&candidates, &args.steps, state)?
+    };
+
+    // In card order, whatever order they were picked in.
+    let mut steps = Vec::with_capacity(selections.len());
+    for (index, mut step) in candidates.into_iter().enumerate() {
+        if !selections.contains(&index) {
+            continue;
+        }
+        client
+            .set_card_step_completion(project_id, step.id, !args.reopen)
+            .await?;
+        step.completed = !args.reopen;
+        steps.push(StepSummary::from(step));
+    }
+    let count = steps.len();
+
+    Ok(CardStepCompleteOutput {
+        ok: true,
+        project_id,
+        card_id,
+        card_title: card.title,
+        reopened: args.reopen,
+        steps,
+        count,
+    })
+}
+
+// Each reference is a step id or part of one step's title (case-insensitive);
+// a title fragment that fits several steps is an error rather than a guess.
+fn select_steps(
+    candidates: &[CardStep],
+    references: &[String],
+    state: &str,
+) -> AppResult<Vec<usize>> {
+    let mut selections = Vec::new();
+    for reference in references {
+        let reference = reference.trim();
+        let by_id = reference
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| candidates.iter().position(|step| step.id == id));
+        let index = match by_id {
+            Some(index) => index,
+            None => {
+                let needle = reference.to_lowercase();
+                let matches: Vec<usize> = candidates
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, step)| step.title.to_lowercase().contains(&needle))
+                    .map(|(index, _)| index)
+                    .collect();
+                match matches.as_slice() {
+                    [index] => *index,
+                    [] => {
+                        return Err(AppError::no_account(format!(
+                            "No {state} step matches \"{reference}\"."
+                        )));
+                    }
+                    _ => {
+                        let titles: Vec<&str> = matches
+                            .iter()
+                            .map(|index| candidates[*index].title.as_str())
+                            .collect();
+                        return Err(AppError::invalid_input(format!(
+                            "\"{reference}\" matches several steps: {}. Use a longer title or the step id.",
+                            titles.join(", ")
+                        )));
+                    }
+                }
+            }
+        };
+        if !selections.contains(&index) {
+            selections.push(index);
+        }
+    }
+    Ok(selections)
+}
+
+fn prompt_select_steps(candidates: &[CardStep]) -> AppResult<Vec<usize>> {
+    let labels: Vec<String> = candidates
+        .iter()
+        .map(|step| match step.due_on.as_deref() {
+            Some(due_on) => format!("{} (due {due_on})", step.title),
+            None => step.title.clone(),
+        })
+        .collect();
+    let selections = MultiSelect::new("Steps", labels)
+        .with_help_message(MULTISELECT_HELP_MESSAGE)
+        .raw_prompt()
+        .map_err(|err| prompt_error("select steps", err))?;
+    Ok(selections
+        .into_iter()
+        .map(|selection| selection.index)
+        .collect())
+}
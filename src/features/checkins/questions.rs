@@ -0,0 +1,272 @@
+//! `checkin list/answers/answer`: a project's Automatic Check-ins. Answers
+//! are filed under a day (`group_on`), so answering late still lands on the
+//! day that was asked about.
+
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{
+    CreateQuestionAnswerPayload, Question, QuestionAnswer, QuestionSchedule,
+};
+use crate::cli::{CheckinAnswerArgs, CheckinAnswersArgs, CheckinListArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::notes::resolve_notes_input;
+use crate::rich_text::{html_to_text, markdown_to_html};
+use crate::time_zone;
+use crate::ui;
+use serde::Serialize;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+#[derive(Debug, Serialize)]
+pub struct CheckinListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub questions: Vec<QuestionSummary>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuestionSummary {
+    pub question_id: u64,
+    pub title: String,
+    /// e.g. `every week on Mon, Wed at 16:30`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    pub paused: bool,
+    pub answers_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckinAnswersOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub question_id: u64,
+    pub since: String,
+    pub answers: Vec<AnswerSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnswerSummary {
+    pub answer_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckinAnswerOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub question_id: u64,
+    /// What `--from-completed` added to the answer.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub completed_todos: Vec<String>,
+    #[serde(flatten)]
+    pub answer: AnswerSummary,
+}
+
+impl From<Question> for QuestionSummary {
+    fn from(question: Question) -> Self {
+        Self {
+            question_id: question.id,
+            title: question.title,
+            schedule: question.schedule.as_ref().map(describe_schedule),
+            paused: question.paused,
+            answers_count: question.answers_count,
+            app_url: question.app_url,
+        }
+    }
+}
+
+impl From<QuestionAnswer> for AnswerSummary {
+    fn from(answer: QuestionAnswer) -> Self {
+        Self {
+            answer_id: answer.id,
+            author: answer.creator.map(|creator| creator.name),
+            group_on: answer.group_on,
+            created_at: answer.created_at,
+            content: html_to_text(&answer.content),
+            app_url: answer.app_url,
+        }
+    }
+}
+
+pub async fn run_list(args: CheckinListArgs) -> AppResult<CheckinListOutput> {
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let questionnaire_id = lookup::dock_tool_id(&project, "questionnaire", "Automatic Check-ins")?;
+    let questions = client
+        .list_questions(project.id, questionnaire_id)
+        .await?
+        .into_iter()
+        .map(QuestionSummary::from)
+        .collect();
+
+    Ok(CheckinListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        questions,
+    })
+}
+
+pub async fn run_answers(args: CheckinAnswersArgs) -> AppResult<CheckinAnswersOutput> {
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let since = dates::parse_since(&args.since, "--since", today)?.to_string();
+    let (project_id, question_id) = lookup::resolve_recording(
+        &client,
+        &args.question,
+        args.project.as_deref(),
+        lookup::QUESTION,
+    )
+    .await?;
+
+    let answers: Vec<AnswerSummary> = client
+        .list_question_answers(project_id, question_id, &since)
+        .await?
+        .into_iter()
+        .filter(|answer| {
+            answer
+                .group_on
+                .as_deref()
+                .is_none_or(|group_on| group_on >= since.as_str())
+        })
+        .map(AnswerSummary::from)
+        .collect();
+    let count = answers.len();
+
+    Ok(CheckinAnswersOutput {
+        ok: true,
+        project_id,
+        question_id,
+        since,
+        answers,
+        count,
+    })
+}
+
+pub async fn run_answer(args: CheckinAnswerArgs) -> AppResult<CheckinAnswerOutput> {
+    let typed = resolve_notes_input(args.content, args.content_file)?;
+    if typed.is_none() && !args.from_completed && !ui::can_prompt() {
+        return Err(AppError::invalid_input(
+            "No terminal for $EDITOR; pass --content, --content-file, or --from-completed.",
+        ));
+    }
+
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let date = match args.date.as_deref() {
+        Some(date) => dates::parse_since(date, "--date", today)?,
+        None => today,
+    };
+    let (project_id, question_id) = lookup::resolve_recording(
+        &client,
+        &args.question,
+        args.project.as_deref(),
+        lookup::QUESTION,
+    )
+    .await?;
+
+    let completed_todos = if args.from_completed {
+        completed_on(&client, date).await?
+    } else {
+        Vec::new()
+    };
+    let completed_list: String = completed_todos
+        .iter()
+        .map(|todo| format!("- {todo}\n"))
+        .collect();
+
+    let content = match (typed, completed_list.is_empty()) {
+        (Some(typed), true) => typed,
+        (Some(typed), false) => format!("{}\n\n{completed_list}", typed.trim_end()),
+        (None, false) => completed_list,
+        (None, true) if args.from_completed => {
+            return Err(AppError::invalid_input(format!(
+                "No to-dos completed on {date}; nothing to answer with."
+            )));
+        }
+        (None, true) => ui::edit_text("")
+            .map_err(|err| AppError::generic(format!("Editing the answer failed: {err}")))?,
+    };
+    if content.trim().is_empty() {
+        return Err(AppError::invalid_input(
+            "Answer is empty; nothing was posted.",
+        ));
+    }
+
+    let answer = client
+        .create_question_answer(
+            project_id,
+            question_id,
+            &CreateQuestionAnswerPayload {
+                content: markdown_to_html(&content),
+                group_on: date.to_string(),
+            },
+        )
+        .await?;
+
+    Ok(CheckinAnswerOutput {
+        ok: true,
+        project_id,
+        question_id,
+        completed_todos,
+        answer: AnswerSummary::from(answer),
+    })
+}
+
+// From the account's progress feed, by the day Basecamp stamps each
+// completion with; each entry is `<to-do> (<project>)`.
+async fn completed_on(client: &BasecampClient, date: Date) -> AppResult<Vec<String>> {
+    let day = date.to_string();
+    let (me, events) =
+        tokio::try_join!(client.fetch_my_profile(), client.list_progress_events(&day))?;
+
+    let mut completed: Vec<String> = events
+        .into_iter()
+        .filter(|event| {
+            event.kind == "todo_completed"
+                && event.created_at.get(..10) == Some(day.as_str())
+                && event
+                    .creator
+                    .as_ref()
+                    .is_some_and(|creator| creator.id == me.id)
+        })
+        .map(|event| match event.bucket {
+            Some(bucket) if !bucket.name.is_empty() => format!("{} ({})", event.title, bucket.name),
+            _ => event.title,
+        })
+        .collect();
+    // The feed is newest first; an update reads better in the order the work
+    // was done.
+    completed.reverse();
+    completed.dedup();
+    Ok(completed)
+}
+
+fn describe_schedule(schedule: &QuestionSchedule) -> String {
+    let mut description = schedule.frequency.replace('_', " ");
+    if schedule.frequency != "every_day" && !schedule.days.is_empty() {
+        let days: Vec<&str> = schedule
+            .days
+            .iter()
+            .filter_map(|day| WEEKDAYS.get(*day as usize).copied())
+            .collect();
+        description.push_str(&format!(" on {}", days.join(", ")));
+    }
+    description.push_str(&format!(" at {:02}:{:02}", schedule.hour, schedule.minute));
+    description
+}
@@ -0,0 +1,116 @@
+//! `comment list/add`: comments on any recording (to-do, message, document,
+//! upload, ...). Basecamp serves them all from one endpoint, so the command
+//! never needs to know what kind of recording it is.
+
+use crate::basecamp::models::CreateCommentPayload;
+use crate::cli::{CommentAddArgs, CommentListArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::notes::resolve_notes_input;
+use crate::features::uploads::file;
+use crate::rich_text::{html_to_text, markdown_to_html};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct CommentListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub recording_id: u64,
+    pub comments: Vec<RecordingComment>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecordingComment {
+    pub comment_id: u64,
+    pub author: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommentAddOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub recording_id: u64,
+    pub comment_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_list(args: CommentListArgs) -> AppResult<CommentListOutput> {
+    let client = integration::session_client()?;
+    let (project_id, recording_id) = lookup::resolve_recording(
+        &client,
+        &args.recording,
+        args.project.as_deref(),
+        lookup::RECORDING,
+    )
+    .await?;
+
+    let comments: Vec<RecordingComment> = client
+        .list_comments(project_id, recording_id)
+        .await?
+        .into_iter()
+        .map(|comment| RecordingComment {
+            comment_id: comment.id,
+            author: comment
+                .creator
+                .map(|creator| creator.name)
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            created_at: comment.created_at,
+            content: html_to_text(&comment.content),
+            app_url: comment.app_url,
+        })
+        .collect();
+    let count = comments.len();
+
+    Ok(CommentListOutput {
+        ok: true,
+        project_id,
+        recording_id,
+        comments,
+        count,
+    })
+}
+
+pub async fn run_add(args: CommentAddArgs) -> AppResult<CommentAddOutput> {
+    // Checked before any request so a missing comment never costs a lookup.
+    let content = resolve_notes_input(args.content, args.content_file)?
+        .filter(|content| !content.trim().is_empty());
+    if content.is_none() && args.attachments.is_empty() {
+        return Err(AppError::invalid_input("Comment text cannot be blank."));
+    }
+    for path in &args.attachments {
+        file::ensure_file(path)?;
+    }
+
+    let client = integration::session_client()?;
+    let (project_id, recording_id) = lookup::resolve_recording(
+        &client,
+        &args.recording,
+        args.project.as_deref(),
+        lookup::RECORDING,
+    )
+    .await?;
+    let attached = file::attach_files(&client, &args.attachments).await?;
+    let content = file::append_embeds(content.map(|content| markdown_to_html(&content)), &attached)
+        .unwrap_or_default();
+    let comment = client
+        .create_comment(project_id, recording_id, &CreateCommentPayload { content })
+        .await?;
+
+    Ok(CommentAddOutput {
+        ok: true,
+        project_id,
+        recording_id,
+        comment_id: comment.id,
+        app_url: comment.app_url,
+    })
+}
@@ -0,0 +1,301 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::daemon::{DaemonRequest, DaemonStatus, FreshResponse};
+use crate::cli::DaemonArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::session_recovery::{self, RefreshOutcome};
+use crate::features::todos::picker::resolve_todoset_id;
+use colored::Colorize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// Basecamp access tokens last two weeks; refreshing at half-life keeps a
+// daemon-backed machine from ever meeting an expired one.
+const TOKEN_REFRESH_AFTER: Duration = Duration::from_secs(7 * 86_400);
+
+struct DaemonState {
+    /// Keyed by (`daemon::session_key`, URL): only the session that warmed
+    /// a response may skip revalidating it.
+    fresh: HashMap<(String, String), Instant>,
+    freshness: Duration,
+    // Bumped by every invalidation, so a refresh that overlapped a write
+    // does not mark its pre-write responses fresh.
+    generation: u64,
+    status: DaemonStatus,
+}
+
+type SharedState = Arc<Mutex<DaemonState>>;
+
+/// Runs in the foreground until Ctrl-C or SIGTERM; supervise it with launchd or systemd.
+#[cfg(unix)]
+pub async fn run(args: DaemonArgs) -> AppResult<()> {
+    let cache = integration::response_cache()?.ok_or_else(|| {
+        AppError::invalid_input(
+            "The daemon needs a response cache directory. Set BASECAMP_CLI_CACHE_DIR or `cache.dir`.",
+        )
+    })?;
+    let socket_path = cache.daemon_socket_path();
+    let listener = bind_socket(&socket_path).await?;
+
+    let state: SharedState = Arc::new(Mutex::new(DaemonState {
+        fresh: HashMap::new(),
+        generation: 0,
+        // One missed tick is tolerated before reads fall back to revalidating.
+        freshness: Duration::from_secs(args.interval.saturating_mul(2)),
+        status: DaemonStatus {
+            pid: std::process::id(),
+            started_at: unix_now(),
+            interval_seconds: args.interval,
+            account_id: None,
+            last_warm_at: None,
+            warm_responses: 0,
+            last_token_refresh_at: None,
+            last_error: None,
+        },
+    }));
+
+    println!(
+        "{} {}",
+        "Daemon listening".green(),
+        format!(
+            "(socket: {}, interval: {}s)",
+            socket_path.display(),
+            args.interval
+        )
+        .bright_black()
+    );
+
+    // systemd and launchd stop services with SIGTERM; both signals clean up.
+    let mut terminate =
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .map_err(|err| AppError::generic(format!("Failed to watch for SIGTERM: {err}")))?;
+    let server = tokio::spawn(serve(listener, Arc::clone(&state)));
+    let mut ticker = tokio::time::interval(Duration::from_secs(args.interval));
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => refresh_cycle(&state).await,
+            _ = tokio::signal::ctrl_c() => break,
+            _ = terminate.recv() => break,
+        }
+    }
+
+    server.abort();
+    let _ = std::fs::remove_file(&socket_path);
+    eprintln!("{}", "Daemon stopped.".bright_black());
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub async fn run(_args: DaemonArgs) -> AppResult<()> {
+    Err(AppError::invalid_input(
+        "`basecamp-cli daemon` needs Unix domain sockets and is not available on this platform.",
+    ))
+}
+
+#[cfg(unix)]
+async fn bind_socket(socket_path: &Path) -> AppResult<tokio::net::UnixListener> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Some(dir) = socket_path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| {
+            AppError::generic(format!(
+                "Failed to create cache directory {}: {err}",
+                dir.display()
+            ))
+        })?;
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700)).map_err(|err| {
+            AppError::generic(format!(
+                "Failed to secure cache directory {}: {err}",
+                dir.display()
+            ))
+        })?;
+    }
+
+    if socket_path.exists() {
+        if tokio::net::UnixStream::connect(socket_path).await.is_ok() {
+            return Err(AppError::invalid_input(format!(
+                "A daemon is already listening on {}.",
+                socket_path.display()
+            )));
+        }
+        // Left behind by a daemon that did not shut down cleanly.
+        let _ = std::fs::remove_file(socket_path);
+    }
+
+    let listener = tokio::net::UnixListener::bind(socket_path).map_err(|err| {
+        AppError::generic(format!(
+            "Failed to listen on {}: {err}",
+            socket_path.display()
+        ))
+    })?;
+    std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600)).map_err(
+        |err| {
+            AppError::generic(format!(
+                "Failed to secure daemon socket {}: {err}",
+                socket_path.display()
+            ))
+        },
+    )?;
+    Ok(listener)
+}
+
+#[cfg(unix)]
+async fn serve(listener: tokio::net::UnixListener, state: SharedState) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        tokio::spawn(handle_connection(stream, Arc::clone(&state)));
+    }
+}
+
+#[cfg(unix)]
+async fn handle_connection(stream: tokio::net::UnixStream, state: SharedState) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (reader, mut writer) = stream.into_split();
+    let mut line = String::new();
+    if BufReader::new(reader).read_line(&mut line).await.is_err() {
+        return;
+    }
+
+    let response = match serde_json::from_str::<DaemonRequest>(&line) {
+        Ok(request) => respond(&state, request),
+        Err(_) => serde_json::to_string(&FreshResponse::default()),
+    };
+    if let Ok(mut response) = response {
+        response.push('\n');
+        let _ = writer.write_all(response.as_bytes()).await;
+    }
+}
+
+fn respond(state: &SharedState, request: DaemonRequest) -> serde_json::Result<String> {
+    let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+    match request {
+        DaemonRequest::Fresh { session, url } => {
+            let fresh = state
+                .fresh
+                .get(&(session, url))
+                .is_some_and(|warmed_at| warmed_at.elapsed() < state.freshness);
+            serde_json::to_string(&FreshResponse { fresh })
+        }
+        DaemonRequest::Invalidate => {
+            state.fresh.clear();
+            state.generation += 1;
+            serde_json::to_string(&FreshResponse::default())
+        }
+        DaemonRequest::Status => serde_json::to_string(&state.status),
+    }
+}
+
+async fn refresh_cycle(state: &SharedState) {
+    match refresh_tokens_if_due().await {
+        Ok(true) => {
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.status.last_token_refresh_at = Some(unix_now());
+        }
+        Ok(false) => {}
+        Err(err) => {
+            record_error(state, err);
+            return;
+        }
+    }
+
+    let generation = state
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .generation;
+    match warm_cache().await {
+        Ok((account_id, session, urls)) => {
+            let now = Instant::now();
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.generation == generation {
+                state.fresh = urls
+                    .into_iter()
+                    .map(|url| ((session.clone(), url), now))
+                    .collect();
+            }
+            state.status.account_id = Some(account_id);
+            state.status.last_warm_at = Some(unix_now());
+            state.status.warm_responses = state.fresh.len();
+            state.status.last_error = None;
+            eprintln!(
+                "{}",
+                format!("warmed {} cached responses", state.fresh.len()).bright_black()
+            );
+        }
+        Err(err) => record_error(state, err),
+    }
+}
+
+fn record_error(state: &SharedState, err: AppError) {
+    eprintln!(
+        "{}",
+        format!("daemon refresh failed: {}", err.message).yellow()
+    );
+    let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+    state.fresh.clear();
+    state.status.last_error = Some(err.message);
+}
+
+async fn refresh_tokens_if_due() -> AppResult<bool> {
+    let updated_at = integration::load_config()?
+        .session
+        .updated_at
+        .and_then(|raw| raw.parse::<u64>().ok());
+    let due = updated_at.is_none_or(|updated_at| {
+        unix_now().saturating_sub(updated_at) >= TOKEN_REFRESH_AFTER.as_secs()
+    });
+    if !due {
+        return Ok(false);
+    }
+
+    match session_recovery::refresh_stored_tokens().await? {
+        RefreshOutcome::Refreshed => Ok(true),
+        RefreshOutcome::Unavailable => Ok(false),
+        RefreshOutcome::Rejected => Err(AppError::oauth(
+            "Launchpad rejected the stored refresh token. Run `basecamp-cli login` again.",
+        )),
+    }
+}
+
+// Metadata nearly every interactive command reads first: profile, projects,
+// and the to-do lists of pinned projects. Paged lists are warmed through
+// their last page, so callers that follow `Link` headers hit every page.
+async fn warm_cache() -> AppResult<(u64, String, Vec<String>)> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?)
+        .with_daemon_reads(false);
+
+    let mut urls = Vec::new();
+    client.fetch_my_profile().await?;
+    urls.push(client.account_url("my/profile.json"));
+    urls.extend(client.warm_pages("projects.json").await?);
+
+    for pin in integration::load_config()?.projects.pins {
+        let project = client.get_project(pin.id).await?;
+        urls.push(client.account_url(&format!("projects/{}.json", project.id)));
+        let Ok(todoset_id) = resolve_todoset_id(&project) else {
+            continue;
+        };
+        urls.extend(
+            client
+                .warm_pages(&format!(
+                    "buckets/{}/todosets/{todoset_id}/todolists.json",
+                    project.id
+                ))
+                .await?,
+        );
+    }
+
+    Ok((session.account_id, client.daemon_session(), urls))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
@@ -0,0 +1,34 @@
+use crate::basecamp::daemon::{self, DaemonStatus};
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct DaemonStatusOutput {
+    pub ok: bool,
+    pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub status: Option<DaemonStatus>,
+}
+
+pub async fn run() -> AppResult<DaemonStatusOutput> {
+    let Some(cache) = integration::response_cache()? else {
+        return Ok(DaemonStatusOutput {
+            ok: true,
+            running: false,
+            socket: None,
+            status: None,
+        });
+    };
+
+    let socket = cache.daemon_socket_path();
+    let status = daemon::status(&socket).await;
+    Ok(DaemonStatusOutput {
+        ok: true,
+        running: status.is_some(),
+        socket: Some(socket.display().to_string()),
+        status,
+    })
+}
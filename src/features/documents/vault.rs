@@ -0,0 +1,286 @@
+//! `doc list/show/create/edit`: documents in a project's Docs & Files
+//! (its "vault"). Folders nest, and `--folder` walks them by name from the
+//! top so a path like `Meetings/2026` works without looking up ids.
+
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{Document, DocumentPayload, Project};
+use crate::cli::{DocCreateArgs, DocEditArgs, DocListArgs, DocShowArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::notes::resolve_notes_input;
+use crate::rich_text::{html_to_text, markdown_to_html};
+use crate::ui;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct DocListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub folder_id: u64,
+    /// From the top folder, e.g. `Docs & Files/Meetings`.
+    pub folder_path: String,
+    pub folders: Vec<FolderSummary>,
+    pub documents: Vec<DocumentSummary>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FolderSummary {
+    pub folder_id: u64,
+    pub title: String,
+    pub documents_count: u32,
+    pub folders_count: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DocumentSummary {
+    pub document_id: u64,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    pub comments_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+/// For `doc show`, `doc create`, and `doc edit`.
+#[derive(Debug, Serialize)]
+pub struct DocOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    #[serde(flatten)]
+    pub document: DocumentSummary,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub content: String,
+}
+
+pub(crate) struct Folder {
+    pub id: u64,
+    pub path: String,
+}
+
+impl From<Document> for DocumentSummary {
+    fn from(document: Document) -> Self {
+        Self {
+            document_id: document.id,
+            title: document.title,
+            creator: document.creator.map(|creator| creator.name),
+            updated_at: document.updated_at,
+            comments_count: document.comments_count,
+            app_url: document.app_url,
+        }
+    }
+}
+
+pub async fn run_list(args: DocListArgs) -> AppResult<DocListOutput> {
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let folder = resolve_folder(&client, &project, args.folder.as_deref()).await?;
+
+    let folders = client
+        .list_vaults(project.id, folder.id)
+        .await?
+        .into_iter()
+        .map(|vault| FolderSummary {
+            folder_id: vault.id,
+            title: vault.title,
+            documents_count: vault.documents_count,
+            folders_count: vault.vaults_count,
+        })
+        .collect();
+    let documents = client
+        .list_documents(project.id, folder.id)
+        .await?
+        .into_iter()
+        .map(DocumentSummary::from)
+        .collect();
+
+    Ok(DocListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        folder_id: folder.id,
+        folder_path: folder.path,
+        folders,
+        documents,
+    })
+}
+
+pub async fn run_show(args: DocShowArgs) -> AppResult<DocOutput> {
+    let client = integration::session_client()?;
+    let (project_id, document_id) = lookup::resolve_recording(
+        &client,
+        &args.document,
+        args.project.as_deref(),
+        lookup::DOCUMENT,
+    )
+    .await?;
+    let document = client.get_document(project_id, document_id).await?;
+    Ok(doc_output(project_id, document))
+}
+
+pub async fn run_create(args: DocCreateArgs) -> AppResult<DocOutput> {
+    let title = required_title(&args.title)?;
+    let content = match resolve_notes_input(args.content, args.content_file)? {
+        Some(content) => content,
+        None => edit_in_editor("")?,
+    };
+    if content.trim().is_empty() {
+        return Err(AppError::invalid_input(
+            "Document body is empty; nothing was created.",
+        ));
+    }
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let folder = resolve_folder(&client, &project, args.folder.as_deref()).await?;
+    let document = client
+        .create_document(
+            project.id,
+            folder.id,
+            &DocumentPayload {
+                title,
+                content: markdown_to_html(&content),
+                status: Some("active".to_string()),
+            },
+        )
+        .await?;
+
+    Ok(doc_output(project.id, document))
+}
+
+pub async fn run_edit(args: DocEditArgs) -> AppResult<DocOutput> {
+    let title = args.title.as_deref().map(required_title).transpose()?;
+    let content = resolve_notes_input(args.content, args.content_file)?;
+
+    let client = integration::session_client()?;
+    let (project_id, document_id) = lookup::resolve_recording(
+        &client,
+        &args.document,
+        args.project.as_deref(),
+        lookup::DOCUMENT,
+    )
+    .await?;
+    let document = client.get_document(project_id, document_id).await?;
+
+    // Basecamp needs both fields on every update. A body left alone is sent
+    // back as stored, so formatting markdown cannot express survives a
+    // title-only edit.
+    let content = match (content, &title) {
+        (Some(content), _) => markdown_to_html(&content),
+        (None, Some(_)) => document.content.clone(),
+        (None, None) => {
+            let current = html_to_text(&document.content);
+            let edited = edit_in_editor(&current)?;
+            if edited.trim() == current.trim() {
+                return Err(AppError::invalid_input(
+                    "No changes; the document was left as is.",
+                ));
+            }
+            markdown_to_html(&edited)
+        }
+    };
+    let updated = client
+        .update_document(
+            project_id,
+            document_id,
+            &DocumentPayload {
+                title: title.unwrap_or_else(|| document.title.clone()),
+                content,
+                status: None,
+            },
+        )
+        .await?;
+
+    Ok(doc_output(project_id, updated))
+}
+
+// `--folder`: nothing for the top folder, a folder id, or a `/`-separated
+// path of folder names (case-insensitive) below the top.
+pub(crate) async fn resolve_folder(
+    client: &BasecampClient,
+    project: &Project,
+    folder: Option<&str>,
+) -> AppResult<Folder> {
+    let root_id = lookup::dock_tool_id(project, "vault", "Docs & Files")?;
+    let root_title = project
+        .dock
+        .iter()
+        .find(|item| item.id == root_id)
+        .map(|item| item.title.clone())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| "Docs & Files".to_string());
+
+    let reference = folder.map(str::trim).unwrap_or_default();
+    if let Ok(id) = reference.parse::<u64>() {
+        let vault = client.get_vault(project.id, id).await?;
+        return Ok(Folder {
+            id: vault.id,
+            path: vault.title,
+        });
+    }
+
+    let mut current = Folder {
+        id: root_id,
+        path: root_title,
+    };
+    for name in reference
+        .split('/')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        let children = client.list_vaults(project.id, current.id).await?;
+        let Some(child) = children
+            .iter()
+            .find(|child| child.title.eq_ignore_ascii_case(name))
+        else {
+            let names: Vec<&str> = children.iter().map(|child| child.title.as_str()).collect();
+            let hint = if names.is_empty() {
+                "It has no subfolders.".to_string()
+            } else {
+                format!("Subfolders: {}.", names.join(", "))
+            };
+            return Err(AppError::no_account(format!(
+                "No folder \"{name}\" in \"{}\". {hint}",
+                current.path
+            )));
+        };
+        current = Folder {
+            id: child.id,
+            path: format!("{}/{}", current.path, child.title),
+        };
+    }
+    Ok(current)
+}
+
+fn edit_in_editor(initial: &str) -> AppResult<String> {
+    if !ui::can_prompt() {
+        return Err(AppError::invalid_input(
+            "No terminal for $EDITOR; pass --content or --content-file.",
+        ));
+    }
+    ui::edit_text(initial)
+        .map_err(|err| AppError::generic(format!("Editing the document failed: {err}")))
+}
+
+fn required_title(title: &str) -> AppResult<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(AppError::invalid_input("Document title cannot be blank."));
+    }
+    Ok(title.to_string())
+}
+
+fn doc_output(project_id: u64, document: Document) -> DocOutput {
+    let content = html_to_text(&document.content);
+    DocOutput {
+        ok: true,
+        project_id,
+        document: DocumentSummary::from(document),
+        content,
+    }
+}
@@ -0,0 +1,129 @@
+use crate::basecamp::models::{CreateMessagePayload, Message};
+use crate::cli::{MessageListArgs, MessagePostArgs, MessageShowArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::notes::resolve_notes_input;
+use crate::rich_text::{html_to_text, markdown_to_html};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct MessageListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub messages: Vec<MessageSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MessageSummary {
+    pub message_id: u64,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    pub comments_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+/// For `message show` and `message post`.
+#[derive(Debug, Serialize)]
+pub struct MessageOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    #[serde(flatten)]
+    pub message: MessageSummary,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub content: String,
+}
+
+impl From<Message> for MessageSummary {
+    fn from(message: Message) -> Self {
+        Self {
+            message_id: message.id,
+            subject: message.subject,
+            creator: message.creator.map(|creator| creator.name),
+            created_at: message.created_at,
+            comments_count: message.comments_count,
+            app_url: message.app_url,
+        }
+    }
+}
+
+pub async fn run_list(args: MessageListArgs) -> AppResult<MessageListOutput> {
+    if args.limit == 0 {
+        return Err(AppError::invalid_input("--limit must be at least 1."));
+    }
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let board_id = lookup::dock_tool_id(&project, "message_board", "message board")?;
+    let mut messages = client
+        .list_messages(project.id, board_id, Some(args.limit))
+        .await?;
+    messages.truncate(args.limit);
+    let messages: Vec<MessageSummary> = messages.into_iter().map(MessageSummary::from).collect();
+    let count = messages.len();
+
+    Ok(MessageListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        messages,
+        count,
+    })
+}
+
+pub async fn run_show(args: MessageShowArgs) -> AppResult<MessageOutput> {
+    let client = integration::session_client()?;
+    let (project_id, message_id) = lookup::resolve_recording(
+        &client,
+        &args.message,
+        args.project.as_deref(),
+        lookup::MESSAGE,
+    )
+    .await?;
+    let message = client.get_message(project_id, message_id).await?;
+    Ok(message_output(project_id, message))
+}
+
+pub async fn run_post(args: MessagePostArgs) -> AppResult<MessageOutput> {
+    let subject = args.subject.trim().to_string();
+    if subject.is_empty() {
+        return Err(AppError::invalid_input("Message subject cannot be blank."));
+    }
+    let content = resolve_notes_input(args.content, args.content_file)?
+        .filter(|content| !content.trim().is_empty())
+        .map(|content| markdown_to_html(&content));
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let board_id = lookup::dock_tool_id(&project, "message_board", "message board")?;
+    let message = client
+        .create_message(
+            project.id,
+            board_id,
+            &CreateMessagePayload {
+                subject,
+                content,
+                status: "active".to_string(),
+            },
+        )
+        .await?;
+
+    Ok(message_output(project.id, message))
+}
+
+fn message_output(project_id: u64, message: Message) -> MessageOutput {
+    let content = html_to_text(&message.content);
+    MessageOutput {
+        ok: true,
+        project_id,
+        message: MessageSummary::from(message),
+        content,
+    }
+}
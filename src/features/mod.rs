@@ -1,2 +1,18 @@
+pub mod aliases;
 pub mod auth;
+pub mod campfire;
+pub mod cards;
+pub mod checkins;
+pub mod comments;
+pub mod daemon;
+pub mod documents;
+pub mod messages;
+pub mod people;
+pub mod projects;
+pub mod schedule;
+pub mod stats;
+pub mod templates;
+pub mod todolists;
 pub mod todos;
+pub mod uploads;
+pub mod webhooks;
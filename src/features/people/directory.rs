@@ -0,0 +1,114 @@
+//! `people list/show`: the account's people, or one project's. These are
+//! the same lists `--assignee` and `--participant` references resolve
+//! against, so `people list` shows exactly which emails and names work.
+
+use crate::basecamp::models::ProjectPerson;
+use crate::cli::{PeopleListArgs, PeopleShowArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::features::todos::people::resolve_account_people_references;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct PeopleListOutput {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub people: Vec<PersonDetails>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonShowOutput {
+    pub ok: bool,
+    #[serde(flatten)]
+    pub person: PersonDetails,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonDetails {
+    pub person_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub admin: bool,
+    pub owner: bool,
+    pub client: bool,
+}
+
+impl From<ProjectPerson> for PersonDetails {
+    fn from(person: ProjectPerson) -> Self {
+        Self {
+            person_id: person.id,
+            name: person.name,
+            email_address: person.email_address,
+            title: person.title.filter(|title| !title.trim().is_empty()),
+            admin: person.admin,
+            owner: person.owner,
+            client: person.client,
+        }
+    }
+}
+
+pub async fn run_list(args: PeopleListArgs) -> AppResult<PeopleListOutput> {
+    let client = integration::session_client()?;
+    let (project, people) = match args.project.as_deref() {
+        Some(reference) => {
+            let project = lookup::resolve_project(&client, reference).await?;
+            let people = client.list_project_people(project.id).await?;
+            (Some(project), people)
+        }
+        None => (None, client.list_people().await?),
+    };
+
+    let mut people: Vec<PersonDetails> = people.into_iter().map(PersonDetails::from).collect();
+    people.sort_by_cached_key(|person| person.name.to_lowercase());
+    let count = people.len();
+    let (project_id, project_name) = project.map(|project| (project.id, project.name)).unzip();
+
+    Ok(PeopleListOutput {
+        ok: true,
+        project_id,
+        project_name,
+        people,
+        count,
+    })
+}
+
+// An id is fetched directly; an email or name is matched across the account
+// the way `--assignee` matches it.
+pub async fn run_show(args: PeopleShowArgs) -> AppResult<PersonShowOutput> {
+    let reference = args.person.trim();
+    if reference.is_empty() || reference.starts_with('@') {
+        return Err(AppError::invalid_input(
+            "Give a person id, email address, or name.",
+        ));
+    }
+
+    let client = integration::session_client()?;
+    let person = match reference.parse::<u64>() {
+        Ok(id) => client.get_person(id).await?,
+        Err(_) => {
+            let everyone = client.list_people().await?;
+            let ids = resolve_account_people_references(
+                std::slice::from_ref(&args.person),
+                &everyone,
+                "people show",
+            )?;
+            everyone
+                .into_iter()
+                .find(|person| ids.first() == Some(&person.id))
+                .ok_or_else(|| AppError::no_account(format!("No one matches \"{reference}\".")))?
+        }
+    };
+
+    Ok(PersonShowOutput {
+        ok: true,
+        person: PersonDetails::from(person),
+    })
+}
@@ -0,0 +1,171 @@
+//! `project archive`, `project trash`, and `project restore`. Each takes
+//! several projects so finished ones can be cleared out in one go.
+
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::Project;
+use crate::cli::ProjectLifecycleArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::ui::{can_prompt, prompt_error};
+use inquire::Confirm;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ProjectLifecycle {
+    Archive,
+    Trash,
+    Restore,
+}
+
+impl ProjectLifecycle {
+    fn verb(self) -> &'static str {
+        match self {
+            Self::Archive => "archive",
+            Self::Trash => "trash",
+            Self::Restore => "restore",
+        }
+    }
+
+    pub fn past_tense(self) -> &'static str {
+        match self {
+            Self::Archive => "Archived",
+            Self::Trash => "Trashed",
+            Self::Restore => "Restored",
+        }
+    }
+
+    fn target_status(self) -> &'static str {
+        match self {
+            Self::Archive => "archived",
+            Self::Trash => "trashed",
+            Self::Restore => "active",
+        }
+    }
+
+    fn question(self, count: usize) -> String {
+        match self {
+            Self::Archive => format!("Archive {count} project(s)?"),
+            Self::Trash => format!("Move {count} project(s) to trash?"),
+            Self::Restore => format!("Restore {count} project(s)?"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectLifecycleOutput {
+    pub ok: bool,
+    pub action: String,
+    pub projects: Vec<ChangedProject>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangedProject {
+    pub project_id: u64,
+    pub project_name: String,
+}
+
+pub async fn run(
+    action: ProjectLifecycle,
+    args: ProjectLifecycleArgs,
+) -> AppResult<ProjectLifecycleOutput> {
+    if !args.force && !can_prompt() {
+        return Err(AppError::invalid_input(format!(
+            "`basecamp-cli project {}` asks for confirmation in an interactive terminal; pass --force to skip it.",
+            action.verb()
+        )));
+    }
+
+    let client = integration::session_client()?;
+
+    let mut projects: Vec<Project> = Vec::with_capacity(args.projects.len());
+    let mut inactive = None;
+    for reference in &args.projects {
+        let project = match action {
+            ProjectLifecycle::Restore => {
+                resolve_inactive_project(&client, reference, &mut inactive).await?
+            }
+            _ => lookup::resolve_project(&client, reference).await?,
+        };
+        if project.status.as_deref() == Some(action.target_status()) {
+            return Err(AppError::invalid_input(format!(
+                "Project \"{}\" ({}) is already {}.",
+                project.name,
+                project.id,
+                action.target_status()
+            )));
+        }
+        if !projects.iter().any(|seen| seen.id == project.id) {
+            projects.push(project);
+        }
+    }
+
+    if !args.force {
+        for project in &projects {
+            eprintln!("  - {} (id: {})", project.name, project.id);
+        }
+        let confirmed = Confirm::new(&action.question(projects.len()))
+            .with_default(false)
+            .prompt()
+            .map_err(|err| prompt_error("confirm project change", err))?;
+        if !confirmed {
+            return Err(AppError::invalid_input(format!(
+                "Project {} cancelled.",
+                action.verb()
+            )));
+        }
+    }
+
+    let mut changed = Vec::with_capacity(projects.len());
+    for project in projects {
+        match action {
+            ProjectLifecycle::Trash => client.trash_project(project.id).await?,
+            _ => {
+                client
+                    .set_project_status(project.id, action.target_status())
+                    .await?
+            }
+        }
+        changed.push(ChangedProject {
+            project_id: project.id,
+            project_name: project.name,
+        });
+    }
+
+    let count = changed.len();
+    Ok(ProjectLifecycleOutput {
+        ok: true,
+        action: action.verb().to_string(),
+        projects: changed,
+        count,
+    })
+}
+
+// Names are matched against archived and trashed projects, which
+// `projects.json` leaves out by default; ids and aliases fetch directly.
+async fn resolve_inactive_project(
+    client: &BasecampClient,
+    reference: &str,
+    inactive: &mut Option<Vec<Project>>,
+) -> AppResult<Project> {
+    let trimmed = reference.trim();
+    if trimmed.starts_with('@') || trimmed.parse::<u64>().is_ok() {
+        return lookup::resolve_project(client, trimmed).await;
+    }
+
+    let projects = match inactive {
+        Some(projects) => projects,
+        None => {
+            let mut projects = client.list_projects_with_status("archived").await?;
+            projects.extend(client.list_projects_with_status("trashed").await?);
+            inactive.insert(projects)
+        }
+    };
+    let index = lookup::match_by_name(
+        projects.iter().map(|project| project.name.as_str()),
+        trimmed,
+        "archived or trashed project",
+    )?;
+    Ok(projects[index].clone())
+}
@@ -0,0 +1,252 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::Project;
+use crate::cli::IdOrUrl;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::TargetAlias;
+use crate::ui::{PROMPT_MARGIN, fit_width};
+use std::collections::BTreeMap;
+
+pub struct OrderedProject {
+    pub index: usize,
+    pub label: String,
+}
+
+pub async fn resolve_project(client: &BasecampClient, reference: &str) -> AppResult<Project> {
+    let reference = reference.trim();
+    if let Some(alias) = reference.strip_prefix('@') {
+        let project_id = resolve_alias(alias)?.project_id;
+        return client.get_project(project_id).await;
+    }
+
+    if let Ok(project_id) = reference.parse::<u64>() {
+        return client.get_project(project_id).await;
+    }
+
+    let mut projects = client.list_projects().await?;
+    let index = match_by_name(
+        projects.iter().map(|project| project.name.as_str()),
+        reference,
+        "project",
+    )?;
+    Ok(projects.swap_remove(index))
+}
+
+/// A session client and the project `reference` names, for commands that
+/// start from `--project`.
+pub async fn resolve_session_project(reference: &str) -> AppResult<(BasecampClient, Project)> {
+    let client = integration::session_client()?;
+    let project = resolve_project(&client, reference).await?;
+    Ok((client, project))
+}
+
+/// The id of an enabled dock tool (`message_board`, `chat`, `vault`, ...);
+/// `label` names it in the error.
+pub fn dock_tool_id(project: &Project, tool: &str, label: &str) -> AppResult<u64> {
+    project
+        .dock
+        .iter()
+        .find(|item| item.name == tool && item.enabled)
+        .map(|item| item.id)
+        .ok_or_else(|| {
+            AppError::no_account(format!(
+                "Project \"{}\" has no {label} turned on.",
+                project.name
+            ))
+        })
+}
+
+/// What `resolve_recording` accepts: `label` names it in errors, and a URL
+/// must point at `url_kind` (the path segment before the id) when set.
+pub struct RecordingKind {
+    pub label: &'static str,
+    pub url_kind: Option<&'static str>,
+}
+
+pub const MESSAGE: RecordingKind = RecordingKind {
+    label: "message",
+    url_kind: Some("messages"),
+};
+pub const DOCUMENT: RecordingKind = RecordingKind {
+    label: "document",
+    url_kind: Some("documents"),
+};
+pub const QUESTION: RecordingKind = RecordingKind {
+    label: "question",
+    url_kind: Some("questions"),
+};
+pub const CARD: RecordingKind = RecordingKind {
+    label: "card",
+    url_kind: Some("cards"),
+};
+/// Any commentable recording, for `comment`.
+pub const RECORDING: RecordingKind = RecordingKind {
+    label: "recording",
+    url_kind: None,
+};
+
+/// `(project_id, recording_id)` for a recording given by id or URL. A bare
+/// id needs `project` (id, name, or `@alias`); a URL carries its own, and
+/// must belong to the client's account and point at `kind`.
+pub async fn resolve_recording(
+    client: &BasecampClient,
+    target: &IdOrUrl,
+    project: Option<&str>,
+    kind: RecordingKind,
+) -> AppResult<(u64, u64)> {
+    let label = kind.label;
+    match target {
+        IdOrUrl::Url(link) => {
+            let link = link.ensure_account(client.account_id())?;
+            let (url_kind, id) = link.recording.as_ref().ok_or_else(|| {
+                AppError::invalid_input(format!("That URL points at a project, not a {label}."))
+            })?;
+            if kind.url_kind.is_some_and(|expected| expected != url_kind) {
+                return Err(AppError::invalid_input(format!(
+                    "That URL points at {url_kind}, not a {label}."
+                )));
+            }
+            Ok((link.project_id, *id))
+        }
+        IdOrUrl::Id(id) => {
+            let project = project.ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "A {label} id needs --project; a {label} URL does not."
+                ))
+            })?;
+            Ok((resolve_project(client, project).await?.id, *id))
+        }
+    }
+}
+
+/// Where an `@name` points: a project, or one of its lists.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedAlias {
+    pub project_id: u64,
+    pub todolist_id: Option<u64>,
+}
+
+/// Aliases from `alias set` and `project pin --alias` are both stored in
+/// `config.aliases`.
+pub fn resolve_alias(alias: &str) -> AppResult<ResolvedAlias> {
+    let alias = alias.trim().trim_start_matches('@').to_ascii_lowercase();
+    integration::load_config()?
+        .aliases
+        .get(&alias)
+        .map(|target| ResolvedAlias {
+            project_id: target.project_id,
+            todolist_id: target.todolist_id,
+        })
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "Unknown alias @{alias}. Run `basecamp-cli alias list` to list aliases."
+            ))
+        })
+}
+
+pub fn normalize_alias(raw: &str) -> AppResult<String> {
+    let alias = raw.trim().trim_start_matches('@');
+    let valid = !alias.is_empty()
+        && alias
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+
+    if !valid {
+        return Err(AppError::invalid_input(format!(
+            "Invalid alias \"{raw}\". Use letters, digits, `-`, or `_` (for example `web`)."
+        )));
+    }
+
+    Ok(alias.to_ascii_lowercase())
+}
+
+/// The aliases naming `project_id` itself (not one of its lists).
+pub fn project_aliases(aliases: &BTreeMap<String, TargetAlias>, project_id: u64) -> Vec<String> {
+    aliases
+        .iter()
+        .filter(|(_, target)| target.project_id == project_id && target.todolist_id.is_none())
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+// Pinned projects come first in pin order; the rest keep API order. Project
+// aliases join the label so typing `@name` in the prompt finds the project.
+pub fn pinned_first(projects: &[Project]) -> AppResult<Vec<OrderedProject>> {
+    let config = integration::load_config()?;
+    let pins = config.projects.pins;
+    let aliases_of = |project_id: u64| -> String {
+        project_aliases(&config.aliases, project_id)
+            .iter()
+            .map(|name| format!(" @{name}"))
+            .collect()
+    };
+
+    let mut ordered = Vec::with_capacity(projects.len());
+    for pin in &pins {
+        if let Some(index) = projects.iter().position(|project| project.id == pin.id) {
+            let suffix = format!(" ({}) - pinned{}", projects[index].id, aliases_of(pin.id));
+            ordered.push(OrderedProject {
+                index,
+                label: format!("{}{suffix}", fit_label(&projects[index].name, &suffix)),
+            });
+        }
+    }
+
+    for (index, project) in projects.iter().enumerate() {
+        if pins.iter().any(|pin| pin.id == project.id) {
+            continue;
+        }
+        let aliases = aliases_of(project.id);
+        let suffix = if aliases.is_empty() {
+            format!(" ({})", project.id)
+        } else {
+            format!(" ({}) -{aliases}", project.id)
+        };
+        ordered.push(OrderedProject {
+            index,
+            label: format!("{}{suffix}", fit_label(&project.name, &suffix)),
+        });
+    }
+
+    Ok(ordered)
+}
+
+fn fit_label(name: &str, suffix: &str) -> String {
+    fit_width(name, suffix.chars().count() + PROMPT_MARGIN)
+}
+
+// Exact (case-insensitive) names win; otherwise a unique substring match is accepted.
+pub fn match_by_name<'a>(
+    names: impl Iterator<Item = &'a str>,
+    reference: &str,
+    kind: &str,
+) -> AppResult<usize> {
+    let needle = reference.to_lowercase();
+    let names: Vec<String> = names.map(|name| name.trim().to_lowercase()).collect();
+
+    let exact: Vec<usize> = (0..names.len())
+        .filter(|index| names[*index] == needle)
+        .collect();
+    if exact.len() == 1 {
+        return Ok(exact[0]);
+    }
+
+    let candidates = if exact.is_empty() {
+        (0..names.len())
+            .filter(|index| names[*index].contains(&needle))
+            .collect()
+    } else {
+        exact
+    };
+
+    match candidates.as_slice() {
+        [index] => Ok(*index),
+        [] => Err(AppError::no_account(format!(
+            "No {kind} matched \"{reference}\"."
+        ))),
+        _ => Err(AppError::invalid_input(format!(
+            "\"{reference}\" matches {} {kind}s. Use a more specific name or the numeric id.",
+            candidates.len()
+        ))),
+    }
+}
@@ -0,0 +1,180 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::dry_run;
+use crate::basecamp::models::{Project, ProjectConstructionPayload, ProjectPayload};
+use crate::cli::{ProjectCreateArgs, ProjectUpdateArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Basecamp builds template projects in the background; this is how long to
+/// wait for one before giving up.
+const CONSTRUCTION_POLLS: u32 = 60;
+const CONSTRUCTION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Shared by create and update.
+#[derive(Debug, Serialize)]
+pub struct ProjectChangeOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_create(args: ProjectCreateArgs) -> AppResult<ProjectChangeOutput> {
+    let payload = ProjectPayload {
+        name: required_name(&args.name)?,
+        description: args
+            .description
+            .as_deref()
+            .map(str::trim)
+            .filter(|description| !description.is_empty())
+            .map(str::to_string),
+    };
+
+    let client = integration::session_client()?;
+    let project = match args.from_template {
+        Some(template_id) => construct(&client, template_id, payload).await?,
+        None => client.create_project(&payload).await?,
+    };
+
+    Ok(change_output(project, None, args.from_template))
+}
+
+pub async fn run_update(args: ProjectUpdateArgs) -> AppResult<ProjectChangeOutput> {
+    if args.name.is_none() && args.description.is_none() {
+        return Err(AppError::invalid_input(
+            "Nothing to update. Pass --name and/or --description.",
+        ));
+    }
+    let name = args.name.as_deref().map(required_name).transpose()?;
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+
+    // Basecamp needs the name on every update and clears a description left
+    // out, so both are sent back when unchanged.
+    let description = match args.description {
+        Some(description) => Some(description.trim().to_string()),
+        None => project.description.clone(),
+    };
+    let updated = client
+        .update_project(
+            project.id,
+            &ProjectPayload {
+                name: name.unwrap_or_else(|| project.name.clone()),
+                description,
+            },
+        )
+        .await?;
+
+    if updated.name != project.name && !dry_run::enabled() {
+        rename_in_config(updated.id, &updated.name)?;
+    }
+    Ok(change_output(updated, Some(project.name), None))
+}
+
+// Polls until Basecamp reports the project built from the template.
+async fn construct(
+    client: &BasecampClient,
+    template_id: u64,
+    project: ProjectPayload,
+) -> AppResult<Project> {
+    let name = project.name.clone();
+    let mut construction = client
+        .construct_project(template_id, &ProjectConstructionPayload { project })
+        .await?;
+
+    for _ in 0..CONSTRUCTION_POLLS {
+        if construction.status == "completed" {
+            break;
+        }
+        tokio::time::sleep(CONSTRUCTION_POLL_INTERVAL).await;
+        construction = client
+            .get_project_construction(template_id, construction.id)
+            .await?;
+    }
+
+    if construction.status != "completed" {
+        return Err(AppError::generic(format!(
+            "Basecamp is still building \"{name}\" from template {template_id}; it will appear in the project list when done."
+        )));
+    }
+    match construction.project {
+        Some(project) => Ok(project),
+        // Dry run: nothing was built.
+        None if dry_run::enabled() => Ok(Project {
+            id: 0,
+            name,
+            description: None,
+            status: None,
+            app_url: None,
+            dock: Vec::new(),
+        }),
+        None => Err(AppError::generic(format!(
+            "Basecamp finished building \"{name}\" but did not report the project."
+        ))),
+    }
+}
+
+// Pins and aliases keep the name they were saved with; a rename updates them.
+fn rename_in_config(project_id: u64, name: &str) -> AppResult<()> {
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let mut changed = false;
+    for pin in config
+        .projects
+        .pins
+        .iter_mut()
+        .filter(|pin| pin.id == project_id)
+    {
+        pin.name = name.to_string();
+        changed = true;
+    }
+    for alias in config
+        .aliases
+        .values_mut()
+        .filter(|alias| alias.project_id == project_id)
+    {
+        alias.project_name = name.to_string();
+        changed = true;
+    }
+    if changed {
+        integration::save_config(&config)?;
+    }
+    Ok(())
+}
+
+fn change_output(
+    project: Project,
+    previous_name: Option<String>,
+    template_id: Option<u64>,
+) -> ProjectChangeOutput {
+    ProjectChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        description: project
+            .description
+            .filter(|description| !description.trim().is_empty()),
+        previous_name,
+        template_id,
+        app_url: project.app_url,
+    }
+}
+
+fn required_name(name: &str) -> AppResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::invalid_input("Project name cannot be empty."));
+    }
+    Ok(name.to_string())
+}
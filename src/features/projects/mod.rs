@@ -0,0 +1,6 @@
+pub mod lifecycle;
+pub mod lookup;
+pub mod manage;
+pub mod people;
+pub mod pin;
+pub mod show;
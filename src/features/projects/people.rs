@@ -0,0 +1,208 @@
+//! `project people list/add/remove`. Adding resolves people across the
+//! account, since they are not on the project yet, and invites email
+//! addresses nobody in the account has.
+
+use crate::basecamp::dry_run;
+use crate::basecamp::models::{NewPerson, ProjectAccessPayload, ProjectPerson};
+use crate::cli::{ProjectPeopleChangeArgs, ProjectPeopleListArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::projects::lookup;
+use crate::features::todos::people::{
+    resolve_account_people_references, resolve_people_references,
+};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ProjectPeopleOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub people: Vec<PersonSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectPeopleChangeOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub granted: Vec<PersonSummary>,
+    pub revoked: Vec<PersonSummary>,
+    /// Email addresses that were invited to Basecamp; they are in `granted`
+    /// too.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invited: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonSummary {
+    pub person_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+}
+
+impl From<ProjectPerson> for PersonSummary {
+    fn from(person: ProjectPerson) -> Self {
+        Self {
+            person_id: person.id,
+            name: person.name,
+            email_address: person.email_address,
+        }
+    }
+}
+
+pub async fn run_list(args: ProjectPeopleListArgs) -> AppResult<ProjectPeopleOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let people: Vec<PersonSummary> = client
+        .list_project_people(project.id)
+        .await?
+        .into_iter()
+        .map(PersonSummary::from)
+        .collect();
+    let count = people.len();
+
+    Ok(ProjectPeopleOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        people,
+        count,
+    })
+}
+
+pub async fn run_add(args: ProjectPeopleChangeArgs) -> AppResult<ProjectPeopleChangeOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let everyone = client.list_people().await?;
+
+    let mut references = Vec::new();
+    let mut create = Vec::new();
+    for reference in args.people.iter().map(|value| value.trim()) {
+        let Some(invite) = parse_email_reference(reference) else {
+            references.push(reference.to_string());
+            continue;
+        };
+        let known = everyone.iter().any(|person| {
+            person
+                .email_address
+                .as_deref()
+                .is_some_and(|email| email.eq_ignore_ascii_case(&invite.email_address))
+        });
+        if known {
+            references.push(invite.email_address);
+        } else if !create.iter().any(|person: &NewPerson| {
+            person
+                .email_address
+                .eq_ignore_ascii_case(&invite.email_address)
+        }) {
+            create.push(invite);
+        }
+    }
+    let grant = resolve_account_people_references(&references, &everyone, "people")?;
+    if grant.is_empty() && create.is_empty() {
+        return Err(AppError::invalid_input("Name at least one person to add."));
+    }
+
+    let payload = ProjectAccessPayload {
+        grant,
+        revoke: Vec::new(),
+        create,
+    };
+    let change = client.update_project_access(project.id, &payload).await?;
+    let invited = payload
+        .create
+        .iter()
+        .map(|person| person.email_address.clone())
+        .collect();
+    // A dry run gets no response, so it reports what was asked for.
+    let granted = if dry_run::enabled() {
+        requested(&payload.grant, &everyone, &payload.create)
+    } else {
+        change
+            .granted
+            .into_iter()
+            .map(PersonSummary::from)
+            .collect()
+    };
+
+    Ok(ProjectPeopleChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        granted,
+        revoked: Vec::new(),
+        invited,
+    })
+}
+
+pub async fn run_remove(args: ProjectPeopleChangeArgs) -> AppResult<ProjectPeopleChangeOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let members = client.list_project_people(project.id).await?;
+    let revoke = resolve_people_references(&args.people, &members, "people")?;
+    if revoke.is_empty() {
+        return Err(AppError::invalid_input(
+            "Name at least one person to remove.",
+        ));
+    }
+
+    let payload = ProjectAccessPayload {
+        grant: Vec::new(),
+        revoke,
+        create: Vec::new(),
+    };
+    let change = client.update_project_access(project.id, &payload).await?;
+    let revoked = if dry_run::enabled() {
+        requested(&payload.revoke, &members, &[])
+    } else {
+        change
+            .revoked
+            .into_iter()
+            .map(PersonSummary::from)
+            .collect()
+    };
+
+    Ok(ProjectPeopleChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        granted: Vec::new(),
+        revoked,
+        invited: Vec::new(),
+    })
+}
+
+// `jane@acme.com` or `Jane Doe <jane@acme.com>`. Without a name, the part
+// before `@` stands in, since Basecamp requires one for an invite.
+fn parse_email_reference(reference: &str) -> Option<NewPerson> {
+    if reference.starts_with('@') {
+        return None;
+    }
+    let (name, email) = match reference
+        .strip_suffix('>')
+        .and_then(|rest| rest.split_once('<'))
+    {
+        Some((name, email)) => (name.trim(), email.trim()),
+        None => ("", reference),
+    };
+    let (local, domain) = email.split_once('@')?;
+    if local.is_empty() || domain.is_empty() || email.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some(NewPerson {
+        name: if name.is_empty() { local } else { name }.to_string(),
+        email_address: email.to_string(),
+    })
+}
+
+fn requested(ids: &[u64], people: &[ProjectPerson], invites: &[NewPerson]) -> Vec<PersonSummary> {
+    ids.iter()
+        .filter_map(|id| people.iter().find(|person| person.id == *id))
+        .map(|person| PersonSummary::from(person.clone()))
+        .chain(invites.iter().map(|person| PersonSummary {
+            person_id: 0,
+            name: person.name.clone(),
+            email_address: Some(person.email_address.clone()),
+        }))
+        .collect()
+}
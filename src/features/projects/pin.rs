@@ -0,0 +1,148 @@
+use crate::cli::{ProjectPinArgs, ProjectUnpinArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::{PinnedProject, TargetAlias};
+use crate::features::projects::lookup;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ProjectPinOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectPinsOutput {
+    pub ok: bool,
+    pub pins: Vec<PinEntry>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PinEntry {
+    pub id: u64,
+    pub name: String,
+    /// Project aliases (`alias set`, `project pin --alias`) for this project.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+pub async fn run_pin(args: ProjectPinArgs) -> AppResult<ProjectPinOutput> {
+    let alias = args
+        .alias
+        .as_deref()
+        .map(lookup::normalize_alias)
+        .transpose()?;
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+
+    // `--alias` is a project alias like `alias set <name> <project>`, but
+    // never silently moves a name that already points somewhere else.
+    if let Some(alias) = alias.as_deref() {
+        if let Some(target) = config.aliases.get(alias)
+            && (target.project_id != project.id || target.todolist_id.is_some())
+        {
+            return Err(AppError::invalid_input(format!(
+                "Alias @{alias} is already used for \"{}\". Remove it with `basecamp-cli alias remove {alias}` first.",
+                target
+                    .todolist_name
+                    .as_deref()
+                    .unwrap_or(&target.project_name)
+            )));
+        }
+        config.aliases.insert(
+            alias.to_string(),
+            TargetAlias {
+                project_id: project.id,
+                project_name: project.name.clone(),
+                todolist_id: None,
+                todolist_name: None,
+            },
+        );
+    }
+
+    let pins = &mut config.projects.pins;
+    match pins.iter_mut().find(|pin| pin.id == project.id) {
+        Some(pin) => pin.name = project.name.clone(),
+        None => pins.push(PinnedProject {
+            id: project.id,
+            name: project.name.clone(),
+            legacy_alias: None,
+        }),
+    }
+    integration::save_config(&config)?;
+
+    Ok(ProjectPinOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        alias,
+    })
+}
+
+/// Removes the pin only; aliases for the project stay until `alias remove`.
+pub fn run_unpin(args: ProjectUnpinArgs) -> AppResult<ProjectPinOutput> {
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let reference = args.project.trim();
+
+    let index = if let Some(alias) = reference.strip_prefix('@') {
+        let project_id = lookup::resolve_alias(alias)?.project_id;
+        config
+            .projects
+            .pins
+            .iter()
+            .position(|pin| pin.id == project_id)
+    } else if let Ok(project_id) = reference.parse::<u64>() {
+        config
+            .projects
+            .pins
+            .iter()
+            .position(|pin| pin.id == project_id)
+    } else {
+        Some(lookup::match_by_name(
+            config.projects.pins.iter().map(|pin| pin.name.as_str()),
+            reference,
+            "pinned project",
+        )?)
+    }
+    .ok_or_else(|| AppError::no_account(format!("Project \"{reference}\" is not pinned.")))?;
+
+    let removed = config.projects.pins.remove(index);
+    integration::save_config(&config)?;
+
+    Ok(ProjectPinOutput {
+        ok: true,
+        project_id: removed.id,
+        project_name: removed.name,
+        alias: None,
+    })
+}
+
+pub fn run_pins() -> AppResult<ProjectPinsOutput> {
+    let config = integration::load_config()?;
+    let pins: Vec<PinEntry> = config
+        .projects
+        .pins
+        .into_iter()
+        .map(|pin| PinEntry {
+            aliases: lookup::project_aliases(&config.aliases, pin.id),
+            id: pin.id,
+            name: pin.name,
+        })
+        .collect();
+    let count = pins.len();
+
+    Ok(ProjectPinsOutput {
+        ok: true,
+        pins,
+        count,
+    })
+}
@@ -0,0 +1,56 @@
+use crate::cli::ProjectShowArgs;
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ProjectShowOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    pub people_count: usize,
+    /// The project's dock, in Basecamp's order. Disabled tools keep their ids.
+    pub tools: Vec<ProjectTool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectTool {
+    /// `todoset`, `message_board`, `vault`, `chat`, `schedule`, ...
+    pub name: String,
+    pub title: String,
+    pub id: u64,
+    pub enabled: bool,
+}
+
+pub async fn run(args: ProjectShowArgs) -> AppResult<ProjectShowOutput> {
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let people = client.list_project_people(project.id).await?;
+
+    Ok(ProjectShowOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        description: project
+            .description
+            .map(|description| description.trim().to_string())
+            .unwrap_or_default(),
+        people_count: people.len(),
+        tools: project
+            .dock
+            .into_iter()
+            .map(|tool| ProjectTool {
+                name: tool.name,
+                title: tool.title,
+                id: tool.id,
+                enabled: tool.enabled,
+            })
+            .collect(),
+        app_url: project.app_url,
+    })
+}
@@ -0,0 +1,369 @@
+//! `schedule list/add/export`: a project's Schedule tool. Times typed without
+//! an offset get the offset from `time_zone::profile_offset_at_local`, like
+//! dates elsewhere in the CLI.
+
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{CreateScheduleEntryPayload, ScheduleEntry};
+use crate::cli::{ScheduleAddArgs, ScheduleExportArgs, ScheduleListArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::{integration, transfer};
+use crate::features::projects::lookup;
+use crate::features::todos::people::resolve_people_references;
+use crate::ical;
+use crate::rich_text::{html_to_text, markdown_to_html};
+use crate::time_zone;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub from: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    pub entries: Vec<ScheduleEntrySummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleEntrySummary {
+    pub entry_id: u64,
+    pub summary: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub all_day: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub participants: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleAddOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(flatten)]
+    pub entry: ScheduleEntrySummary,
+}
+
+/// What `schedule export` produced; `document` is the iCalendar text.
+#[derive(Debug)]
+pub struct ScheduleExport {
+    pub project_id: u64,
+    pub project_name: String,
+    pub count: usize,
+    pub document: String,
+    /// Where the document was written; `None` means it goes to stdout.
+    pub path: Option<String>,
+}
+
+/// A `--starts-at`/`--ends-at` value before the profile's offset is known.
+enum When {
+    Day(Date),
+    /// Minutes after midnight.
+    Time(Date, u32),
+    /// Already carries its offset (`Z` or `+02:00`); sent as typed.
+    Exact(String),
+}
+
+impl From<ScheduleEntry> for ScheduleEntrySummary {
+    fn from(entry: ScheduleEntry) -> Self {
+        Self {
+            entry_id: entry.id,
+            summary: entry.summary,
+            starts_at: entry.starts_at,
+            ends_at: entry.ends_at,
+            all_day: entry.all_day,
+            participants: entry
+                .participants
+                .into_iter()
+                .map(|person| person.name)
+                .collect(),
+            app_url: entry.app_url,
+        }
+    }
+}
+
+pub async fn run_list(args: ScheduleListArgs) -> AppResult<ScheduleListOutput> {
+    let client = integration::session_client()?;
+    let (from, to) = date_range(&client, Some(&args.from), args.to.as_deref()).await?;
+    let from = from.unwrap_or_default();
+
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let schedule_id = lookup::dock_tool_id(&project, "schedule", "Schedule")?;
+    let mut entries: Vec<ScheduleEntrySummary> = client
+        .list_schedule_entries(project.id, schedule_id)
+        .await?
+        .into_iter()
+        .filter(|entry| overlaps(entry, Some(&from), to.as_deref()))
+        .map(ScheduleEntrySummary::from)
+        .collect();
+    entries.sort_by(|left, right| left.starts_at.cmp(&right.starts_at));
+    let count = entries.len();
+
+    Ok(ScheduleListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        from,
+        to,
+        entries,
+        count,
+    })
+}
+
+// All-day entries stay whole days; timed ones are written in UTC so every
+// calendar app shows them at the right local time.
+pub async fn run_export(args: ScheduleExportArgs) -> AppResult<ScheduleExport> {
+    let client = integration::session_client()?;
+    let (from, to) = date_range(&client, args.from.as_deref(), args.to.as_deref()).await?;
+
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let schedule_id = lookup::dock_tool_id(&project, "schedule", "Schedule")?;
+    let mut entries: Vec<ScheduleEntry> = client
+        .list_schedule_entries(project.id, schedule_id)
+        .await?
+        .into_iter()
+        .filter(|entry| overlaps(entry, from.as_deref(), to.as_deref()))
+        .collect();
+    entries.sort_by(|left, right| left.starts_at.cmp(&right.starts_at));
+
+    let events = entries
+        .into_iter()
+        .map(calendar_event)
+        .collect::<AppResult<Vec<_>>>()?;
+    let document = ical::render(&format!("{} schedule", project.name), &events);
+
+    let path = match args.ical.as_deref() {
+        Some(path) => {
+            transfer::write_export_file(path, document.as_bytes(), args.force)?;
+            Some(path.display().to_string())
+        }
+        None => None,
+    };
+
+    Ok(ScheduleExport {
+        project_id: project.id,
+        project_name: project.name,
+        count: events.len(),
+        document,
+        path,
+    })
+}
+
+pub async fn run_add(args: ScheduleAddArgs) -> AppResult<ScheduleAddOutput> {
+    let summary = args.summary.trim().to_string();
+    if summary.is_empty() {
+        return Err(AppError::invalid_input(
+            "Schedule entry summary cannot be blank.",
+        ));
+    }
+
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let starts = parse_when(&args.starts_at, today, "--starts-at")?;
+    let ends = parse_when(&args.ends_at, today, "--ends-at")?;
+    let all_day = match (&starts, &ends) {
+        (When::Day(_), When::Day(_)) => true,
+        (When::Day(_), _) | (_, When::Day(_)) => {
+            return Err(AppError::invalid_input(
+                "Give a time on both --starts-at and --ends-at, or on neither for an all-day entry.",
+            ));
+        }
+        _ => false,
+    };
+    ensure_order(&starts, &ends)?;
+
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let schedule_id = lookup::dock_tool_id(&project, "schedule", "Schedule")?;
+    let participant_ids = if args.participants.is_empty() {
+        Vec::new()
+    } else {
+        let people = client.list_project_people(project.id).await?;
+        resolve_people_references(&args.participants, &people, "--participant")?
+    };
+
+    let payload = CreateScheduleEntryPayload {
+        summary,
+        starts_at: to_timestamp(&client, starts, false).await?,
+        ends_at: to_timestamp(&client, ends, true).await?,
+        all_day,
+        description: args
+            .description
+            .filter(|description| !description.trim().is_empty())
+            .map(|description| markdown_to_html(&description)),
+        participant_ids,
+        notify: args.notify,
+    };
+    let entry = client
+        .create_schedule_entry(project.id, schedule_id, &payload)
+        .await?;
+
+    Ok(ScheduleAddOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        entry: ScheduleEntrySummary::from(entry),
+    })
+}
+
+// `--from`/`--to` as `YYYY-MM-DD`, checked against each other.
+async fn date_range(
+    client: &BasecampClient,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> AppResult<(Option<String>, Option<String>)> {
+    if from.is_none() && to.is_none() {
+        return Ok((None, None));
+    }
+    let today = time_zone::profile_today(client).await?;
+    let parse = |value: Option<&str>, label: &str| {
+        value
+            .map(|value| dates::parse_todo_date(value, today, label).map(|date| date.to_string()))
+            .transpose()
+    };
+    let from = parse(from, "--from date")?;
+    let to = parse(to, "--to date")?;
+    if let (Some(from), Some(to)) = (from.as_deref(), to.as_deref())
+        && to < from
+    {
+        return Err(AppError::invalid_input(format!(
+            "--to {to} is before --from {from}; nothing can match."
+        )));
+    }
+    Ok((from, to))
+}
+
+// Day granular, on the dates as Basecamp wrote them.
+fn overlaps(entry: &ScheduleEntry, from: Option<&str>, to: Option<&str>) -> bool {
+    let starts_on = entry.starts_at.get(..10).unwrap_or_default();
+    let ends_on = entry.ends_at.get(..10).unwrap_or(starts_on);
+    from.is_none_or(|from| ends_on >= from) && to.is_none_or(|to| starts_on <= to)
+}
+
+fn calendar_event(entry: ScheduleEntry) -> AppResult<ical::Event> {
+    let unreadable = |value: &str| {
+        AppError::generic(format!(
+            "Schedule entry {} has an unreadable time \"{value}\".",
+            entry.id
+        ))
+    };
+    let (start, end) = if entry.all_day {
+        let starts_on =
+            Date::parse_iso(&entry.starts_at).ok_or_else(|| unreadable(&entry.starts_at))?;
+        let ends_on = Date::parse_iso(&entry.ends_at).unwrap_or(starts_on);
+        (
+            ical::EventTime::Day(starts_on),
+            ical::EventTime::Day(ends_on.max(starts_on).add_days(1)),
+        )
+    } else {
+        let starts_at =
+            ical::parse_timestamp(&entry.starts_at).ok_or_else(|| unreadable(&entry.starts_at))?;
+        let ends_at = ical::parse_timestamp(&entry.ends_at).unwrap_or(starts_at);
+        (
+            ical::EventTime::Instant(starts_at),
+            ical::EventTime::Instant(ends_at.max(starts_at)),
+        )
+    };
+
+    Ok(ical::Event {
+        uid: format!("schedule-entry-{}@basecamp-cli", entry.id),
+        summary: entry.summary,
+        description: entry
+            .description
+            .as_deref()
+            .map(html_to_text)
+            .unwrap_or_default(),
+        url: entry.app_url,
+        start,
+        end,
+    })
+}
+
+// `<date>`, `<date> HH:MM`, `<date>THH:MM[:SS]`, or a full ISO 8601
+// timestamp with its offset. `<date>` takes the to-do date forms.
+fn parse_when(value: &str, today: Date, flag: &str) -> AppResult<When> {
+    let trimmed = value.trim();
+    let invalid = || {
+        AppError::invalid_input(format!(
+            "Invalid {flag} \"{trimmed}\". Use a date (YYYY-MM-DD, tomorrow, fri, ...), optionally followed by HH:MM."
+        ))
+    };
+
+    if let Some((date, time)) = trimmed
+        .split_once('T')
+        .filter(|(date, _)| dates::is_iso_date(date))
+    {
+        let date = Date::parse_iso(date).ok_or_else(invalid)?;
+        if time.ends_with('Z')
+            || time.contains('+')
+            || time.get(5..).is_some_and(|rest| rest.contains('-'))
+        {
+            return Ok(When::Exact(trimmed.to_string()));
+        }
+        return Ok(When::Time(date, parse_clock(time).ok_or_else(invalid)?));
+    }
+
+    match trimmed.rsplit_once(' ') {
+        Some((date, time)) if time.contains(':') => {
+            let date = dates::parse_todo_date(date, today, flag).map_err(|_| invalid())?;
+            Ok(When::Time(date, parse_clock(time).ok_or_else(invalid)?))
+        }
+        _ => Ok(When::Day(
+            dates::parse_todo_date(trimmed, today, flag).map_err(|_| invalid())?,
+        )),
+    }
+}
+
+// `H:MM`, `HH:MM`, or `HH:MM:SS` (seconds dropped), as minutes after midnight.
+fn parse_clock(value: &str) -> Option<u32> {
+    let mut parts = value.split(':');
+    let hours = parts.next()?.parse::<u32>().ok()?;
+    let minutes = parts
+        .next()
+        .filter(|part| part.len() == 2)?
+        .parse::<u32>()
+        .ok()?;
+    if let Some(seconds) = parts.next() {
+        seconds.get(..2)?.parse::<u32>().ok()?;
+    }
+    (hours < 24 && minutes < 60 && parts.next().is_none()).then_some(hours * 60 + minutes)
+}
+
+fn ensure_order(starts: &When, ends: &When) -> AppResult<()> {
+    let key = |when: &When| match when {
+        When::Day(date) => Some((*date, 0)),
+        When::Time(date, minutes) => Some((*date, *minutes)),
+        When::Exact(_) => None,
+    };
+    if let (Some(starts), Some(ends)) = (key(starts), key(ends))
+        && ends < starts
+    {
+        return Err(AppError::invalid_input("--ends-at is before --starts-at."));
+    }
+    Ok(())
+}
+
+// An all-day end covers its whole last day.
+async fn to_timestamp(client: &BasecampClient, when: When, end: bool) -> AppResult<String> {
+    let (date, minutes) = match when {
+        When::Exact(value) => return Ok(value),
+        When::Day(date) if end => (date, 23 * 60 + 59),
+        When::Day(date) => (date, 0),
+        When::Time(date, minutes) => (date, minutes),
+    };
+    let local = date.unix_days() * 86_400 + i64::from(minutes) * 60;
+    let offset = time_zone::profile_offset_at_local(client, local).await?;
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    Ok(format!(
+        "{date}T{:02}:{:02}:00{sign}{:02}:{:02}",
+        minutes / 60,
+        minutes % 60,
+        offset / 3600,
+        offset % 3600 / 60
+    ))
+}
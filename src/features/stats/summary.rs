@@ -0,0 +1,104 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::TimelineEvent;
+use crate::cli::StatsArgs;
+use crate::dates::{self, Date};
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use crate::time_zone;
+use serde::Serialize;
+use std::collections::HashMap;
+
+const MOST_ACTIVE_LIMIT: usize = 5;
+
+#[derive(Debug, Serialize)]
+pub struct StatsOutput {
+    pub ok: bool,
+    pub account_id: u64,
+    pub since: String,
+    pub until: String,
+    pub events_scanned: usize,
+    pub todos_created: usize,
+    pub todos_completed: usize,
+    pub messages_posted: usize,
+    pub comments_posted: usize,
+    pub most_active_projects: Vec<ProjectActivity>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectActivity {
+    pub project_id: u64,
+    pub project_name: String,
+    pub events: usize,
+}
+
+pub async fn run(args: StatsArgs) -> AppResult<StatsOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+    let until = time_zone::profile_today(&client).await?;
+    let since = dates::parse_since(&args.since, "--since", until)?;
+    let events = client.list_progress_events(&since.to_string()).await?;
+
+    let mut output = summarize(events, since);
+    output.account_id = session.account_id;
+    output.until = until.to_string();
+    Ok(output)
+}
+
+fn summarize(events: Vec<TimelineEvent>, since: Date) -> StatsOutput {
+    let mut output = StatsOutput {
+        ok: true,
+        account_id: 0,
+        since: since.to_string(),
+        until: String::new(),
+        events_scanned: 0,
+        todos_created: 0,
+        todos_completed: 0,
+        messages_posted: 0,
+        comments_posted: 0,
+        most_active_projects: Vec::new(),
+    };
+    let mut projects: HashMap<u64, ProjectActivity> = HashMap::new();
+
+    for event in events {
+        if Date::parse_iso(&event.created_at).is_none_or(|created| created < since) {
+            continue;
+        }
+
+        output.events_scanned += 1;
+        match event.kind.as_str() {
+            "todo_created" => output.todos_created += 1,
+            "todo_completed" => output.todos_completed += 1,
+            "message_created" => output.messages_posted += 1,
+            "comment_created" => output.comments_posted += 1,
+            _ => {}
+        }
+
+        if let Some(bucket) = event.bucket {
+            projects
+                .entry(bucket.id)
+                .or_insert_with(|| ProjectActivity {
+                    project_id: bucket.id,
+                    project_name: if bucket.name.is_empty() {
+                        format!("Project {}", bucket.id)
+                    } else {
+                        bucket.name
+                    },
+                    events: 0,
+                })
+                .events += 1;
+        }
+    }
+
+    let mut ranked = projects.into_values().collect::<Vec<_>>();
+    ranked.sort_by(|left, right| {
+        right
+            .events
+            .cmp(&left.events)
+            .then_with(|| left.project_name.cmp(&right.project_name))
+    });
+    ranked.truncate(MOST_ACTIVE_LIMIT);
+    output.most_active_projects = ranked;
+
+    output
+}
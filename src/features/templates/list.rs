@@ -0,0 +1,257 @@
+use super::todo::normalize_name;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{CreateTodoPayload, CreateTodolistPayload, Todo};
+use crate::cli::{ListTemplateApplyArgs, ListTemplateSaveArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::{ListTemplate, ListTemplateGroup, ListTemplateTodo};
+use crate::features::projects::lookup;
+use crate::features::todos::picker::{resolve_todolist, resolve_todoset_id, todolist_display_name};
+use crate::time_zone;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ListTemplateSaveOutput {
+    pub ok: bool,
+    pub name: String,
+    pub replaced: bool,
+    pub source_todolist_id: u64,
+    pub source_todolist_name: String,
+    pub groups: usize,
+    pub todos: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTemplateApplyOutput {
+    pub ok: bool,
+    pub name: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub start: String,
+    pub groups_created: usize,
+    pub todos_created: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTemplateListOutput {
+    pub ok: bool,
+    pub templates: Vec<ListTemplateSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListTemplateSummary {
+    pub name: String,
+    pub todolist_name: String,
+    pub groups: usize,
+    pub todos: usize,
+}
+
+pub async fn run_save(args: ListTemplateSaveArgs) -> AppResult<ListTemplateSaveOutput> {
+    let name = normalize_name(&args.name)?;
+    if !args.force
+        && integration::load_config()?
+            .templates
+            .lists
+            .contains_key(&name)
+    {
+        return Err(AppError::invalid_input(format!(
+            "List template \"{name}\" already exists. Re-run with --force to replace it."
+        )));
+    }
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+
+    let todos = client.list_todos(project.id, todolist.id, false).await?;
+    let mut groups = Vec::new();
+    for group in client.list_todolist_groups(project.id, todolist.id).await? {
+        let group_todos = client.list_todos(project.id, group.id, false).await?;
+        groups.push((todolist_display_name(&group), group_todos));
+    }
+
+    // Due dates are stored relative to the earliest one so the plan can be
+    // shifted to any start date on apply.
+    let anchor = todos
+        .iter()
+        .chain(groups.iter().flat_map(|(_, todos)| todos))
+        .filter_map(|todo| todo.due_on.as_deref().and_then(Date::parse_iso))
+        .min();
+    let snapshot = |todos: Vec<Todo>| -> Vec<ListTemplateTodo> {
+        todos
+            .into_iter()
+            .map(|todo| ListTemplateTodo {
+                due_offset_days: todo
+                    .due_on
+                    .as_deref()
+                    .and_then(Date::parse_iso)
+                    .zip(anchor)
+                    .map(|(due_on, anchor)| due_on.days_since(anchor)),
+                content: todo.content,
+                notes: todo.description.filter(|notes| !notes.trim().is_empty()),
+            })
+            .collect()
+    };
+
+    let template = ListTemplate {
+        name: todolist_display_name(&todolist),
+        todos: snapshot(todos),
+        groups: groups
+            .into_iter()
+            .map(|(name, todos)| ListTemplateGroup {
+                name,
+                todos: snapshot(todos),
+            })
+            .collect(),
+    };
+    let group_count = template.groups.len();
+    let todo_count = count_todos(&template);
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let replaced = config
+        .templates
+        .lists
+        .insert(name.clone(), template)
+        .is_some();
+    integration::save_config(&config)?;
+
+    Ok(ListTemplateSaveOutput {
+        ok: true,
+        name,
+        replaced,
+        source_todolist_id: todolist.id,
+        source_todolist_name: todolist_display_name(&todolist),
+        groups: group_count,
+        todos: todo_count,
+    })
+}
+
+pub async fn run_apply(args: ListTemplateApplyArgs) -> AppResult<ListTemplateApplyOutput> {
+    let name = normalize_name(&args.name)?;
+    let template = integration::load_config()?
+        .templates
+        .lists
+        .remove(&name)
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "List template \"{name}\" does not exist. Run `basecamp-cli list template list`."
+            ))
+        })?;
+    let todolist_name = args
+        .title
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| template.name.clone());
+
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let start = match args.start.as_deref() {
+        Some(value) => dates::parse_start(value, "--start", today)?,
+        None => today,
+    };
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let todoset_id = resolve_todoset_id(&project)?;
+
+    let todolist = client
+        .create_todolist(
+            project.id,
+            todoset_id,
+            &CreateTodolistPayload {
+                name: todolist_name,
+                description: None,
+            },
+        )
+        .await?;
+    let mut todos_created =
+        create_todos(&client, project.id, todolist.id, &template.todos, start).await?;
+
+    for group in &template.groups {
+        let created_group = client
+            .create_todolist_group(
+                project.id,
+                todolist.id,
+                &CreateTodolistPayload {
+                    name: group.name.clone(),
+                    description: None,
+                },
+            )
+            .await?;
+        todos_created +=
+            create_todos(&client, project.id, created_group.id, &group.todos, start).await?;
+    }
+
+    Ok(ListTemplateApplyOutput {
+        ok: true,
+        name,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: todolist.id,
+        todolist_name: todolist_display_name(&todolist),
+        start: start.to_string(),
+        groups_created: template.groups.len(),
+        todos_created,
+    })
+}
+
+pub fn run_list() -> AppResult<ListTemplateListOutput> {
+    let templates: Vec<ListTemplateSummary> = integration::load_config()?
+        .templates
+        .lists
+        .into_iter()
+        .map(|(name, template)| ListTemplateSummary {
+            todos: count_todos(&template),
+            groups: template.groups.len(),
+            todolist_name: template.name,
+            name,
+        })
+        .collect();
+    let count = templates.len();
+
+    Ok(ListTemplateListOutput {
+        ok: true,
+        templates,
+        count,
+    })
+}
+
+async fn create_todos(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    todos: &[ListTemplateTodo],
+    start: Date,
+) -> AppResult<usize> {
+    for todo in todos {
+        client
+            .create_todo(
+                project_id,
+                todolist_id,
+                &CreateTodoPayload {
+                    content: todo.content.clone(),
+                    notes: todo.notes.clone(),
+                    assignee_ids: None,
+                    completion_subscriber_ids: None,
+                    starts_on: None,
+                    due_on: todo
+                        .due_offset_days
+                        .map(|days| start.add_days(days).to_string()),
+                },
+            )
+            .await?;
+    }
+    Ok(todos.len())
+}
+
+fn count_todos(template: &ListTemplate) -> usize {
+    template.todos.len()
+        + template
+            .groups
+            .iter()
+            .map(|group| group.todos.len())
+            .sum::<usize>()
+}
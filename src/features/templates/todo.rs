@@ -0,0 +1,248 @@
+use crate::cli::{TemplateDeleteArgs, TemplateSaveArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::TodoTemplate;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize)]
+pub struct TemplateSaveOutput {
+    pub ok: bool,
+    pub name: String,
+    pub replaced: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateListOutput {
+    pub ok: bool,
+    pub templates: Vec<TemplateSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateSummary {
+    pub name: String,
+    #[serde(flatten)]
+    pub template: TodoTemplate,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateDeleteOutput {
+    pub ok: bool,
+    pub name: String,
+}
+
+/// Template fields after placeholders and the relative due date are resolved.
+#[derive(Debug)]
+pub struct AppliedTodoTemplate {
+    pub content: String,
+    pub notes: Option<String>,
+    pub assignees: Vec<String>,
+    pub notify: Vec<String>,
+    pub due_on: Option<String>,
+}
+
+pub fn run_save(args: TemplateSaveArgs) -> AppResult<TemplateSaveOutput> {
+    let name = normalize_name(&args.name)?;
+    let content = args.content.trim().to_string();
+    if content.is_empty() {
+        return Err(AppError::invalid_input("`--content` cannot be blank."));
+    }
+    let due_in = args
+        .due_in
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    if let Some(value) = due_in.as_deref() {
+        parse_due_in(value)?;
+    }
+
+    let template = TodoTemplate {
+        content,
+        notes: args
+            .notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty()),
+        assignees: args.assignees,
+        notify: args.notify,
+        due_in,
+    };
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let replaced = config.templates.todos.contains_key(&name);
+    if replaced && !args.force {
+        return Err(AppError::invalid_input(format!(
+            "Template \"{name}\" already exists. Re-run with --force to replace it."
+        )));
+    }
+    config.templates.todos.insert(name.clone(), template);
+    integration::save_config(&config)?;
+
+    Ok(TemplateSaveOutput {
+        ok: true,
+        name,
+        replaced,
+    })
+}
+
+pub fn run_list() -> AppResult<TemplateListOutput> {
+    let templates: Vec<TemplateSummary> = integration::load_config()?
+        .templates
+        .todos
+        .into_iter()
+        .map(|(name, template)| TemplateSummary { name, template })
+        .collect();
+    let count = templates.len();
+
+    Ok(TemplateListOutput {
+        ok: true,
+        templates,
+        count,
+    })
+}
+
+pub fn run_delete(args: TemplateDeleteArgs) -> AppResult<TemplateDeleteOutput> {
+    let name = normalize_name(&args.name)?;
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    if config.templates.todos.remove(&name).is_none() {
+        return Err(AppError::invalid_input(format!(
+            "Template \"{name}\" does not exist."
+        )));
+    }
+    integration::save_config(&config)?;
+
+    Ok(TemplateDeleteOutput { ok: true, name })
+}
+
+/// Loads a template and fills `{date}`, `{due}`, and `--var key=value`
+/// placeholders; `today` is the profile's, for `{date}` and `due_in`.
+pub fn apply(name: &str, raw_vars: &[String], today: Date) -> AppResult<AppliedTodoTemplate> {
+    let name = normalize_name(name)?;
+    let template = integration::load_config()?
+        .templates
+        .todos
+        .remove(&name)
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "Template \"{name}\" does not exist. Run `basecamp-cli template list`."
+            ))
+        })?;
+
+    let due_on = template
+        .due_in
+        .as_deref()
+        .map(parse_due_in)
+        .transpose()?
+        .map(|days| today.add_days(days).to_string());
+
+    let mut vars = parse_vars(raw_vars)?;
+    vars.entry("date".to_string())
+        .or_insert_with(|| today.to_string());
+    if let Some(due_on) = due_on.as_deref() {
+        vars.entry("due".to_string())
+            .or_insert_with(|| due_on.to_string());
+    }
+
+    Ok(AppliedTodoTemplate {
+        content: render(&template.content, &vars)?,
+        notes: template
+            .notes
+            .as_deref()
+            .map(|notes| render(notes, &vars))
+            .transpose()?,
+        assignees: template.assignees,
+        notify: template.notify,
+        due_on,
+    })
+}
+
+fn parse_due_in(value: &str) -> AppResult<i64> {
+    dates::parse_day_count(value).ok_or_else(|| {
+        AppError::invalid_input(format!(
+            "Invalid due_in value \"{value}\". Use a duration like 3d or 2w."
+        ))
+    })
+}
+
+fn parse_vars(raw_vars: &[String]) -> AppResult<BTreeMap<String, String>> {
+    let mut vars = BTreeMap::new();
+    for raw in raw_vars {
+        let (key, value) = raw
+            .split_once('=')
+            .filter(|(key, _)| is_placeholder_key(key.trim()))
+            .ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "Invalid --var \"{raw}\". Use key=value with a key of letters, digits, `-`, or `_`."
+                ))
+            })?;
+        vars.insert(key.trim().to_ascii_lowercase(), value.to_string());
+    }
+    Ok(vars)
+}
+
+// Braces that do not wrap a placeholder key are kept as written.
+fn render(pattern: &str, vars: &BTreeMap<String, String>) -> AppResult<String> {
+    let mut rendered = String::with_capacity(pattern.len());
+    let mut missing: Vec<String> = Vec::new();
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let key = after
+            .find('}')
+            .map(|end| &after[..end])
+            .filter(|key| is_placeholder_key(key));
+
+        let Some(key) = key else {
+            rendered.push('{');
+            rest = after;
+            continue;
+        };
+
+        match vars.get(&key.to_ascii_lowercase()) {
+            Some(value) => rendered.push_str(value),
+            None => {
+                if !missing.iter().any(|name| name == key) {
+                    missing.push(key.to_string());
+                }
+            }
+        }
+        rest = &after[key.len() + 1..];
+    }
+    rendered.push_str(rest);
+
+    if !missing.is_empty() {
+        let hints = missing
+            .iter()
+            .map(|key| format!("--var {key}=..."))
+            .collect::<Vec<String>>()
+            .join(" ");
+        return Err(AppError::invalid_input(format!(
+            "Template placeholders need values: {hints}"
+        )));
+    }
+
+    Ok(rendered)
+}
+
+fn is_placeholder_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+}
+
+pub(super) fn normalize_name(raw: &str) -> AppResult<String> {
+    let name = raw.trim();
+    if !is_placeholder_key(name) {
+        return Err(AppError::invalid_input(format!(
+            "Invalid template name \"{raw}\". Use letters, digits, `-`, or `_`."
+        )));
+    }
+
+    Ok(name.to_ascii_lowercase())
+}
@@ -0,0 +1,91 @@
+use crate::basecamp::models::CreateTodolistPayload;
+use crate::cli::{ListGroupAddArgs, ListGroupListArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::projects::lookup;
+use crate::features::todos::picker::{resolve_todolist, todolist_display_name};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct GroupListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub groups: Vec<GroupSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupSummary {
+    pub group_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_ratio: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupAddOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub group_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_list(args: ListGroupListArgs) -> AppResult<GroupListOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+    let groups: Vec<GroupSummary> = client
+        .list_todolist_groups(project.id, todolist.id)
+        .await?
+        .into_iter()
+        .map(|group| GroupSummary {
+            group_id: group.id,
+            name: todolist_display_name(&group),
+            completed_ratio: group.completed_ratio,
+        })
+        .collect();
+    let count = groups.len();
+
+    Ok(GroupListOutput {
+        ok: true,
+        project_id: project.id,
+        todolist_id: todolist.id,
+        todolist_name: todolist_display_name(&todolist),
+        groups,
+        count,
+    })
+}
+
+pub async fn run_add(args: ListGroupAddArgs) -> AppResult<GroupAddOutput> {
+    let name = args.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::invalid_input("Group name cannot be blank."));
+    }
+
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+    let group = client
+        .create_todolist_group(
+            project.id,
+            todolist.id,
+            &CreateTodolistPayload {
+                name,
+                description: None,
+            },
+        )
+        .await?;
+
+    Ok(GroupAddOutput {
+        ok: true,
+        project_id: project.id,
+        todolist_id: todolist.id,
+        todolist_name: todolist_display_name(&todolist),
+        group_id: group.id,
+        name: todolist_display_name(&group),
+        app_url: group.app_url,
+    })
+}
@@ -0,0 +1,146 @@
+use crate::basecamp::models::{CreateTodolistPayload, UpdateTodolistPayload};
+use crate::cli::{ListArchiveArgs, ListCreateArgs, ListListArgs, ListRenameArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::projects::lookup;
+use crate::features::todos::picker::{resolve_todolist, resolve_todoset_id, todolist_display_name};
+use crate::rich_text::plain_to_html;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ListListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolists: Vec<TodolistSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodolistSummary {
+    pub todolist_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_ratio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+/// Shared by create, rename, and archive.
+#[derive(Debug, Serialize)]
+pub struct ListChangeOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_list(args: ListListArgs) -> AppResult<ListListOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolists: Vec<TodolistSummary> = client
+        .list_todolists(project.id, resolve_todoset_id(&project)?)
+        .await?
+        .into_iter()
+        .map(|todolist| TodolistSummary {
+            todolist_id: todolist.id,
+            name: todolist_display_name(&todolist),
+            completed_ratio: todolist.completed_ratio,
+            app_url: todolist.app_url,
+        })
+        .collect();
+    let count = todolists.len();
+
+    Ok(ListListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolists,
+        count,
+    })
+}
+
+pub async fn run_create(args: ListCreateArgs) -> AppResult<ListChangeOutput> {
+    let name = required_name(&args.name)?;
+    let description = args
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|description| !description.is_empty())
+        .map(plain_to_html);
+
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolist = client
+        .create_todolist(
+            project.id,
+            resolve_todoset_id(&project)?,
+            &CreateTodolistPayload { name, description },
+        )
+        .await?;
+
+    Ok(ListChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: todolist.id,
+        name: todolist_display_name(&todolist),
+        previous_name: None,
+        app_url: todolist.app_url,
+    })
+}
+
+pub async fn run_rename(args: ListRenameArgs) -> AppResult<ListChangeOutput> {
+    let name = required_name(&args.name)?;
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+    let previous_name = todolist_display_name(&todolist);
+
+    // Basecamp clears a description left out of an update, so it is sent back.
+    let updated = client
+        .update_todolist(
+            project.id,
+            todolist.id,
+            &UpdateTodolistPayload {
+                name,
+                description: todolist.description,
+            },
+        )
+        .await?;
+
+    Ok(ListChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: updated.id,
+        name: todolist_display_name(&updated),
+        previous_name: Some(previous_name),
+        app_url: updated.app_url,
+    })
+}
+
+pub async fn run_archive(args: ListArchiveArgs) -> AppResult<ListChangeOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+    client.archive_recording(project.id, todolist.id).await?;
+
+    Ok(ListChangeOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: todolist.id,
+        name: todolist_display_name(&todolist),
+        previous_name: None,
+        app_url: todolist.app_url,
+    })
+}
+
+fn required_name(value: &str) -> AppResult<String> {
+    let name = value.trim();
+    if name.is_empty() {
+        return Err(AppError::invalid_input("List name cannot be blank."));
+    }
+    Ok(name.to_string())
+}
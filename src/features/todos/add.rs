@@ -1,17 +1,34 @@
+use super::defaults::apply_target_defaults;
+use super::due::{ensure_date_range, resolve_due_date, resolve_start_date};
+use super::notes::{notes_to_html, resolve_notes_input};
+use super::people::{me_reference, resolve_default_references, resolve_people_references};
+use super::picker::{
+    prompt_select_project_watching, prompt_select_todolist, resolve_group, resolve_todoset_id,
+    todolist_display_name,
+};
+use super::position::move_todo;
 use crate::basecamp::client::BasecampClient;
-use crate::basecamp::models::{CreateTodoPayload, Project, ProjectPerson, Todolist};
-use crate::cli::TodoAddArgs;
+use crate::basecamp::dry_run;
+use crate::basecamp::links;
+use crate::basecamp::models::{
+    CreateTodoPayload, CreateTodolistPayload, Project, ProjectPerson, Todolist,
+};
+use crate::cli::{TodoAddArgs, TodoPosition, TodolistId};
+use crate::dates;
 use crate::error::{AppError, AppResult};
 use crate::features::auth::integration;
-use crate::ui::prompt_error;
+use crate::features::auth::models::TodoDraft;
+use crate::features::templates::todo as todo_template;
+use crate::features::uploads::file::{self, AttachedFile};
+use crate::time_zone;
+use crate::ui::{MULTISELECT_HELP_MESSAGE, SELECT_HELP_MESSAGE, Step, can_prompt, prompt_step};
 use colored::Colorize;
 use inquire::validator::Validation;
 use inquire::{Confirm, MultiSelect, Select, Text};
 use serde::Serialize;
-use std::io::{self, IsTerminal};
-
-const SELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Enter to select";
-const MULTISELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Space to select one, Right to all, Left to none, Enter to confirm";
+use std::cell::RefCell;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
 
 #[derive(Debug, Serialize)]
 pub struct TodoAddOutput {
@@ -22,163 +39,979 @@ pub struct TodoAddOutput {
     pub todolist_name: String,
     pub todo_id: u64,
     pub content: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    /// Set when `--position` moved the new to-do; 1-based.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    pub app_url: String,
 }
 
-pub async fn run(args: TodoAddArgs) -> AppResult<TodoAddOutput> {
-    ensure_interactive_terminal()?;
-
+pub async fn run(mut args: TodoAddArgs) -> AppResult<TodoAddOutput> {
+    if args.resume {
+        return resume(args).await;
+    }
+    args.notes = resolve_notes_input(args.notes.take(), args.notes_file.take())?;
+    for path in &args.attachments {
+        file::ensure_file(path)?;
+    }
     let session = integration::resolve_session_context()?;
-    let client = BasecampClient::new(session.account_id, session.access_token.clone())?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+    if let Some(name) = args.template.as_deref() {
+        let today = time_zone::profile_today(&client).await?;
+        let template = todo_template::apply(name, &args.vars, today)?;
+        args.content = args.content.or(Some(template.content));
+        args.notes = args.notes.or(template.notes);
+        args.due_on = args.due_on.or(template.due_on);
+        if args.assignees.is_empty() {
+            args.assignees = template.assignees;
+        }
+        if args.notify.is_empty() {
+            args.notify = template.notify;
+        }
+    }
 
-    let projects = client.list_projects().await?;
-    if projects.is_empty() {
-        return Err(AppError::no_account(
-            "No Basecamp projects were found for the current account.",
-        ));
+    let mut todolist_id =
+        resolve_todolist_flag(&mut args.project_id, args.todolist_id, "--todolist-id")?;
+    if !args.no_defaults {
+        apply_target_defaults(&mut args.project_id, &mut todolist_id)?;
     }
 
-    let project_index = prompt_select_project(&projects)?;
-    let project = &projects[project_index];
-    let todoset_id = resolve_todoset_id(project)?;
+    // With the target and title on the command line nothing is left to ask,
+    // so cron jobs and scripts never need a terminal.
+    let unattended = args.project_id.is_some()
+        && todolist_id.is_some()
+        && normalize_optional(args.content.clone()).is_some();
+    if !unattended {
+        ensure_interactive_terminal()?;
+    }
 
-    let todolists = client.list_todolists(project.id, todoset_id).await?;
-    if todolists.is_empty() {
-        return Err(AppError::no_account(format!(
-            "Project \"{}\" has no to-do lists.",
-            project.name
-        )));
+    if args.me {
+        args.assignees.push(me_reference(&client).await?);
     }
 
-    let todolist_index = prompt_select_todolist(&todolists)?;
-    let selected_todolist = &todolists[todolist_index];
+    // A bad `--due-on` or `--starts-on` fails before any prompt.
+    let flag_due_on = resolve_due_date(&client, args.due_on.as_deref()).await?;
+    let flag_starts_on = resolve_start_date(&client, args.starts_on.as_deref()).await?;
+    if args.due_on.is_some() {
+        ensure_date_range(flag_starts_on.as_deref(), flag_due_on.as_deref())?;
+    }
+    let answers = AddWizard::new(
+        &client,
+        &args,
+        todolist_id,
+        unattended,
+        flag_due_on,
+        flag_starts_on,
+    )
+    .run()
+    .await?;
 
-    let mut target_todolist_id = selected_todolist.id;
-    let mut target_todolist_name = todolist_display_name(selected_todolist);
+    // Typed answers are worth keeping if the request fails; flags can simply
+    // be run again.
+    let draft = if unattended {
+        DraftHandling::None
+    } else {
+        DraftHandling::SaveOnFailure
+    };
+    // Files go up only once the prompts are answered, so a cancelled add
+    // leaves nothing behind.
+    let attached = file::attach_files(&client, &args.attachments).await?;
+    create(
+        &client,
+        session.account_id,
+        answers,
+        &attached,
+        args.position,
+        draft,
+    )
+    .await
+}
 
-    if prompt_use_group()? {
-        let groups = client
-            .list_todolist_groups(project.id, selected_todolist.id)
-            .await?;
+/// What happens to the saved draft around the create request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DraftHandling {
+    None,
+    SaveOnFailure,
+    ClearOnSuccess,
+}
 
-        if groups.is_empty() {
-            eprintln!(
-                "{}",
-                "No groups found in selected list. Creating todo in the list.".bright_black()
-            );
-        } else {
-            let group_index = prompt_select_group(&groups)?;
-            let group = &groups[group_index];
-            target_todolist_id = group.id;
-            target_todolist_name = format!(
-                "{} / {}",
-                target_todolist_name,
-                todolist_display_name(group)
-            );
-        }
+async fn resume(args: TodoAddArgs) -> AppResult<TodoAddOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let draft = integration::load_state()?.todo_draft.ok_or_else(|| {
+        AppError::no_account(
+            "No saved to-do draft. `todo add` saves one when the to-do cannot be created after the prompts.",
+        )
+    })?;
+    if draft.account_id != session.account_id {
+        return Err(AppError::invalid_input(format!(
+            "The draft was saved in account {}, not the current account {}.",
+            draft.account_id, session.account_id
+        )));
     }
+    eprintln!(
+        "{}",
+        format!(
+            "Resuming the draft from {}: \"{}\" in project \"{}\" / list \"{}\".",
+            dates::format_unix_utc(draft.saved_at),
+            draft.content,
+            draft.project_name,
+            draft.todolist_name
+        )
+        .bright_black()
+    );
 
-    let content = resolve_content(args.content)?;
-    let notes = resolve_notes(args.notes)?;
-    let people = resolve_optional_people(client.list_project_people(project.id).await);
-    let assignee_id = prompt_assignee(people.as_deref())?;
-    let completion_subscriber_ids = prompt_completion_subscribers(people.as_deref())?;
-    let due_on = resolve_due_on(args.due_on)?;
+    let answers = AddAnswers {
+        project_id: draft.project_id,
+        project_name: draft.project_name,
+        target_todolist_id: draft.todolist_id,
+        target_todolist_name: draft.todolist_name,
+        content: draft.content,
+        notes: draft.notes,
+        assignee_ids: Some(draft.assignee_ids).filter(|ids| !ids.is_empty()),
+        completion_subscriber_ids: Some(draft.completion_subscriber_ids)
+            .filter(|ids| !ids.is_empty()),
+        starts_on: draft.starts_on,
+        due_on: draft.due_on,
+    };
+    create(
+        &client,
+        session.account_id,
+        answers,
+        &[],
+        args.position,
+        DraftHandling::ClearOnSuccess,
+    )
+    .await
+}
 
-    let created_todo = client
+async fn create(
+    client: &BasecampClient,
+    account_id: u64,
+    answers: AddAnswers,
+    attached: &[AttachedFile],
+    position: Option<TodoPosition>,
+    draft: DraftHandling,
+) -> AppResult<TodoAddOutput> {
+    let result = client
         .create_todo(
-            project.id,
-            target_todolist_id,
+            answers.project_id,
+            answers.target_todolist_id,
             &CreateTodoPayload {
-                content: content.clone(),
-                notes,
-                assignee_ids: assignee_id.map(|id| vec![id]),
-                completion_subscriber_ids,
-                due_on,
+                content: answers.content.clone(),
+                notes: file::append_embeds(notes_to_html(answers.notes.clone()), attached),
+                assignee_ids: answers.assignee_ids.clone(),
+                completion_subscriber_ids: answers.completion_subscriber_ids.clone(),
+                starts_on: answers.starts_on.clone(),
+                due_on: answers.due_on.clone(),
             },
         )
-        .await?;
+        .await;
+    let created_todo = match result {
+        Ok(todo) => todo,
+        Err(err) => {
+            if draft == DraftHandling::SaveOnFailure {
+                save_draft(account_id, &answers);
+            }
+            return Err(err);
+        }
+    };
+    if draft == DraftHandling::ClearOnSuccess {
+        clear_draft();
+    }
+
+    // The to-do exists at this point, so a failed move is a warning rather
+    // than an error that would invite a duplicate retry.
+    let position = match position {
+        Some(position) => {
+            match move_todo(
+                client,
+                answers.project_id,
+                answers.target_todolist_id,
+                created_todo.id,
+                position,
+            )
+            .await
+            {
+                Ok(position) => Some(position),
+                Err(err) => {
+                    eprintln!(
+                        "{}",
+                        format!("Created the to-do but could not move it: {}", err.message)
+                            .yellow()
+                    );
+                    None
+                }
+            }
+        }
+        None => None,
+    };
 
     Ok(TodoAddOutput {
         ok: true,
-        project_id: project.id,
-        project_name: project.name.clone(),
-        todolist_id: target_todolist_id,
-        todolist_name: target_todolist_name,
+        project_id: answers.project_id,
+        project_name: answers.project_name,
+        todolist_id: answers.target_todolist_id,
+        todolist_name: answers.target_todolist_name,
         todo_id: created_todo.id,
         content: created_todo.content,
+        assignees: created_todo
+            .assignees
+            .into_iter()
+            .map(|assignee| assignee.name)
+            .collect(),
+        starts_on: created_todo.starts_on,
+        due_on: created_todo.due_on,
+        position,
+        app_url: created_todo
+            .app_url
+            .unwrap_or_else(|| links::todo_url(account_id, answers.project_id, created_todo.id)),
     })
 }
 
-fn resolve_todoset_id(project: &Project) -> AppResult<u64> {
-    project
-        .dock
-        .iter()
-        .find(|item| item.name == "todoset" && item.enabled)
-        .map(|item| item.id)
-        .ok_or_else(|| {
-            AppError::no_account(format!(
-                "Project \"{}\" does not expose a usable todoset in dock.",
-                project.name
-            ))
+// Failing to save only costs the draft, so it warns instead of replacing
+// the request error.
+fn save_draft(account_id: u64, answers: &AddAnswers) {
+    if dry_run::enabled() {
+        return;
+    }
+
+    let saved_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let draft = TodoDraft {
+        account_id,
+        saved_at,
+        project_id: answers.project_id,
+        project_name: answers.project_name.clone(),
+        todolist_id: answers.target_todolist_id,
+        todolist_name: answers.target_todolist_name.clone(),
+        content: answers.content.clone(),
+        notes: answers.notes.clone(),
+        assignee_ids: answers.assignee_ids.clone().unwrap_or_default(),
+        completion_subscriber_ids: answers
+            .completion_subscriber_ids
+            .clone()
+            .unwrap_or_default(),
+        starts_on: answers.starts_on.clone(),
+        due_on: answers.due_on.clone(),
+    };
+    let saved = integration::lock_state().and_then(|_lock| {
+        let mut state = integration::load_state()?;
+        state.todo_draft = Some(draft);
+        integration::save_state(&state)
+    });
+    match saved {
+        Ok(()) => eprintln!(
+            "{}",
+            "Saved your answers; run `basecamp-cli todo add --resume` to try again.".yellow()
+        ),
+        Err(err) => eprintln!(
+            "{}",
+            format!("Could not save your answers as a draft: {}", err.message).yellow()
+        ),
+    }
+}
+
+// The to-do already exists, so a stale draft is only worth a warning.
+fn clear_draft() {
+    if dry_run::enabled() {
+        return;
+    }
+
+    let cleared = integration::lock_state().and_then(|_lock| {
+        let mut state = integration::load_state()?;
+        state.todo_draft = None;
+        integration::save_state(&state)
+    });
+    if let Err(err) = cleared {
+        eprintln!(
+            "{}",
+            format!(
+                "Created the to-do but could not clear the draft: {}",
+                err.message
+            )
+            .yellow()
+        );
+    }
+}
+
+/// The `todo add` prompts in order. Esc on one goes back to the previous
+/// prompt that was actually shown; steps settled by flags are skipped both
+/// ways.
+#[derive(Debug, Clone, Copy)]
+enum AddStep {
+    Project,
+    Todolist,
+    Group,
+    Title,
+    Notes,
+    Assignee,
+    Notify,
+    DueDate,
+    StartDate,
+}
+
+impl AddStep {
+    fn next(self) -> Option<Self> {
+        match self {
+            Self::Project => Some(Self::Todolist),
+            Self::Todolist => Some(Self::Group),
+            Self::Group => Some(Self::Title),
+            Self::Title => Some(Self::Notes),
+            Self::Notes => Some(Self::Assignee),
+            Self::Assignee => Some(Self::Notify),
+            Self::Notify => Some(Self::DueDate),
+            Self::DueDate => Some(Self::StartDate),
+            Self::StartDate => None,
+        }
+    }
+}
+
+struct AddAnswers {
+    project_id: u64,
+    project_name: String,
+    target_todolist_id: u64,
+    target_todolist_name: String,
+    content: String,
+    notes: Option<String>,
+    assignee_ids: Option<Vec<u64>>,
+    completion_subscriber_ids: Option<Vec<u64>>,
+    starts_on: Option<String>,
+    due_on: Option<String>,
+}
+
+/// Answers so far. Going back keeps them: lists and people fetched for a
+/// project are reused if it is picked again, and typed text is pre-filled.
+struct AddWizard<'a> {
+    client: &'a BasecampClient,
+    args: &'a TodoAddArgs,
+    todolist_id: Option<u64>,
+    unattended: bool,
+    flag_due_on: Option<String>,
+    flag_starts_on: Option<String>,
+    projects: Option<Vec<Project>>,
+    project: Option<Project>,
+    prefetch_project_id: Option<u64>,
+    todolists_task: Option<JoinHandle<AppResult<Vec<Todolist>>>>,
+    people_task: Option<JoinHandle<AppResult<Vec<ProjectPerson>>>>,
+    todolists: Option<Vec<Todolist>>,
+    people: Option<Option<Vec<ProjectPerson>>>,
+    todolist: Option<(u64, String)>,
+    /// A list made during this run has no groups to offer.
+    todolist_created: bool,
+    target: Option<(u64, String)>,
+    content: Option<String>,
+    notes: Option<String>,
+    assignee_ids: Option<Vec<u64>>,
+    picked_notify: Option<Vec<u64>>,
+    completion_subscriber_ids: Option<Vec<u64>>,
+    typed_due_on: Option<String>,
+    due_on: Option<String>,
+    typed_starts_on: Option<String>,
+    starts_on: Option<String>,
+}
+
+impl<'a> AddWizard<'a> {
+    fn new(
+        client: &'a BasecampClient,
+        args: &'a TodoAddArgs,
+        todolist_id: Option<u64>,
+        unattended: bool,
+        flag_due_on: Option<String>,
+        flag_starts_on: Option<String>,
+    ) -> Self {
+        Self {
+            client,
+            args,
+            todolist_id,
+            unattended,
+            flag_due_on,
+            flag_starts_on,
+            projects: None,
+            project: None,
+            prefetch_project_id: None,
+            todolists_task: None,
+            people_task: None,
+            todolists: None,
+            people: None,
+            todolist: None,
+            todolist_created: false,
+            target: None,
+            content: None,
+            notes: None,
+            assignee_ids: None,
+            picked_notify: None,
+            completion_subscriber_ids: None,
+            typed_due_on: None,
+            due_on: None,
+            typed_starts_on: None,
+            starts_on: None,
+        }
+    }
+
+    async fn run(mut self) -> AppResult<AddAnswers> {
+        let mut shown = Vec::new();
+        let mut step = Some(AddStep::Project);
+        while let Some(current) = step {
+            step = match self.ask(current).await? {
+                Step::Next(prompted) => {
+                    if prompted {
+                        shown.push(current);
+                    }
+                    current.next()
+                }
+                // Esc on the first prompt cancels, as it does elsewhere.
+                Step::Back => Some(
+                    shown
+                        .pop()
+                        .ok_or_else(|| AppError::invalid_input("Add to-do cancelled."))?,
+                ),
+            };
+        }
+        self.abort_prefetch();
+        ensure_date_range(self.starts_on.as_deref(), self.due_on.as_deref())?;
+
+        let (Some(project), Some((target_todolist_id, target_todolist_name)), Some(content)) =
+            (self.project, self.target, self.content)
+        else {
+            return Err(AppError::generic(
+                "The to-do target or title was not resolved.",
+            ));
+        };
+        Ok(AddAnswers {
+            project_id: project.id,
+            project_name: project.name,
+            target_todolist_id,
+            target_todolist_name,
+            content,
+            notes: self.notes,
+            assignee_ids: self.assignee_ids,
+            completion_subscriber_ids: self.completion_subscriber_ids,
+            starts_on: self.starts_on,
+            due_on: self.due_on,
         })
+    }
+
+    /// `Next(true)` when a prompt was shown, so a later Esc can return here.
+    async fn ask(&mut self, step: AddStep) -> AppResult<Step<bool>> {
+        match step {
+            AddStep::Project => self.ask_project().await,
+            AddStep::Todolist => self.ask_todolist().await,
+            AddStep::Group => self.ask_group().await,
+            AddStep::Title => self.ask_title(),
+            AddStep::Notes => self.ask_notes(),
+            AddStep::Assignee => self.ask_assignee().await,
+            AddStep::Notify => self.ask_notify(),
+            AddStep::DueDate => self.ask_due_date().await,
+            AddStep::StartDate => self.ask_start_date().await,
+        }
+    }
+
+    async fn ask_project(&mut self) -> AppResult<Step<bool>> {
+        if let Some(project_id) = self.args.project_id {
+            if self.project.is_none() {
+                let project = self.client.get_project(project_id).await?;
+                self.use_project(project)?;
+            }
+            return Ok(Step::Next(false));
+        }
+
+        if self.projects.is_none() {
+            let projects = self.client.list_projects().await?;
+            if projects.is_empty() {
+                return Err(AppError::no_account(
+                    "No Basecamp projects were found for the current account.",
+                ));
+            }
+            self.projects = Some(projects);
+        }
+
+        let projects = self.projects.as_deref().unwrap_or_default();
+        let watch = ProjectWatch::new(self.client, projects, self.prefetch_project_id);
+        let Step::Next(index) =
+            prompt_select_project_watching(projects, &|index| watch.highlighted(index))?
+        else {
+            watch.abort();
+            return Ok(Step::Back);
+        };
+        let project = projects
+            .get(index)
+            .cloned()
+            .ok_or_else(|| AppError::invalid_input("Project selection out of range."))?;
+        if let Some(prefetch) = watch.take(project.id) {
+            self.set_prefetch(prefetch);
+        }
+        self.use_project(project)?;
+        Ok(Step::Next(true))
+    }
+
+    async fn ask_todolist(&mut self) -> AppResult<Step<bool>> {
+        let project = self
+            .project
+            .clone()
+            .ok_or_else(|| AppError::generic("No project was selected."))?;
+        if let Some(todolist_id) = self.todolist_id {
+            if let Some(task) = self.todolists_task.take() {
+                task.abort();
+            }
+            self.target = Some(
+                resolve_flag_target(
+                    self.client,
+                    project.id,
+                    todolist_id,
+                    self.args.group_id,
+                    self.args.group.as_deref(),
+                )
+                .await?,
+            );
+            return Ok(Step::Next(false));
+        }
+
+        if self.todolists.is_none() {
+            let task = self
+                .todolists_task
+                .take()
+                .ok_or_else(|| AppError::generic("To-do lists were not requested."))?;
+            self.todolists = Some(join_prefetch(task).await?);
+        }
+        let todolists = self.todolists.as_deref().unwrap_or_default();
+        if todolists.is_empty() {
+            return self.ask_new_todolist(&project).await;
+        }
+
+        let Step::Next(index) = prompt_select_todolist(todolists)? else {
+            return Ok(Step::Back);
+        };
+        let todolist = todolists
+            .get(index)
+            .ok_or_else(|| AppError::invalid_input("To-do list selection out of range."))?;
+        self.todolist = Some((todolist.id, todolist_display_name(todolist)));
+        self.todolist_created = false;
+        Ok(Step::Next(true))
+    }
+
+    async fn ask_new_todolist(&mut self, project: &Project) -> AppResult<Step<bool>> {
+        // A new list has no groups, so `--group` cannot match anything in it.
+        if self.args.group.is_some() {
+            return Err(no_todolists(project));
+        }
+        match prompt_create_todolist(project)? {
+            Step::Back => return Ok(Step::Back),
+            Step::Next(false) => return Err(no_todolists(project)),
+            Step::Next(true) => {}
+        }
+        let Step::Next(name) = prompt_todolist_name()? else {
+            return Ok(Step::Back);
+        };
+
+        let todolist = create_todolist(self.client, project, name).await?;
+        self.todolist = Some((todolist.id, todolist_display_name(&todolist)));
+        self.todolist_created = true;
+        self.todolists.get_or_insert_with(Vec::new).push(todolist);
+        Ok(Step::Next(true))
+    }
+
+    async fn ask_group(&mut self) -> AppResult<Step<bool>> {
+        // `--todolist-id` settled the list and group in the list step.
+        if self.todolist_id.is_some() {
+            return Ok(Step::Next(false));
+        }
+        let project_id = self
+            .project
+            .as_ref()
+            .map(|project| project.id)
+            .ok_or_else(|| AppError::generic("No project was selected."))?;
+        let (todolist_id, todolist_name) = self
+            .todolist
+            .clone()
+            .ok_or_else(|| AppError::generic("No to-do list was selected."))?;
+
+        if let Some(reference) = self.args.group.as_deref() {
+            let group = resolve_group(self.client, project_id, todolist_id, reference).await?;
+            self.target = Some((
+                group.id,
+                format!("{todolist_name} / {}", todolist_display_name(&group)),
+            ));
+            return Ok(Step::Next(false));
+        }
+        if self.todolist_created {
+            self.target = Some((todolist_id, todolist_name));
+            return Ok(Step::Next(false));
+        }
+
+        loop {
+            match prompt_use_group()? {
+                Step::Back => return Ok(Step::Back),
+                Step::Next(false) => break,
+                Step::Next(true) => {}
+            }
+
+            let groups = self
+                .client
+                .list_todolist_groups(project_id, todolist_id)
+                .await?;
+            if groups.is_empty() {
+                eprintln!(
+                    "{}",
+                    "No groups found in selected list. Creating todo in the list.".bright_black()
+                );
+                break;
+            }
+
+            // Esc on the group list asks "Use a group?" again.
+            if let Step::Next(index) = prompt_select_group(&groups)? {
+                let group = groups
+                    .get(index)
+                    .ok_or_else(|| AppError::invalid_input("Group selection out of range."))?;
+                self.target = Some((
+                    group.id,
+                    format!("{todolist_name} / {}", todolist_display_name(group)),
+                ));
+                return Ok(Step::Next(true));
+            }
+        }
+
+        self.target = Some((todolist_id, todolist_name));
+        Ok(Step::Next(true))
+    }
+
+    fn ask_title(&mut self) -> AppResult<Step<bool>> {
+        if let Some(content) = normalize_optional(self.args.content.clone()) {
+            self.content = Some(content);
+            return Ok(Step::Next(false));
+        }
+
+        let Step::Next(content) = prompt_title(self.content.as_deref())? else {
+            return Ok(Step::Back);
+        };
+        self.content = Some(content);
+        Ok(Step::Next(true))
+    }
+
+    fn ask_notes(&mut self) -> AppResult<Step<bool>> {
+        if self.args.notes.is_some() || self.unattended {
+            self.notes = normalize_optional(self.args.notes.clone());
+            return Ok(Step::Next(false));
+        }
+
+        let Step::Next(notes) = prompt_optional_text("Notes (optional)", self.notes.as_deref())?
+        else {
+            return Ok(Step::Back);
+        };
+        self.notes = notes;
+        Ok(Step::Next(true))
+    }
+
+    async fn ask_assignee(&mut self) -> AppResult<Step<bool>> {
+        self.load_people().await?;
+        let people = self.people.as_ref().and_then(Option::as_deref);
+        if !self.args.assignees.is_empty() {
+            let ids = resolve_people_references(
+                &self.args.assignees,
+                people.unwrap_or_default(),
+                "--assignee",
+            )?;
+            self.assignee_ids = Some(ids).filter(|ids| !ids.is_empty());
+            return Ok(Step::Next(false));
+        }
+
+        let Some(people) = people.filter(|people| !self.unattended && !people.is_empty()) else {
+            self.assignee_ids = None;
+            return Ok(Step::Next(false));
+        };
+        let Step::Next(id) = prompt_assignee(people)? else {
+            return Ok(Step::Back);
+        };
+        self.assignee_ids = id.map(|id| vec![id]);
+        Ok(Step::Next(true))
+    }
+
+    fn ask_notify(&mut self) -> AppResult<Step<bool>> {
+        let people = self.people.as_ref().and_then(Option::as_deref);
+        if !self.args.notify.is_empty() {
+            let ids = resolve_people_references(
+                &self.args.notify,
+                people.unwrap_or_default(),
+                "--notify",
+            )?;
+            self.completion_subscriber_ids = Some(ids).filter(|ids| !ids.is_empty());
+            return Ok(Step::Next(false));
+        }
+
+        let prompted = !self.unattended && people.is_some_and(|people| !people.is_empty());
+        if prompted {
+            let current = self.picked_notify.clone().unwrap_or_default();
+            let Step::Next(picked) = prompt_completion_subscribers(people, &current)? else {
+                return Ok(Step::Back);
+            };
+            self.picked_notify = picked;
+        } else {
+            self.picked_notify = None;
+        }
+        self.completion_subscriber_ids = match self.picked_notify.clone() {
+            Some(ids) => Some(ids),
+            None => default_completion_subscribers(people)?,
+        };
+        Ok(Step::Next(prompted))
+    }
+
+    async fn ask_due_date(&mut self) -> AppResult<Step<bool>> {
+        if self.args.due_on.is_some() || self.unattended {
+            self.due_on = self.flag_due_on.clone();
+            return Ok(Step::Next(false));
+        }
+
+        let Step::Next(typed) = prompt_optional_text(
+            "Due date (optional: YYYY-MM-DD, tomorrow, +3d, fri, eom)",
+            self.typed_due_on.as_deref(),
+        )?
+        else {
+            return Ok(Step::Back);
+        };
+        self.due_on = resolve_due_date(self.client, typed.as_deref()).await?;
+        self.typed_due_on = typed;
+        Ok(Step::Next(true))
+    }
+
+    // A start date only means something with a due date, so it is only
+    // asked for once there is one.
+    async fn ask_start_date(&mut self) -> AppResult<Step<bool>> {
+        if self.args.starts_on.is_some() || self.unattended || self.due_on.is_none() {
+            self.starts_on = self.flag_starts_on.clone();
+            return Ok(Step::Next(false));
+        }
+
+        let Step::Next(typed) = prompt_optional_text(
+            "Start date (optional: YYYY-MM-DD, today, +1d, mon)",
+            self.typed_starts_on.as_deref(),
+        )?
+        else {
+            return Ok(Step::Back);
+        };
+        self.starts_on = resolve_start_date(self.client, typed.as_deref()).await?;
+        self.typed_starts_on = typed;
+        Ok(Step::Next(true))
+    }
+
+    // Picking the same project again keeps what was already fetched.
+    fn use_project(&mut self, project: Project) -> AppResult<()> {
+        if self.prefetch_project_id != Some(project.id) {
+            let todoset_id = resolve_todoset_id(&project)?;
+            self.set_prefetch(spawn_prefetch(self.client, project.id, todoset_id));
+        }
+        self.project = Some(project);
+        Ok(())
+    }
+
+    fn set_prefetch(&mut self, prefetch: ProjectPrefetch) {
+        self.abort_prefetch();
+        self.prefetch_project_id = Some(prefetch.project_id);
+        self.todolists_task = Some(prefetch.todolists);
+        self.people_task = Some(prefetch.people);
+        self.todolists = None;
+        self.people = None;
+    }
+
+    fn abort_prefetch(&mut self) {
+        if let Some(task) = self.todolists_task.take() {
+            task.abort();
+        }
+        if let Some(task) = self.people_task.take() {
+            task.abort();
+        }
+    }
+
+    // People are optional for the prompts but required to resolve
+    // `--assignee`/`--notify`.
+    async fn load_people(&mut self) -> AppResult<()> {
+        if self.people.is_some() {
+            return Ok(());
+        }
+
+        let task = self
+            .people_task
+            .take()
+            .ok_or_else(|| AppError::generic("Project people were not requested."))?;
+        let result = join_prefetch(task).await;
+        let people = if !self.args.assignees.is_empty() || !self.args.notify.is_empty() {
+            Some(result?)
+        } else {
+            resolve_optional_people(result)
+        };
+        self.people = Some(people);
+        Ok(())
+    }
 }
 
-fn prompt_select_project(projects: &[Project]) -> AppResult<usize> {
-    let labels: Vec<String> = projects
-        .iter()
-        .map(|project| format!("{} ({})", project.name, project.id))
-        .collect();
+/// A list id flag as a list id; a list `@alias` also sets the project.
+pub(super) fn resolve_todolist_flag(
+    project_id: &mut Option<u64>,
+    todolist: Option<TodolistId>,
+    flag: &str,
+) -> AppResult<Option<u64>> {
+    let Some(todolist) = todolist else {
+        return Ok(None);
+    };
 
-    Select::new("Project", labels)
-        .with_help_message(SELECT_HELP_MESSAGE)
-        .with_starting_cursor(0)
-        .raw_prompt()
-        .map(|selection| selection.index)
-        .map_err(|err| prompt_error("select project", err))
+    match (*project_id, todolist.project_id) {
+        (Some(project_id), Some(alias_project_id)) if project_id != alias_project_id => {
+            Err(AppError::invalid_input(format!(
+                "`{flag}` is in project {alias_project_id}, not `--project-id` {project_id}."
+            )))
+        }
+        (None, None) => Err(AppError::invalid_input(format!(
+            "`{flag}` needs `--project-id` unless it is a list @alias."
+        ))),
+        (_, alias_project_id) => {
+            *project_id = project_id.or(alias_project_id);
+            Ok(Some(todolist.id))
+        }
+    }
 }
 
-fn prompt_select_todolist(todolists: &[Todolist]) -> AppResult<usize> {
-    let labels: Vec<String> = todolists
-        .iter()
-        .map(|list| format!("{} ({})", todolist_display_name(list), list.id))
-        .collect();
+/// Uses `--project-id` when given, otherwise prompts; either way starts
+/// fetching the project's lists and people.
+pub(super) async fn resolve_project(
+    client: &BasecampClient,
+    project_id: Option<u64>,
+) -> AppResult<(Project, ProjectPrefetch)> {
+    let Some(project_id) = project_id else {
+        return select_project(client).await;
+    };
 
-    Select::new("To-do list", labels)
-        .with_help_message(SELECT_HELP_MESSAGE)
-        .with_starting_cursor(0)
-        .raw_prompt()
-        .map(|selection| selection.index)
-        .map_err(|err| prompt_error("select to-do list", err))
+    let project = client.get_project(project_id).await?;
+    let prefetch = spawn_prefetch(client, project.id, resolve_todoset_id(&project)?);
+    Ok((project, prefetch))
 }
 
-fn prompt_use_group() -> AppResult<bool> {
-    Confirm::new("Use a group?")
-        .with_default(false)
-        .prompt()
-        .map_err(|err| prompt_error("confirm group usage", err))
+/// Uses `--todolist-id` and `--group-id`/`--group` when given, otherwise
+/// prompts.
+pub(super) async fn resolve_target(
+    client: &BasecampClient,
+    project: &Project,
+    todolists: JoinHandle<AppResult<Vec<Todolist>>>,
+    todolist_id: Option<u64>,
+    group_id: Option<u64>,
+    group: Option<&str>,
+) -> AppResult<(u64, String)> {
+    match todolist_id {
+        Some(todolist_id) => {
+            todolists.abort();
+            resolve_flag_target(client, project.id, todolist_id, group_id, group).await
+        }
+        None => select_target(client, project, todolists, group).await,
+    }
 }
 
-fn prompt_select_group(groups: &[Todolist]) -> AppResult<usize> {
-    let labels: Vec<String> = groups
-        .iter()
-        .map(|group| format!("{} ({})", todolist_display_name(group), group.id))
-        .collect();
+async fn select_project(client: &BasecampClient) -> AppResult<(Project, ProjectPrefetch)> {
+    let mut projects = client.list_projects().await?;
+    if projects.is_empty() {
+        return Err(AppError::no_account(
+            "No Basecamp projects were found for the current account.",
+        ));
+    }
 
-    Select::new("Group", labels)
-        .with_help_message(SELECT_HELP_MESSAGE)
-        .with_starting_cursor(0)
-        .raw_prompt()
-        .map(|selection| selection.index)
-        .map_err(|err| prompt_error("select group", err))
+    let watch = ProjectWatch::new(client, &projects, None);
+    let selection = prompt_select_project_watching(&projects, &|index| watch.highlighted(index))
+        .and_then(|step| step.or_cancel("select project"));
+    let index = match selection {
+        Ok(index) => index,
+        Err(err) => {
+            watch.abort();
+            return Err(err);
+        }
+    };
+    let watched = projects
+        .get(index)
+        .and_then(|project| watch.take(project.id));
+    let project = projects.swap_remove(index);
+    let prefetch = match watched {
+        Some(prefetch) => prefetch,
+        None => spawn_prefetch(client, project.id, resolve_todoset_id(&project)?),
+    };
+
+    Ok((project, prefetch))
 }
 
-fn resolve_content(positional_content: Option<String>) -> AppResult<String> {
-    if let Some(value) = normalize_optional(positional_content) {
-        return Ok(value);
+async fn select_target(
+    client: &BasecampClient,
+    project: &Project,
+    todolists: JoinHandle<AppResult<Vec<Todolist>>>,
+    group: Option<&str>,
+) -> AppResult<(u64, String)> {
+    let todolists = join_prefetch(todolists).await?;
+    if todolists.is_empty() {
+        // A new list has no groups, so `--group` cannot match anything in it.
+        if group.is_some()
+            || !prompt_create_todolist(project)?.or_cancel("confirm list creation")?
+        {
+            return Err(no_todolists(project));
+        }
+        let name = prompt_todolist_name()?.or_cancel("read list name")?;
+        let todolist = create_todolist(client, project, name).await?;
+        return Ok((todolist.id, todolist_display_name(&todolist)));
     }
 
-    let required_message = "Title/content is required.".to_string();
-    let content = Text::new("Title")
+    let index = prompt_select_todolist(&todolists)?.or_cancel("select to-do list")?;
+    let selected_todolist = &todolists[index];
+    let todolist_name = todolist_display_name(selected_todolist);
+
+    if let Some(reference) = group {
+        let group = resolve_group(client, project.id, selected_todolist.id, reference).await?;
+        return Ok((
+            group.id,
+            format!("{todolist_name} / {}", todolist_display_name(&group)),
+        ));
+    }
+
+    if !prompt_use_group()?.or_cancel("confirm group usage")? {
+        return Ok((selected_todolist.id, todolist_name));
+    }
+
+    let groups = client
+        .list_todolist_groups(project.id, selected_todolist.id)
+        .await?;
+    if groups.is_empty() {
+        eprintln!(
+            "{}",
+            "No groups found in selected list. Creating todo in the list.".bright_black()
+        );
+        return Ok((selected_todolist.id, todolist_name));
+    }
+
+    let group = &groups[prompt_select_group(&groups)?.or_cancel("select group")?];
+    Ok((
+        group.id,
+        format!("{todolist_name} / {}", todolist_display_name(group)),
+    ))
+}
+
+fn no_todolists(project: &Project) -> AppError {
+    AppError::no_account(format!("Project \"{}\" has no to-do lists.", project.name))
+}
+
+fn prompt_create_todolist(project: &Project) -> AppResult<Step<bool>> {
+    let result = Confirm::new(&format!(
+        "Project \"{}\" has no to-do lists. Create one?",
+        project.name
+    ))
+    .with_default(true)
+    .prompt();
+    prompt_step(result, "confirm list creation")
+}
+
+fn prompt_todolist_name() -> AppResult<Step<String>> {
+    let required_message = "List name is required.".to_string();
+    let result = Text::new("List name")
         .with_help_message("Required.")
         .with_validator(move |value: &str| {
             if value.trim().is_empty() {
@@ -187,51 +1020,227 @@ fn resolve_content(positional_content: Option<String>) -> AppResult<String> {
                 Ok(Validation::Valid)
             }
         })
-        .prompt()
-        .map_err(|err| prompt_error("read title", err))?;
+        .prompt();
+    let Step::Next(name) = prompt_step(result, "read list name")? else {
+        return Ok(Step::Back);
+    };
 
-    normalize_optional(Some(content))
-        .ok_or_else(|| AppError::invalid_input("Title/content is required."))
+    normalize_optional(Some(name))
+        .map(Step::Next)
+        .ok_or_else(|| AppError::invalid_input("List name is required."))
 }
 
-fn resolve_notes(flag_notes: Option<String>) -> AppResult<Option<String>> {
-    if let Some(value) = flag_notes {
-        return Ok(normalize_optional(Some(value)));
+async fn create_todolist(
+    client: &BasecampClient,
+    project: &Project,
+    name: String,
+) -> AppResult<Todolist> {
+    let todoset_id = resolve_todoset_id(project)?;
+    let todolist = client
+        .create_todolist(
+            project.id,
+            todoset_id,
+            &CreateTodolistPayload {
+                name,
+                description: None,
+            },
+        )
+        .await?;
+    eprintln!(
+        "{}",
+        format!(
+            "Created list \"{}\" (id: {}).",
+            todolist_display_name(&todolist),
+            todolist.id
+        )
+        .bright_black()
+    );
+    Ok(todolist)
+}
+
+// Fetching the records both names them in the output and fails early on
+// ids from another project.
+async fn resolve_flag_target(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    group_id: Option<u64>,
+    group: Option<&str>,
+) -> AppResult<(u64, String)> {
+    let todolist = client.get_todolist(project_id, todolist_id).await?;
+    let todolist_name = todolist_display_name(&todolist);
+    let group = match (group_id, group) {
+        (Some(group_id), _) => client.get_todolist(project_id, group_id).await?,
+        (None, Some(reference)) => {
+            resolve_group(client, project_id, todolist.id, reference).await?
+        }
+        (None, None) => return Ok((todolist.id, todolist_name)),
+    };
+    Ok((
+        group.id,
+        format!("{todolist_name} / {}", todolist_display_name(&group)),
+    ))
+}
+
+pub(super) struct ProjectPrefetch {
+    project_id: u64,
+    pub(super) todolists: JoinHandle<AppResult<Vec<Todolist>>>,
+    pub(super) people: JoinHandle<AppResult<Vec<ProjectPerson>>>,
+}
+
+impl ProjectPrefetch {
+    fn abort(self) {
+        self.todolists.abort();
+        self.people.abort();
     }
+}
 
-    prompt_optional_text("Notes (optional)")
+/// At most this many projects are fetched in the background while the
+/// project prompt is open.
+const WATCHED_PREFETCH_LIMIT: usize = 5;
+
+/// Warms up the projects the cursor lands on while the project prompt is
+/// open, so the list and people prompts after it open without a wait.
+struct ProjectWatch<'a> {
+    client: &'a BasecampClient,
+    projects: &'a [Project],
+    /// Already fetched or fetching outside the watch.
+    known_project_id: Option<u64>,
+    started: RefCell<Vec<ProjectPrefetch>>,
 }
 
-fn resolve_due_on(flag_due_on: Option<String>) -> AppResult<Option<String>> {
-    if let Some(value) = flag_due_on {
-        if let Some(trimmed) = normalize_optional(Some(value)) {
-            validate_due_date(&trimmed)?;
-            return Ok(Some(trimmed));
+impl<'a> ProjectWatch<'a> {
+    fn new(
+        client: &'a BasecampClient,
+        projects: &'a [Project],
+        known_project_id: Option<u64>,
+    ) -> Self {
+        Self {
+            client,
+            projects,
+            known_project_id,
+            started: RefCell::new(Vec::new()),
         }
+    }
 
-        return Ok(None);
+    fn highlighted(&self, index: usize) {
+        let Some(project) = self.projects.get(index) else {
+            return;
+        };
+        let mut started = self.started.borrow_mut();
+        if self.known_project_id == Some(project.id)
+            || started.len() >= WATCHED_PREFETCH_LIMIT
+            || started
+                .iter()
+                .any(|prefetch| prefetch.project_id == project.id)
+        {
+            return;
+        }
+        if let Some(prefetch) = spawn_project_prefetch(self.client, project) {
+            started.push(prefetch);
+        }
+    }
+
+    /// The fetches for `project_id`, if any; the others are cancelled.
+    fn take(self, project_id: u64) -> Option<ProjectPrefetch> {
+        let mut taken = None;
+        for prefetch in self.started.into_inner() {
+            if prefetch.project_id == project_id {
+                taken = Some(prefetch);
+            } else {
+                prefetch.abort();
+            }
+        }
+        taken
     }
 
-    prompt_due_on()
+    fn abort(self) {
+        for prefetch in self.started.into_inner() {
+            prefetch.abort();
+        }
+    }
+}
+
+fn spawn_project_prefetch(client: &BasecampClient, project: &Project) -> Option<ProjectPrefetch> {
+    let todoset_id = resolve_todoset_id(project).ok()?;
+    Some(spawn_prefetch(client, project.id, todoset_id))
 }
 
-fn prompt_optional_text(prompt: &str) -> AppResult<Option<String>> {
-    let value = Text::new(prompt)
-        .prompt()
-        .map_err(|err| prompt_error(&format!("read {prompt}"), err))?;
+fn spawn_prefetch(client: &BasecampClient, project_id: u64, todoset_id: u64) -> ProjectPrefetch {
+    let todolists_client = client.clone();
+    let people_client = client.clone();
 
-    Ok(normalize_optional(Some(value)))
+    ProjectPrefetch {
+        project_id,
+        todolists: tokio::spawn(async move {
+            todolists_client
+                .list_todolists(project_id, todoset_id)
+                .await
+        }),
+        people: tokio::spawn(async move { people_client.list_project_people(project_id).await }),
+    }
 }
 
-fn prompt_assignee(people: Option<&[ProjectPerson]>) -> AppResult<Option<u64>> {
-    let Some(people) = people else {
-        return Ok(None);
+pub(super) async fn join_prefetch<T>(handle: JoinHandle<AppResult<T>>) -> AppResult<T> {
+    handle
+        .await
+        .map_err(|err| AppError::generic(format!("Background request failed: {err}")))?
+}
+
+fn prompt_use_group() -> AppResult<Step<bool>> {
+    let result = Confirm::new("Use a group?").with_default(false).prompt();
+    prompt_step(result, "confirm group usage")
+}
+
+fn prompt_select_group(groups: &[Todolist]) -> AppResult<Step<usize>> {
+    let labels: Vec<String> = groups
+        .iter()
+        .map(|group| format!("{} ({})", todolist_display_name(group), group.id))
+        .collect();
+
+    let result = Select::new("Group", labels)
+        .with_help_message(SELECT_HELP_MESSAGE)
+        .with_starting_cursor(0)
+        .raw_prompt()
+        .map(|selection| selection.index);
+    prompt_step(result, "select group")
+}
+
+/// `previous` is the title typed before going back, offered for editing.
+fn prompt_title(previous: Option<&str>) -> AppResult<Step<String>> {
+    let required_message = "Title/content is required.".to_string();
+    let mut prompt = Text::new("Title")
+        .with_help_message("Required.")
+        .with_validator(move |value: &str| {
+            if value.trim().is_empty() {
+                Ok(Validation::Invalid(required_message.clone().into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        });
+    if let Some(previous) = previous {
+        prompt = prompt.with_initial_value(previous);
+    }
+    let Step::Next(content) = prompt_step(prompt.prompt(), "read title")? else {
+        return Ok(Step::Back);
     };
 
-    if people.is_empty() {
-        return Ok(None);
+    normalize_optional(Some(content))
+        .map(Step::Next)
+        .ok_or_else(|| AppError::invalid_input("Title/content is required."))
+}
+
+fn prompt_optional_text(prompt: &str, previous: Option<&str>) -> AppResult<Step<Option<String>>> {
+    let mut text = Text::new(prompt);
+    if let Some(previous) = previous {
+        text = text.with_initial_value(previous);
     }
 
+    Ok(prompt_step(text.prompt(), &format!("read {prompt}"))?
+        .map(|value| normalize_optional(Some(value))))
+}
+
+fn prompt_assignee(people: &[ProjectPerson]) -> AppResult<Step<Option<u64>>> {
     let mut labels = Vec::with_capacity(people.len() + 1);
     labels.push("No assignee".to_string());
     labels.extend(
@@ -243,30 +1252,36 @@ fn prompt_assignee(people: Option<&[ProjectPerson]>) -> AppResult<Option<u64>> {
             }),
     );
 
-    let selection = Select::new("Assignee", labels)
+    let result = Select::new("Assignee", labels)
         .with_help_message(SELECT_HELP_MESSAGE)
         .with_starting_cursor(0)
         .raw_prompt()
-        .map(|selected| selected.index)
-        .map_err(|err| prompt_error("select assignee", err))?;
+        .map(|selected| selected.index);
+    let Step::Next(selection) = prompt_step(result, "select assignee")? else {
+        return Ok(Step::Back);
+    };
 
     if selection == 0 {
-        return Ok(None);
+        return Ok(Step::Next(None));
     }
 
     people
         .get(selection - 1)
-        .map(|person| Some(person.id))
+        .map(|person| Step::Next(Some(person.id)))
         .ok_or_else(|| AppError::invalid_input("Assignee selection out of range."))
 }
 
-fn prompt_completion_subscribers(people: Option<&[ProjectPerson]>) -> AppResult<Option<Vec<u64>>> {
+/// `current` ids start checked; `None` means nobody was picked.
+pub(super) fn prompt_completion_subscribers(
+    people: Option<&[ProjectPerson]>,
+    current: &[u64],
+) -> AppResult<Step<Option<Vec<u64>>>> {
     let Some(people) = people else {
-        return Ok(None);
+        return Ok(Step::Next(None));
     };
 
     if people.is_empty() {
-        return Ok(None);
+        return Ok(Step::Next(None));
     }
 
     let labels: Vec<String> = people
@@ -277,13 +1292,22 @@ fn prompt_completion_subscribers(people: Option<&[ProjectPerson]>) -> AppResult<
         })
         .collect();
 
-    let selections = MultiSelect::new("When done, notify", labels)
+    let checked: Vec<usize> = people
+        .iter()
+        .enumerate()
+        .filter(|(_, person)| current.contains(&person.id))
+        .map(|(index, _)| index)
+        .collect();
+    let result = MultiSelect::new("When done, notify", labels)
         .with_help_message(MULTISELECT_HELP_MESSAGE)
-        .raw_prompt()
-        .map_err(|err| prompt_error("select completion notifications", err))?;
+        .with_default(&checked)
+        .raw_prompt();
+    let Step::Next(selections) = prompt_step(result, "select completion notifications")? else {
+        return Ok(Step::Back);
+    };
 
     if selections.is_empty() {
-        return Ok(None);
+        return Ok(Step::Next(None));
     }
 
     let mut ids = Vec::with_capacity(selections.len());
@@ -294,92 +1318,31 @@ fn prompt_completion_subscribers(people: Option<&[ProjectPerson]>) -> AppResult<
         ids.push(person.id);
     }
 
-    Ok(Some(ids))
-}
-
-fn prompt_due_on() -> AppResult<Option<String>> {
-    let due_on = prompt_optional_text("Due date (optional, YYYY-MM-DD)")?;
-    if let Some(value) = due_on {
-        validate_due_date(&value)?;
-        return Ok(Some(value));
-    }
-    Ok(None)
+    Ok(Step::Next(Some(ids)))
 }
 
-fn validate_due_date(value: &str) -> AppResult<()> {
-    if value.len() != 10 {
-        return Err(AppError::invalid_input(
-            "Invalid due date. Use YYYY-MM-DD format.",
-        ));
-    }
-
-    let bytes = value.as_bytes();
-    if bytes[4] != b'-' || bytes[7] != b'-' {
-        return Err(AppError::invalid_input(
-            "Invalid due date. Use YYYY-MM-DD format.",
-        ));
-    }
-
-    let year = value[0..4]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid year in due date."))?;
-    let month = value[5..7]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid month in due date."))?;
-    let day = value[8..10]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid day in due date."))?;
-
-    if year == 0 {
-        return Err(AppError::invalid_input("Invalid year in due date."));
-    }
-
-    if !(1..=12).contains(&month) {
-        return Err(AppError::invalid_input("Invalid month in due date."));
-    }
-
-    let max_day = days_in_month(year, month);
-    if day == 0 || day > max_day {
-        return Err(AppError::invalid_input("Invalid day in due date."));
-    }
-
-    Ok(())
-}
-
-fn days_in_month(year: u32, month: u32) -> u32 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if is_leap_year(year) {
-                29
-            } else {
-                28
-            }
-        }
-        _ => 31,
-    }
-}
-
-fn is_leap_year(year: u32) -> bool {
-    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
-}
-
-fn todolist_display_name(todolist: &Todolist) -> String {
-    let title = todolist.title.trim();
-    if !title.is_empty() {
-        return title.to_string();
+// Applied only when nobody was chosen, so an explicit pick always wins.
+pub(super) fn default_completion_subscribers(
+    people: Option<&[ProjectPerson]>,
+) -> AppResult<Option<Vec<u64>>> {
+    let defaults = integration::load_config()?.defaults.notify_on_complete;
+    if defaults.is_empty() {
+        return Ok(None);
     }
 
-    let name = todolist.name.trim();
-    if !name.is_empty() {
-        return name.to_string();
-    }
+    let Some(people) = people else {
+        eprintln!(
+            "{}",
+            "Skipping defaults.notify_on_complete: project people are unavailable.".yellow()
+        );
+        return Ok(None);
+    };
 
-    format!("List {}", todolist.id)
+    let ids = resolve_default_references(&defaults, people, "defaults.notify_on_complete")?;
+    Ok(Some(ids).filter(|ids| !ids.is_empty()))
 }
 
-fn normalize_optional(value: Option<String>) -> Option<String> {
+pub(super) fn normalize_optional(value: Option<String>) -> Option<String> {
     value.and_then(|raw| {
         let trimmed = raw.trim().to_string();
         if trimmed.is_empty() {
@@ -390,13 +1353,13 @@ fn normalize_optional(value: Option<String>) -> Option<String> {
     })
 }
 
-fn ensure_interactive_terminal() -> AppResult<()> {
-    if io::stdin().is_terminal() && io::stderr().is_terminal() {
+pub(super) fn ensure_interactive_terminal() -> AppResult<()> {
+    if can_prompt() {
         return Ok(());
     }
 
     Err(AppError::invalid_input(
-        "`basecamp-cli todo add` requires an interactive terminal for prompts.",
+        "`basecamp-cli todo add` requires an interactive terminal for prompts. Pass content, `--project-id`, and `--todolist-id` to run without one.",
     ))
 }
 
@@ -0,0 +1,133 @@
+use super::people::{me_reference, resolve_people_references};
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::UpdateTodoPayload;
+use crate::cli::TodoAssignArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoAssignOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub todo_id: u64,
+    pub content: String,
+    /// Everyone assigned after the change, not just the people added.
+    pub assignees: Vec<String>,
+    /// How many of the requested people were not assigned yet.
+    pub added: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run(mut args: TodoAssignArgs) -> AppResult<TodoAssignOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name) = match target.direct {
+        Some((project_id, todo_id)) => ("direct", None, project_id, todo_id, None),
+        None => {
+            ensure_search_mode_terminal("assign")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::IncompleteOnly,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No open to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .get(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name.clone()),
+            )
+        }
+    };
+
+    if args.me {
+        args.assignees.push(me_reference(&client).await?);
+    }
+    let (todo, people) = tokio::try_join!(
+        client.get_todo(project_id, todo_id),
+        client.list_project_people(project_id),
+    )?;
+    let requested = resolve_people_references(&args.assignees, &people, "--assignee")?;
+
+    let mut assignee_ids: Vec<u64> = todo.assignees.iter().map(|assignee| assignee.id).collect();
+    let current = assignee_ids.len();
+    for id in requested {
+        if !assignee_ids.contains(&id) {
+            assignee_ids.push(id);
+        }
+    }
+    let added = assignee_ids.len() - current;
+
+    // Nothing new to assign means nothing to send.
+    let todo = if added == 0 {
+        todo
+    } else {
+        // Basecamp clears fields missing from an update, so everything else
+        // is sent back as it is.
+        client
+            .update_todo(
+                project_id,
+                todo_id,
+                &UpdateTodoPayload {
+                    content: todo.content,
+                    notes: todo.description,
+                    starts_on: todo.starts_on,
+                    due_on: todo.due_on,
+                    assignee_ids: Some(assignee_ids),
+                    completion_subscriber_ids: Some(
+                        todo.completion_subscribers
+                            .iter()
+                            .map(|person| person.id)
+                            .collect(),
+                    ),
+                },
+            )
+            .await?
+    };
+
+    Ok(TodoAssignOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todo_id: todo.id,
+        content: todo.content,
+        assignees: todo
+            .assignees
+            .into_iter()
+            .map(|assignee| assignee.name)
+            .collect(),
+        added,
+        app_url: todo.app_url,
+    })
+}
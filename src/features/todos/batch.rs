@@ -0,0 +1,104 @@
+use crate::basecamp::links::BasecampLink;
+use crate::error::{AppError, AppResult};
+use serde::Serialize;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use url::Url;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct TodoReference {
+    pub(super) line: usize,
+    pub(super) project_id: u64,
+    pub(super) todo_id: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FailedTodo {
+    pub line: usize,
+    pub reference: String,
+    pub error: String,
+}
+
+/// Reads one to-do reference per line from a file, or stdin for `-`:
+/// `project_id todo_id`, `project_id:todo_id`, or a Basecamp to-do URL.
+/// Blank lines and `#` comments are skipped; unparsable lines are reported
+/// as failures instead of aborting the batch.
+pub(super) fn read_reference_file(
+    path: &Path,
+    account_id: u64,
+) -> AppResult<(Vec<TodoReference>, Vec<FailedTodo>)> {
+    let contents = if path.as_os_str() == "-" {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|err| AppError::generic(format!("Failed to read stdin: {err}")))?;
+        contents
+    } else {
+        fs::read_to_string(path).map_err(|err| {
+            AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+        })?
+    };
+
+    let mut references = Vec::new();
+    let mut failed = Vec::new();
+    for (index, raw) in contents.lines().enumerate() {
+        let line = index + 1;
+        let value = raw.trim();
+        if value.is_empty() || value.starts_with('#') {
+            continue;
+        }
+
+        match parse_todo_reference(value, account_id) {
+            Ok((project_id, todo_id)) => references.push(TodoReference {
+                line,
+                project_id,
+                todo_id,
+            }),
+            Err(error) => failed.push(FailedTodo {
+                line,
+                reference: value.to_string(),
+                error,
+            }),
+        }
+    }
+
+    if references.is_empty() && failed.is_empty() {
+        return Err(AppError::invalid_input(format!(
+            "{} does not contain any to-do references.",
+            path.display()
+        )));
+    }
+
+    Ok((references, failed))
+}
+
+fn parse_todo_reference(value: &str, account_id: u64) -> Result<(u64, u64), String> {
+    if let Some((project, todo)) = value
+        .split_once(':')
+        .or_else(|| value.split_once(char::is_whitespace))
+        && let (Ok(project_id), Ok(todo_id)) = (project.trim().parse(), todo.trim().parse())
+    {
+        return Ok((project_id, todo_id));
+    }
+
+    if Url::parse(value).is_err() {
+        return Err(
+            "Expected `project_id todo_id`, `project_id:todo_id`, or a Basecamp to-do URL."
+                .to_string(),
+        );
+    }
+    let Some((link, todo_id)) =
+        BasecampLink::parse(value).and_then(|link| link.todo_id().map(|todo_id| (link, todo_id)))
+    else {
+        return Err("URL is not a Basecamp to-do URL.".to_string());
+    };
+    if link.account_id != account_id {
+        return Err(format!(
+            "URL belongs to account {}, not the current account {account_id}.",
+            link.account_id
+        ));
+    }
+
+    Ok((link.project_id, todo_id))
+}
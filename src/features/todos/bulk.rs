@@ -0,0 +1,264 @@
+use super::add::{
+    default_completion_subscribers, ensure_interactive_terminal, join_prefetch, normalize_optional,
+    resolve_project, resolve_target, resolve_todolist_flag,
+};
+use super::batch::FailedTodo;
+use super::defaults::apply_target_defaults;
+use super::due::{ensure_date_range, resolve_due_date, resolve_start_date};
+use super::notes::{notes_to_html, reads_notes_from_stdin, resolve_notes_input};
+use super::people::{me_reference, resolve_people_references};
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{CreateTodoPayload, CreatedTodo, ProjectPerson};
+use crate::cli::TodoAddArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+#[derive(Debug, Serialize)]
+pub struct TodoBulkAddOutput {
+    pub ok: bool,
+    pub mode: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub created: Vec<BulkCreatedTodo>,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<FailedTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkCreatedTodo {
+    pub line: usize,
+    pub todo_id: u64,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+/// One entry of a `--from-file` JSON array. Unset fields fall back to the
+/// matching command-line flags.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BulkTodoEntry {
+    content: String,
+    #[serde(default)]
+    notes: Option<String>,
+    #[serde(default)]
+    starts_on: Option<String>,
+    #[serde(default)]
+    due_on: Option<String>,
+    #[serde(default)]
+    assignees: Vec<String>,
+    #[serde(default)]
+    notify: Vec<String>,
+}
+
+struct BulkItem {
+    line: usize,
+    entry: BulkTodoEntry,
+}
+
+pub async fn run(mut args: TodoAddArgs) -> AppResult<TodoBulkAddOutput> {
+    if args.stdin && reads_notes_from_stdin(args.notes.as_deref(), args.notes_file.as_deref()) {
+        return Err(AppError::invalid_input(
+            "`--stdin` already reads titles from stdin; pass the notes with `--notes-file <path>`.",
+        ));
+    }
+    args.notes = resolve_notes_input(args.notes.take(), args.notes_file.take())?;
+    // Input is read and checked before any prompt or request, so a bad file
+    // never costs a project/list selection.
+    let (mode, items) = match args.from_file.as_deref() {
+        Some(path) => ("file", read_json_entries(path)?),
+        None => ("stdin", read_title_lines()?),
+    };
+    if items.is_empty() {
+        return Err(AppError::invalid_input("No to-dos to create."));
+    }
+
+    let mut todolist_id =
+        resolve_todolist_flag(&mut args.project_id, args.todolist_id, "--todolist-id")?;
+    if !args.no_defaults {
+        apply_target_defaults(&mut args.project_id, &mut todolist_id)?;
+    }
+    if args.project_id.is_none() || todolist_id.is_none() {
+        ensure_interactive_terminal()?;
+    }
+
+    let client = integration::session_client()?;
+
+    let (project, prefetch) = resolve_project(&client, args.project_id).await?;
+    let (todolist_id, todolist_name) = resolve_target(
+        &client,
+        &project,
+        prefetch.todolists,
+        todolist_id,
+        args.group_id,
+        args.group.as_deref(),
+    )
+    .await?;
+
+    if args.me {
+        args.assignees.push(me_reference(&client).await?);
+    }
+    let needs_people = !args.assignees.is_empty()
+        || !args.notify.is_empty()
+        || items
+            .iter()
+            .any(|item| !item.entry.assignees.is_empty() || !item.entry.notify.is_empty());
+    let people = if needs_people {
+        Some(join_prefetch(prefetch.people).await?)
+    } else {
+        join_prefetch(prefetch.people).await.ok()
+    };
+    // Resolved once: the same defaults apply to every entry without `notify`.
+    let default_notify = default_completion_subscribers(people.as_deref())?;
+
+    let mut created = Vec::with_capacity(items.len());
+    let mut failed = Vec::new();
+    for item in items {
+        let line = item.line;
+        let content = item.entry.content.trim().to_string();
+        let result = create_entry(
+            &client,
+            project.id,
+            todolist_id,
+            item.entry,
+            &args,
+            people.as_deref().unwrap_or_default(),
+            default_notify.as_deref(),
+        )
+        .await;
+        match result {
+            Ok(todo) => created.push(BulkCreatedTodo {
+                line,
+                todo_id: todo.id,
+                content: todo.content,
+                app_url: todo.app_url,
+            }),
+            Err(err) => failed.push(FailedTodo {
+                line,
+                reference: content,
+                error: err.message,
+            }),
+        }
+    }
+
+    let count = created.len();
+    Ok(TodoBulkAddOutput {
+        ok: failed.is_empty(),
+        mode: mode.to_string(),
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id,
+        todolist_name,
+        created,
+        count,
+        failed,
+    })
+}
+
+async fn create_entry(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    entry: BulkTodoEntry,
+    args: &TodoAddArgs,
+    people: &[ProjectPerson],
+    default_notify: Option<&[u64]>,
+) -> AppResult<CreatedTodo> {
+    let content = normalize_optional(Some(entry.content))
+        .ok_or_else(|| AppError::invalid_input("Title/content is required."))?;
+    let notes = notes_to_html(entry.notes.or_else(|| args.notes.clone()));
+    let due_on = entry.due_on.or_else(|| args.due_on.clone());
+    let due_on = resolve_due_date(client, due_on.as_deref()).await?;
+    let starts_on = entry.starts_on.or_else(|| args.starts_on.clone());
+    let starts_on = resolve_start_date(client, starts_on.as_deref()).await?;
+    ensure_date_range(starts_on.as_deref(), due_on.as_deref())?;
+
+    let assignees = if entry.assignees.is_empty() {
+        &args.assignees
+    } else {
+        &entry.assignees
+    };
+    let notify = if entry.notify.is_empty() {
+        &args.notify
+    } else {
+        &entry.notify
+    };
+    let assignee_ids = resolve_people_references(assignees, people, "assignees")?;
+    let completion_subscriber_ids = if notify.is_empty() {
+        default_notify.map(<[u64]>::to_vec)
+    } else {
+        Some(resolve_people_references(notify, people, "notify")?)
+    };
+
+    client
+        .create_todo(
+            project_id,
+            todolist_id,
+            &CreateTodoPayload {
+                content,
+                notes,
+                assignee_ids: Some(assignee_ids).filter(|ids| !ids.is_empty()),
+                completion_subscriber_ids: completion_subscriber_ids.filter(|ids| !ids.is_empty()),
+                starts_on,
+                due_on,
+            },
+        )
+        .await
+}
+
+// One title per line; blank lines and `#` comments are skipped.
+fn read_title_lines() -> AppResult<Vec<BulkItem>> {
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|err| AppError::generic(format!("Failed to read stdin: {err}")))?;
+
+    Ok(contents
+        .lines()
+        .enumerate()
+        .filter(|(_, raw)| {
+            let value = raw.trim();
+            !value.is_empty() && !value.starts_with('#')
+        })
+        .map(|(index, raw)| BulkItem {
+            line: index + 1,
+            entry: BulkTodoEntry {
+                content: raw.trim().to_string(),
+                notes: None,
+                starts_on: None,
+                due_on: None,
+                assignees: Vec::new(),
+                notify: Vec::new(),
+            },
+        })
+        .collect())
+}
+
+// A JSON array of entries; `line` reports the 1-based entry number.
+fn read_json_entries(path: &Path) -> AppResult<Vec<BulkItem>> {
+    let contents = fs::read_to_string(path).map_err(|err| {
+        AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+    })?;
+    let entries: Vec<BulkTodoEntry> = serde_json::from_str(&contents).map_err(|err| {
+        AppError::invalid_input(format!(
+            "{} is not a JSON array of to-dos: {err}",
+            path.display()
+        ))
+    })?;
+
+    Ok(entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| BulkItem {
+            line: index + 1,
+            entry,
+        })
+        .collect())
+}
@@ -0,0 +1,129 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::CreateCommentPayload;
+use crate::cli::TodoCommentArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::rich_text::plain_to_html;
+use crate::ui::{can_prompt, prompt_error};
+use inquire::Text;
+use inquire::validator::Validation;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoCommentOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub todo_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo_content: Option<String>,
+    pub comment_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run(args: TodoCommentArgs) -> AppResult<TodoCommentOutput> {
+    // Checked before any request so a missing comment never costs a search.
+    let text = match args.text.as_deref().map(str::trim) {
+        Some("") => return Err(AppError::invalid_input("Comment text cannot be blank.")),
+        Some(text) => Some(text.to_string()),
+        None => None,
+    };
+    if text.is_none() && !can_prompt() {
+        return Err(AppError::invalid_input(
+            "Comment text is required when no interactive terminal is available.",
+        ));
+    }
+
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name, todo_content) =
+        if let Some((project_id, todo_id)) = target.direct {
+            ("direct", None, project_id, todo_id, None, None)
+        } else {
+            ensure_search_mode_terminal("comment")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::Any,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .into_iter()
+                .nth(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name),
+                Some(matched.content),
+            )
+        };
+
+    let text = match text {
+        Some(text) => text,
+        None => prompt_comment_text()?,
+    };
+
+    let comment = client
+        .create_comment(
+            project_id,
+            todo_id,
+            &CreateCommentPayload {
+                content: plain_to_html(&text),
+            },
+        )
+        .await?;
+
+    Ok(TodoCommentOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todo_id,
+        todo_content,
+        comment_id: comment.id,
+        app_url: comment.app_url,
+    })
+}
+
+fn prompt_comment_text() -> AppResult<String> {
+    let text = Text::new("Comment")
+        .with_help_message("Required.")
+        .with_validator(|value: &str| {
+            if value.trim().is_empty() {
+                Ok(Validation::Invalid("Comment text is required.".into()))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt()
+        .map_err(|err| prompt_error("read comment", err))?;
+
+    Ok(text.trim().to_string())
+}
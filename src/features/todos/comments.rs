@@ -0,0 +1,112 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::cli::TodoCommentsArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::rich_text::html_to_text;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoCommentsOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub todo_id: u64,
+    pub todo_content: String,
+    pub comments: Vec<TodoComment>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoComment {
+    pub comment_id: u64,
+    pub author: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run(args: TodoCommentsArgs) -> AppResult<TodoCommentsOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name) =
+        if let Some((project_id, todo_id)) = target.direct {
+            ("direct", None, project_id, todo_id, None)
+        } else {
+            ensure_search_mode_terminal("comments")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::Any,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .into_iter()
+                .nth(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name),
+            )
+        };
+
+    let (todo, comments) = tokio::try_join!(
+        client.get_todo(project_id, todo_id),
+        client.list_comments(project_id, todo_id)
+    )?;
+
+    let comments: Vec<TodoComment> = comments
+        .into_iter()
+        .map(|comment| TodoComment {
+            comment_id: comment.id,
+            author: comment
+                .creator
+                .map(|creator| creator.name)
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            created_at: comment.created_at,
+            content: html_to_text(&comment.content),
+            app_url: comment.app_url,
+        })
+        .collect();
+    let count = comments.len();
+
+    Ok(TodoCommentsOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todo_id,
+        todo_content: todo.content,
+        comments,
+        count,
+    })
+}
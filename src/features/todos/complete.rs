@@ -1,12 +1,20 @@
+use super::batch::{self, FailedTodo};
+use super::filter::MatchFilter;
+use super::picker::{resolve_project, resolve_todolist, todolist_display_name};
 use super::search::{
     TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todos,
-    resolve_query, search_todos,
+    resolve_query, search_todos_sorted,
 };
+use super::target::TodoTarget;
+use super::undo;
 use crate::basecamp::client::BasecampClient;
-use crate::cli::TodoCompleteArgs;
+use crate::basecamp::links;
+use crate::cli::{SearchSort, TodoCompleteArgs};
 use crate::error::{AppError, AppResult};
 use crate::features::auth::integration;
+use crate::features::auth::models::{TodoBatchAction, TodoBatchItem};
 use serde::Serialize;
+use std::path::Path;
 
 #[derive(Debug, Serialize)]
 pub struct TodoCompleteOutput {
@@ -18,6 +26,8 @@ pub struct TodoCompleteOutput {
     pub scope_project_id: Option<u64>,
     pub completed: Vec<CompletedTodo>,
     pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<FailedTodo>,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,17 +38,51 @@ pub struct CompletedTodo {
     pub project_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
+    pub app_url: String,
 }
 
 pub async fn run(args: TodoCompleteArgs) -> AppResult<TodoCompleteOutput> {
     let session = integration::resolve_session_context()?;
-    let client = BasecampClient::new(session.account_id, session.access_token.clone())?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
 
-    if let Some(todo_id) = args.id {
-        let project_id = args.project_id.ok_or_else(|| {
-            AppError::invalid_input("`--project-id` is required when using `--id`.")
-        })?;
+    let output = run_mode(&client, args, session.account_id).await?;
+    undo::record_batch(
+        session.account_id,
+        TodoBatchAction::Complete,
+        output
+            .completed
+            .iter()
+            .map(|todo| TodoBatchItem {
+                project_id: todo.project_id,
+                todo_id: todo.todo_id,
+                content: todo.content.clone(),
+            })
+            .collect(),
+    )?;
+    Ok(output)
+}
 
+async fn run_mode(
+    client: &BasecampClient,
+    args: TodoCompleteArgs,
+    account_id: u64,
+) -> AppResult<TodoCompleteOutput> {
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, account_id)?;
+    // `--all` asks for every text match, so a number stays search text.
+    let target = if args.all {
+        target
+    } else {
+        target.resolve_numeric_query(client).await?
+    };
+    let filter = MatchFilter {
+        assignees: args.assignees,
+        due_before: args.due_before,
+        due_after: args.due_after,
+        list: args.list.clone().filter(|_| args.position.is_none()),
+    };
+    if let Some((project_id, todo_id)) = target.direct {
+        filter.ensure_unused("a to-do URL or id")?;
         client.complete_todo(project_id, todo_id).await?;
 
         return Ok(TodoCompleteOutput {
@@ -51,24 +95,59 @@ pub async fn run(args: TodoCompleteArgs) -> AppResult<TodoCompleteOutput> {
                 project_id,
                 project_name: None,
                 content: None,
+                app_url: links::todo_url(account_id, project_id, todo_id),
             }],
             count: 1,
+            failed: Vec::new(),
         });
     }
 
+    if let Some(path) = args.from_file.as_deref() {
+        return run_file_mode(client, path, account_id).await;
+    }
+
+    if let (Some(list), Some(position)) = (args.list.as_deref(), args.position.as_deref()) {
+        let project_reference = match (args.project.as_deref(), target.project_id) {
+            (Some(project), _) => project.to_string(),
+            (None, Some(project_id)) => project_id.to_string(),
+            (None, None) => {
+                return Err(AppError::invalid_input(
+                    "`--project` or `--project-id` is required when using `--list`.",
+                ));
+            }
+        };
+        return run_position_mode(client, &project_reference, list, position, account_id).await;
+    }
+
+    if args.all {
+        let query = target.query.unwrap_or_default();
+        return run_all_mode(
+            client,
+            &query,
+            target.project_id,
+            &filter,
+            args.sort,
+            account_id,
+        )
+        .await;
+    }
+
     ensure_search_mode_terminal("complete")?;
-    let query = resolve_query(args.query)?;
-    let matches = search_todos(
-        &client,
+    let query = resolve_query(target.query)?;
+    let matches = search_todos_sorted(
+        client,
         &query,
-        args.project_id,
+        target.project_id,
         TodoCompletionFilter::IncompleteOnly,
+        args.sort,
     )
     .await?;
+    let matches = filter.apply(client, matches).await?;
 
     if matches.is_empty() {
         return Err(AppError::no_account(format!(
-            "No to-dos matched \"{query}\"."
+            "No to-dos matched \"{query}\"{}.",
+            filter.no_match_suffix()
         )));
     }
 
@@ -98,6 +177,7 @@ pub async fn run(args: TodoCompleteArgs) -> AppResult<TodoCompleteOutput> {
             project_id,
             project_name: Some(project_name),
             content: Some(content),
+            app_url: links::todo_url(account_id, project_id, todo_id),
         });
     }
 
@@ -106,8 +186,192 @@ pub async fn run(args: TodoCompleteArgs) -> AppResult<TodoCompleteOutput> {
         ok: true,
         mode: "search".to_string(),
         query: Some(query),
-        scope_project_id: args.project_id,
+        scope_project_id: target.project_id,
+        completed,
+        count,
+        failed: Vec::new(),
+    })
+}
+
+async fn run_all_mode(
+    client: &BasecampClient,
+    query: &str,
+    scope_project_id: Option<u64>,
+    filter: &MatchFilter,
+    sort: SearchSort,
+    account_id: u64,
+) -> AppResult<TodoCompleteOutput> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Err(AppError::invalid_input("Search query cannot be blank."));
+    }
+
+    let matches = search_todos_sorted(
+        client,
+        &query,
+        scope_project_id,
+        TodoCompletionFilter::IncompleteOnly,
+        sort,
+    )
+    .await?;
+    let matches = filter.apply(client, matches).await?;
+
+    // One failure does not stop the rest; `line` is the 1-based match rank.
+    let mut completed = Vec::with_capacity(matches.len());
+    let mut failed = Vec::new();
+    for (index, matched) in matches.into_iter().enumerate() {
+        match client
+            .complete_todo(matched.project_id, matched.todo_id)
+            .await
+        {
+            Ok(()) => completed.push(CompletedTodo {
+                todo_id: matched.todo_id,
+                project_id: matched.project_id,
+                project_name: Some(matched.project_name),
+                content: Some(matched.content),
+                app_url: links::todo_url(account_id, matched.project_id, matched.todo_id),
+            }),
+            Err(err) => failed.push(FailedTodo {
+                line: index + 1,
+                reference: format!("{}:{}", matched.project_id, matched.todo_id),
+                error: err.message,
+            }),
+        }
+    }
+
+    let count = completed.len();
+    Ok(TodoCompleteOutput {
+        ok: failed.is_empty(),
+        mode: "all".to_string(),
+        query: Some(query),
+        scope_project_id,
+        completed,
+        count,
+        failed,
+    })
+}
+
+async fn run_position_mode(
+    client: &BasecampClient,
+    project_reference: &str,
+    list_reference: &str,
+    position_spec: &str,
+    account_id: u64,
+) -> AppResult<TodoCompleteOutput> {
+    let ranges = parse_position_ranges(position_spec)?;
+    let project = resolve_project(client, project_reference).await?;
+    let todolist = resolve_todolist(client, &project, list_reference).await?;
+
+    let mut todos = client.list_todos(project.id, todolist.id, false).await?;
+    todos.sort_by_key(|todo| todo.position.unwrap_or(u32::MAX));
+
+    // Checked before expanding, so `1..99999999999` is an error rather than
+    // a huge allocation.
+    if let Some((_, out_of_range)) = ranges.iter().find(|(_, end)| *end > todos.len()) {
+        return Err(AppError::invalid_input(format!(
+            "Position {out_of_range} is out of range; list \"{}\" has {} open to-do(s).",
+            todolist_display_name(&todolist),
+            todos.len()
+        )));
+    }
+    let mut positions: Vec<usize> = ranges
+        .into_iter()
+        .flat_map(|(start, end)| start..=end)
+        .collect();
+    positions.sort_unstable();
+    positions.dedup();
+
+    let mut completed = Vec::with_capacity(positions.len());
+    for position in positions {
+        let todo = &todos[position - 1];
+        client.complete_todo(project.id, todo.id).await?;
+
+        completed.push(CompletedTodo {
+            todo_id: todo.id,
+            project_id: project.id,
+            project_name: Some(project.name.clone()),
+            content: Some(todo.content.clone()),
+            app_url: todo
+                .app_url
+                .clone()
+                .unwrap_or_else(|| links::todo_url(account_id, project.id, todo.id)),
+        });
+    }
+
+    let count = completed.len();
+    Ok(TodoCompleteOutput {
+        ok: true,
+        mode: "position".to_string(),
+        query: None,
+        scope_project_id: Some(project.id),
         completed,
         count,
+        failed: Vec::new(),
     })
 }
+
+async fn run_file_mode(
+    client: &BasecampClient,
+    path: &Path,
+    account_id: u64,
+) -> AppResult<TodoCompleteOutput> {
+    let (references, mut failed) = batch::read_reference_file(path, account_id)?;
+
+    let mut completed = Vec::with_capacity(references.len());
+    for reference in references {
+        match client
+            .complete_todo(reference.project_id, reference.todo_id)
+            .await
+        {
+            Ok(()) => completed.push(CompletedTodo {
+                todo_id: reference.todo_id,
+                project_id: reference.project_id,
+                project_name: None,
+                content: None,
+                app_url: links::todo_url(account_id, reference.project_id, reference.todo_id),
+            }),
+            Err(err) => failed.push(FailedTodo {
+                line: reference.line,
+                reference: format!("{}:{}", reference.project_id, reference.todo_id),
+                error: err.message,
+            }),
+        }
+    }
+    failed.sort_by_key(|item| item.line);
+
+    let count = completed.len();
+    Ok(TodoCompleteOutput {
+        ok: failed.is_empty(),
+        mode: "file".to_string(),
+        query: None,
+        scope_project_id: None,
+        completed,
+        count,
+        failed,
+    })
+}
+
+// Inclusive 1-based `(start, end)` ranges; expanded only once the list's
+// length is known.
+fn parse_position_ranges(spec: &str) -> AppResult<Vec<(usize, usize)>> {
+    let invalid = || {
+        AppError::invalid_input(format!(
+            "Invalid --position \"{spec}\". Use 1-based positions like `1`, `1..3`, or `1,3,5`."
+        ))
+    };
+
+    let mut ranges = Vec::new();
+    for part in spec.split(',').map(str::trim) {
+        let (start, end) = match part.split_once("..") {
+            Some((start, end)) => (start.trim(), end.trim_start_matches('=').trim()),
+            None => (part, part),
+        };
+        let start = start.parse::<usize>().map_err(|_| invalid())?;
+        let end = end.parse::<usize>().map_err(|_| invalid())?;
+        if start == 0 || end < start {
+            return Err(invalid());
+        }
+        ranges.push((start, end));
+    }
+    Ok(ranges)
+}
@@ -0,0 +1,77 @@
+//! Default `todo add` target: `defaults.project_id`/`defaults.todolist_id`
+//! from the nearest `.basecamp-cli.json` (this directory or a parent),
+//! otherwise from the profile's `config.json`.
+
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::DefaultsConfig;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const DIRECTORY_FILE: &str = ".basecamp-cli.json";
+
+#[derive(Debug, Default, Deserialize)]
+struct DirectoryConfig {
+    #[serde(default)]
+    defaults: DefaultsConfig,
+}
+
+/// Fills unset flags from the defaults. The default list only applies
+/// inside the default project, so `--project-id` elsewhere still prompts for
+/// a list.
+pub(super) fn apply_target_defaults(
+    project_id: &mut Option<u64>,
+    todolist_id: &mut Option<u64>,
+) -> AppResult<()> {
+    let (source, defaults) = target_defaults()?;
+    let Some(default_project_id) = defaults.project_id else {
+        if defaults.todolist_id.is_some() {
+            return Err(AppError::invalid_input(format!(
+                "{source}: defaults.todolist_id needs defaults.project_id."
+            )));
+        }
+        return Ok(());
+    };
+
+    let project_id = *project_id.get_or_insert(default_project_id);
+    if todolist_id.is_none() && project_id == default_project_id {
+        *todolist_id = defaults.todolist_id;
+    }
+    Ok(())
+}
+
+fn target_defaults() -> AppResult<(String, DefaultsConfig)> {
+    if let Some((path, defaults)) = directory_defaults()?
+        && (defaults.project_id.is_some() || defaults.todolist_id.is_some())
+    {
+        return Ok((path.display().to_string(), defaults));
+    }
+    Ok((
+        "config.json".to_string(),
+        integration::load_config()?.defaults,
+    ))
+}
+
+/// Only the nearest `.basecamp-cli.json` is read.
+fn directory_defaults() -> AppResult<Option<(PathBuf, DefaultsConfig)>> {
+    let Ok(current_dir) = env::current_dir() else {
+        return Ok(None);
+    };
+    let Some(path) = current_dir
+        .ancestors()
+        .map(|dir| dir.join(DIRECTORY_FILE))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+
+    let raw = fs::read_to_string(&path).map_err(|err| {
+        AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+    })?;
+    let config: DirectoryConfig = serde_json::from_str(&raw).map_err(|err| {
+        AppError::invalid_input(format!("Failed to parse {}: {err}", path.display()))
+    })?;
+    Ok(Some((path, config.defaults)))
+}
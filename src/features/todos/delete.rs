@@ -0,0 +1,122 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todos,
+    resolve_query, search_todos_sorted,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::cli::TodoDeleteArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::ui::prompt_error;
+use inquire::Confirm;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoDeleteOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_project_id: Option<u64>,
+    pub trashed: Vec<TrashedTodo>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrashedTodo {
+    pub todo_id: u64,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+pub async fn run(args: TodoDeleteArgs) -> AppResult<TodoDeleteOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    if let Some((project_id, todo_id)) = target.direct {
+        client.trash_recording(project_id, todo_id).await?;
+
+        return Ok(TodoDeleteOutput {
+            ok: true,
+            mode: "direct".to_string(),
+            query: None,
+            scope_project_id: Some(project_id),
+            trashed: vec![TrashedTodo {
+                todo_id,
+                project_id,
+                project_name: None,
+                content: None,
+            }],
+            count: 1,
+        });
+    }
+
+    ensure_search_mode_terminal("delete")?;
+    let query = resolve_query(target.query)?;
+    let matches = search_todos_sorted(
+        &client,
+        &query,
+        target.project_id,
+        TodoCompletionFilter::Any,
+        args.sort,
+    )
+    .await?;
+
+    if matches.is_empty() {
+        return Err(AppError::no_account(format!(
+            "No to-dos matched \"{query}\"."
+        )));
+    }
+
+    let selections = prompt_select_todos(&matches)?;
+    if selections.is_empty() {
+        return Err(AppError::invalid_input(
+            "Select at least one to-do to delete.",
+        ));
+    }
+
+    print_selected_todos(&matches, &selections)?;
+    // Multi-select makes stray picks easy; trashing is the one search action
+    // that removes the item from every list, so ask once more.
+    let confirmed = Confirm::new(&format!("Move {} to-do(s) to trash?", selections.len()))
+        .with_default(false)
+        .prompt()
+        .map_err(|err| prompt_error("confirm delete", err))?;
+    if !confirmed {
+        return Err(AppError::invalid_input("Delete cancelled."));
+    }
+
+    let mut trashed = Vec::with_capacity(selections.len());
+    for selection in selections {
+        let matched = matches
+            .get(selection)
+            .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+
+        client
+            .trash_recording(matched.project_id, matched.todo_id)
+            .await?;
+
+        trashed.push(TrashedTodo {
+            todo_id: matched.todo_id,
+            project_id: matched.project_id,
+            project_name: Some(matched.project_name.clone()),
+            content: Some(matched.content.clone()),
+        });
+    }
+
+    let count = trashed.len();
+    Ok(TodoDeleteOutput {
+        ok: true,
+        mode: "search".to_string(),
+        query: Some(query),
+        scope_project_id: target.project_id,
+        trashed,
+        count,
+    })
+}
@@ -0,0 +1,63 @@
+use crate::basecamp::client::BasecampClient;
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::time_zone;
+
+// Bulk runs resolve many entries; the profile is read once per process.
+/// Turns a typed due date into `YYYY-MM-DD`. Strict dates pass through;
+/// relative forms (`tomorrow`, `+3d`, `fri`, `eom`, ...) are resolved against
+/// today from `time_zone::profile_today`, which is only called for them.
+pub(super) async fn resolve_due_date(
+    client: &BasecampClient,
+    value: Option<&str>,
+) -> AppResult<Option<String>> {
+    resolve_todo_date(client, value, "due date").await
+}
+
+/// Same forms as [`resolve_due_date`], for `starts_on`.
+pub(super) async fn resolve_start_date(
+    client: &BasecampClient,
+    value: Option<&str>,
+) -> AppResult<Option<String>> {
+    resolve_todo_date(client, value, "start date").await
+}
+
+/// Basecamp only shows a start date as the beginning of a range, so one
+/// without a due date, or after it, is rejected before any write.
+pub(super) fn ensure_date_range(starts_on: Option<&str>, due_on: Option<&str>) -> AppResult<()> {
+    let Some(starts_on) = starts_on else {
+        return Ok(());
+    };
+    let Some(due_on) = due_on else {
+        return Err(AppError::invalid_input(
+            "A start date needs a due date; set `--due-on` too.",
+        ));
+    };
+
+    // Both are `YYYY-MM-DD`, so string order is date order.
+    if starts_on > due_on {
+        return Err(AppError::invalid_input(format!(
+            "Start date {starts_on} is after due date {due_on}."
+        )));
+    }
+    Ok(())
+}
+
+async fn resolve_todo_date(
+    client: &BasecampClient,
+    value: Option<&str>,
+    label: &str,
+) -> AppResult<Option<String>> {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+
+    let today = if dates::is_iso_date(value) {
+        Date::today_utc()
+    } else {
+        time_zone::profile_today(client).await?
+    };
+    Ok(Some(
+        dates::parse_todo_date(value, today, label)?.to_string(),
+    ))
+}
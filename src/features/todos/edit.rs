@@ -1,17 +1,30 @@
+use super::add::prompt_completion_subscribers;
+use super::due::{ensure_date_range, resolve_due_date, resolve_start_date};
+use super::notes::{notes_to_html, resolve_notes_input};
+use super::people::resolve_people_references;
 use super::search::{
-    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
-    resolve_query, search_todos,
+    TodoCompletionFilter, TodoMatch, ensure_search_mode_terminal, print_selected_todos,
+    prompt_select_todo, resolve_query, search_todos,
 };
+use super::target::TodoTarget;
 use crate::basecamp::client::BasecampClient;
-use crate::basecamp::models::UpdateTodoPayload;
+use crate::basecamp::links;
+use crate::basecamp::models::{Todo, UpdateTodoPayload};
 use crate::cli::TodoEditArgs;
 use crate::error::{AppError, AppResult};
 use crate::features::auth::integration;
-use crate::ui::prompt_error;
+use crate::features::uploads::file;
+use crate::rich_text::html_to_markdown;
+use crate::ui::{can_prompt, prompt_error};
+use colored::Colorize;
 use inquire::Text;
 use inquire::validator::Validation;
 use serde::Serialize;
-use std::io::{self, IsTerminal};
+use tokio::task::JoinHandle;
+
+// Top-ranked matches are fetched while the picker is open, so the edit
+// prompts usually start without another round trip.
+const PREFETCH_TODOS: usize = 5;
 
 #[derive(Debug, Serialize)]
 pub struct TodoEditOutput {
@@ -27,12 +40,21 @@ pub struct TodoEditOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due_on: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    /// Who is notified when the to-do is completed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<String>,
+    pub app_url: String,
 }
 
 pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
     let session = integration::resolve_session_context()?;
-    let client = BasecampClient::new(session.account_id, session.access_token.clone())?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
 
     let TodoEditArgs {
         query,
@@ -40,35 +62,70 @@ pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
         project_id,
         content,
         notes,
+        notes_file,
         due_on,
+        starts_on,
+        notify,
+        attachments,
+        open: _,
         json: _,
     } = args;
+    let notes = resolve_notes_input(notes, notes_file)?;
+    for path in &attachments {
+        file::ensure_file(path)?;
+    }
 
     let content_override = resolve_content_override(content)?;
     let notes_flag_provided = notes.is_some();
     let due_on_flag_provided = due_on.is_some();
-    let notes_override = normalize_optional(notes);
-    let due_on_override = resolve_due_on_override(due_on)?;
-
-    let (mode, direct_mode, query_output, project_id, todo_id, project_name) =
-        if let Some(todo_id) = id {
-            let project_id = project_id.ok_or_else(|| {
-                AppError::invalid_input("`--project-id` is required when using `--id`.")
-            })?;
-
-            ("direct".to_string(), true, None, project_id, todo_id, None)
+    let starts_on_flag_provided = starts_on.is_some();
+    let notify_flag_provided = !notify.is_empty();
+    let notes_override = notes_to_html(notes);
+    let due_on_override = resolve_due_date(&client, due_on.as_deref()).await?;
+    let starts_on_override = resolve_start_date(&client, starts_on.as_deref()).await?;
+
+    let target = TodoTarget::resolve(query, id, project_id, session.account_id)?
+        .resolve_numeric_query(&client)
+        .await?;
+    let (mode, direct_mode, query_output, project_id, todo_id, project_name, prefetched) =
+        if let Some((project_id, todo_id)) = target.direct {
+            (
+                "direct".to_string(),
+                true,
+                None,
+                project_id,
+                todo_id,
+                None,
+                None,
+            )
         } else {
             ensure_search_mode_terminal("edit")?;
-            let query = resolve_query(query)?;
-            let matches =
-                search_todos(&client, &query, project_id, TodoCompletionFilter::Any).await?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::Any,
+            )
+            .await?;
             if matches.is_empty() {
                 return Err(AppError::no_account(format!(
                     "No to-dos matched \"{query}\"."
                 )));
             }
 
-            let selection = prompt_select_todo(&matches)?;
+            let mut prefetches = prefetch_todos(&client, &matches);
+            let selection = prompt_select_todo(&matches);
+            let prefetched = match selection {
+                Ok(selection) if selection < prefetches.len() => {
+                    Some(prefetches.swap_remove(selection))
+                }
+                _ => None,
+            };
+            for handle in prefetches {
+                handle.abort();
+            }
+            let selection = selection?;
             let selections = [selection];
             print_selected_todos(&matches, &selections)?;
             let matched = matches
@@ -82,14 +139,30 @@ pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
                 matched.project_id,
                 matched.todo_id,
                 Some(matched.project_name.clone()),
+                prefetched,
             )
         };
 
-    let todo = client.get_todo(project_id, todo_id).await?;
-    let has_direct_overrides =
-        direct_mode && (content_override.is_some() || notes_flag_provided || due_on_flag_provided);
-
-    let (content, notes, due_on) = if has_direct_overrides {
+    let todo = match prefetched {
+        Some(handle) => handle
+            .await
+            .map_err(|err| AppError::generic(format!("Failed to fetch to-do: {err}")))??,
+        None => client.get_todo(project_id, todo_id).await?,
+    };
+    let has_direct_overrides = direct_mode
+        && (content_override.is_some()
+            || notes_flag_provided
+            || due_on_flag_provided
+            || starts_on_flag_provided
+            || notify_flag_provided
+            || !attachments.is_empty());
+    let current_subscriber_ids: Vec<u64> = todo
+        .completion_subscribers
+        .iter()
+        .map(|person| person.id)
+        .collect();
+
+    let (content, notes, due_on, starts_on, subscriber_ids) = if has_direct_overrides {
         let current_content =
             normalize_optional(Some(todo.content.clone())).unwrap_or_else(|| todo.content.clone());
         let content = content_override.unwrap_or(current_content);
@@ -103,11 +176,24 @@ pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
         } else {
             normalize_optional(todo.due_on.clone())
         };
+        let starts_on = if starts_on_flag_provided {
+            starts_on_override.clone()
+        } else {
+            normalize_optional(todo.starts_on.clone())
+        };
+        let subscriber_ids = if notify_flag_provided {
+            resolve_notify(&client, project_id, &notify).await?
+        } else {
+            current_subscriber_ids
+        };
 
-        (content, notes, due_on)
+        (content, notes, due_on, starts_on, subscriber_ids)
     } else {
-        let needs_prompt =
-            content_override.is_none() || !notes_flag_provided || !due_on_flag_provided;
+        let needs_prompt = content_override.is_none()
+            || !notes_flag_provided
+            || !due_on_flag_provided
+            || !starts_on_flag_provided
+            || !notify_flag_provided;
         if needs_prompt {
             ensure_edit_mode_terminal()?;
         }
@@ -119,26 +205,57 @@ pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
         let notes = if notes_flag_provided {
             notes_override
         } else {
-            prompt_editable_optional_text("Notes (optional)", todo.description.as_deref())?
+            prompt_editable_notes(todo.description.as_deref())?
         };
         let due_on = if due_on_flag_provided {
             due_on_override
         } else {
-            prompt_editable_due_on(todo.due_on.as_deref())?
+            let typed = prompt_editable_optional_text(
+                "Due date (optional: YYYY-MM-DD, tomorrow, +3d, fri, eom)",
+                todo.due_on.as_deref(),
+            )?;
+            resolve_due_date(&client, typed.as_deref()).await?
+        };
+        // Without a due date there is no range to start, so the prompt is
+        // skipped and any current start date is dropped.
+        let starts_on = if starts_on_flag_provided {
+            starts_on_override
+        } else if due_on.is_none() {
+            None
+        } else {
+            let typed = prompt_editable_optional_text(
+                "Start date (optional: YYYY-MM-DD, today, +1d, mon)",
+                todo.starts_on.as_deref(),
+            )?;
+            resolve_start_date(&client, typed.as_deref()).await?
+        };
+        let subscriber_ids = if notify_flag_provided {
+            resolve_notify(&client, project_id, &notify).await?
+        } else {
+            prompt_editable_subscribers(&client, project_id, &current_subscriber_ids).await?
         };
 
-        (content, notes, due_on)
+        (content, notes, due_on, starts_on, subscriber_ids)
     };
+    ensure_date_range(starts_on.as_deref(), due_on.as_deref())?;
+    let attached = file::attach_files(&client, &attachments).await?;
+    let notes = file::append_embeds(notes, &attached);
 
+    // Basecamp clears fields missing from an update, so the current start
+    // date, assignees, and completion subscribers are always sent back.
     let payload = UpdateTodoPayload {
         content: content.clone(),
         notes: notes.clone(),
+        starts_on: starts_on.clone(),
         due_on: due_on.clone(),
+        assignee_ids: Some(todo.assignees.iter().map(|assignee| assignee.id).collect()),
+        completion_subscriber_ids: Some(subscriber_ids),
     };
     let updated = client.update_todo(project_id, todo_id, &payload).await?;
 
     let output_content = normalize_optional(Some(updated.content)).unwrap_or(content);
     let output_description = normalize_optional(updated.description).or(notes);
+    let output_starts_on = normalize_optional(updated.starts_on).or(starts_on);
     let output_due_on = normalize_optional(updated.due_on).or(due_on);
 
     Ok(TodoEditOutput {
@@ -150,10 +267,78 @@ pub async fn run(args: TodoEditArgs) -> AppResult<TodoEditOutput> {
         todo_id: updated.id,
         content: output_content,
         description: output_description,
+        starts_on: output_starts_on,
         due_on: output_due_on,
+        assignees: updated
+            .assignees
+            .into_iter()
+            .map(|assignee| assignee.name)
+            .collect(),
+        notify: updated
+            .completion_subscribers
+            .into_iter()
+            .map(|person| person.name)
+            .collect(),
+        app_url: updated
+            .app_url
+            .unwrap_or_else(|| links::todo_url(session.account_id, project_id, updated.id)),
     })
 }
 
+/// `--notify` replaces the list; a blank value clears it.
+async fn resolve_notify(
+    client: &BasecampClient,
+    project_id: u64,
+    references: &[String],
+) -> AppResult<Vec<u64>> {
+    if references
+        .iter()
+        .all(|reference| reference.trim().is_empty())
+    {
+        return Ok(Vec::new());
+    }
+    let people = client.list_project_people(project_id).await?;
+    resolve_people_references(references, &people, "--notify")
+}
+
+/// Current subscribers start checked. People who have since left the project
+/// can't be listed, so they stay subscribed.
+async fn prompt_editable_subscribers(
+    client: &BasecampClient,
+    project_id: u64,
+    current: &[u64],
+) -> AppResult<Vec<u64>> {
+    let people = client.list_project_people(project_id).await?;
+    if people.is_empty() {
+        return Ok(current.to_vec());
+    }
+
+    let mut ids = prompt_completion_subscribers(Some(&people), current)?
+        .or_cancel("select completion notifications")?
+        .unwrap_or_default();
+    ids.extend(
+        current
+            .iter()
+            .filter(|id| !people.iter().any(|person| person.id == **id)),
+    );
+    Ok(ids)
+}
+
+fn prefetch_todos(
+    client: &BasecampClient,
+    matches: &[TodoMatch],
+) -> Vec<JoinHandle<AppResult<Todo>>> {
+    matches
+        .iter()
+        .take(PREFETCH_TODOS)
+        .map(|matched| {
+            let client = client.clone();
+            let (project_id, todo_id) = (matched.project_id, matched.todo_id);
+            tokio::spawn(async move { client.get_todo(project_id, todo_id).await })
+        })
+        .collect()
+}
+
 fn resolve_content_override(flag_content: Option<String>) -> AppResult<Option<String>> {
     let Some(raw) = flag_content else {
         return Ok(None);
@@ -164,14 +349,6 @@ fn resolve_content_override(flag_content: Option<String>) -> AppResult<Option<St
     Ok(Some(value))
 }
 
-fn resolve_due_on_override(flag_due_on: Option<String>) -> AppResult<Option<String>> {
-    let value = normalize_optional(flag_due_on);
-    if let Some(due_on) = value.as_deref() {
-        validate_due_date(due_on)?;
-    }
-    Ok(value)
-}
-
 fn prompt_editable_content(current_content: &str) -> AppResult<String> {
     let current_value = current_content.trim();
     let required_message = "Title/content is required.".to_string();
@@ -196,6 +373,35 @@ fn prompt_editable_content(current_content: &str) -> AppResult<String> {
         .ok_or_else(|| AppError::invalid_input("Title/content is required."))
 }
 
+/// Notes are edited as markdown. Untouched notes are sent back as stored, so
+/// formatting markdown can't express survives; multi-line notes don't fit a
+/// one-line prompt, so Enter keeps them and typed text replaces them.
+fn prompt_editable_notes(description: Option<&str>) -> AppResult<Option<String>> {
+    let current = normalize_optional(description.map(html_to_markdown));
+    let Some(markdown) = current
+        .as_deref()
+        .filter(|markdown| markdown.contains('\n'))
+    else {
+        let typed = prompt_editable_optional_text("Notes (optional)", current.as_deref())?;
+        if typed.is_some() && typed == current {
+            return Ok(normalize_optional(description.map(str::to_string)));
+        }
+        return Ok(notes_to_html(typed));
+    };
+
+    eprintln!("{}", markdown.bright_black());
+    let typed = Text::new("Notes")
+        .with_help_message(
+            "Enter keeps the notes above; typed text replaces them (--notes-file for multi-line)",
+        )
+        .prompt()
+        .map_err(|err| prompt_error("read Notes", err))?;
+    match normalize_optional(Some(typed)) {
+        Some(typed) => Ok(notes_to_html(Some(typed))),
+        None => Ok(normalize_optional(description.map(str::to_string))),
+    }
+}
+
 fn prompt_editable_optional_text(prompt: &str, current: Option<&str>) -> AppResult<Option<String>> {
     let current_value = current.and_then(|value| {
         let trimmed = value.trim();
@@ -217,73 +423,6 @@ fn prompt_editable_optional_text(prompt: &str, current: Option<&str>) -> AppResu
     Ok(normalize_optional(Some(value)))
 }
 
-fn prompt_editable_due_on(current_due_on: Option<&str>) -> AppResult<Option<String>> {
-    let value = prompt_editable_optional_text("Due date (optional, YYYY-MM-DD)", current_due_on)?;
-    if let Some(due_on) = value.as_deref() {
-        validate_due_date(due_on)?;
-    }
-    Ok(value)
-}
-
-fn validate_due_date(value: &str) -> AppResult<()> {
-    if value.len() != 10 {
-        return Err(AppError::invalid_input(
-            "Invalid due date. Use YYYY-MM-DD format.",
-        ));
-    }
-
-    let bytes = value.as_bytes();
-    if bytes[4] != b'-' || bytes[7] != b'-' {
-        return Err(AppError::invalid_input(
-            "Invalid due date. Use YYYY-MM-DD format.",
-        ));
-    }
-
-    let year = value[0..4]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid year in due date."))?;
-    let month = value[5..7]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid month in due date."))?;
-    let day = value[8..10]
-        .parse::<u32>()
-        .map_err(|_| AppError::invalid_input("Invalid day in due date."))?;
-
-    if year == 0 {
-        return Err(AppError::invalid_input("Invalid year in due date."));
-    }
-
-    if !(1..=12).contains(&month) {
-        return Err(AppError::invalid_input("Invalid month in due date."));
-    }
-
-    let max_day = days_in_month(year, month);
-    if day == 0 || day > max_day {
-        return Err(AppError::invalid_input("Invalid day in due date."));
-    }
-
-    Ok(())
-}
-
-fn days_in_month(year: u32, month: u32) -> u32 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if is_leap_year(year) {
-                29
-            } else {
-                28
-            }
-        }
-        _ => 31,
-    }
-}
-
-fn is_leap_year(year: u32) -> bool {
-    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
-}
-
 fn normalize_optional(value: Option<String>) -> Option<String> {
     value.and_then(|raw| {
         let trimmed = raw.trim().to_string();
@@ -296,7 +435,7 @@ fn normalize_optional(value: Option<String>) -> Option<String> {
 }
 
 fn ensure_edit_mode_terminal() -> AppResult<()> {
-    if io::stdin().is_terminal() && io::stderr().is_terminal() {
+    if can_prompt() {
         return Ok(());
     }
 
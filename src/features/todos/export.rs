@@ -0,0 +1,318 @@
+use super::picker::{resolve_project, resolve_todolist, resolve_todoset_id, todolist_display_name};
+use super::show::creator_name;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{Project, Todo, Todolist};
+use crate::cli::{ExportFormat, TodoExportArgs};
+use crate::csv;
+use crate::dates::Date;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::{integration, transfer};
+use crate::ical;
+use crate::rich_text;
+use serde::Serialize;
+
+const CSV_HEADER: [&str; 18] = [
+    "project_id",
+    "project_name",
+    "todolist_id",
+    "todolist_name",
+    "group_id",
+    "group_name",
+    "todo_id",
+    "content",
+    "notes",
+    "completed",
+    "completed_at",
+    "completed_by",
+    "starts_on",
+    "due_on",
+    "assignees",
+    "created_by",
+    "created_at",
+    "app_url",
+];
+
+#[derive(Debug, Serialize)]
+pub struct TodoExportOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_name: Option<String>,
+    pub count: usize,
+    pub todos: Vec<ExportedTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedTodo {
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+    pub todo_id: u64,
+    pub content: String,
+    /// Rich text (HTML) as Basecamp stores it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `description` as plain text.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    pub completed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    pub assignees: Vec<ExportedAssignee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedAssignee {
+    pub id: u64,
+    pub name: String,
+}
+
+/// What `todo export` produced; `document` is the CSV, JSON, or iCalendar
+/// text.
+#[derive(Debug)]
+pub struct TodoExport {
+    pub output: TodoExportOutput,
+    pub document: String,
+    /// Where the document was written; `None` means it goes to stdout.
+    pub path: Option<String>,
+}
+
+pub async fn run(args: TodoExportArgs) -> AppResult<TodoExport> {
+    let client = integration::session_client()?;
+
+    let project = resolve_project(&client, &args.project).await?;
+    let (todolists, selected) = match args.list.as_deref() {
+        Some(reference) => {
+            let todolist = resolve_todolist(&client, &project, reference).await?;
+            let selected = (todolist.id, todolist_display_name(&todolist));
+            (vec![todolist], Some(selected))
+        }
+        None => {
+            let todoset_id = resolve_todoset_id(&project)?;
+            (client.list_todolists(project.id, todoset_id).await?, None)
+        }
+    };
+
+    let mut todos = Vec::new();
+    for todolist in &todolists {
+        export_todolist(&client, &project, todolist, &mut todos).await?;
+    }
+
+    // A calendar only has room for what is still coming due.
+    if args.ical.is_some() {
+        todos.retain(|todo| !todo.completed && todo.due_on.is_some());
+    }
+    let count = todos.len();
+    let (todolist_id, todolist_name) = selected.unzip();
+    let output = TodoExportOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id,
+        todolist_name,
+        count,
+        todos,
+    };
+
+    let document = match (&args.ical, args.format) {
+        (Some(_), _) => render_ical(&output),
+        (None, ExportFormat::Csv) => render_csv(&output),
+        (None, ExportFormat::Json) => serde_json::to_string_pretty(&output)
+            .map(|rendered| format!("{rendered}\n"))
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?,
+    };
+
+    let path = match args.ical.as_deref().or(args.output.as_deref()) {
+        Some(path) => {
+            transfer::write_export_file(path, document.as_bytes(), args.force)?;
+            Some(path.display().to_string())
+        }
+        None => None,
+    };
+
+    Ok(TodoExport {
+        output,
+        document,
+        path,
+    })
+}
+
+// A list's own to-dos come first, then each group's; within each, open
+// to-dos in list order followed by completed ones.
+async fn export_todolist(
+    client: &BasecampClient,
+    project: &Project,
+    todolist: &Todolist,
+    todos: &mut Vec<ExportedTodo>,
+) -> AppResult<()> {
+    let todolist_name = todolist_display_name(todolist);
+    for todo in fetch_all_todos(client, project.id, todolist.id).await? {
+        todos.push(exported(todo, todolist.id, &todolist_name, None));
+    }
+
+    for group in client.list_todolist_groups(project.id, todolist.id).await? {
+        let group_name = todolist_display_name(&group);
+        for todo in fetch_all_todos(client, project.id, group.id).await? {
+            todos.push(exported(
+                todo,
+                todolist.id,
+                &todolist_name,
+                Some((group.id, &group_name)),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) async fn fetch_all_todos(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+) -> AppResult<Vec<Todo>> {
+    let mut open = client.list_todos(project_id, todolist_id, false).await?;
+    open.sort_by_key(|todo| todo.position.unwrap_or(u32::MAX));
+    let completed = client.list_todos(project_id, todolist_id, true).await?;
+    open.extend(completed);
+    Ok(open)
+}
+
+fn exported(
+    todo: Todo,
+    todolist_id: u64,
+    todolist_name: &str,
+    group: Option<(u64, &str)>,
+) -> ExportedTodo {
+    let notes = todo
+        .description
+        .as_deref()
+        .map(rich_text::html_to_text)
+        .unwrap_or_default();
+    let (completed_at, completed_by) = match todo.completion {
+        Some(completion) => (completion.created_at, creator_name(completion.creator)),
+        None => (None, None),
+    };
+
+    ExportedTodo {
+        todolist_id,
+        todolist_name: todolist_name.to_string(),
+        group_id: group.map(|(id, _)| id),
+        group_name: group.map(|(_, name)| name.to_string()),
+        todo_id: todo.id,
+        content: todo.content,
+        description: todo
+            .description
+            .filter(|description| !description.trim().is_empty()),
+        notes,
+        completed: todo.completed.unwrap_or(completed_at.is_some()),
+        completed_at,
+        completed_by,
+        starts_on: todo.starts_on,
+        due_on: todo.due_on,
+        assignees: todo
+            .assignees
+            .into_iter()
+            .map(|assignee| ExportedAssignee {
+                id: assignee.id,
+                name: assignee.name,
+            })
+            .collect(),
+        created_by: creator_name(todo.creator),
+        created_at: todo.created_at,
+        updated_at: todo.updated_at,
+        app_url: todo.app_url,
+    }
+}
+
+// Each to-do is an all-day event on its due date, or spanning from its start
+// date when it has one.
+fn render_ical(output: &TodoExportOutput) -> String {
+    let events: Vec<ical::Event> = output
+        .todos
+        .iter()
+        .filter_map(|todo| {
+            let due = Date::parse_iso(todo.due_on.as_deref()?)?;
+            let starts = todo
+                .starts_on
+                .as_deref()
+                .and_then(Date::parse_iso)
+                .filter(|starts| *starts <= due)
+                .unwrap_or(due);
+            Some(ical::Event {
+                uid: format!("todo-{}@basecamp-cli", todo.todo_id),
+                summary: todo.content.clone(),
+                description: todo.notes.clone(),
+                url: todo.app_url.clone(),
+                start: ical::EventTime::Day(starts),
+                end: ical::EventTime::Day(due.add_days(1)),
+            })
+        })
+        .collect();
+    let name = match &output.todolist_name {
+        Some(todolist_name) => format!("{} / {todolist_name}", output.project_name),
+        None => format!("{} to-dos", output.project_name),
+    };
+    ical::render(&name, &events)
+}
+
+fn render_csv(output: &TodoExportOutput) -> String {
+    let mut document = String::new();
+    csv::push_row(
+        &mut document,
+        CSV_HEADER.iter().map(|field| field.to_string()),
+    );
+
+    for todo in &output.todos {
+        let assignees = todo
+            .assignees
+            .iter()
+            .map(|assignee| assignee.name.as_str())
+            .collect::<Vec<_>>()
+            .join("; ");
+        csv::push_row(
+            &mut document,
+            [
+                output.project_id.to_string(),
+                output.project_name.clone(),
+                todo.todolist_id.to_string(),
+                todo.todolist_name.clone(),
+                todo.group_id.map(|id| id.to_string()).unwrap_or_default(),
+                todo.group_name.clone().unwrap_or_default(),
+                todo.todo_id.to_string(),
+                todo.content.clone(),
+                todo.notes.clone(),
+                todo.completed.to_string(),
+                todo.completed_at.clone().unwrap_or_default(),
+                todo.completed_by.clone().unwrap_or_default(),
+                todo.starts_on.clone().unwrap_or_default(),
+                todo.due_on.clone().unwrap_or_default(),
+                assignees,
+                todo.created_by.clone().unwrap_or_default(),
+                todo.created_at.clone().unwrap_or_default(),
+                todo.app_url.clone().unwrap_or_default(),
+            ],
+        );
+    }
+
+    document
+}
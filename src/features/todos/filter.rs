@@ -0,0 +1,270 @@
+//! `--assignee`, `--due-before`, `--due-after`, and `--list` on search
+//! matches. Basecamp's search only takes text, so these run on the results;
+//! matches whose search payload lacks the fields are fetched one by one.
+
+use super::due::resolve_due_date;
+use super::people::resolve_people_references;
+use super::search::{TodoDetails, TodoMatch};
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::Todo;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use std::collections::HashMap;
+
+/// To-dos fetched at once when search results need their details.
+const DETAILS_BATCH: usize = 8;
+
+#[derive(Debug, Default)]
+pub(super) struct MatchFilter {
+    pub assignees: Vec<String>,
+    pub due_before: Option<String>,
+    pub due_after: Option<String>,
+    pub list: Option<String>,
+}
+
+/// A filter with its dates and list alias resolved.
+struct ResolvedFilter<'a> {
+    assignees: &'a [String],
+    due_before: Option<String>,
+    due_after: Option<String>,
+    list: Option<ListFilter>,
+}
+
+enum ListFilter {
+    Id(u64),
+    /// Lowercased; matches any list or group whose name contains it.
+    Name(String),
+}
+
+impl MatchFilter {
+    pub(super) fn is_empty(&self) -> bool {
+        self.assignees.is_empty()
+            && self.due_before.is_none()
+            && self.due_after.is_none()
+            && self.list.is_none()
+    }
+
+    /// Filters only narrow search matches; `target` names what was given
+    /// instead.
+    pub(super) fn ensure_unused(&self, target: &str) -> AppResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        Err(AppError::invalid_input(format!(
+            "--assignee, --due-before, --due-after, and --list filter search matches; they do not apply to {target}."
+        )))
+    }
+
+    /// Appended to "No to-dos matched ..." so an empty result is not blamed
+    /// on the search text alone.
+    pub(super) fn no_match_suffix(&self) -> &'static str {
+        if self.is_empty() {
+            ""
+        } else {
+            " with these filters"
+        }
+    }
+
+    /// Search matches that pass, in their original order.
+    pub(super) async fn apply(
+        &self,
+        client: &BasecampClient,
+        matches: Vec<TodoMatch>,
+    ) -> AppResult<Vec<TodoMatch>> {
+        if self.is_empty() {
+            return Ok(matches);
+        }
+
+        let filter = self.resolve(client).await?;
+        let matches = with_details(client, matches).await?;
+        let allowed = if filter.assignees.is_empty() {
+            HashMap::new()
+        } else {
+            assignee_ids_by_project(client, &matches, filter.assignees).await?
+        };
+
+        Ok(matches
+            .into_iter()
+            .filter(|matched| filter.keeps(matched, &allowed))
+            .collect())
+    }
+
+    async fn resolve(&self, client: &BasecampClient) -> AppResult<ResolvedFilter<'_>> {
+        let due_before = resolve_due_date(client, self.due_before.as_deref()).await?;
+        let due_after = resolve_due_date(client, self.due_after.as_deref()).await?;
+        // Both are `YYYY-MM-DD`, so string order is date order.
+        if let (Some(before), Some(after)) = (&due_before, &due_after)
+            && after > before
+        {
+            return Err(AppError::invalid_input(format!(
+                "--due-after {after} is later than --due-before {before}; nothing can match."
+            )));
+        }
+
+        let list = match self.list.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(reference) if reference.starts_with('@') => {
+                let alias = lookup::resolve_alias(reference)?;
+                let todolist_id = alias.todolist_id.ok_or_else(|| {
+                    AppError::invalid_input(format!(
+                        "{reference} is a project alias; --list needs a list alias, id, or name."
+                    ))
+                })?;
+                Some(ListFilter::Id(todolist_id))
+            }
+            Some(reference) => Some(match reference.parse::<u64>() {
+                Ok(id) => ListFilter::Id(id),
+                Err(_) => ListFilter::Name(reference.to_lowercase()),
+            }),
+        };
+
+        Ok(ResolvedFilter {
+            assignees: &self.assignees,
+            due_before,
+            due_after,
+            list,
+        })
+    }
+}
+
+impl ResolvedFilter<'_> {
+    fn keeps(&self, matched: &TodoMatch, allowed: &HashMap<u64, Vec<u64>>) -> bool {
+        let Some(details) = matched.details.as_ref() else {
+            return false;
+        };
+
+        if !self.assignees.is_empty() {
+            let Some(ids) = allowed.get(&matched.project_id) else {
+                return false;
+            };
+            if !details.assignee_ids.iter().any(|id| ids.contains(id)) {
+                return false;
+            }
+        }
+
+        if self.due_before.is_some() || self.due_after.is_some() {
+            let Some(due_on) = details.due_on.as_deref() else {
+                return false;
+            };
+            if self
+                .due_before
+                .as_deref()
+                .is_some_and(|before| due_on > before)
+                || self
+                    .due_after
+                    .as_deref()
+                    .is_some_and(|after| due_on < after)
+            {
+                return false;
+            }
+        }
+
+        match &self.list {
+            None => true,
+            Some(ListFilter::Id(id)) => details.parent_id == *id,
+            Some(ListFilter::Name(name)) => details.parent_title.to_lowercase().contains(name),
+        }
+    }
+}
+
+impl TodoDetails {
+    pub(super) fn from_todo(todo: &Todo) -> Option<Self> {
+        let parent = todo.parent.as_ref()?;
+        Some(Self {
+            due_on: todo.due_on.clone(),
+            assignee_ids: todo.assignees.iter().map(|assignee| assignee.id).collect(),
+            parent_id: parent.id,
+            parent_title: parent.title.clone(),
+        })
+    }
+}
+
+// Fills in details the search payload did not carry, a few requests at a
+// time.
+async fn with_details(
+    client: &BasecampClient,
+    mut matches: Vec<TodoMatch>,
+) -> AppResult<Vec<TodoMatch>> {
+    let missing: Vec<usize> = (0..matches.len())
+        .filter(|index| matches[*index].details.is_none())
+        .collect();
+
+    for batch in missing.chunks(DETAILS_BATCH) {
+        let tasks: Vec<_> = batch
+            .iter()
+            .map(|index| {
+                let client = client.clone();
+                let (project_id, todo_id) = (matches[*index].project_id, matches[*index].todo_id);
+                tokio::spawn(async move { client.get_todo(project_id, todo_id).await })
+            })
+            .collect();
+        for (index, task) in batch.iter().zip(tasks) {
+            let todo = task
+                .await
+                .map_err(|err| AppError::generic(format!("Background request failed: {err}")))??;
+            matches[*index].details = TodoDetails::from_todo(&todo);
+        }
+    }
+
+    Ok(matches)
+}
+
+// `--assignee` references resolved against each project's people. A person
+// only on some projects filters those; the rest match nobody.
+async fn assignee_ids_by_project(
+    client: &BasecampClient,
+    matches: &[TodoMatch],
+    references: &[String],
+) -> AppResult<HashMap<u64, Vec<u64>>> {
+    let references = expand_teams(references)?;
+    let mut allowed = HashMap::new();
+    for matched in matches {
+        if allowed.contains_key(&matched.project_id) {
+            continue;
+        }
+
+        let people = client.list_project_people(matched.project_id).await?;
+        let mut ids = Vec::new();
+        for reference in &references {
+            match resolve_people_references(std::slice::from_ref(reference), &people, "--assignee")
+            {
+                Ok(resolved) => ids.extend(resolved),
+                // Not on this project; an ambiguous name still fails.
+                Err(err) if err.code == 4 => {}
+                Err(err) => return Err(err),
+            }
+        }
+        allowed.insert(matched.project_id, ids);
+    }
+    Ok(allowed)
+}
+
+// Team members are matched one by one, so a team filters every project that
+// has any of them.
+fn expand_teams(references: &[String]) -> AppResult<Vec<String>> {
+    let mut expanded = Vec::new();
+    let mut teams = None;
+    for reference in references.iter().map(|value| value.trim()) {
+        let Some(team_name) = reference.strip_prefix('@') else {
+            expanded.push(reference.to_string());
+            continue;
+        };
+
+        let teams = match teams.as_mut() {
+            Some(teams) => teams,
+            None => teams.insert(integration::load_config()?.teams),
+        };
+        let members = teams
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(team_name))
+            .map(|(_, members)| members)
+            .ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "Unknown team @{team_name} in --assignee. Define it under \"teams\" in config."
+                ))
+            })?;
+        expanded.extend(members.iter().cloned());
+    }
+    Ok(expanded)
+}
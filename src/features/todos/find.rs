@@ -0,0 +1,71 @@
+use super::filter::MatchFilter;
+use super::search::{TodoCompletionFilter, search_todos_sorted};
+use crate::cli::TodoSearchArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoSearchOutput {
+    pub ok: bool,
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_project_id: Option<u64>,
+    pub matches: Vec<FoundTodo>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FoundTodo {
+    pub todo_id: u64,
+    pub project_id: u64,
+    pub project_name: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+// Unlike complete/re-open, search never prompts, so it is safe to pipe.
+pub async fn run(args: TodoSearchArgs) -> AppResult<TodoSearchOutput> {
+    let query = args.query.trim().to_string();
+    if query.is_empty() {
+        return Err(AppError::invalid_input("Search query cannot be blank."));
+    }
+
+    let client = integration::session_client()?;
+
+    let completion = if args.completed {
+        TodoCompletionFilter::CompletedOnly
+    } else {
+        TodoCompletionFilter::IncompleteOnly
+    };
+    let filter = MatchFilter {
+        assignees: args.assignees,
+        due_before: args.due_before,
+        due_after: args.due_after,
+        list: args.list,
+    };
+    let matches =
+        search_todos_sorted(&client, &query, args.project_id, completion, args.sort).await?;
+    let matches: Vec<FoundTodo> = filter
+        .apply(&client, matches)
+        .await?
+        .into_iter()
+        .map(|matched| FoundTodo {
+            todo_id: matched.todo_id,
+            project_id: matched.project_id,
+            project_name: matched.project_name,
+            content: matched.content,
+            updated_at: matched.updated_at,
+        })
+        .collect();
+    let count = matches.len();
+
+    Ok(TodoSearchOutput {
+        ok: true,
+        query,
+        scope_project_id: args.project_id,
+        matches,
+        count,
+    })
+}
@@ -0,0 +1,193 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::show::creator_name;
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{ProjectPerson, RecordingEvent};
+use crate::cli::TodoHistoryArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Serialize)]
+pub struct TodoHistoryOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    pub todo_id: u64,
+    pub todo_content: String,
+    /// Oldest first.
+    pub events: Vec<TodoHistoryEvent>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoHistoryEvent {
+    pub event_id: u64,
+    /// Basecamp's action name, e.g. `assignment_changed`.
+    pub action: String,
+    /// One-line description with person ids replaced by names.
+    pub summary: String,
+    pub actor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Raw action-specific fields as Basecamp returns them.
+    #[serde(skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+pub async fn run(args: TodoHistoryArgs) -> AppResult<TodoHistoryOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name) =
+        if let Some((project_id, todo_id)) = target.direct {
+            ("direct", None, project_id, todo_id, None)
+        } else {
+            ensure_search_mode_terminal("history")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::Any,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .into_iter()
+                .nth(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name),
+            )
+        };
+
+    let (todo, mut events, people) = tokio::try_join!(
+        client.get_todo(project_id, todo_id),
+        client.list_recording_events(project_id, todo_id),
+        client.list_project_people(project_id),
+    )?;
+
+    // Timestamps share Basecamp's offset, so string order is time order.
+    events.sort_by(|left, right| left.created_at.cmp(&right.created_at));
+    let events: Vec<TodoHistoryEvent> = events
+        .into_iter()
+        .map(|event| history_event(event, &people))
+        .collect();
+    let count = events.len();
+
+    Ok(TodoHistoryOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todo_id,
+        todo_content: todo.content,
+        events,
+        count,
+    })
+}
+
+fn history_event(event: RecordingEvent, people: &[ProjectPerson]) -> TodoHistoryEvent {
+    TodoHistoryEvent {
+        event_id: event.id,
+        summary: summarize(&event.action, &event.details, people),
+        action: event.action,
+        actor: creator_name(event.creator).unwrap_or_else(|| "Unknown".to_string()),
+        created_at: event.created_at,
+        details: event.details,
+    }
+}
+
+// Known actions get a fixed wording; others are their name in words. Detail
+// fields follow as `name: value`, with `*_person_ids` lists shown as names.
+fn summarize(action: &str, details: &Value, people: &[ProjectPerson]) -> String {
+    let mut summary = match action {
+        "created" => "Created".to_string(),
+        "completed" => "Completed".to_string(),
+        "uncompleted" => "Re-opened".to_string(),
+        "assignment_changed" => "Assignees changed".to_string(),
+        "completion_subscribers_changed" => "Notify list changed".to_string(),
+        "due_date_changed" => "Due date changed".to_string(),
+        other => {
+            let words = other.replace('_', " ");
+            let mut chars = words.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => "Changed".to_string(),
+            }
+        }
+    };
+
+    let Some(fields) = details.as_object() else {
+        return summary;
+    };
+    let parts: Vec<String> = fields
+        .iter()
+        .filter_map(|(name, value)| {
+            let rendered = if name.ends_with("person_ids") {
+                person_names(value, people)
+            } else {
+                scalar(value)
+            }?;
+            let label = name.trim_end_matches("_ids").trim_end_matches("_id");
+            Some(format!("{}: {rendered}", label.replace('_', " ")))
+        })
+        .collect();
+    if !parts.is_empty() {
+        summary.push_str(" (");
+        summary.push_str(&parts.join("; "));
+        summary.push(')');
+    }
+    summary
+}
+
+fn person_names(value: &Value, people: &[ProjectPerson]) -> Option<String> {
+    let ids = value.as_array()?;
+    if ids.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = ids
+        .iter()
+        .filter_map(Value::as_u64)
+        .map(|id| {
+            people
+                .iter()
+                .find(|person| person.id == id)
+                .map(|person| person.name.clone())
+                .unwrap_or_else(|| format!("person {id}"))
+        })
+        .collect();
+    Some(names.join(", "))
+}
+
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) if !text.is_empty() => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,465 @@
+use super::batch::FailedTodo;
+use super::due::resolve_due_date;
+use super::notes::notes_to_html;
+use super::picker::{resolve_project, resolve_todoset_id, todolist_display_name};
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{CreateTodoPayload, CreateTodolistPayload};
+use crate::cli::{ImportFormat, TodoImportArgs};
+use crate::csv::{self, CsvRecord};
+use crate::dates;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::Serialize;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+const DEFAULT_LIST_NAME: &str = "Imported";
+
+const CONTENT_COLUMNS: [&str; 4] = ["content", "title", "task", "name"];
+const NOTES_COLUMNS: [&str; 3] = ["notes", "description", "note"];
+const DUE_COLUMNS: [&str; 4] = ["due_on", "due", "due_date", "date"];
+const START_COLUMNS: [&str; 3] = ["starts_on", "start", "start_date"];
+const LIST_COLUMNS: [&str; 4] = ["todolist_name", "todolist", "list", "section"];
+const COMPLETED_COLUMNS: [&str; 2] = ["completed", "done"];
+
+#[derive(Debug, Serialize)]
+pub struct TodoImportOutput {
+    pub ok: bool,
+    pub dry_run: bool,
+    /// `todoist` or `csv`.
+    pub format: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub lists: Vec<ImportedList>,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ImportWarning>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<FailedTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportedList {
+    pub name: String,
+    /// `None` for a list a dry run would create, or whose creation failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_id: Option<u64>,
+    /// Whether the list is new rather than an existing list with the same name.
+    pub created: bool,
+    pub todos: Vec<ImportedTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportedTodo {
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo_id: Option<u64>,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    pub completed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportWarning {
+    pub line: usize,
+    pub message: String,
+}
+
+/// One to-do read from the file, before dates are resolved.
+struct ImportRow {
+    line: usize,
+    list: Option<String>,
+    content: String,
+    notes: Option<String>,
+    due: Option<String>,
+    start: Option<String>,
+    completed: bool,
+}
+
+pub async fn run(args: TodoImportArgs) -> AppResult<TodoImportOutput> {
+    // The file is read and checked before any request, so a bad export never
+    // reaches Basecamp.
+    let records = read_records(&args.path)?;
+    let format = match args.format {
+        ImportFormat::Auto if is_todoist_header(&records) => ImportFormat::Todoist,
+        ImportFormat::Auto => ImportFormat::Csv,
+        format => format,
+    };
+    let rows = match format {
+        ImportFormat::Todoist => todoist_rows(&records)?,
+        _ => csv_rows(&records)?,
+    };
+    if rows.is_empty() {
+        return Err(AppError::invalid_input(format!(
+            "{} does not contain any to-dos.",
+            args.path.display()
+        )));
+    }
+
+    let default_list = args
+        .list
+        .clone()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| default_list_name(&args.path));
+    let groups = group_by_list(rows, &default_list);
+
+    let client = integration::session_client()?;
+
+    let project = resolve_project(&client, &args.project).await?;
+    let todoset_id = resolve_todoset_id(&project)?;
+    let existing = client.list_todolists(project.id, todoset_id).await?;
+
+    let mut warnings = Vec::new();
+    let mut failed = Vec::new();
+    let mut lists = Vec::with_capacity(groups.len());
+    let mut count = 0;
+    for (name, rows) in groups {
+        let existing_id = existing
+            .iter()
+            .find(|todolist| todolist_display_name(todolist).eq_ignore_ascii_case(&name))
+            .map(|todolist| todolist.id);
+
+        let todolist_id = match existing_id {
+            Some(todolist_id) => Some(todolist_id),
+            None if args.dry_run => None,
+            None => {
+                let payload = CreateTodolistPayload {
+                    name: name.clone(),
+                    description: None,
+                };
+                match client
+                    .create_todolist(project.id, todoset_id, &payload)
+                    .await
+                {
+                    Ok(todolist) => Some(todolist.id),
+                    Err(err) => {
+                        for row in &rows {
+                            failed.push(FailedTodo {
+                                line: row.line,
+                                reference: row.content.clone(),
+                                error: format!("List \"{name}\" was not created: {}", err.message),
+                            });
+                        }
+                        lists.push(ImportedList {
+                            name,
+                            todolist_id: None,
+                            created: true,
+                            todos: Vec::new(),
+                        });
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let mut todos = Vec::with_capacity(rows.len());
+        for row in rows {
+            let (starts_on, due_on, notes) = resolve_dates(&client, &row, &mut warnings).await;
+            let mut todo = ImportedTodo {
+                line: row.line,
+                todo_id: None,
+                content: row.content,
+                starts_on,
+                due_on,
+                completed: row.completed,
+            };
+
+            if let Some(todolist_id) = todolist_id.filter(|_| !args.dry_run) {
+                let payload = CreateTodoPayload {
+                    content: todo.content.clone(),
+                    notes: notes_to_html(notes),
+                    assignee_ids: None,
+                    completion_subscriber_ids: None,
+                    starts_on: todo.starts_on.clone(),
+                    due_on: todo.due_on.clone(),
+                };
+                match create_todo(&client, project.id, todolist_id, &payload, todo.completed).await
+                {
+                    Ok(todo_id) => todo.todo_id = Some(todo_id),
+                    Err(err) => {
+                        failed.push(FailedTodo {
+                            line: todo.line,
+                            reference: todo.content,
+                            error: err.message,
+                        });
+                        continue;
+                    }
+                }
+            }
+
+            count += 1;
+            todos.push(todo);
+        }
+
+        lists.push(ImportedList {
+            name,
+            todolist_id,
+            created: existing_id.is_none(),
+            todos,
+        });
+    }
+    failed.sort_by_key(|item| item.line);
+
+    Ok(TodoImportOutput {
+        ok: failed.is_empty(),
+        dry_run: args.dry_run,
+        format: match format {
+            ImportFormat::Todoist => "todoist".to_string(),
+            _ => "csv".to_string(),
+        },
+        project_id: project.id,
+        project_name: project.name,
+        lists,
+        count,
+        warnings,
+        failed,
+    })
+}
+
+async fn create_todo(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    payload: &CreateTodoPayload,
+    completed: bool,
+) -> AppResult<u64> {
+    let created = client.create_todo(project_id, todolist_id, payload).await?;
+    if completed {
+        client.complete_todo(project_id, created.id).await?;
+    }
+    Ok(created.id)
+}
+
+// Dates Basecamp cannot take (`every monday`, `Mar 5 at 10am`) do not fail the
+// row: the to-do is imported without them, the original text is kept in its
+// notes, and a warning names the line.
+async fn resolve_dates(
+    client: &BasecampClient,
+    row: &ImportRow,
+    warnings: &mut Vec<ImportWarning>,
+) -> (Option<String>, Option<String>, Option<String>) {
+    let mut notes = row.notes.clone();
+    let due_on = resolve_date(client, row, row.due.as_deref(), "due", warnings, &mut notes).await;
+    let mut starts_on = resolve_date(
+        client,
+        row,
+        row.start.as_deref(),
+        "start",
+        warnings,
+        &mut notes,
+    )
+    .await;
+
+    // Basecamp needs a start date to open a range that ends on the due date.
+    if let Some(start) = starts_on.as_deref()
+        && due_on.as_deref().is_none_or(|due| start > due)
+    {
+        warnings.push(ImportWarning {
+            line: row.line,
+            message: format!("Dropped start date {start}; it needs a due date on or after it."),
+        });
+        starts_on = None;
+    }
+
+    (starts_on, due_on, notes)
+}
+
+async fn resolve_date(
+    client: &BasecampClient,
+    row: &ImportRow,
+    value: Option<&str>,
+    label: &str,
+    warnings: &mut Vec<ImportWarning>,
+    notes: &mut Option<String>,
+) -> Option<String> {
+    let value = value?;
+    // Exports often carry a time (`2026-03-05 10:00`, `2026-03-05T10:00:00`).
+    let candidate = match value.get(..10) {
+        Some(prefix) if dates::is_iso_date(prefix) => prefix,
+        _ => value,
+    };
+    if let Ok(date) = resolve_due_date(client, Some(candidate)).await {
+        return date;
+    }
+
+    warnings.push(ImportWarning {
+        line: row.line,
+        message: format!(
+            "Kept {label} \"{value}\" in the notes; it is not a date Basecamp accepts."
+        ),
+    });
+    let kept = format!("{} {label}: {value}", capitalized(label));
+    *notes = Some(match notes.take() {
+        Some(existing) => format!("{existing}\n\n{kept}"),
+        None => kept,
+    });
+    None
+}
+
+fn capitalized(label: &str) -> String {
+    let mut chars = label.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn read_records(path: &Path) -> AppResult<Vec<CsvRecord>> {
+    let contents = if path.as_os_str() == "-" {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|err| AppError::generic(format!("Failed to read stdin: {err}")))?;
+        contents
+    } else {
+        fs::read_to_string(path).map_err(|err| {
+            AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+        })?
+    };
+
+    csv::parse(&contents)
+        .map_err(|err| AppError::invalid_input(format!("Invalid CSV in {}: {err}", path.display())))
+}
+
+fn is_todoist_header(records: &[CsvRecord]) -> bool {
+    records.first().is_some_and(|header| {
+        column(&header.fields, &["type"]).is_some()
+            && column(&header.fields, &["content"]).is_some()
+            && column(&header.fields, &["indent"]).is_some()
+    })
+}
+
+fn column(header: &[String], names: &[&str]) -> Option<usize> {
+    names.iter().find_map(|name| {
+        header
+            .iter()
+            .position(|field| field.trim().eq_ignore_ascii_case(name))
+    })
+}
+
+fn field(record: &CsvRecord, index: Option<usize>) -> Option<String> {
+    index
+        .and_then(|index| record.fields.get(index))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+// Todoist project exports: `TYPE` is `section`, `task`, or `note` (a comment
+// on the task above). Sections become lists; subtasks (`INDENT` > 1) are
+// imported as plain to-dos, since Basecamp has no nesting.
+fn todoist_rows(records: &[CsvRecord]) -> AppResult<Vec<ImportRow>> {
+    let Some((header, records)) = records.split_first() else {
+        return Ok(Vec::new());
+    };
+    let header = &header.fields;
+    let kind = column(header, &["type"]);
+    let content = column(header, &["content"]);
+    let description = column(header, &["description"]);
+    let date = column(header, &["date"]);
+
+    let mut rows: Vec<ImportRow> = Vec::new();
+    let mut section = None;
+    for record in records {
+        let kind = field(record, kind).unwrap_or_default().to_ascii_lowercase();
+        let Some(text) = field(record, content) else {
+            continue;
+        };
+        match kind.as_str() {
+            "section" => section = Some(text),
+            "task" => rows.push(ImportRow {
+                line: record.line,
+                list: section.clone(),
+                content: text,
+                notes: field(record, description),
+                due: field(record, date),
+                start: None,
+                completed: false,
+            }),
+            "note" => {
+                if let Some(task) = rows.last_mut() {
+                    task.notes = Some(match task.notes.take() {
+                        Some(notes) => format!("{notes}\n\n{text}"),
+                        None => text,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(rows)
+}
+
+// Generic CSV: a header row names the columns, matched case-insensitively.
+// Only a content column is required; `todo export` files import as-is.
+fn csv_rows(records: &[CsvRecord]) -> AppResult<Vec<ImportRow>> {
+    let Some((header, records)) = records.split_first() else {
+        return Ok(Vec::new());
+    };
+    let header = &header.fields;
+    let Some(content) = column(header, &CONTENT_COLUMNS) else {
+        return Err(AppError::invalid_input(format!(
+            "CSV header needs a to-do column named one of: {}.",
+            CONTENT_COLUMNS.join(", ")
+        )));
+    };
+    let notes = column(header, &NOTES_COLUMNS);
+    let due = column(header, &DUE_COLUMNS);
+    let start = column(header, &START_COLUMNS);
+    let list = column(header, &LIST_COLUMNS);
+    let completed = column(header, &COMPLETED_COLUMNS);
+
+    Ok(records
+        .iter()
+        .filter_map(|record| {
+            Some(ImportRow {
+                line: record.line,
+                list: field(record, list),
+                content: field(record, Some(content))?,
+                notes: field(record, notes),
+                due: field(record, due),
+                start: field(record, start),
+                completed: field(record, completed).is_some_and(|value| {
+                    matches!(
+                        value.to_ascii_lowercase().as_str(),
+                        "true" | "yes" | "y" | "1" | "x"
+                    )
+                }),
+            })
+        })
+        .collect())
+}
+
+/// Rows grouped by target list, lists in order of first appearance.
+fn group_by_list(rows: Vec<ImportRow>, default_list: &str) -> Vec<(String, Vec<ImportRow>)> {
+    let mut groups: Vec<(String, Vec<ImportRow>)> = Vec::new();
+    for row in rows {
+        let name = row.list.clone().unwrap_or_else(|| default_list.to_string());
+        match groups
+            .iter_mut()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(&name))
+        {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((name, vec![row])),
+        }
+    }
+    groups
+}
+
+// Todoist names its export after the project (`Groceries.csv`), which makes a
+// good list name for to-dos outside any section.
+fn default_list_name(path: &Path) -> String {
+    if path.as_os_str() == "-" {
+        return DEFAULT_LIST_NAME.to_string();
+    }
+
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().trim().to_string())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| DEFAULT_LIST_NAME.to_string())
+}
@@ -0,0 +1,42 @@
+use super::picker::{resolve_project, resolve_todolist, todolist_display_name};
+use super::show::{TodoDetail, detail};
+use crate::cli::TodoListArgs;
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub count: usize,
+    pub todos: Vec<TodoDetail>,
+}
+
+pub async fn run(args: TodoListArgs) -> AppResult<TodoListOutput> {
+    let client = integration::session_client()?;
+
+    let project = resolve_project(&client, &args.project).await?;
+    let todolist = resolve_todolist(&client, &project, &args.list).await?;
+
+    // Open to-dos in list order, then completed ones when asked for.
+    let mut todos = client.list_todos(project.id, todolist.id, false).await?;
+    todos.sort_by_key(|todo| todo.position.unwrap_or(u32::MAX));
+    if args.completed {
+        todos.extend(client.list_todos(project.id, todolist.id, true).await?);
+    }
+    let todos: Vec<TodoDetail> = todos.into_iter().map(detail).collect();
+
+    Ok(TodoListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        todolist_id: todolist.id,
+        todolist_name: todolist_display_name(&todolist),
+        count: todos.len(),
+        todos,
+    })
+}
@@ -0,0 +1,124 @@
+use crate::basecamp::models::Assignment;
+use crate::cli::TodoMineArgs;
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use serde::Serialize;
+use std::cmp::Ordering;
+
+#[derive(Debug, Serialize)]
+pub struct TodoMineOutput {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_project_id: Option<u64>,
+    pub projects: Vec<MyProjectTodos>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MyProjectTodos {
+    pub project_id: u64,
+    pub project_name: String,
+    pub todos: Vec<MyTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MyTodo {
+    pub todo_id: u64,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    /// Marked as a priority on the "My Assignments" page.
+    pub priority: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run(args: TodoMineArgs) -> AppResult<TodoMineOutput> {
+    let client = integration::session_client()?;
+
+    let assignments = client.list_my_assignments().await?;
+    let assigned = assignments
+        .priorities
+        .into_iter()
+        .map(|assignment| (assignment, true))
+        .chain(
+            assignments
+                .non_priorities
+                .into_iter()
+                .map(|assignment| (assignment, false)),
+        );
+
+    let mut projects: Vec<MyProjectTodos> = Vec::new();
+    for (assignment, priority) in assigned {
+        // Card steps and other assignables share the page; only open to-dos count.
+        if !assignment.recording_type.eq_ignore_ascii_case("todo") || assignment.completed {
+            continue;
+        }
+        let Some(bucket) = assignment.bucket.as_ref() else {
+            continue;
+        };
+        if args
+            .project_id
+            .is_some_and(|project_id| project_id != bucket.id)
+        {
+            continue;
+        }
+
+        let (project_id, project_name) = (bucket.id, bucket.name.clone());
+        let todo = my_todo(assignment, priority);
+        match projects
+            .iter_mut()
+            .find(|project| project.project_id == project_id)
+        {
+            Some(project) => project.todos.push(todo),
+            None => projects.push(MyProjectTodos {
+                project_id,
+                project_name,
+                todos: vec![todo],
+            }),
+        }
+    }
+
+    projects.sort_by_key(|project| project.project_name.to_lowercase());
+    for project in &mut projects {
+        project.todos.sort_by(compare_by_due_date);
+    }
+    let count = projects.iter().map(|project| project.todos.len()).sum();
+
+    Ok(TodoMineOutput {
+        ok: true,
+        scope_project_id: args.project_id,
+        projects,
+        count,
+    })
+}
+
+fn my_todo(assignment: Assignment, priority: bool) -> MyTodo {
+    MyTodo {
+        todo_id: assignment.id,
+        content: assignment.content.trim().to_string(),
+        todolist_name: assignment
+            .parent
+            .map(|parent| parent.title.trim().to_string())
+            .filter(|title| !title.is_empty()),
+        starts_on: assignment.starts_on,
+        due_on: assignment.due_on,
+        priority,
+        app_url: assignment.app_url,
+    }
+}
+
+// Earliest due first, undated last; `YYYY-MM-DD` sorts as text.
+fn compare_by_due_date(left: &MyTodo, right: &MyTodo) -> Ordering {
+    match (&left.due_on, &right.due_on) {
+        (Some(left_due), Some(right_due)) => left_due.cmp(right_due),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+    .then_with(|| right.priority.cmp(&left.priority))
+}
@@ -1,5 +1,31 @@
 pub mod add;
+pub mod assign;
+mod batch;
+pub mod bulk;
+pub mod comment;
+pub mod comments;
 pub mod complete;
+mod defaults;
+pub mod delete;
+mod due;
 pub mod edit;
+pub mod export;
+mod filter;
+pub mod find;
+pub mod history;
+pub mod import;
+pub mod list;
+pub mod mine;
+pub(crate) mod notes;
+pub(crate) mod people;
+pub(crate) mod picker;
+pub mod position;
+pub mod quick;
 pub mod re_open;
+pub mod recur;
+pub mod report;
 mod search;
+pub mod show;
+pub mod stats;
+mod target;
+pub mod undo;
@@ -0,0 +1,45 @@
+//! To-do notes are typed as markdown and stored as Basecamp rich text.
+
+use crate::error::{AppError, AppResult};
+use crate::rich_text::markdown_to_html;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+const STDIN: &str = "-";
+
+/// Resolves `--notes`/`--notes-file`; `-` for either reads the notes from
+/// stdin, so generated text needs no shell quoting.
+pub(crate) fn resolve_notes_input(
+    notes: Option<String>,
+    notes_file: Option<PathBuf>,
+) -> AppResult<Option<String>> {
+    let path = match (notes, notes_file) {
+        (_, Some(path)) => path,
+        (Some(notes), None) if notes == STDIN => PathBuf::from(STDIN),
+        (notes, None) => return Ok(notes),
+    };
+
+    if path.as_os_str() == STDIN {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|err| AppError::generic(format!("Failed to read stdin: {err}")))?;
+        return Ok(Some(contents));
+    }
+
+    fs::read_to_string(&path)
+        .map(Some)
+        .map_err(|err| AppError::invalid_input(format!("Failed to read {}: {err}", path.display())))
+}
+
+pub(super) fn reads_notes_from_stdin(notes: Option<&str>, notes_file: Option<&Path>) -> bool {
+    notes == Some(STDIN) || notes_file.is_some_and(|path| path.as_os_str() == STDIN)
+}
+
+/// Blank notes stay unset rather than becoming an empty paragraph.
+pub(super) fn notes_to_html(notes: Option<String>) -> Option<String> {
+    notes
+        .filter(|notes| !notes.trim().is_empty())
+        .map(|notes| markdown_to_html(&notes))
+}
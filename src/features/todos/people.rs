@@ -0,0 +1,152 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::ProjectPerson;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use colored::Colorize;
+
+/// Expands `--assignee`/`--notify` references to project person ids.
+///
+/// A reference is a person id, an email address, a name, or `@team` for a
+/// group defined under `teams` in config. Team members use the same forms but
+/// cannot reference other teams. Duplicates are dropped, keeping order.
+pub(crate) fn resolve_people_references(
+    references: &[String],
+    people: &[ProjectPerson],
+    flag: &str,
+) -> AppResult<Vec<u64>> {
+    resolve_people_in(references, people, flag, "this project")
+}
+
+/// Like [`resolve_people_references`], against everyone in the account, for
+/// adding people to a project.
+pub(crate) fn resolve_account_people_references(
+    references: &[String],
+    people: &[ProjectPerson],
+    flag: &str,
+) -> AppResult<Vec<u64>> {
+    resolve_people_in(references, people, flag, "this account")
+}
+
+fn resolve_people_in(
+    references: &[String],
+    people: &[ProjectPerson],
+    flag: &str,
+    scope: &str,
+) -> AppResult<Vec<u64>> {
+    let mut ids = Vec::new();
+    let mut teams = None;
+
+    for reference in references.iter().map(|value| value.trim()) {
+        if reference.is_empty() {
+            continue;
+        }
+
+        let Some(team_name) = reference.strip_prefix('@') else {
+            push_unique(&mut ids, resolve_person(reference, people, flag, scope)?);
+            continue;
+        };
+
+        let teams = match teams.as_mut() {
+            Some(teams) => teams,
+            None => teams.insert(integration::load_config()?.teams),
+        };
+        let members = teams
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(team_name))
+            .map(|(_, members)| members)
+            .ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "Unknown team @{team_name} in {flag}. Define it under \"teams\" in config."
+                ))
+            })?;
+
+        for member in members.iter().map(|value| value.trim()) {
+            if member.starts_with('@') {
+                return Err(AppError::invalid_input(format!(
+                    "Team @{team_name} references {member}; teams cannot contain other teams."
+                )));
+            }
+            let id = resolve_person(member, people, flag, scope).map_err(|err| {
+                AppError::new(err.code, format!("Team @{team_name}: {}", err.message))
+            })?;
+            push_unique(&mut ids, id);
+        }
+    }
+
+    Ok(ids)
+}
+
+/// `--me` as a person reference: the signed-in person's id, which must also
+/// be on the project.
+pub(super) async fn me_reference(client: &BasecampClient) -> AppResult<String> {
+    Ok(client.fetch_my_profile().await?.id.to_string())
+}
+
+/// Like [`resolve_people_references`], but references that do not resolve
+/// on this project are skipped with a warning. Used for config defaults,
+/// which apply across projects with different members.
+pub(super) fn resolve_default_references(
+    references: &[String],
+    people: &[ProjectPerson],
+    setting: &str,
+) -> AppResult<Vec<u64>> {
+    let mut ids = Vec::new();
+    for reference in references {
+        match resolve_people_references(std::slice::from_ref(reference), people, setting) {
+            Ok(resolved) => resolved
+                .into_iter()
+                .for_each(|id| push_unique(&mut ids, id)),
+            Err(err) => eprintln!(
+                "{}",
+                format!("Skipping {setting} entry \"{reference}\": {}", err.message).yellow()
+            ),
+        }
+    }
+    Ok(ids)
+}
+
+fn resolve_person(
+    reference: &str,
+    people: &[ProjectPerson],
+    flag: &str,
+    scope: &str,
+) -> AppResult<u64> {
+    if let Ok(id) = reference.parse::<u64>() {
+        return people
+            .iter()
+            .find(|person| person.id == id)
+            .map(|person| person.id)
+            .ok_or_else(|| {
+                AppError::no_account(format!("Person {id} in {flag} is not on {scope}."))
+            });
+    }
+
+    if reference.contains('@') {
+        return people
+            .iter()
+            .find(|person| {
+                person
+                    .email_address
+                    .as_deref()
+                    .is_some_and(|email| email.eq_ignore_ascii_case(reference))
+            })
+            .map(|person| person.id)
+            .ok_or_else(|| {
+                AppError::no_account(format!("No one on {scope} has email {reference} ({flag})."))
+            });
+    }
+
+    let index = lookup::match_by_name(
+        people.iter().map(|person| person.name.as_str()),
+        reference,
+        "person",
+    )?;
+    Ok(people[index].id)
+}
+
+fn push_unique(ids: &mut Vec<u64>, id: u64) {
+    if !ids.contains(&id) {
+        ids.push(id);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{Project, Todolist};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+use crate::ui::{PROMPT_MARGIN, SELECT_HELP_MESSAGE, Step, fit_width, prompt_step};
+use inquire::Select;
+use std::cell::Cell;
+use std::cmp::Reverse;
+
+pub(crate) fn resolve_todoset_id(project: &Project) -> AppResult<u64> {
+    project
+        .dock
+        .iter()
+        .find(|item| item.name == "todoset" && item.enabled)
+        .map(|item| item.id)
+        .ok_or_else(|| {
+            AppError::no_account(format!(
+                "Project \"{}\" does not expose a usable todoset in dock.",
+                project.name
+            ))
+        })
+}
+
+pub(super) fn prompt_select_project(projects: &[Project]) -> AppResult<Step<usize>> {
+    prompt_select_project_watching(projects, &|_| {})
+}
+
+/// Like `prompt_select_project`, and calls `highlighted` with the index of
+/// the project under the cursor when the prompt opens and after each change
+/// to the filter. Arrow keys are not reported; inquire has no hook for them.
+pub(super) fn prompt_select_project_watching(
+    projects: &[Project],
+    highlighted: &dyn Fn(usize),
+) -> AppResult<Step<usize>> {
+    let ordered = lookup::pinned_first(projects)?;
+    let labels: Vec<String> = ordered.iter().map(|entry| entry.label.clone()).collect();
+
+    // The scorer sees every label in order on each filter change, so the last
+    // call knows the best match, which the cursor resets to.
+    let last = labels.len().saturating_sub(1);
+    let best: Cell<Option<(usize, i64)>> = Cell::new(None);
+    let scorer = |input: &str, label: &String, value: &str, index: usize| {
+        let score = Select::<String>::DEFAULT_SCORER(input, label, value, index);
+        if index == 0 {
+            best.set(None);
+        }
+        if let Some(score) = score
+            && best.get().is_none_or(|(_, top)| score > top)
+        {
+            best.set(Some((index, score)));
+        }
+        if index == last
+            && let Some(entry) = best.get().and_then(|(top, _)| ordered.get(top))
+        {
+            highlighted(entry.index);
+        }
+        score
+    };
+
+    let result = Select::new("Project", labels)
+        .with_help_message(SELECT_HELP_MESSAGE)
+        .with_starting_cursor(0)
+        .with_scorer(&scorer)
+        // Ties keep list order, so the best match is the one reported.
+        .with_sorter(&|options| options.sort_by_key(|&(index, score)| (Reverse(score), index)))
+        .raw_prompt()
+        .map(|selection| selection.index);
+    let Step::Next(selection) = prompt_step(result, "select project")? else {
+        return Ok(Step::Back);
+    };
+
+    ordered
+        .get(selection)
+        .map(|entry| Step::Next(entry.index))
+        .ok_or_else(|| AppError::invalid_input("Project selection out of range."))
+}
+
+pub(super) async fn resolve_project(
+    client: &BasecampClient,
+    reference: &str,
+) -> AppResult<Project> {
+    lookup::resolve_project(client, reference).await
+}
+
+pub(super) fn prompt_select_todolist(todolists: &[Todolist]) -> AppResult<Step<usize>> {
+    let aliases = integration::load_config()?.aliases;
+    let labels: Vec<String> = todolists
+        .iter()
+        .map(|list| {
+            let alias: String = aliases
+                .iter()
+                .filter(|(_, target)| target.todolist_id == Some(list.id))
+                .map(|(name, _)| format!(" @{name}"))
+                .collect();
+            let suffix = format!(" ({}){alias}", list.id);
+            let name = fit_width(
+                &todolist_display_name(list),
+                suffix.chars().count() + PROMPT_MARGIN,
+            );
+            format!("{name}{suffix}")
+        })
+        .collect();
+
+    let result = Select::new("To-do list", labels)
+        .with_help_message(SELECT_HELP_MESSAGE)
+        .with_starting_cursor(0)
+        .raw_prompt()
+        .map(|selection| selection.index);
+    prompt_step(result, "select to-do list")
+}
+
+pub(crate) fn todolist_display_name(todolist: &Todolist) -> String {
+    let title = todolist.title.trim();
+    if !title.is_empty() {
+        return title.to_string();
+    }
+
+    let name = todolist.name.trim();
+    if !name.is_empty() {
+        return name.to_string();
+    }
+
+    format!("List {}", todolist.id)
+}
+
+pub(crate) async fn resolve_todolist(
+    client: &BasecampClient,
+    project: &Project,
+    reference: &str,
+) -> AppResult<Todolist> {
+    let reference = reference.trim();
+    if reference.starts_with('@') {
+        return resolve_todolist_alias(client, project, reference).await;
+    }
+
+    let todoset_id = resolve_todoset_id(project)?;
+    let mut todolists = client.list_todolists(project.id, todoset_id).await?;
+
+    if let Ok(todolist_id) = reference.parse::<u64>()
+        && let Some(index) = todolists.iter().position(|list| list.id == todolist_id)
+    {
+        return Ok(todolists.swap_remove(index));
+    }
+
+    let names: Vec<String> = todolists.iter().map(todolist_display_name).collect();
+    let index = lookup::match_by_name(names.iter().map(String::as_str), reference, "to-do list")?;
+    Ok(todolists.swap_remove(index))
+}
+
+async fn resolve_todolist_alias(
+    client: &BasecampClient,
+    project: &Project,
+    alias: &str,
+) -> AppResult<Todolist> {
+    let resolved = lookup::resolve_alias(alias)?;
+    let Some(todolist_id) = resolved.todolist_id else {
+        return Err(AppError::invalid_input(format!(
+            "Alias {alias} names a project, not a to-do list."
+        )));
+    };
+    if resolved.project_id != project.id {
+        return Err(AppError::invalid_input(format!(
+            "Alias {alias} is a list in project {}, not \"{}\".",
+            resolved.project_id, project.name
+        )));
+    }
+
+    client.get_todolist(project.id, todolist_id).await
+}
+
+pub(crate) async fn resolve_group(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    reference: &str,
+) -> AppResult<Todolist> {
+    let reference = reference.trim();
+    let mut groups = client.list_todolist_groups(project_id, todolist_id).await?;
+
+    if let Ok(group_id) = reference.parse::<u64>()
+        && let Some(index) = groups.iter().position(|group| group.id == group_id)
+    {
+        return Ok(groups.swap_remove(index));
+    }
+
+    let names: Vec<String> = groups.iter().map(todolist_display_name).collect();
+    let index = lookup::match_by_name(names.iter().map(String::as_str), reference, "group")?;
+    Ok(groups.swap_remove(index))
+}
@@ -0,0 +1,118 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::cli::{TodoPosition, TodoPositionArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoPositionOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    /// The list or group holding the to-do.
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    pub todo_id: u64,
+    pub content: String,
+    /// 1-based, among the open to-dos of the list.
+    pub position: u32,
+}
+
+pub async fn run(args: TodoPositionArgs) -> AppResult<TodoPositionOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name) = match target.direct {
+        Some((project_id, todo_id)) => ("direct", None, project_id, todo_id, None),
+        None => {
+            ensure_search_mode_terminal("position")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::IncompleteOnly,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No open to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .into_iter()
+                .nth(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name),
+            )
+        }
+    };
+
+    let todo = client.get_todo(project_id, todo_id).await?;
+    if todo.completed.unwrap_or(false) {
+        return Err(AppError::invalid_input(format!(
+            "To-do {todo_id} is completed; only open to-dos can be moved."
+        )));
+    }
+    let parent = todo.parent.ok_or_else(|| {
+        AppError::generic(format!(
+            "Basecamp did not report the list of to-do {todo_id}."
+        ))
+    })?;
+    let position = move_todo(&client, project_id, parent.id, todo_id, args.to).await?;
+
+    Ok(TodoPositionOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todolist_id: parent.id,
+        todolist_name: parent.title,
+        todo_id,
+        content: todo.content,
+        position,
+    })
+}
+
+/// Moves an open to-do that is already in `todolist_id`. Positions past the
+/// end land at the bottom; returns the position used.
+pub(super) async fn move_todo(
+    client: &BasecampClient,
+    project_id: u64,
+    todolist_id: u64,
+    todo_id: u64,
+    position: TodoPosition,
+) -> AppResult<u32> {
+    let open = client.list_todos(project_id, todolist_id, false).await?;
+    let last = u32::try_from(open.len()).unwrap_or(u32::MAX).max(1);
+    let position = match position {
+        TodoPosition::Top => 1,
+        TodoPosition::Bottom => last,
+        TodoPosition::At(position) => position.min(last),
+    };
+
+    client
+        .reposition_recording(project_id, todo_id, position)
+        .await?;
+    Ok(position)
+}
@@ -0,0 +1,159 @@
+//! `todo quick`: one line of text with inline tokens becomes `todo add`
+//! flags, so a hotkey can create a to-do without any prompt.
+
+use crate::cli::{TodoAddArgs, TodoQuickArgs, TodolistId};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::projects::lookup;
+
+/// Words that may sit between the title and a trailing date
+/// ("Call Ana on fri") and are dropped with it.
+const DATE_CONNECTORS: [&str; 3] = ["on", "by", "due"];
+
+/// What the text said, before any reference is resolved.
+#[derive(Debug, Default)]
+struct QuickEntry {
+    content: String,
+    target: Option<String>,
+    assignees: Vec<String>,
+    me: bool,
+    notify: Vec<String>,
+    due_on: Option<String>,
+    starts_on: Option<String>,
+}
+
+/// Parses the text and resolves `#target`; people and dates are left to
+/// `todo add`, which reports bad ones the same way as its flags.
+pub async fn add_args(args: TodoQuickArgs) -> AppResult<TodoAddArgs> {
+    let entry = parse(&args.text.join(" "))?;
+    let teams = integration::load_config()?.teams;
+    let as_reference = |name: String| {
+        if teams.keys().any(|team| team.eq_ignore_ascii_case(&name)) {
+            format!("@{name}")
+        } else {
+            name
+        }
+    };
+
+    let (project_id, todolist_id) = match entry.target.as_deref() {
+        Some(reference) => resolve_target(reference).await?,
+        None => (None, None),
+    };
+
+    Ok(TodoAddArgs {
+        content: Some(entry.content),
+        due_on: entry.due_on,
+        starts_on: entry.starts_on,
+        project_id,
+        todolist_id,
+        assignees: entry.assignees.into_iter().map(as_reference).collect(),
+        me: entry.me,
+        notify: entry.notify.into_iter().map(as_reference).collect(),
+        open: args.open,
+        json: args.json,
+        ..TodoAddArgs::default()
+    })
+}
+
+// An alias (pin or `alias set`, project or list) wins; anything else is a
+// project id or name.
+async fn resolve_target(reference: &str) -> AppResult<(Option<u64>, Option<TodolistId>)> {
+    if let Ok(alias) = lookup::resolve_alias(reference) {
+        let todolist_id = alias.todolist_id.map(|id| TodolistId {
+            id,
+            project_id: Some(alias.project_id),
+        });
+        return Ok((Some(alias.project_id), todolist_id));
+    }
+
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, reference).await?;
+    Ok((Some(project.id), None))
+}
+
+// Tokens: `#target`, `@person` (`@me` for yourself), `!notify:a,b`,
+// `!due:<date>`, `!start:<date>`. A trailing date phrase such as
+// `tomorrow` or `next fri` is the due date unless `!due:` gave one. A
+// leading `\` keeps a word as text.
+fn parse(text: &str) -> AppResult<QuickEntry> {
+    let mut entry = QuickEntry::default();
+    let mut words = Vec::new();
+
+    for word in text.split_whitespace() {
+        if let Some(literal) = word.strip_prefix('\\') {
+            words.push(literal.to_string());
+        } else if let Some(target) = word.strip_prefix('#').filter(|rest| !rest.is_empty()) {
+            if entry.target.replace(target.to_string()).is_some() {
+                return Err(AppError::invalid_input(
+                    "Quick add takes one #project or #list target.",
+                ));
+            }
+        } else if let Some(person) = word.strip_prefix('@').filter(|rest| !rest.is_empty()) {
+            if person.eq_ignore_ascii_case("me") {
+                entry.me = true;
+            } else {
+                entry.assignees.push(person.to_string());
+            }
+        } else if let Some((key, value)) =
+            word.strip_prefix('!').and_then(|rest| rest.split_once(':'))
+        {
+            match key.to_ascii_lowercase().as_str() {
+                "notify" => entry.notify.extend(
+                    value
+                        .split(',')
+                        .map(|person| person.trim().trim_start_matches('@'))
+                        .filter(|person| !person.is_empty())
+                        .map(str::to_string),
+                ),
+                "due" => entry.due_on = Some(value.to_string()),
+                "start" => entry.starts_on = Some(value.to_string()),
+                other => {
+                    return Err(AppError::invalid_input(format!(
+                        "Unknown quick add token \"!{other}:\". Use !notify:, !due:, or !start:."
+                    )));
+                }
+            }
+        } else {
+            words.push(word.to_string());
+        }
+    }
+
+    if entry.due_on.is_none()
+        && let Some((due_on, taken)) = trailing_date(&words)
+    {
+        entry.due_on = Some(due_on);
+        words.truncate(words.len() - taken);
+        if words.len() > 1
+            && words
+                .last()
+                .is_some_and(|word| DATE_CONNECTORS.contains(&word.to_ascii_lowercase().as_str()))
+        {
+            words.pop();
+        }
+    }
+
+    entry.content = words.join(" ");
+    if entry.content.is_empty() {
+        return Err(AppError::invalid_input(
+            "Quick add needs a title besides the tokens.",
+        ));
+    }
+    Ok(entry)
+}
+
+// The last one or two words, when they read as a due date. Only the shape is
+// checked here; `todo add` resolves it against `profile_today`. A date
+// alone is the title, not a due date.
+fn trailing_date(words: &[String]) -> Option<(String, usize)> {
+    let is_date = |phrase: &str| dates::parse_todo_date(phrase, Date::today_utc(), "").is_ok();
+
+    if words.len() > 2 {
+        let phrase = words[words.len() - 2..].join(" ");
+        if phrase.to_ascii_lowercase().starts_with("next ") && is_date(&phrase) {
+            return Some((phrase, 2));
+        }
+    }
+    let last = words.last().filter(|_| words.len() > 1)?;
+    is_date(last).then(|| (last.clone(), 1))
+}
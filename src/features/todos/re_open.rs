@@ -1,12 +1,23 @@
+use super::add::resolve_todolist_flag;
+use super::batch::{self, FailedTodo};
+use super::filter::MatchFilter;
+use super::picker::{
+    prompt_select_project, prompt_select_todolist, resolve_todoset_id, todolist_display_name,
+};
 use super::search::{
-    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todos,
-    resolve_query, search_todos,
+    TodoCompletionFilter, TodoDetails, TodoMatch, ensure_search_mode_terminal,
+    print_selected_todos, prompt_select_todos, resolve_query, search_todos_sorted,
 };
+use super::target::TodoTarget;
+use super::undo;
 use crate::basecamp::client::BasecampClient;
 use crate::cli::TodoReOpenArgs;
 use crate::error::{AppError, AppResult};
 use crate::features::auth::integration;
+use crate::features::auth::models::{TodoBatchAction, TodoBatchItem};
+use crate::ui::can_prompt;
 use serde::Serialize;
+use std::path::Path;
 
 #[derive(Debug, Serialize)]
 pub struct TodoReOpenOutput {
@@ -18,6 +29,8 @@ pub struct TodoReOpenOutput {
     pub scope_project_id: Option<u64>,
     pub reopened: Vec<ReOpenedTodo>,
     pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<FailedTodo>,
 }
 
 #[derive(Debug, Serialize)]
@@ -32,13 +45,40 @@ pub struct ReOpenedTodo {
 
 pub async fn run(args: TodoReOpenArgs) -> AppResult<TodoReOpenOutput> {
     let session = integration::resolve_session_context()?;
-    let client = BasecampClient::new(session.account_id, session.access_token.clone())?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
 
-    if let Some(todo_id) = args.id {
-        let project_id = args.project_id.ok_or_else(|| {
-            AppError::invalid_input("`--project-id` is required when using `--id`.")
-        })?;
+    let output = run_mode(&client, args, session.account_id).await?;
+    undo::record_batch(
+        session.account_id,
+        TodoBatchAction::ReOpen,
+        output
+            .reopened
+            .iter()
+            .map(|todo| TodoBatchItem {
+                project_id: todo.project_id,
+                todo_id: todo.todo_id,
+                content: todo.content.clone(),
+            })
+            .collect(),
+    )?;
+    Ok(output)
+}
 
+async fn run_mode(
+    client: &BasecampClient,
+    args: TodoReOpenArgs,
+    account_id: u64,
+) -> AppResult<TodoReOpenOutput> {
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, account_id)?;
+    let filter = MatchFilter {
+        assignees: args.assignees,
+        due_before: args.due_before,
+        due_after: args.due_after,
+        list: args.list,
+    };
+    if let Some((project_id, todo_id)) = target.direct {
+        filter.ensure_unused("a to-do URL")?;
         client.re_open_todo(project_id, todo_id).await?;
 
         return Ok(TodoReOpenOutput {
@@ -53,22 +93,117 @@ pub async fn run(args: TodoReOpenArgs) -> AppResult<TodoReOpenOutput> {
                 content: None,
             }],
             count: 1,
+            failed: Vec::new(),
         });
     }
 
+    if let Some(path) = args.from_file.as_deref() {
+        return run_file_mode(client, path, account_id).await;
+    }
+
+    if args.from_list {
+        let mut project_id = target.project_id;
+        let list_id = resolve_todolist_flag(&mut project_id, args.list_id, "--list-id")?;
+        return run_list_mode(client, project_id, list_id).await;
+    }
+
     ensure_search_mode_terminal("re-open")?;
-    let query = resolve_query(args.query)?;
-    let matches = search_todos(
-        &client,
+    let query = resolve_query(target.query)?;
+    let matches = search_todos_sorted(
+        client,
         &query,
-        args.project_id,
+        target.project_id,
         TodoCompletionFilter::CompletedOnly,
+        args.sort,
     )
     .await?;
+    let matches = filter.apply(client, matches).await?;
+
+    if matches.is_empty() {
+        return Err(AppError::no_account(format!(
+            "No completed to-dos matched \"{query}\"{}.",
+            filter.no_match_suffix()
+        )));
+    }
+
+    let selections = prompt_select_todos(&matches)?;
+    if selections.is_empty() {
+        return Err(AppError::invalid_input(
+            "Select at least one to-do to re-open.",
+        ));
+    }
+
+    print_selected_todos(&matches, &selections)?;
+
+    let reopened = re_open_selected(client, &matches, selections).await?;
+    let count = reopened.len();
+    Ok(TodoReOpenOutput {
+        ok: true,
+        mode: "search".to_string(),
+        query: Some(query),
+        scope_project_id: target.project_id,
+        reopened,
+        count,
+        failed: Vec::new(),
+    })
+}
+
+async fn run_list_mode(
+    client: &BasecampClient,
+    project_id: Option<u64>,
+    list_id: Option<u64>,
+) -> AppResult<TodoReOpenOutput> {
+    ensure_list_mode_terminal()?;
+
+    let project = match project_id {
+        Some(project_id) => client.get_project(project_id).await?,
+        None => {
+            let mut projects = client.list_projects().await?;
+            if projects.is_empty() {
+                return Err(AppError::no_account(
+                    "No Basecamp projects were found for the current account.",
+                ));
+            }
+            let project_index = prompt_select_project(&projects)?.or_cancel("select project")?;
+            projects.swap_remove(project_index)
+        }
+    };
+
+    let (todolist_id, todolist_name) = match list_id {
+        Some(list_id) => (list_id, format!("List {list_id}")),
+        None => {
+            let todoset_id = resolve_todoset_id(&project)?;
+            let todolists = client.list_todolists(project.id, todoset_id).await?;
+            if todolists.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "Project \"{}\" has no to-do lists.",
+                    project.name
+                )));
+            }
+            let index = prompt_select_todolist(&todolists)?.or_cancel("select to-do list")?;
+            let todolist = &todolists[index];
+            (todolist.id, todolist_display_name(todolist))
+        }
+    };
+
+    let matches: Vec<TodoMatch> = client
+        .list_todos(project.id, todolist_id, true)
+        .await?
+        .into_iter()
+        .filter(|todo| todo.completed.unwrap_or(true))
+        .map(|todo| TodoMatch {
+            details: TodoDetails::from_todo(&todo),
+            todo_id: todo.id,
+            project_id: project.id,
+            project_name: project.name.clone(),
+            content: todo.content,
+            updated_at: todo.updated_at,
+        })
+        .collect();
 
     if matches.is_empty() {
         return Err(AppError::no_account(format!(
-            "No completed to-dos matched \"{query}\"."
+            "No completed to-dos found in list \"{todolist_name}\"."
         )));
     }
 
@@ -81,6 +216,64 @@ pub async fn run(args: TodoReOpenArgs) -> AppResult<TodoReOpenOutput> {
 
     print_selected_todos(&matches, &selections)?;
 
+    let reopened = re_open_selected(client, &matches, selections).await?;
+    let count = reopened.len();
+    Ok(TodoReOpenOutput {
+        ok: true,
+        mode: "list".to_string(),
+        query: None,
+        scope_project_id: Some(project.id),
+        reopened,
+        count,
+        failed: Vec::new(),
+    })
+}
+
+async fn run_file_mode(
+    client: &BasecampClient,
+    path: &Path,
+    account_id: u64,
+) -> AppResult<TodoReOpenOutput> {
+    let (references, mut failed) = batch::read_reference_file(path, account_id)?;
+
+    let mut reopened = Vec::with_capacity(references.len());
+    for reference in references {
+        match client
+            .re_open_todo(reference.project_id, reference.todo_id)
+            .await
+        {
+            Ok(()) => reopened.push(ReOpenedTodo {
+                todo_id: reference.todo_id,
+                project_id: reference.project_id,
+                project_name: None,
+                content: None,
+            }),
+            Err(err) => failed.push(FailedTodo {
+                line: reference.line,
+                reference: format!("{}:{}", reference.project_id, reference.todo_id),
+                error: err.message,
+            }),
+        }
+    }
+    failed.sort_by_key(|item| item.line);
+
+    let count = reopened.len();
+    Ok(TodoReOpenOutput {
+        ok: failed.is_empty(),
+        mode: "file".to_string(),
+        query: None,
+        scope_project_id: None,
+        reopened,
+        count,
+        failed,
+    })
+}
+
+async fn re_open_selected(
+    client: &BasecampClient,
+    matches: &[TodoMatch],
+    selections: Vec<usize>,
+) -> AppResult<Vec<ReOpenedTodo>> {
     let mut reopened = Vec::with_capacity(selections.len());
     for selection in selections {
         let matched = matches
@@ -101,13 +294,15 @@ pub async fn run(args: TodoReOpenArgs) -> AppResult<TodoReOpenOutput> {
         });
     }
 
-    let count = reopened.len();
-    Ok(TodoReOpenOutput {
-        ok: true,
-        mode: "search".to_string(),
-        query: Some(query),
-        scope_project_id: args.project_id,
-        reopened,
-        count,
-    })
+    Ok(reopened)
+}
+
+fn ensure_list_mode_terminal() -> AppResult<()> {
+    if can_prompt() {
+        return Ok(());
+    }
+
+    Err(AppError::invalid_input(
+        "`basecamp-cli todo re-open --from-list` requires an interactive terminal for prompts.",
+    ))
 }
@@ -0,0 +1,528 @@
+//! `todo recur`: Basecamp has no recurring to-dos, so schedules live in
+//! config and `todo recur run` (meant for cron) creates the instances.
+
+use super::add::default_completion_subscribers;
+use super::notes::notes_to_html;
+use super::people::resolve_people_references;
+use super::picker::{resolve_todolist, resolve_todoset_id, todolist_display_name};
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::dry_run;
+use crate::basecamp::links;
+use crate::basecamp::models::{CreateTodoPayload, CreatedTodo, Project, Todolist};
+use crate::cli::{TodoRecurAddArgs, TodoRecurRemoveArgs, TodoRecurRunArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::RecurringTodo;
+use crate::features::projects::lookup;
+use crate::time_zone;
+use serde::Serialize;
+
+/// How far `run` looks back for a missed instance, and `list` ahead for the
+/// next one. Every schedule matches at least once a month.
+const SCAN_DAYS: i64 = 366;
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+#[derive(Debug, Serialize)]
+pub struct TodoRecurAddOutput {
+    pub ok: bool,
+    #[serde(flatten)]
+    pub schedule: RecurSchedule,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoRecurListOutput {
+    pub ok: bool,
+    pub schedules: Vec<RecurSchedule>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoRecurRemoveOutput {
+    pub ok: bool,
+    pub id: u32,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoRecurRunOutput {
+    pub ok: bool,
+    pub today: String,
+    pub created: Vec<RecurCreatedTodo>,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<RecurFailure>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurSchedule {
+    pub id: u32,
+    pub content: String,
+    pub every: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_id: u64,
+    pub todolist_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_created_on: Option<String>,
+    /// Due date of the next instance `run` would create; may be in the past
+    /// when one is waiting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_due_on: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurCreatedTodo {
+    pub schedule_id: u32,
+    pub todo_id: u64,
+    pub content: String,
+    pub due_on: String,
+    pub project_id: u64,
+    pub project_name: String,
+    pub todolist_name: String,
+    pub app_url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurFailure {
+    pub schedule_id: u32,
+    pub content: String,
+    pub error: String,
+}
+
+/// A parsed `--every` value.
+#[derive(Debug, Clone, Copy)]
+enum Every {
+    Day,
+    Weekday,
+    /// Bit `n` set for weekday `n` (0 = Sunday).
+    Weekdays(u8),
+    /// Day of the month; months without it use their last day.
+    MonthDay(u32),
+}
+
+impl Every {
+    fn parse(value: &str) -> AppResult<Self> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "day" | "daily" => return Ok(Self::Day),
+            "weekday" | "weekdays" => return Ok(Self::Weekday),
+            _ => {}
+        }
+
+        let ordinal = ["st", "nd", "rd", "th"]
+            .iter()
+            .find_map(|suffix| normalized.strip_suffix(suffix))
+            .and_then(|day| day.parse::<u32>().ok());
+        if let Some(day) = ordinal {
+            return if (1..=31).contains(&day) {
+                Ok(Self::MonthDay(day))
+            } else {
+                Err(invalid_every(value))
+            };
+        }
+
+        let mut mask = 0u8;
+        for name in normalized.split(',').map(str::trim) {
+            let weekday = dates::parse_weekday(name).ok_or_else(|| invalid_every(value))?;
+            mask |= 1 << weekday;
+        }
+        Ok(Self::Weekdays(mask))
+    }
+
+    /// Canonical text stored in config: `day`, `weekday`, `monday,thursday`, `15th`.
+    fn label(self) -> String {
+        match self {
+            Self::Day => "day".to_string(),
+            Self::Weekday => "weekday".to_string(),
+            // Listed Monday first.
+            Self::Weekdays(mask) => [1, 2, 3, 4, 5, 6, 0]
+                .into_iter()
+                .filter(|weekday| mask & (1 << weekday) != 0)
+                .map(|weekday| WEEKDAY_NAMES[weekday])
+                .collect::<Vec<_>>()
+                .join(","),
+            Self::MonthDay(day) => {
+                let suffix = match (day % 10, day % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{day}{suffix}")
+            }
+        }
+    }
+
+    fn matches(self, date: Date) -> bool {
+        match self {
+            Self::Day => true,
+            Self::Weekday => (1..=5).contains(&date.weekday()),
+            Self::Weekdays(mask) => mask & (1 << date.weekday()) != 0,
+            Self::MonthDay(day) => date.day() == day.min(date.end_of_month().day()),
+        }
+    }
+}
+
+fn invalid_every(value: &str) -> AppError {
+    AppError::invalid_input(format!(
+        "Invalid --every \"{}\". Use day, weekday, weekday names (monday or mon,thu), or a day of the month (1st, 15th).",
+        value.trim()
+    ))
+}
+
+pub async fn run_add(args: TodoRecurAddArgs) -> AppResult<TodoRecurAddOutput> {
+    let content = args.content.trim().to_string();
+    if content.is_empty() {
+        return Err(AppError::invalid_input("Title/content is required."));
+    }
+    let every = Every::parse(&args.every)?;
+
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let todolist = match args.list.as_deref() {
+        Some(list) => resolve_todolist(&client, &project, list).await?,
+        None => default_todolist(&client, &project, &args.project).await?,
+    };
+
+    // Checked now so a typo fails here rather than in a cron job later.
+    if !args.assignees.is_empty() || !args.notify.is_empty() {
+        let people = client.list_project_people(project.id).await?;
+        resolve_people_references(&args.assignees, &people, "assignees")?;
+        resolve_people_references(&args.notify, &people, "notify")?;
+    }
+    let today = time_zone::profile_today(&client).await?;
+
+    let schedule = {
+        let _lock = integration::lock_state()?;
+        let mut config = integration::load_config()?;
+        let id = config
+            .recurring
+            .iter()
+            .map(|schedule| schedule.id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let schedule = RecurringTodo {
+            id,
+            account_id: session.account_id,
+            content,
+            notes: args
+                .notes
+                .map(|notes| notes.trim().to_string())
+                .filter(|notes| !notes.is_empty()),
+            every: every.label(),
+            project_id: project.id,
+            project_name: project.name.clone(),
+            todolist_id: todolist.id,
+            todolist_name: todolist_display_name(&todolist),
+            assignees: args.assignees,
+            notify: args.notify,
+            starts_on: today.to_string(),
+            last_created_on: None,
+        };
+        config.recurring.push(schedule.clone());
+        integration::save_config(&config)?;
+        schedule
+    };
+
+    Ok(TodoRecurAddOutput {
+        ok: true,
+        schedule: summary(schedule, today),
+    })
+}
+
+pub async fn run_list() -> AppResult<TodoRecurListOutput> {
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let schedules: Vec<RecurSchedule> = integration::load_config()?
+        .recurring
+        .into_iter()
+        .map(|schedule| summary(schedule, today))
+        .collect();
+    let count = schedules.len();
+
+    Ok(TodoRecurListOutput {
+        ok: true,
+        schedules,
+        count,
+    })
+}
+
+pub fn run_remove(args: TodoRecurRemoveArgs) -> AppResult<TodoRecurRemoveOutput> {
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let index = config
+        .recurring
+        .iter()
+        .position(|schedule| schedule.id == args.id)
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "Recurring to-do {} does not exist. Run `basecamp-cli todo recur list` to list them.",
+                args.id
+            ))
+        })?;
+    let removed = config.recurring.remove(index);
+    integration::save_config(&config)?;
+
+    Ok(TodoRecurRemoveOutput {
+        ok: true,
+        id: removed.id,
+        content: removed.content,
+    })
+}
+
+/// Creates one instance per schedule that has come due since its last one.
+/// Missed days (the machine was off) collapse into the latest, so a weekly
+/// chore never piles up.
+pub async fn run_due(_args: TodoRecurRunArgs) -> AppResult<TodoRecurRunOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+
+    let schedules: Vec<RecurringTodo> = integration::load_config()?
+        .recurring
+        .into_iter()
+        .filter(|schedule| schedule.account_id == session.account_id)
+        .collect();
+
+    let mut created = Vec::new();
+    let mut failed = Vec::new();
+    for schedule in schedules {
+        let due_on = match Every::parse(&schedule.every) {
+            Ok(every) => latest_due(every, first_open_day(&schedule, today), today),
+            Err(err) => {
+                failed.push(RecurFailure {
+                    schedule_id: schedule.id,
+                    content: schedule.content,
+                    error: err.message,
+                });
+                continue;
+            }
+        };
+        let Some(due_on) = due_on else {
+            continue;
+        };
+        let previous = match claim_instance(schedule.id, due_on)? {
+            Claim::Claimed { previous } => previous,
+            Claim::Taken => continue,
+        };
+
+        match create_instance(&client, &schedule, due_on).await {
+            Ok(todo) => {
+                created.push(RecurCreatedTodo {
+                    schedule_id: schedule.id,
+                    todo_id: todo.id,
+                    content: todo.content,
+                    due_on: due_on.to_string(),
+                    project_id: schedule.project_id,
+                    project_name: schedule.project_name,
+                    todolist_name: schedule.todolist_name,
+                    app_url: todo.app_url.unwrap_or_else(|| {
+                        links::todo_url(session.account_id, schedule.project_id, todo.id)
+                    }),
+                });
+            }
+            Err(err) => {
+                release_claim(schedule.id, due_on, previous)?;
+                failed.push(RecurFailure {
+                    schedule_id: schedule.id,
+                    content: schedule.content,
+                    error: err.message,
+                });
+            }
+        }
+    }
+
+    let count = created.len();
+    Ok(TodoRecurRunOutput {
+        ok: failed.is_empty(),
+        today: today.to_string(),
+        created,
+        count,
+        failed,
+    })
+}
+
+// A project alias pointing at a list picks that list; otherwise the project
+// must have exactly one.
+async fn default_todolist(
+    client: &BasecampClient,
+    project: &Project,
+    reference: &str,
+) -> AppResult<Todolist> {
+    if let Some(alias) = reference.trim().strip_prefix('@')
+        && let Some(todolist_id) = lookup::resolve_alias(alias)?.todolist_id
+    {
+        return client.get_todolist(project.id, todolist_id).await;
+    }
+
+    let todoset_id = resolve_todoset_id(project)?;
+    let mut todolists = client.list_todolists(project.id, todoset_id).await?;
+    match todolists.len() {
+        0 => Err(AppError::no_account(format!(
+            "Project \"{}\" has no to-do lists.",
+            project.name
+        ))),
+        1 => Ok(todolists.remove(0)),
+        count => Err(AppError::invalid_input(format!(
+            "Project \"{}\" has {count} to-do lists; pick one with --list.",
+            project.name
+        ))),
+    }
+}
+
+async fn create_instance(
+    client: &BasecampClient,
+    schedule: &RecurringTodo,
+    due_on: Date,
+) -> AppResult<CreatedTodo> {
+    let people = client.list_project_people(schedule.project_id).await?;
+    let assignee_ids = resolve_people_references(&schedule.assignees, &people, "assignees")?;
+    let completion_subscriber_ids = if schedule.notify.is_empty() {
+        default_completion_subscribers(Some(&people))?
+    } else {
+        Some(resolve_people_references(
+            &schedule.notify,
+            &people,
+            "notify",
+        )?)
+    };
+
+    client
+        .create_todo(
+            schedule.project_id,
+            schedule.todolist_id,
+            &CreateTodoPayload {
+                content: schedule.content.clone(),
+                notes: notes_to_html(schedule.notes.clone()),
+                assignee_ids: Some(assignee_ids).filter(|ids| !ids.is_empty()),
+                completion_subscriber_ids: completion_subscriber_ids.filter(|ids| !ids.is_empty()),
+                starts_on: None,
+                due_on: Some(due_on.to_string()),
+            },
+        )
+        .await
+}
+
+enum Claim {
+    /// This run creates the instance; `previous` is restored if it fails.
+    Claimed { previous: Option<String> },
+    /// Another run already created it, or the schedule was removed.
+    Taken,
+}
+
+// Records the instance before it is created, re-reading the schedule under
+// the state lock, so overlapping runs (two cron jobs) never both create it.
+// A run cut short between the two loses that instance rather than
+// duplicating it. The lock is not held across the request.
+fn claim_instance(schedule_id: u32, due_on: Date) -> AppResult<Claim> {
+    if dry_run::enabled() {
+        return Ok(Claim::Claimed { previous: None });
+    }
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    let Some(schedule) = config
+        .recurring
+        .iter_mut()
+        .find(|schedule| schedule.id == schedule_id)
+    else {
+        return Ok(Claim::Taken);
+    };
+    if schedule
+        .last_created_on
+        .as_deref()
+        .and_then(Date::parse_iso)
+        .is_some_and(|last| last >= due_on)
+    {
+        return Ok(Claim::Taken);
+    }
+
+    let previous = schedule.last_created_on.replace(due_on.to_string());
+    integration::save_config(&config)?;
+    Ok(Claim::Claimed { previous })
+}
+
+// Creation failed, so the schedule stays due for the next run.
+fn release_claim(schedule_id: u32, due_on: Date, previous: Option<String>) -> AppResult<()> {
+    if dry_run::enabled() {
+        return Ok(());
+    }
+
+    let _lock = integration::lock_state()?;
+    let mut config = integration::load_config()?;
+    if let Some(schedule) = config
+        .recurring
+        .iter_mut()
+        .find(|schedule| schedule.id == schedule_id)
+        && schedule.last_created_on.as_deref() == Some(due_on.to_string().as_str())
+    {
+        schedule.last_created_on = previous;
+        integration::save_config(&config)?;
+    }
+    Ok(())
+}
+
+fn summary(schedule: RecurringTodo, today: Date) -> RecurSchedule {
+    let next_due_on = Every::parse(&schedule.every).ok().and_then(|every| {
+        let from = first_open_day(&schedule, today);
+        latest_due(every, from, today)
+            .or_else(|| first_due(every, from.max(today.add_days(1))))
+            .map(|date| date.to_string())
+    });
+
+    RecurSchedule {
+        id: schedule.id,
+        content: schedule.content,
+        every: schedule.every,
+        project_id: schedule.project_id,
+        project_name: schedule.project_name,
+        todolist_id: schedule.todolist_id,
+        todolist_name: schedule.todolist_name,
+        assignees: schedule.assignees,
+        notify: schedule.notify,
+        last_created_on: schedule.last_created_on,
+        next_due_on,
+    }
+}
+
+// The earliest day a new instance may be due: the schedule's start, or the
+// day after the last instance.
+fn first_open_day(schedule: &RecurringTodo, today: Date) -> Date {
+    let starts_on = Date::parse_iso(&schedule.starts_on).unwrap_or(today);
+    schedule
+        .last_created_on
+        .as_deref()
+        .and_then(Date::parse_iso)
+        .map_or(starts_on, |last| starts_on.max(last.add_days(1)))
+}
+
+fn latest_due(every: Every, from: Date, today: Date) -> Option<Date> {
+    (0..=SCAN_DAYS)
+        .map(|offset| today.add_days(-offset))
+        .take_while(|date| *date >= from)
+        .find(|date| every.matches(*date))
+}
+
+fn first_due(every: Every, from: Date) -> Option<Date> {
+    (0..=SCAN_DAYS)
+        .map(|offset| from.add_days(offset))
+        .find(|date| every.matches(*date))
+}
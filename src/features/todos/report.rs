@@ -0,0 +1,157 @@
+use crate::basecamp::models::ReportTodo;
+use crate::cli::{TodoDueArgs, TodoOverdueArgs};
+use crate::dates::{self, Date};
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::time_zone;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoReportOutput {
+    pub ok: bool,
+    pub report: String,
+    /// Today from `time_zone::profile_today`; every `days_until_due` counts from it.
+    pub today: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_project_id: Option<u64>,
+    pub todos: Vec<ReportedTodo>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportedTodo {
+    pub todo_id: u64,
+    pub project_id: u64,
+    pub project_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todolist_name: Option<String>,
+    pub content: String,
+    pub due_on: String,
+    /// Negative when overdue.
+    pub days_until_due: i64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run_overdue(args: TodoOverdueArgs) -> AppResult<TodoReportOutput> {
+    let client = integration::session_client()?;
+    let (today, report) = tokio::try_join!(
+        time_zone::profile_today(&client),
+        client.overdue_todos_report()
+    )?;
+
+    let candidates = report
+        .under_a_week_late
+        .into_iter()
+        .chain(report.over_a_week_late)
+        .chain(report.over_a_month_late)
+        .chain(report.over_three_months_late);
+    // Basecamp buckets lateness by the account's clock; the cut-off here is
+    // `profile_today`, so nothing due today counts as late.
+    let todos = collect(candidates, today, args.project_id, |due_on| due_on < today);
+
+    Ok(output("overdue", today, None, args.project_id, todos))
+}
+
+pub async fn run_due(args: TodoDueArgs) -> AppResult<TodoReportOutput> {
+    let within = parse_within(&args.within)?;
+    let client = integration::session_client()?;
+    let today = time_zone::profile_today(&client).await?;
+    let until = today.add_days(within);
+    let report = client
+        .upcoming_schedule_report(&today.to_string(), &until.to_string())
+        .await?;
+
+    let todos = collect(
+        report.assignables.into_iter(),
+        today,
+        args.project_id,
+        |due_on| today <= due_on && due_on <= until,
+    );
+
+    Ok(output("due", today, Some(until), args.project_id, todos))
+}
+
+fn collect(
+    candidates: impl Iterator<Item = ReportTodo>,
+    today: Date,
+    scope_project_id: Option<u64>,
+    keep_due: impl Fn(Date) -> bool,
+) -> Vec<ReportedTodo> {
+    let mut todos: Vec<ReportedTodo> = candidates
+        .filter(|todo| todo.recording_type.eq_ignore_ascii_case("todo") && !todo.completed)
+        .filter_map(|todo| {
+            let due = todo.due_on.as_deref().and_then(Date::parse_iso)?;
+            let bucket = todo.bucket?;
+            if !keep_due(due) || scope_project_id.is_some_and(|id| id != bucket.id) {
+                return None;
+            }
+
+            Some(ReportedTodo {
+                todo_id: todo.id,
+                project_id: bucket.id,
+                project_name: bucket.name,
+                todolist_name: todo
+                    .parent
+                    .map(|parent| parent.title.trim().to_string())
+                    .filter(|title| !title.is_empty()),
+                content: todo.content.trim().to_string(),
+                due_on: due.to_string(),
+                days_until_due: due.days_since(today),
+                assignees: todo
+                    .assignees
+                    .into_iter()
+                    .map(|assignee| assignee.name)
+                    .collect(),
+                app_url: todo.app_url,
+            })
+        })
+        .collect();
+
+    todos.sort_by(|left, right| {
+        (&left.due_on, left.project_name.to_lowercase(), left.todo_id).cmp(&(
+            &right.due_on,
+            right.project_name.to_lowercase(),
+            right.todo_id,
+        ))
+    });
+    todos
+}
+
+fn output(
+    report: &str,
+    today: Date,
+    until: Option<Date>,
+    scope_project_id: Option<u64>,
+    todos: Vec<ReportedTodo>,
+) -> TodoReportOutput {
+    let count = todos.len();
+    TodoReportOutput {
+        ok: true,
+        report: report.to_string(),
+        today: today.to_string(),
+        until: until.map(|date| date.to_string()),
+        scope_project_id,
+        todos,
+        count,
+    }
+}
+
+// A bare number is days, as in `--within 7`; `3d` and `2w` also work.
+fn parse_within(value: &str) -> AppResult<i64> {
+    let trimmed = value.trim();
+    trimmed
+        .parse::<i64>()
+        .ok()
+        .filter(|days| (0..=36_500).contains(days))
+        .or_else(|| dates::parse_day_count(trimmed))
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "Invalid --within value \"{trimmed}\". Use a number of days like 7, or 3d or 2w."
+            ))
+        })
+}
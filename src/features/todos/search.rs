@@ -1,16 +1,23 @@
 use crate::basecamp::client::BasecampClient;
 use crate::basecamp::models::TodoSearchResult;
+use crate::cli::SearchSort;
 use crate::error::{AppError, AppResult};
-use crate::ui::prompt_error;
+use crate::rich_text;
+use crate::ui::{
+    MULTISELECT_HELP_MESSAGE, PROMPT_MARGIN, SELECT_HELP_MESSAGE, can_prompt, fit_width,
+    prompt_error,
+};
 use colored::Colorize;
 use inquire::validator::Validation;
 use inquire::{MultiSelect, Select, Text};
-use std::io::{self, IsTerminal};
+use std::cmp::Ordering;
+use std::collections::HashSet;
 
 const SEARCH_PER_PAGE: u32 = 50;
 const SEARCH_MAX_PAGES: u32 = 20;
-const SELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Enter to select";
-const MULTISELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Space to select one, Right to all, Left to none, Enter to confirm";
+const PROMPT_PAGE_SIZE: usize = 50;
+// Project names in to-do labels get what is left after this many columns.
+const PROJECT_NAME_RESERVED: usize = 48;
 
 #[derive(Debug, Clone, Copy)]
 pub(super) enum TodoCompletionFilter {
@@ -25,6 +32,19 @@ pub(super) struct TodoMatch {
     pub project_id: u64,
     pub project_name: String,
     pub content: String,
+    pub updated_at: Option<String>,
+    /// What the client-side filters need; `None` until fetched when the
+    /// search payload did not carry it.
+    pub details: Option<TodoDetails>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct TodoDetails {
+    pub due_on: Option<String>,
+    pub assignee_ids: Vec<u64>,
+    /// The list or group holding the to-do.
+    pub parent_id: u64,
+    pub parent_title: String,
 }
 
 impl TodoCompletionFilter {
@@ -38,7 +58,7 @@ impl TodoCompletionFilter {
 }
 
 pub(super) fn ensure_search_mode_terminal(command_name: &str) -> AppResult<()> {
-    if io::stdin().is_terminal() && io::stderr().is_terminal() {
+    if can_prompt() {
         return Ok(());
     }
 
@@ -74,41 +94,99 @@ pub(super) async fn search_todos(
     query: &str,
     scope_project_id: Option<u64>,
     completion_filter: TodoCompletionFilter,
+) -> AppResult<Vec<TodoMatch>> {
+    search_todos_sorted(
+        client,
+        query,
+        scope_project_id,
+        completion_filter,
+        SearchSort::Relevance,
+    )
+    .await
+}
+
+/// Pages can overlap, so matches are deduplicated by recording before they
+/// are ordered.
+pub(super) async fn search_todos_sorted(
+    client: &BasecampClient,
+    query: &str,
+    scope_project_id: Option<u64>,
+    completion_filter: TodoCompletionFilter,
+    sort: SearchSort,
 ) -> AppResult<Vec<TodoMatch>> {
     let recordings = client
         .search_todos(query, scope_project_id, SEARCH_PER_PAGE, SEARCH_MAX_PAGES)
         .await?;
 
-    Ok(recordings
+    let mut seen = HashSet::new();
+    let matches: Vec<TodoMatch> = recordings
         .into_iter()
         .filter_map(|recording| to_todo_match(recording, completion_filter))
-        .collect())
+        .filter(|todo| seen.insert((todo.project_id, todo.todo_id)))
+        .collect();
+
+    Ok(rank_matches(query, matches, sort))
 }
 
 pub(super) fn prompt_select_todos(matches: &[TodoMatch]) -> AppResult<Vec<usize>> {
-    let labels = todo_labels(matches);
-
-    MultiSelect::new("To-dos", labels)
-        .with_help_message(MULTISELECT_HELP_MESSAGE)
-        .with_formatter(&format_selected_count)
-        .raw_prompt()
-        .map(|selections| {
-            selections
-                .into_iter()
-                .map(|selection| selection.index)
-                .collect()
-        })
-        .map_err(|err| prompt_error("select to-dos", err))
+    let mut visible = PROMPT_PAGE_SIZE.min(matches.len());
+    let mut selected: Vec<usize> = Vec::new();
+
+    loop {
+        let mut labels = todo_labels(&matches[..visible]);
+        let has_more = visible < matches.len();
+        if has_more {
+            labels.push(show_more_label(matches.len() - visible));
+        }
+
+        let selections = MultiSelect::new("To-dos", labels)
+            .with_help_message(MULTISELECT_HELP_MESSAGE)
+            .with_formatter(&format_selected_count)
+            .with_default(&selected)
+            .raw_prompt()
+            .map_err(|err| prompt_error("select to-dos", err))?;
+
+        selected = selections
+            .into_iter()
+            .map(|selection| selection.index)
+            .collect();
+
+        if has_more && selected.contains(&visible) {
+            selected.retain(|index| *index != visible);
+            visible = (visible + PROMPT_PAGE_SIZE).min(matches.len());
+            continue;
+        }
+
+        return Ok(selected);
+    }
 }
 
 pub(super) fn prompt_select_todo(matches: &[TodoMatch]) -> AppResult<usize> {
-    let labels = todo_labels(matches);
-    Select::new("To-do", labels)
-        .with_help_message(SELECT_HELP_MESSAGE)
-        .with_starting_cursor(0)
-        .raw_prompt()
-        .map(|selection| selection.index)
-        .map_err(|err| prompt_error("select to-do", err))
+    let mut visible = PROMPT_PAGE_SIZE.min(matches.len());
+    let mut cursor = 0;
+
+    loop {
+        let mut labels = todo_labels(&matches[..visible]);
+        let has_more = visible < matches.len();
+        if has_more {
+            labels.push(show_more_label(matches.len() - visible));
+        }
+
+        let selection = Select::new("To-do", labels)
+            .with_help_message(SELECT_HELP_MESSAGE)
+            .with_starting_cursor(cursor)
+            .raw_prompt()
+            .map(|selection| selection.index)
+            .map_err(|err| prompt_error("select to-do", err))?;
+
+        if has_more && selection == visible {
+            cursor = visible;
+            visible = (visible + PROMPT_PAGE_SIZE).min(matches.len());
+            continue;
+        }
+
+        return Ok(selection);
+    }
 }
 
 pub(super) fn print_selected_todos(matches: &[TodoMatch], selections: &[usize]) -> AppResult<()> {
@@ -120,7 +198,8 @@ pub(super) fn print_selected_todos(matches: &[TodoMatch], selections: &[usize])
             "(id: {}, project: {} / {})",
             matched.todo_id, matched.project_name, matched.project_id
         );
-        println!("  - {} {}", matched.content, metadata.bright_black());
+        let title = fit_width(&matched.content, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
     }
 
     Ok(())
@@ -130,12 +209,139 @@ fn todo_labels(matches: &[TodoMatch]) -> Vec<String> {
     matches
         .iter()
         .map(|todo| {
-            let project_label = format!("{} / {}", todo.project_name, todo.project_id);
-            format!("{} - {} ({})", todo.content, project_label, todo.todo_id)
+            let project_name = fit_width(&todo.project_name, PROJECT_NAME_RESERVED);
+            let suffix = format!(
+                " - {} / {} ({})",
+                project_name, todo.project_id, todo.todo_id
+            );
+            let title = fit_width(&todo.content, suffix.chars().count() + PROMPT_MARGIN);
+            format!("{title}{suffix}")
         })
         .collect()
 }
 
+fn show_more_label(remaining: usize) -> String {
+    format!(
+        "Show more ({remaining} remaining, next {})",
+        remaining.min(PROMPT_PAGE_SIZE)
+    )
+}
+
+// `relevance`: most similar first, recency breaking ties. `recent`: most
+// recently updated first, similarity breaking ties.
+fn rank_matches(query: &str, matches: Vec<TodoMatch>, sort: SearchSort) -> Vec<TodoMatch> {
+    let mut scored: Vec<(u32, TodoMatch)> = matches
+        .into_iter()
+        .map(|todo| (similarity_score(query, &todo.content), todo))
+        .collect();
+
+    scored.sort_by(|(left_score, left), (right_score, right)| {
+        let by_score = right_score.cmp(left_score);
+        let by_recency = match (&left.updated_at, &right.updated_at) {
+            (Some(left_at), Some(right_at)) => right_at.cmp(left_at),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        match sort {
+            SearchSort::Relevance => by_score.then(by_recency),
+            SearchSort::Recent => by_recency.then(by_score),
+        }
+    });
+
+    scored.into_iter().map(|(_, todo)| todo).collect()
+}
+
+fn similarity_score(query: &str, content: &str) -> u32 {
+    let query = query.trim().to_lowercase();
+    let content = content.trim().to_lowercase();
+    if query.is_empty() {
+        return 0;
+    }
+
+    if content == query {
+        return 1000;
+    }
+    if content.starts_with(&query) {
+        return 900;
+    }
+    if content.contains(&query) {
+        return 800;
+    }
+
+    // Each query word scores 2 when a content word contains it and 1 when it
+    // only matches loosely, so a typo still ranks above no match at all.
+    let query_words: Vec<&str> = query.split_whitespace().collect();
+    let content_words: Vec<&str> = content.split_whitespace().collect();
+    let points: usize = query_words
+        .iter()
+        .map(|word| {
+            if content_words
+                .iter()
+                .any(|candidate| candidate.contains(*word))
+            {
+                2
+            } else if content_words
+                .iter()
+                .any(|candidate| fuzzy_word_match(word, candidate))
+            {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    (points * 350 / query_words.len().max(1)) as u32
+}
+
+// Words of four or more letters match loosely when one edit apart
+// ("deplyo" for "deploy") or when the query word is an abbreviation whose
+// letters appear in order, starting with the same letter ("dply").
+fn fuzzy_word_match(word: &str, candidate: &str) -> bool {
+    let word: Vec<char> = word.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    if word.len() < 4 {
+        return false;
+    }
+    within_one_edit(&word, &candidate)
+        || (word.first() == candidate.first() && is_subsequence(&word, &candidate))
+}
+
+fn is_subsequence(word: &[char], candidate: &[char]) -> bool {
+    let mut letters = candidate.iter();
+    word.iter()
+        .all(|letter| letters.any(|other| other == letter))
+}
+
+// One insertion, deletion, substitution, or swap of neighbours.
+fn within_one_edit(left: &[char], right: &[char]) -> bool {
+    let (shorter, longer) = if left.len() <= right.len() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+
+    let prefix = shorter
+        .iter()
+        .zip(longer)
+        .take_while(|(left, right)| left == right)
+        .count();
+    if shorter.len() == longer.len() {
+        let rest = prefix + 1;
+        return prefix == shorter.len()
+            || shorter[rest..] == longer[rest..]
+            || (rest < shorter.len()
+                && shorter[prefix] == longer[rest]
+                && shorter[rest] == longer[prefix]
+                && shorter[rest + 1..] == longer[rest + 1..]);
+    }
+    shorter[prefix..] == longer[prefix + 1..]
+}
+
 fn to_todo_match(
     recording: TodoSearchResult,
     completion_filter: TodoCompletionFilter,
@@ -150,6 +356,16 @@ fn to_todo_match(
     }
 
     let content = recording_content(&recording);
+    let updated_at = recording.updated_at;
+    let details = match (recording.assignees, recording.parent) {
+        (Some(assignees), Some(parent)) => Some(TodoDetails {
+            due_on: recording.due_on,
+            assignee_ids: assignees.iter().map(|assignee| assignee.id).collect(),
+            parent_id: parent.id,
+            parent_title: parent.title,
+        }),
+        _ => None,
+    };
     let bucket = recording.bucket?;
     let project_name =
         normalize_optional(Some(bucket.name)).unwrap_or_else(|| format!("Project {}", bucket.id));
@@ -159,12 +375,15 @@ fn to_todo_match(
         project_id: bucket.id,
         project_name,
         content,
+        updated_at,
+        details,
     })
 }
 
 fn recording_content(recording: &TodoSearchResult) -> String {
-    normalize_optional(recording.content.clone())
-        .or_else(|| normalize_optional(recording.title.clone()))
+    let plain = |text: &Option<String>| text.as_deref().map(rich_text::plain_title);
+    normalize_optional(plain(&recording.content))
+        .or_else(|| normalize_optional(plain(&recording.title)))
         .unwrap_or_else(|| format!("Todo {}", recording.id))
 }
 
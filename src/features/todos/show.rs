@@ -0,0 +1,151 @@
+use super::search::{
+    TodoCompletionFilter, ensure_search_mode_terminal, print_selected_todos, prompt_select_todo,
+    resolve_query, search_todos,
+};
+use super::target::TodoTarget;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::{Creator, Todo};
+use crate::cli::TodoShowArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::rich_text::html_to_text;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct TodoShowOutput {
+    pub ok: bool,
+    pub mode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    #[serde(flatten)]
+    pub todo: TodoDetail,
+}
+
+/// One to-do with who created and who completed it, for `todo show` and
+/// `todo list`.
+#[derive(Debug, Serialize)]
+pub struct TodoDetail {
+    pub todo_id: u64,
+    pub content: String,
+    /// Plain text with light markdown, converted from Basecamp rich text.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    pub completed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    pub assignees: Vec<String>,
+    /// Who is notified when the to-do is completed.
+    pub notify: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+pub async fn run(args: TodoShowArgs) -> AppResult<TodoShowOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    let target = TodoTarget::resolve(args.query, args.id, args.project_id, session.account_id)?;
+    let (mode, query, project_id, todo_id, project_name) =
+        if let Some((project_id, todo_id)) = target.direct {
+            ("direct", None, project_id, todo_id, None)
+        } else {
+            ensure_search_mode_terminal("show")?;
+            let query = resolve_query(target.query)?;
+            let matches = search_todos(
+                &client,
+                &query,
+                target.project_id,
+                TodoCompletionFilter::Any,
+            )
+            .await?;
+            if matches.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No to-dos matched \"{query}\"."
+                )));
+            }
+
+            let selection = prompt_select_todo(&matches)?;
+            print_selected_todos(&matches, &[selection])?;
+            let matched = matches
+                .into_iter()
+                .nth(selection)
+                .ok_or_else(|| AppError::invalid_input("To-do selection out of range."))?;
+            (
+                "search",
+                Some(query),
+                matched.project_id,
+                matched.todo_id,
+                Some(matched.project_name),
+            )
+        };
+
+    let todo = client.get_todo(project_id, todo_id).await?;
+
+    Ok(TodoShowOutput {
+        ok: true,
+        mode: mode.to_string(),
+        query,
+        project_id,
+        project_name,
+        todo: detail(todo),
+    })
+}
+
+pub(super) fn detail(todo: Todo) -> TodoDetail {
+    let notes = todo
+        .description
+        .as_deref()
+        .map(html_to_text)
+        .unwrap_or_default();
+    let (completed_at, completed_by) = match todo.completion {
+        Some(completion) => (completion.created_at, creator_name(completion.creator)),
+        None => (None, None),
+    };
+
+    TodoDetail {
+        todo_id: todo.id,
+        content: todo.content,
+        notes,
+        completed: todo.completed.unwrap_or(completed_at.is_some()),
+        completed_by,
+        completed_at,
+        starts_on: todo.starts_on,
+        due_on: todo.due_on,
+        assignees: todo
+            .assignees
+            .into_iter()
+            .map(|assignee| assignee.name)
+            .collect(),
+        notify: todo
+            .completion_subscribers
+            .into_iter()
+            .map(|person| person.name)
+            .collect(),
+        created_by: creator_name(todo.creator),
+        created_at: todo.created_at,
+        updated_at: todo.updated_at,
+        app_url: todo.app_url,
+    }
+}
+
+pub(super) fn creator_name(creator: Option<Creator>) -> Option<String> {
+    creator
+        .map(|creator| creator.name)
+        .filter(|name| !name.is_empty())
+}
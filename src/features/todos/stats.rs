@@ -0,0 +1,135 @@
+use super::export::fetch_all_todos;
+use super::picker::{resolve_project, resolve_todoset_id, todolist_display_name};
+use crate::basecamp::models::Todo;
+use crate::cli::TodoStatsArgs;
+use crate::error::AppResult;
+use crate::features::auth::integration;
+use crate::time_zone;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize)]
+pub struct TodoStatsOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    /// Overdue means open with a due date before this day, from `time_zone::profile_today`.
+    pub today: String,
+    pub totals: TodoCounts,
+    pub lists: Vec<ListStats>,
+    pub assignees: Vec<AssigneeStats>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct TodoCounts {
+    pub open: usize,
+    pub completed: usize,
+    pub overdue: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListStats {
+    pub todolist_id: u64,
+    pub name: String,
+    #[serde(flatten)]
+    pub counts: TodoCounts,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssigneeStats {
+    /// `None` for the unassigned bucket.
+    pub person_id: Option<u64>,
+    pub name: String,
+    #[serde(flatten)]
+    pub counts: TodoCounts,
+}
+
+impl TodoCounts {
+    fn add(&mut self, todo: &Todo, today: &str) {
+        if todo.completed.unwrap_or(false) {
+            self.completed += 1;
+            return;
+        }
+
+        self.open += 1;
+        // Both are `YYYY-MM-DD`, so string order is date order.
+        if todo.due_on.as_deref().is_some_and(|due_on| due_on < today) {
+            self.overdue += 1;
+        }
+    }
+}
+
+pub async fn run(args: TodoStatsArgs) -> AppResult<TodoStatsOutput> {
+    let client = integration::session_client()?;
+
+    let project = resolve_project(&client, &args.project).await?;
+    let todoset_id = resolve_todoset_id(&project)?;
+    let today = time_zone::profile_today(&client).await?.to_string();
+    let todolists = client.list_todolists(project.id, todoset_id).await?;
+
+    let mut totals = TodoCounts::default();
+    let mut lists = Vec::with_capacity(todolists.len());
+    // Keyed by person id; 0 is the unassigned bucket.
+    let mut assignees: HashMap<u64, AssigneeStats> = HashMap::new();
+    for todolist in &todolists {
+        let mut counts = TodoCounts::default();
+        // Group to-dos count toward the list that holds the group.
+        let mut todos = fetch_all_todos(&client, project.id, todolist.id).await?;
+        for group in client.list_todolist_groups(project.id, todolist.id).await? {
+            todos.extend(fetch_all_todos(&client, project.id, group.id).await?);
+        }
+
+        for todo in &todos {
+            counts.add(todo, &today);
+            totals.add(todo, &today);
+            if todo.assignees.is_empty() {
+                assignees
+                    .entry(0)
+                    .or_insert_with(|| AssigneeStats {
+                        person_id: None,
+                        name: "Unassigned".to_string(),
+                        counts: TodoCounts::default(),
+                    })
+                    .counts
+                    .add(todo, &today);
+            }
+            for assignee in &todo.assignees {
+                assignees
+                    .entry(assignee.id)
+                    .or_insert_with(|| AssigneeStats {
+                        person_id: Some(assignee.id),
+                        name: assignee.name.clone(),
+                        counts: TodoCounts::default(),
+                    })
+                    .counts
+                    .add(todo, &today);
+            }
+        }
+
+        lists.push(ListStats {
+            todolist_id: todolist.id,
+            name: todolist_display_name(todolist),
+            counts,
+        });
+    }
+
+    // Most open work first; the unassigned bucket goes last.
+    let mut assignees: Vec<AssigneeStats> = assignees.into_values().collect();
+    assignees.sort_by(|left, right| {
+        left.person_id
+            .is_none()
+            .cmp(&right.person_id.is_none())
+            .then(right.counts.open.cmp(&left.counts.open))
+            .then_with(|| left.name.to_lowercase().cmp(&right.name.to_lowercase()))
+    });
+
+    Ok(TodoStatsOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        today,
+        totals,
+        lists,
+        assignees,
+    })
+}
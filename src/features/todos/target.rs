@@ -0,0 +1,119 @@
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::links::BasecampLink;
+use crate::cli::IdOrUrl;
+use crate::error::{AppError, AppResult};
+use colored::Colorize;
+
+/// Basecamp ids are long; shorter numbers are more likely search text.
+const MIN_ID_DIGITS: usize = 7;
+/// Pages of recently updated to-dos checked for a bare id without a project.
+const RECENT_TODO_PAGES: u32 = 3;
+
+/// `--id`, `--project-id`, and the positional query after pasted Basecamp
+/// URLs are unpacked into plain ids.
+pub(super) struct TodoTarget {
+    /// `(project_id, todo_id)` when the to-do is known without searching.
+    pub(super) direct: Option<(u64, u64)>,
+    pub(super) project_id: Option<u64>,
+    pub(super) query: Option<String>,
+}
+
+impl TodoTarget {
+    pub(super) fn resolve(
+        query: Option<String>,
+        id: Option<IdOrUrl>,
+        project_id: Option<IdOrUrl>,
+        account_id: u64,
+    ) -> AppResult<Self> {
+        let project_id = match project_id {
+            Some(IdOrUrl::Id(project_id)) => Some(project_id),
+            Some(IdOrUrl::Url(link)) => Some(link.ensure_account(account_id)?.project_id),
+            None => None,
+        };
+
+        // A to-do URL in place of the search text skips the search.
+        let query_link = query.as_deref().and_then(BasecampLink::parse);
+        let id = match (id, query_link) {
+            (Some(id), _) => Some(id),
+            (None, Some(link)) => Some(IdOrUrl::Url(link)),
+            (None, None) => None,
+        };
+
+        let direct = match id {
+            Some(IdOrUrl::Id(todo_id)) => {
+                let project_id = project_id.ok_or_else(|| {
+                    AppError::invalid_input("`--project-id` is required when using `--id`.")
+                })?;
+                Some((project_id, todo_id))
+            }
+            Some(IdOrUrl::Url(link)) => {
+                let link = link.ensure_account(account_id)?;
+                let todo_id = link.todo_id().ok_or_else(|| {
+                    AppError::invalid_input(
+                        "URL is not a Basecamp to-do URL (expected .../buckets/<project>/todos/<id>).",
+                    )
+                })?;
+                if let Some(project_id) = project_id
+                    && project_id != link.project_id
+                {
+                    return Err(AppError::invalid_input(format!(
+                        "The to-do URL is in project {}, not `--project-id` {project_id}.",
+                        link.project_id
+                    )));
+                }
+                Some((link.project_id, todo_id))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            query: if direct.is_some() { None } else { query },
+            direct,
+            project_id: direct.map(|(project_id, _)| project_id).or(project_id),
+        })
+    }
+
+    /// A search text that is only a to-do id (7+ digits) becomes direct
+    /// mode when the to-do can be found: in the `--project-id` project, or
+    /// among recently updated to-dos of every project. Otherwise the number
+    /// is searched as text.
+    pub(super) async fn resolve_numeric_query(self, client: &BasecampClient) -> AppResult<Self> {
+        let Some(todo_id) = self
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|query| {
+                query.len() >= MIN_ID_DIGITS && query.bytes().all(|b| b.is_ascii_digit())
+            })
+            .and_then(|query| query.parse::<u64>().ok())
+        else {
+            return Ok(self);
+        };
+
+        let project_id = match self.project_id {
+            Some(project_id) => client
+                .get_todo(project_id, todo_id)
+                .await
+                .ok()
+                .map(|_| project_id),
+            None => client
+                .find_recent_todo_project(todo_id, RECENT_TODO_PAGES)
+                .await
+                .ok()
+                .flatten(),
+        };
+        let Some(project_id) = project_id else {
+            return Ok(self);
+        };
+
+        eprintln!(
+            "{}",
+            format!("Found to-do {todo_id} in project {project_id}.").bright_black()
+        );
+        Ok(Self {
+            direct: Some((project_id, todo_id)),
+            project_id: Some(project_id),
+            query: None,
+        })
+    }
+}
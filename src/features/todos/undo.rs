@@ -0,0 +1,143 @@
+use super::batch::FailedTodo;
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::dry_run;
+use crate::cli::TodoUndoArgs;
+use crate::dates;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::auth::models::{TodoBatch, TodoBatchAction, TodoBatchItem};
+use serde::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Serialize)]
+pub struct TodoUndoOutput {
+    pub ok: bool,
+    /// What the undone run did: `complete` or `re-open`.
+    pub undid: TodoBatchAction,
+    /// When the undone run happened (UTC).
+    pub recorded_at: String,
+    pub todos: Vec<UndoneTodo>,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<FailedTodo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UndoneTodo {
+    pub todo_id: u64,
+    pub project_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// Remembers the to-dos a `complete`/`re-open` run changed so `todo undo`
+/// can reverse them. Runs that changed nothing keep the previous record.
+pub(super) fn record_batch(
+    account_id: u64,
+    action: TodoBatchAction,
+    todos: Vec<TodoBatchItem>,
+) -> AppResult<()> {
+    if todos.is_empty() || dry_run::enabled() {
+        return Ok(());
+    }
+
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+    let _lock = integration::lock_state()?;
+    let mut state = integration::load_state()?;
+    state.last_batch = Some(TodoBatch {
+        action,
+        account_id,
+        recorded_at,
+        todos,
+    });
+    integration::save_state(&state)
+}
+
+pub async fn run(_args: TodoUndoArgs) -> AppResult<TodoUndoOutput> {
+    let session = integration::resolve_session_context()?;
+    let client = BasecampClient::new(session.account_id, session.access_token.clone())?
+        .with_cache(integration::response_cache()?);
+
+    // Take the record before any request so a second `undo` running
+    // alongside this one finds nothing instead of flipping the same to-dos.
+    let batch = {
+        let _lock = integration::lock_state()?;
+        let mut state = integration::load_state()?;
+        let batch = state.last_batch.take().ok_or_else(|| {
+            AppError::no_account(
+                "Nothing to undo. `todo complete` and `todo re-open` record their last run.",
+            )
+        })?;
+        if batch.account_id != session.account_id {
+            return Err(AppError::invalid_input(format!(
+                "The last run was in account {}, not the current account {}.",
+                batch.account_id, session.account_id
+            )));
+        }
+        // A dry run leaves the record for the real `undo`.
+        if !dry_run::enabled() {
+            integration::save_state(&state)?;
+        }
+        batch
+    };
+
+    let TodoBatch {
+        action,
+        recorded_at,
+        todos: recorded,
+        ..
+    } = batch;
+
+    let mut todos = Vec::with_capacity(recorded.len());
+    let mut failed = Vec::new();
+    let mut remaining = Vec::new();
+    for (index, item) in recorded.into_iter().enumerate() {
+        let result = match action {
+            TodoBatchAction::Complete => client.re_open_todo(item.project_id, item.todo_id).await,
+            TodoBatchAction::ReOpen => client.complete_todo(item.project_id, item.todo_id).await,
+        };
+        match result {
+            Ok(()) => todos.push(UndoneTodo {
+                todo_id: item.todo_id,
+                project_id: item.project_id,
+                content: item.content,
+            }),
+            Err(err) => {
+                failed.push(FailedTodo {
+                    line: index + 1,
+                    reference: format!("{}:{}", item.project_id, item.todo_id),
+                    error: err.message,
+                });
+                remaining.push(item);
+            }
+        }
+    }
+
+    // Failed to-dos stay recorded so running `undo` again retries them.
+    if !remaining.is_empty() {
+        let _lock = integration::lock_state()?;
+        let mut state = integration::load_state()?;
+        if state.last_batch.is_none() {
+            state.last_batch = Some(TodoBatch {
+                action,
+                account_id: session.account_id,
+                recorded_at,
+                todos: remaining,
+            });
+            integration::save_state(&state)?;
+        }
+    }
+
+    let count = todos.len();
+    Ok(TodoUndoOutput {
+        ok: failed.is_empty(),
+        undid: action,
+        recorded_at: dates::format_unix_utc(recorded_at),
+        todos,
+        count,
+        failed,
+    })
+}
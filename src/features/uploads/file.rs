@@ -0,0 +1,197 @@
+//! `upload`: Basecamp's two-step file flow. The bytes go to
+//! `attachments.json` first, and the sgid it returns becomes an upload in
+//! Docs & Files. The same sgid embeds the file in rich text as a
+//! `<bc-attachment>`.
+
+use crate::basecamp::client::BasecampClient;
+use crate::basecamp::models::CreateUploadPayload;
+use crate::cli::UploadArgs;
+use crate::error::{AppError, AppResult};
+use crate::features::auth::integration;
+use crate::features::documents::vault;
+use crate::features::projects::lookup;
+use crate::rich_text::markdown_to_html;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize)]
+pub struct UploadOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub folder_id: u64,
+    pub folder_path: String,
+    pub upload_id: u64,
+    pub file_name: String,
+    pub content_type: String,
+    pub byte_size: u64,
+    pub attachable_sgid: String,
+    /// Ready to paste into rich text.
+    pub embed: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+/// A file sent to `attachments.json`, ready to be used by its sgid.
+pub(crate) struct AttachedFile {
+    pub file_name: String,
+    pub content_type: String,
+    pub byte_size: u64,
+    pub sgid: String,
+}
+
+impl AttachedFile {
+    /// The rich-text tag that shows the file inline.
+    pub(crate) fn embed(&self) -> String {
+        format!("<bc-attachment sgid=\"{}\"></bc-attachment>", self.sgid)
+    }
+}
+
+pub async fn run(args: UploadArgs) -> AppResult<UploadOutput> {
+    ensure_file(&args.file)?;
+    let client = integration::session_client()?;
+    let project = lookup::resolve_project(&client, &args.project).await?;
+    let folder = vault::resolve_folder(&client, &project, args.vault.as_deref()).await?;
+
+    let file = attach_file(&client, &args.file).await?;
+    let base_name = args
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    let description = args
+        .description
+        .filter(|description| !description.trim().is_empty())
+        .map(|description| markdown_to_html(&description));
+    let upload = client
+        .create_upload(
+            project.id,
+            folder.id,
+            &CreateUploadPayload {
+                attachable_sgid: file.sgid.clone(),
+                description,
+                base_name,
+            },
+        )
+        .await?;
+
+    let embed = file.embed();
+    Ok(UploadOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        folder_id: folder.id,
+        folder_path: folder.path,
+        upload_id: upload.id,
+        file_name: if upload.filename.is_empty() {
+            file.file_name
+        } else {
+            upload.filename
+        },
+        content_type: file.content_type,
+        byte_size: file.byte_size,
+        attachable_sgid: file.sgid,
+        embed,
+        app_url: upload.app_url,
+    })
+}
+
+/// Fails early, before any project lookup, on a path that is not a file.
+pub(crate) fn ensure_file(path: &Path) -> AppResult<()> {
+    if path.is_file() {
+        Ok(())
+    } else {
+        Err(AppError::invalid_input(format!(
+            "{} is not a file.",
+            path.display()
+        )))
+    }
+}
+
+/// Sends each `--attach` file, in order. Call [`ensure_file`] on them
+/// first, so a typo fails before anything is created.
+pub(crate) async fn attach_files(
+    client: &BasecampClient,
+    paths: &[PathBuf],
+) -> AppResult<Vec<AttachedFile>> {
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        files.push(attach_file(client, path).await?);
+    }
+    Ok(files)
+}
+
+/// Rich text with the files embedded after it; `None` stays `None` when
+/// there are no files.
+pub(crate) fn append_embeds(html: Option<String>, files: &[AttachedFile]) -> Option<String> {
+    if files.is_empty() {
+        return html;
+    }
+    let embeds: String = files.iter().map(AttachedFile::embed).collect();
+    Some(format!("{}{embeds}", html.unwrap_or_default()))
+}
+
+/// Reads `path` and sends it to `attachments.json`.
+pub(crate) async fn attach_file(client: &BasecampClient, path: &Path) -> AppResult<AttachedFile> {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| AppError::invalid_input(format!("{} is not a file name.", path.display())))?
+        .to_string();
+    let bytes = std::fs::read(path).map_err(|err| {
+        AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+    })?;
+    if bytes.is_empty() {
+        return Err(AppError::invalid_input(format!(
+            "{} is empty; Basecamp does not accept empty files.",
+            path.display()
+        )));
+    }
+
+    let content_type = content_type(&file_name).to_string();
+    let byte_size = bytes.len() as u64;
+    let attachment = client
+        .create_attachment(&file_name, &content_type, bytes)
+        .await?;
+    Ok(AttachedFile {
+        file_name,
+        content_type,
+        byte_size,
+        sgid: attachment.attachable_sgid,
+    })
+}
+
+// Basecamp previews images and PDFs by type; anything unknown is still
+// stored, just as a plain download.
+fn content_type(file_name: &str) -> &'static str {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "heic" => "image/heic",
+        "pdf" => "application/pdf",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        _ => "application/octet-stream",
+    }
+}
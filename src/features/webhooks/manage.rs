@@ -0,0 +1,270 @@
+//! `webhook list/create/delete`: a project's webhooks, for provisioning
+//! integrations from scripts. `create` is safe to re-run: a webhook already
+//! posting the same types to the same URL is reported instead of duplicated.
+
+use crate::basecamp::models::{CreateWebhookPayload, Webhook};
+use crate::cli::{WebhookCreateArgs, WebhookDeleteArgs, WebhookListArgs};
+use crate::error::{AppError, AppResult};
+use crate::features::projects::lookup;
+use crate::ui::{can_prompt, prompt_error};
+use inquire::Confirm;
+use serde::Serialize;
+
+/// Every recording type Basecamp can send, spelled as it expects them.
+const WEBHOOK_TYPES: [&str; 18] = [
+    "Comment",
+    "Client::Approval::Response",
+    "Client::Forward",
+    "Client::Reply",
+    "CloudFile",
+    "Document",
+    "GoogleDocument",
+    "Inbox::Forward",
+    "Kanban::Card",
+    "Kanban::Step",
+    "Message",
+    "Question",
+    "Question::Answer",
+    "Schedule::Entry",
+    "Todo",
+    "Todolist",
+    "Upload",
+    "Vault",
+];
+
+#[derive(Debug, Serialize)]
+pub struct WebhookListOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub webhooks: Vec<WebhookSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookCreateOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    /// `false` when an identical webhook already existed.
+    pub created: bool,
+    #[serde(flatten)]
+    pub webhook: WebhookSummary,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookDeleteOutput {
+    pub ok: bool,
+    pub project_id: u64,
+    pub project_name: String,
+    pub deleted: Vec<WebhookSummary>,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookSummary {
+    pub webhook_id: u64,
+    pub payload_url: String,
+    pub types: Vec<String>,
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_url: Option<String>,
+}
+
+impl From<Webhook> for WebhookSummary {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            webhook_id: webhook.id,
+            payload_url: webhook.payload_url,
+            types: webhook.types,
+            active: webhook.active,
+            created_at: webhook.created_at,
+            app_url: webhook.app_url,
+        }
+    }
+}
+
+pub async fn run_list(args: WebhookListArgs) -> AppResult<WebhookListOutput> {
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let webhooks: Vec<WebhookSummary> = client
+        .list_webhooks(project.id)
+        .await?
+        .into_iter()
+        .map(WebhookSummary::from)
+        .collect();
+    let count = webhooks.len();
+
+    Ok(WebhookListOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        webhooks,
+        count,
+    })
+}
+
+pub async fn run_create(args: WebhookCreateArgs) -> AppResult<WebhookCreateOutput> {
+    let payload_url = args.payload_url.trim().to_string();
+    ensure_https(&payload_url)?;
+    let mut types: Vec<String> = Vec::new();
+    for value in &args.types {
+        let canonical = canonical_type(value)?;
+        if !types.iter().any(|seen| seen == canonical) {
+            types.push(canonical.to_string());
+        }
+    }
+
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    if let Some(existing) = client
+        .list_webhooks(project.id)
+        .await?
+        .into_iter()
+        .find(|webhook| webhook.payload_url == payload_url)
+    {
+        if !same_types(&existing.types, &types) {
+            return Err(AppError::invalid_input(format!(
+                "Webhook {} already posts to {payload_url} with types {}; delete it first to change them.",
+                existing.id,
+                existing.types.join(",")
+            )));
+        }
+        return Ok(WebhookCreateOutput {
+            ok: true,
+            project_id: project.id,
+            project_name: project.name,
+            created: false,
+            webhook: WebhookSummary::from(existing),
+        });
+    }
+
+    let webhook = client
+        .create_webhook(project.id, &CreateWebhookPayload { payload_url, types })
+        .await?;
+
+    Ok(WebhookCreateOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        created: true,
+        webhook: WebhookSummary::from(webhook),
+    })
+}
+
+pub async fn run_delete(args: WebhookDeleteArgs) -> AppResult<WebhookDeleteOutput> {
+    if !args.force && !can_prompt() {
+        return Err(AppError::invalid_input(
+            "`basecamp-cli webhook delete` asks for confirmation in an interactive terminal; pass --force to skip it.",
+        ));
+    }
+
+    let (client, project) = lookup::resolve_session_project(&args.project).await?;
+    let webhooks = client.list_webhooks(project.id).await?;
+    let targets: Vec<Webhook> = match args.payload_url.as_deref().map(str::trim) {
+        Some(payload_url) => {
+            let targets: Vec<Webhook> = webhooks
+                .into_iter()
+                .filter(|webhook| webhook.payload_url == payload_url)
+                .collect();
+            if targets.is_empty() {
+                return Err(AppError::no_account(format!(
+                    "No webhook in \"{}\" posts to {payload_url}.",
+                    project.name
+                )));
+            }
+            targets
+        }
+        None => {
+            if let Some(missing) = args
+                .webhooks
+                .iter()
+                .find(|id| !webhooks.iter().any(|webhook| webhook.id == **id))
+            {
+                return Err(AppError::no_account(format!(
+                    "Webhook {missing} is not on project \"{}\".",
+                    project.name
+                )));
+            }
+            webhooks
+                .into_iter()
+                .filter(|webhook| args.webhooks.contains(&webhook.id))
+                .collect()
+        }
+    };
+
+    if !args.force {
+        for webhook in &targets {
+            eprintln!("  - {} (id: {})", webhook.payload_url, webhook.id);
+        }
+        let question = if targets.len() == 1 {
+            "Delete this webhook?".to_string()
+        } else {
+            format!("Delete these {} webhooks?", targets.len())
+        };
+        let confirmed = Confirm::new(&question)
+            .with_default(false)
+            .prompt()
+            .map_err(|err| prompt_error("confirm webhook delete", err))?;
+        if !confirmed {
+            return Err(AppError::invalid_input("Webhook delete cancelled."));
+        }
+    }
+
+    let mut deleted = Vec::with_capacity(targets.len());
+    for webhook in targets {
+        client.delete_webhook(project.id, webhook.id).await?;
+        deleted.push(WebhookSummary::from(webhook));
+    }
+    let count = deleted.len();
+
+    Ok(WebhookDeleteOutput {
+        ok: true,
+        project_id: project.id,
+        project_name: project.name,
+        deleted,
+        count,
+    })
+}
+
+// Basecamp refuses plain-HTTP payload URLs; saying so up front beats a 422.
+fn ensure_https(payload_url: &str) -> AppResult<()> {
+    let host = payload_url
+        .get(..8)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("https://"))
+        .map(|_| &payload_url[8..])
+        .unwrap_or_default();
+    if host.is_empty() || host.starts_with('/') {
+        return Err(AppError::invalid_input(format!(
+            "--payload-url \"{payload_url}\" must be an https:// URL."
+        )));
+    }
+    Ok(())
+}
+
+fn canonical_type(value: &str) -> AppResult<&'static str> {
+    let value = value.trim();
+    WEBHOOK_TYPES
+        .iter()
+        .copied()
+        .find(|known| known.eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            AppError::invalid_input(format!(
+                "Unknown webhook type \"{value}\". Use: {}.",
+                WEBHOOK_TYPES.join(", ")
+            ))
+        })
+}
+
+// No types asked for means every type, which is what Basecamp stores then.
+fn same_types(existing: &[String], requested: &[String]) -> bool {
+    let wanted: Vec<&str> = if requested.is_empty() {
+        WEBHOOK_TYPES.to_vec()
+    } else {
+        requested.iter().map(String::as_str).collect()
+    };
+    existing.len() == wanted.len()
+        && wanted
+            .iter()
+            .all(|wanted| existing.iter().any(|existing| existing == wanted))
+}
@@ -0,0 +1,147 @@
+//! Writes iCalendar (RFC 5545) files: one `VCALENDAR` of `VEVENT`s, CRLF
+//! line ends, text escaped, and lines folded at 75 octets. Only what calendar
+//! apps need to show Basecamp dates is covered.
+
+use crate::dates::Date;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MAX_LINE_OCTETS: usize = 75;
+
+/// When an event starts or ends.
+#[derive(Debug, Clone, Copy)]
+pub enum EventTime {
+    /// A whole day; an all-day event ends on the day after its last.
+    Day(Date),
+    /// Unix seconds, written in UTC.
+    Instant(i64),
+}
+
+#[derive(Debug)]
+pub struct Event {
+    /// Stable across exports, so re-importing updates instead of duplicating.
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub url: Option<String>,
+    pub start: EventTime,
+    pub end: EventTime,
+}
+
+/// The whole calendar document.
+pub fn render(name: &str, events: &[Event]) -> String {
+    let stamp = utc_stamp(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64),
+    );
+
+    let mut document = String::new();
+    push_line(&mut document, "BEGIN:VCALENDAR");
+    push_line(&mut document, "VERSION:2.0");
+    push_line(&mut document, "PRODID:-//basecamp-cli//EN");
+    push_line(&mut document, "CALSCALE:GREGORIAN");
+    push_line(&mut document, "METHOD:PUBLISH");
+    push_line(&mut document, &format!("X-WR-CALNAME:{}", escape(name)));
+    for event in events {
+        push_line(&mut document, "BEGIN:VEVENT");
+        push_line(&mut document, &format!("UID:{}", escape(&event.uid)));
+        push_line(&mut document, &format!("DTSTAMP:{stamp}"));
+        push_line(&mut document, &time_property("DTSTART", event.start));
+        push_line(&mut document, &time_property("DTEND", event.end));
+        push_line(
+            &mut document,
+            &format!("SUMMARY:{}", escape(&event.summary)),
+        );
+        if !event.description.trim().is_empty() {
+            push_line(
+                &mut document,
+                &format!("DESCRIPTION:{}", escape(event.description.trim())),
+            );
+        }
+        if let Some(url) = event.url.as_deref() {
+            push_line(&mut document, &format!("URL:{url}"));
+        }
+        push_line(&mut document, "END:VEVENT");
+    }
+    push_line(&mut document, "END:VCALENDAR");
+    document
+}
+
+/// Unix seconds for a Basecamp ISO 8601 timestamp with its offset
+/// (`2026-10-20T14:00:00.000-05:00`, `...Z`).
+pub fn parse_timestamp(value: &str) -> Option<i64> {
+    let date = Date::parse_iso(value)?;
+    let clock = value.get(11..19)?;
+    let mut parts = clock.split(':').map(|part| part.parse::<i64>().ok());
+    let (hours, minutes, seconds) = (parts.next()??, parts.next()??, parts.next()??);
+
+    let zone = value[19..].trim_start_matches(|ch: char| ch == '.' || ch.is_ascii_digit());
+    let offset = match zone {
+        "Z" | "" => 0,
+        _ => {
+            let sign = match zone.get(..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            let zone_hours = zone.get(1..3)?.parse::<i64>().ok()?;
+            let zone_minutes = zone.get(4..6)?.parse::<i64>().ok()?;
+            sign * (zone_hours * 3600 + zone_minutes * 60)
+        }
+    };
+
+    Some(date.unix_days() * 86_400 + hours * 3600 + minutes * 60 + seconds - offset)
+}
+
+fn time_property(name: &str, time: EventTime) -> String {
+    match time {
+        EventTime::Day(date) => format!("{name};VALUE=DATE:{}", compact_date(date)),
+        EventTime::Instant(seconds) => format!("{name}:{}", utc_stamp(seconds)),
+    }
+}
+
+fn compact_date(date: Date) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+fn utc_stamp(seconds: i64) -> String {
+    let date = Date::from_unix(seconds, 0);
+    let of_day = seconds.rem_euclid(86_400);
+    format!(
+        "{}T{:02}{:02}{:02}Z",
+        compact_date(date),
+        of_day / 3600,
+        of_day % 3600 / 60,
+        of_day % 60
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+// Continuation lines start with a space, which counts toward their 75
+// octets; multi-byte characters are never split.
+fn push_line(document: &mut String, line: &str) {
+    let mut octets = 0;
+    for ch in line.chars() {
+        if octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            document.push_str("\r\n ");
+            octets = 1;
+        }
+        document.push(ch);
+        octets += ch.len_utf8();
+    }
+    document.push_str("\r\n");
+}
@@ -1,161 +1,3591 @@
 mod basecamp;
 mod cli;
+mod csv;
+mod dates;
 mod error;
 mod features;
+mod ical;
+mod redact;
+mod rich_text;
+mod telemetry;
+mod time_zone;
 mod ui;
 
 use clap::Parser;
 use colored::Colorize;
 use inquire::validator::Validation;
 use inquire::{Password, Text};
-use std::io::{self, IsTerminal};
+use serde::Deserialize;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::Instant;
 
+use crate::basecamp::dry_run;
+use crate::basecamp::reauth;
 use crate::cli::{
-    Cli, Command, IntegrationArgs, IntegrationClearArgs, IntegrationCommand, IntegrationSetArgs,
-    LoginArgs, LogoutArgs, TodoAddArgs, TodoArgs, TodoCommand, TodoCompleteArgs, TodoEditArgs,
-    TodoReOpenArgs, WhoamiArgs,
+    AliasArgs, AliasCommand, AliasListArgs, AliasRemoveArgs, AliasSetArgs, AuthArgs, AuthCommand,
+    AuthInspectArgs, CampfireArgs, CampfireCommand, CampfireListArgs, CampfireSendArgs, CardArgs,
+    CardCommand, CardStepAddArgs, CardStepCommand, CardStepCompleteArgs, CheckinAnswerArgs,
+    CheckinAnswersArgs, CheckinArgs, CheckinCommand, CheckinListArgs, Cli, Command, CommentAddArgs,
+    CommentArgs, CommentCommand, CommentListArgs, DaemonArgs, DaemonCommand, DaemonStatusArgs,
+    DocArgs, DocCommand, DocCreateArgs, DocEditArgs, DocListArgs, DocShowArgs, IntegrationArgs,
+    IntegrationClearArgs, IntegrationCommand, IntegrationExportArgs, IntegrationImportArgs,
+    IntegrationSetArgs, ListArchiveArgs, ListArgs, ListCommand, ListCreateArgs, ListGroupAddArgs,
+    ListGroupCommand, ListGroupListArgs, ListListArgs, ListRenameArgs, ListTemplateApplyArgs,
+    ListTemplateCommand, ListTemplateListArgs, ListTemplateSaveArgs, LoginArgs, LogoutArgs,
+    MessageArgs, MessageCommand, MessageListArgs, MessagePostArgs, MessageShowArgs, PeopleArgs,
+    PeopleCommand, PeopleListArgs, PeopleShowArgs, ProjectArgs, ProjectCommand, ProjectCreateArgs,
+    ProjectLifecycleArgs, ProjectPeopleChangeArgs, ProjectPeopleCommand, ProjectPeopleListArgs,
+    ProjectPinArgs, ProjectPinsArgs, ProjectShowArgs, ProjectUnpinArgs, ProjectUpdateArgs,
+    ScheduleAddArgs, ScheduleArgs, ScheduleCommand, ScheduleExportArgs, ScheduleListArgs,
+    SecretsArgs, SecretsCommand, SecretsWhereArgs, SessionArgs, SessionCommand, SessionShowArgs,
+    StatsArgs, TemplateArgs, TemplateCommand, TemplateDeleteArgs, TemplateListArgs,
+    TemplateSaveArgs, TodoAddArgs, TodoArgs, TodoAssignArgs, TodoCommand, TodoCommentArgs,
+    TodoCommentsArgs, TodoCompleteArgs, TodoDeleteArgs, TodoDueArgs, TodoEditArgs, TodoExportArgs,
+    TodoHistoryArgs, TodoImportArgs, TodoListArgs, TodoMineArgs, TodoOverdueArgs, TodoPositionArgs,
+    TodoReOpenArgs, TodoRecurAddArgs, TodoRecurCommand, TodoRecurListArgs, TodoRecurRemoveArgs,
+    TodoRecurRunArgs, TodoSearchArgs, TodoShowArgs, TodoStatsArgs, TodoUndoArgs, UploadArgs,
+    WebhookArgs, WebhookCommand, WebhookCreateArgs, WebhookDeleteArgs, WebhookListArgs, WhoamiArgs,
 };
 use crate::error::{AppError, AppResult};
-use crate::features::auth::{integration, login, logout, whoami};
+use crate::features::aliases::manage as alias_manage;
+use crate::features::auth::models::TodoBatchAction;
+use crate::features::auth::{
+    account_override, inspect, integration, login, logout, session_recovery, transfer, whoami,
+};
+use crate::features::campfire::{chat as campfire_chat, tail as campfire_tail};
+use crate::features::cards::steps as card_steps;
+use crate::features::checkins::questions as checkin_questions;
+use crate::features::comments::recording as recording_comments;
+use crate::features::daemon::{run as daemon_run, status as daemon_status};
+use crate::features::documents::vault as doc_vault;
+use crate::features::messages::board as message_board;
+use crate::features::people::directory as people_directory;
+use crate::features::projects::lifecycle::{self as project_lifecycle, ProjectLifecycle};
+use crate::features::projects::manage as project_manage;
+use crate::features::projects::people as project_people;
+use crate::features::projects::pin as project_pin;
+use crate::features::projects::show as project_show;
+use crate::features::schedule::entries as schedule_entries;
+use crate::features::stats::summary as stats_summary;
+use crate::features::templates::{list as list_template, todo as todo_template};
+use crate::features::todolists::{groups as todolist_groups, manage as todolist_manage};
 use crate::features::todos::{
-    add as todo_add, complete as todo_complete, edit as todo_edit, re_open as todo_re_open,
+    add as todo_add, assign as todo_assign, bulk as todo_bulk, comment as todo_comment,
+    comments as todo_comments, complete as todo_complete, delete as todo_delete, edit as todo_edit,
+    export as todo_export, find as todo_find, history as todo_history, import as todo_import,
+    list as todo_list, mine as todo_mine, position as todo_position, quick as todo_quick,
+    re_open as todo_re_open, recur as todo_recur, report as todo_report, show as todo_show,
+    stats as todo_stats, undo as todo_undo,
+};
+use crate::features::uploads::file as upload_file;
+use crate::features::webhooks::manage as webhook_manage;
+use crate::ui::{
+    can_prompt, configure_prompt_rendering, fit_width, open_browser, print_receipt, prompt_error,
+    set_output_width,
 };
-use crate::ui::{configure_prompt_rendering, prompt_error};
 
 const DEFAULT_REDIRECT_URI: &str = "http://127.0.0.1:45455/callback";
 
-#[tokio::main]
-async fn main() {
-    let exit_code = match run().await {
-        Ok(()) => 0,
-        Err(err) => {
-            eprintln!("{}", format!("Error: {}", err.message).red());
-            err.code
+#[tokio::main]
+async fn main() {
+    configure_prompt_rendering();
+    let cli = Cli::parse();
+    set_output_width(if cli.full_width {
+        None
+    } else {
+        integration::output_max_width()
+    });
+    if cli.dry_run {
+        dry_run::enable();
+    }
+    reauth::set_recovery(session_recovery::recover_token);
+    let command = command_name(&cli.command);
+    let started = Instant::now();
+
+    let exit_code = match run(cli).await {
+        Ok(()) if dry_run::enabled() => {
+            eprintln!("{}", "Dry run: nothing was sent to Basecamp.".yellow());
+            0
+        }
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("{}", format!("Error: {}", err.message).red());
+            err.code
+        }
+    };
+
+    telemetry::emit(command, started.elapsed(), exit_code);
+    std::process::exit(exit_code);
+}
+
+// Stable names for telemetry; arguments are never recorded.
+fn command_name(command: &Command) -> &'static str {
+    match command {
+        Command::Integration(args) => match args.command {
+            IntegrationCommand::Set(_) => "integration set",
+            IntegrationCommand::Show => "integration show",
+            IntegrationCommand::Clear(_) => "integration clear",
+            IntegrationCommand::Export(_) => "integration export",
+            IntegrationCommand::Import(_) => "integration import",
+        },
+        Command::Login(_) => "login",
+        Command::Logout(_) => "logout",
+        Command::Auth(args) => match args.command {
+            AuthCommand::Inspect(_) => "auth inspect",
+        },
+        Command::Session(args) => match args.command {
+            SessionCommand::Show(_) => "session show",
+        },
+        Command::Secrets(args) => match args.command {
+            SecretsCommand::Where(_) => "secrets where",
+        },
+        Command::Whoami(_) => "whoami",
+        Command::Todo(args) => match &args.command {
+            TodoCommand::Add(_) => "todo add",
+            TodoCommand::Quick(_) => "todo quick",
+            TodoCommand::Edit(_) => "todo edit",
+            TodoCommand::Assign(_) => "todo assign",
+            TodoCommand::Position(_) => "todo position",
+            TodoCommand::Complete(_) => "todo complete",
+            TodoCommand::ReOpen(_) => "todo re-open",
+            TodoCommand::Undo(_) => "todo undo",
+            TodoCommand::Recur(args) => match &args.command {
+                TodoRecurCommand::Add(_) => "todo recur add",
+                TodoRecurCommand::List(_) => "todo recur list",
+                TodoRecurCommand::Remove(_) => "todo recur remove",
+                TodoRecurCommand::Run(_) => "todo recur run",
+            },
+            TodoCommand::Export(_) => "todo export",
+            TodoCommand::Import(_) => "todo import",
+            TodoCommand::Stats(_) => "todo stats",
+            TodoCommand::Delete(_) => "todo delete",
+            TodoCommand::Comment(_) => "todo comment",
+            TodoCommand::Comments(_) => "todo comments",
+            TodoCommand::Show(_) => "todo show",
+            TodoCommand::List(_) => "todo list",
+            TodoCommand::History(_) => "todo history",
+            TodoCommand::Search(_) => "todo search",
+            TodoCommand::Mine(_) => "todo mine",
+            TodoCommand::Overdue(_) => "todo overdue",
+            TodoCommand::Due(_) => "todo due",
+        },
+        Command::Project(args) => match &args.command {
+            ProjectCommand::Pin(_) => "project pin",
+            ProjectCommand::Unpin(_) => "project unpin",
+            ProjectCommand::Pins(_) => "project pins",
+            ProjectCommand::Show(_) => "project show",
+            ProjectCommand::Create(_) => "project create",
+            ProjectCommand::Update(_) => "project update",
+            ProjectCommand::Archive(_) => "project archive",
+            ProjectCommand::Trash(_) => "project trash",
+            ProjectCommand::Restore(_) => "project restore",
+            ProjectCommand::People(args) => match &args.command {
+                ProjectPeopleCommand::List(_) => "project people list",
+                ProjectPeopleCommand::Add(_) => "project people add",
+                ProjectPeopleCommand::Remove(_) => "project people remove",
+            },
+        },
+        Command::Alias(args) => match args.command {
+            AliasCommand::Set(_) => "alias set",
+            AliasCommand::List(_) => "alias list",
+            AliasCommand::Remove(_) => "alias remove",
+        },
+        Command::Stats(_) => "stats",
+        Command::Template(args) => match args.command {
+            TemplateCommand::Save(_) => "template save",
+            TemplateCommand::List(_) => "template list",
+            TemplateCommand::Delete(_) => "template delete",
+        },
+        Command::List(args) => match &args.command {
+            ListCommand::List(_) => "list list",
+            ListCommand::Create(_) => "list create",
+            ListCommand::Rename(_) => "list rename",
+            ListCommand::Archive(_) => "list archive",
+            ListCommand::Group(args) => match &args.command {
+                ListGroupCommand::List(_) => "list group list",
+                ListGroupCommand::Add(_) => "list group add",
+            },
+            ListCommand::Template(args) => match &args.command {
+                ListTemplateCommand::Save(_) => "list template save",
+                ListTemplateCommand::Apply(_) => "list template apply",
+                ListTemplateCommand::List(_) => "list template list",
+            },
+        },
+        Command::Daemon(args) => match args.command {
+            None => "daemon",
+            Some(DaemonCommand::Status(_)) => "daemon status",
+        },
+        Command::Message(args) => match args.command {
+            MessageCommand::List(_) => "message list",
+            MessageCommand::Show(_) => "message show",
+            MessageCommand::Post(_) => "message post",
+        },
+        Command::Comment(args) => match args.command {
+            CommentCommand::List(_) => "comment list",
+            CommentCommand::Add(_) => "comment add",
+        },
+        Command::Campfire(args) => match args.command {
+            CampfireCommand::List(_) => "campfire list",
+            CampfireCommand::Send(_) => "campfire send",
+            CampfireCommand::Tail(_) => "campfire tail",
+        },
+        Command::Doc(args) => match args.command {
+            DocCommand::List(_) => "doc list",
+            DocCommand::Show(_) => "doc show",
+            DocCommand::Create(_) => "doc create",
+            DocCommand::Edit(_) => "doc edit",
+        },
+        Command::Upload(_) => "upload",
+        Command::Schedule(args) => match args.command {
+            ScheduleCommand::List(_) => "schedule list",
+            ScheduleCommand::Add(_) => "schedule add",
+            ScheduleCommand::Export(_) => "schedule export",
+        },
+        Command::Checkin(args) => match args.command {
+            CheckinCommand::List(_) => "checkin list",
+            CheckinCommand::Answers(_) => "checkin answers",
+            CheckinCommand::Answer(_) => "checkin answer",
+        },
+        Command::Card(args) => match args.command {
+            CardCommand::Step(CardStepCommand::Add(_)) => "card step add",
+            CardCommand::Step(CardStepCommand::Complete(_)) => "card step complete",
+        },
+        Command::People(args) => match args.command {
+            PeopleCommand::List(_) => "people list",
+            PeopleCommand::Show(_) => "people show",
+        },
+        Command::Webhook(args) => match args.command {
+            WebhookCommand::List(_) => "webhook list",
+            WebhookCommand::Create(_) => "webhook create",
+            WebhookCommand::Delete(_) => "webhook delete",
+        },
+    }
+}
+
+async fn run(cli: Cli) -> AppResult<()> {
+    let verbose = cli.verbose;
+    let receipt = cli.verbose_result;
+
+    match cli.command {
+        Command::Integration(args) => handle_integration(args, verbose),
+        Command::Login(args) => handle_login(args).await,
+        Command::Logout(args) => handle_logout(args, verbose),
+        Command::Auth(args) => handle_auth(args, verbose).await,
+        Command::Session(args) => handle_session(args, verbose),
+        Command::Secrets(args) => handle_secrets(args),
+        Command::Whoami(args) => handle_whoami(args, verbose).await,
+        Command::Todo(args) => handle_todo(args, verbose, receipt).await,
+        Command::Project(args) => handle_project(args, verbose).await,
+        Command::Alias(args) => handle_alias(args, verbose).await,
+        Command::Stats(args) => handle_stats(args, verbose).await,
+        Command::Template(args) => handle_template(args),
+        Command::List(args) => handle_list(args, verbose).await,
+        Command::Daemon(args) => handle_daemon(args, verbose).await,
+        Command::Message(args) => handle_message(args, verbose).await,
+        Command::Comment(args) => handle_comment(args, verbose).await,
+        Command::Campfire(args) => handle_campfire(args, verbose).await,
+        Command::Doc(args) => handle_doc(args, verbose).await,
+        Command::Upload(args) => handle_upload(args, verbose).await,
+        Command::Schedule(args) => handle_schedule(args, verbose).await,
+        Command::Checkin(args) => handle_checkin(args, verbose).await,
+        Command::Card(args) => handle_card(args, verbose, receipt).await,
+        Command::People(args) => handle_people(args, verbose).await,
+        Command::Webhook(args) => handle_webhook(args, verbose).await,
+    }
+}
+
+fn handle_integration(args: IntegrationArgs, verbose: bool) -> AppResult<()> {
+    match args.command {
+        IntegrationCommand::Set(args) => handle_integration_set(args),
+        IntegrationCommand::Show => handle_integration_show(verbose),
+        IntegrationCommand::Clear(args) => handle_integration_clear(args, verbose),
+        IntegrationCommand::Export(args) => handle_integration_export(args, verbose),
+        IntegrationCommand::Import(args) => handle_integration_import(args),
+    }
+}
+
+fn handle_integration_set(args: IntegrationSetArgs) -> AppResult<()> {
+    let values = resolve_integration_set_values(args)?;
+    integration::set_integration(values.client_id, values.client_secret, values.redirect_uri)?;
+    println!("{}", "Integration credentials saved.".green());
+    integration::print_secret_store_location()?;
+    Ok(())
+}
+
+fn handle_integration_show(verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let status = integration::show_integration()?;
+
+    println!(
+        "client_id: {}",
+        if status.has_client_id {
+            "configured"
+        } else {
+            "missing"
+        }
+    );
+    println!(
+        "client_secret: {}",
+        if status.has_client_secret {
+            "configured"
+        } else {
+            "missing"
+        }
+    );
+    println!(
+        "redirect_uri: {}",
+        if status.has_redirect_uri {
+            "configured"
+        } else {
+            "missing"
+        }
+    );
+
+    if let Some(client_id) = status.client_id {
+        println!("client_id (redacted): {client_id}");
+    }
+    if let Some(redirect_uri) = status.redirect_uri {
+        println!("redirect_uri value: {redirect_uri}");
+    }
+
+    Ok(())
+}
+
+fn handle_integration_clear(args: IntegrationClearArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    if !args.force && !confirm("Clear integration credentials and local session? [y/N]")? {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    integration::clear_integration_and_session()?;
+    println!("{}", "Integration credentials and session cleared.".green());
+    Ok(())
+}
+
+fn handle_integration_export(args: IntegrationExportArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let output = transfer::export(&args.path, args.encrypt, !args.no_session, args.force)?;
+
+    let contents = if output.includes_session {
+        "Integration credentials and session"
+    } else {
+        "Integration credentials"
+    };
+    println!(
+        "{} {}",
+        format!("{contents} exported to").green(),
+        output.path
+    );
+    if !output.encrypted {
+        eprintln!(
+            "{}",
+            "Export is not encrypted and contains secrets. Keep it private or re-run with --encrypt."
+                .yellow()
+        );
+    }
+
+    Ok(())
+}
+
+fn handle_integration_import(args: IntegrationImportArgs) -> AppResult<()> {
+    let existing = integration::integration_defaults()?;
+    if existing.client_id.is_some()
+        && !args.force
+        && !confirm("Replace existing integration credentials? [y/N]")?
+    {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    let output = transfer::import(&args.path)?;
+
+    match (output.account_name.as_deref(), output.account_id) {
+        (Some(account_name), Some(account_id)) => println!(
+            "{} for account \"{}\" ({}).",
+            "Integration credentials and session imported".green(),
+            account_name,
+            account_id
+        ),
+        _ => println!("{}", "Integration credentials imported.".green()),
+    }
+    integration::print_secret_store_location()?;
+
+    Ok(())
+}
+
+async fn handle_login(args: LoginArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = login::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+    } else {
+        println!(
+            "Logged in to Basecamp account \"{}\" ({}).",
+            output.account_name, output.account_id
+        );
+        for check in &output.checks {
+            if check.ok {
+                println!(
+                    "  {} {}",
+                    format!("{} ok:", check.name).green(),
+                    check.message.bright_black()
+                );
+            } else {
+                println!(
+                    "  {} {}",
+                    format!("{} check failed:", check.name).yellow(),
+                    check.message
+                );
+            }
+        }
+    }
+
+    integration::print_secret_store_location()?;
+    Ok(())
+}
+
+fn handle_logout(args: LogoutArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = logout::run(args)?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+    } else {
+        println!("{}", "Logged out from local Basecamp session.".green());
+    }
+
+    Ok(())
+}
+
+async fn handle_auth(args: AuthArgs, verbose: bool) -> AppResult<()> {
+    match args.command {
+        AuthCommand::Inspect(args) => handle_auth_inspect(args, verbose).await,
+    }
+}
+
+async fn handle_auth_inspect(args: AuthInspectArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let output = inspect::run().await?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "Token expires at: {}",
+        output.expires_at.as_deref().unwrap_or("unknown")
+    );
+
+    match output.identity.as_ref() {
+        Some(identity) => {
+            let name = identity.name.as_deref().unwrap_or("(no name)");
+            let email = identity
+                .email_address
+                .as_deref()
+                .map(|value| format!(" <{value}>"))
+                .unwrap_or_default();
+            println!(
+                "Identity: {}{} {}",
+                name,
+                email,
+                format!("(identity {})", identity.id).bright_black()
+            );
+        }
+        None => println!("Identity: unknown"),
+    }
+
+    println!("Accounts ({}):", output.accounts.len());
+    for account in &output.accounts {
+        let marker = if account.selected { "*" } else { "-" };
+        println!(
+            "  {} {} {}",
+            marker,
+            account.name,
+            format!("(id: {}, product: {})", account.id, account.product).bright_black()
+        );
+    }
+
+    if !output.accounts.iter().any(|account| account.selected) {
+        eprintln!(
+            "{}",
+            format!(
+                "Selected account {} is not reachable with the current token.",
+                output.selected_account_id
+            )
+            .yellow()
+        );
+    }
+
+    Ok(())
+}
+
+fn handle_session(args: SessionArgs, verbose: bool) -> AppResult<()> {
+    match args.command {
+        SessionCommand::Show(args) => handle_session_show(args, verbose),
+    }
+}
+
+fn handle_session_show(args: SessionShowArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let output = integration::show_session()?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let unknown = || "unknown".to_string();
+    let account_id = output.account_id.map_or_else(unknown, |id| id.to_string());
+    println!(
+        "Account: {} {}",
+        output.account_name.clone().unwrap_or_else(unknown),
+        format!("(id: {account_id})").bright_black()
+    );
+    if let Some(account_href) = output.account_href.as_deref() {
+        println!("Account URL: {account_href}");
+    }
+    if output.source == "environment" {
+        println!("Source: BASECAMP_ACCESS_TOKEN (environment)");
+    } else {
+        println!("Source: login");
+        println!(
+            "Updated: {}",
+            output.updated_at.clone().unwrap_or_else(unknown)
+        );
+    }
+    println!(
+        "Access token: {}",
+        output
+            .access_token_fingerprint
+            .clone()
+            .unwrap_or_else(|| "missing".to_string())
+    );
+    if output.source != "environment" {
+        println!(
+            "Refresh token: {}",
+            output
+                .refresh_token_fingerprint
+                .clone()
+                .unwrap_or_else(|| "missing".to_string())
+        );
+    }
+
+    Ok(())
+}
+
+fn handle_secrets(args: SecretsArgs) -> AppResult<()> {
+    match args.command {
+        SecretsCommand::Where(args) => handle_secrets_where(args),
+    }
+}
+
+fn handle_secrets_where(args: SecretsWhereArgs) -> AppResult<()> {
+    let output = integration::secrets_where()?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let presence = |exists: bool| if exists { "present" } else { "missing" };
+    println!("Profile: {}", output.profile);
+    println!("Config dir: {}", output.config_dir);
+    println!(
+        "Secrets file: {} {}",
+        output.secrets_file,
+        format!("({})", presence(output.secrets_file_exists)).bright_black()
+    );
+
+    match output.keyring.as_ref() {
+        Some(keyring) => {
+            println!("Keychain service: {}", keyring.service);
+            println!(
+                "Keychain account: {} {}",
+                keyring.account,
+                format!("({})", presence(keyring.exists)).bright_black()
+            );
+            if let Some(stale_account) = keyring.stale_account.as_deref() {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "Stale keychain entry from an older version: service={} account={stale_account}. It is no longer read; delete it once the secrets file opens.",
+                        keyring.service
+                    )
+                    .yellow()
+                );
+            }
+        }
+        None => println!(
+            "Keychain: not used {}",
+            format!("(mode: {})", output.mode).bright_black()
+        ),
+    }
+
+    Ok(())
+}
+
+async fn handle_whoami(args: WhoamiArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let output = whoami::run(&args).await?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let email = output
+        .email_address
+        .as_deref()
+        .map(|value| format!(" <{value}>"))
+        .unwrap_or_default();
+
+    if let Some(account_name) = output.account_name.as_deref() {
+        println!(
+            "Current user: {}{} (person {}) on account \"{}\" ({}).",
+            output.name, email, output.id, account_name, output.account_id
+        );
+    } else {
+        println!(
+            "Current user: {}{} (person {}) on account {}.",
+            output.name, email, output.id, output.account_id
+        );
+    }
+
+    if let Some(accounts) = output.accounts.as_deref() {
+        println!("Accounts ({}):", accounts.len());
+        for account in accounts {
+            let metadata = format!(
+                "(id: {}, product: {}, href: {})",
+                account.id, account.product, account.href
+            );
+            if account.active {
+                println!(
+                    "  * {} {} {}",
+                    account.name.green(),
+                    metadata.bright_black(),
+                    "active".green()
+                );
+            } else {
+                println!("  - {} {}", account.name, metadata.bright_black());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_todo(args: TodoArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    account_override::apply(args.account_id).await?;
+    match args.command {
+        TodoCommand::Add(args) => handle_todo_add(*args, verbose, receipt).await,
+        TodoCommand::Quick(args) => {
+            handle_todo_add(todo_quick::add_args(args).await?, verbose, receipt).await
+        }
+        TodoCommand::Edit(args) => handle_todo_edit(args, verbose, receipt).await,
+        TodoCommand::Assign(args) => handle_todo_assign(args, verbose, receipt).await,
+        TodoCommand::Position(args) => handle_todo_position(args, verbose).await,
+        TodoCommand::Complete(args) => handle_todo_complete(args, verbose, receipt).await,
+        TodoCommand::ReOpen(args) => handle_todo_re_open(args, verbose, receipt).await,
+        TodoCommand::Undo(args) => handle_todo_undo(args, verbose, receipt).await,
+        TodoCommand::Recur(args) => match args.command {
+            TodoRecurCommand::Add(args) => handle_todo_recur_add(args, verbose).await,
+            TodoRecurCommand::List(args) => handle_todo_recur_list(args).await,
+            TodoRecurCommand::Remove(args) => handle_todo_recur_remove(args),
+            TodoRecurCommand::Run(args) => handle_todo_recur_run(args, verbose).await,
+        },
+        TodoCommand::Export(args) => handle_todo_export(args, verbose).await,
+        TodoCommand::Import(args) => handle_todo_import(args, verbose).await,
+        TodoCommand::Stats(args) => handle_todo_stats(args, verbose).await,
+        TodoCommand::Delete(args) => handle_todo_delete(args, verbose, receipt).await,
+        TodoCommand::Comment(args) => handle_todo_comment(args, verbose, receipt).await,
+        TodoCommand::Comments(args) => handle_todo_comments(args, verbose).await,
+        TodoCommand::Show(args) => handle_todo_show(args, verbose).await,
+        TodoCommand::List(args) => handle_todo_list(args, verbose).await,
+        TodoCommand::History(args) => handle_todo_history(args, verbose).await,
+        TodoCommand::Search(args) => handle_todo_search(args, verbose).await,
+        TodoCommand::Mine(args) => handle_todo_mine(args, verbose).await,
+        TodoCommand::Overdue(args) => handle_todo_overdue(args, verbose).await,
+        TodoCommand::Due(args) => handle_todo_due(args, verbose).await,
+    }
+}
+
+async fn handle_todo_add(args: TodoAddArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    if args.stdin || args.from_file.is_some() {
+        return handle_todo_bulk_add(args).await;
+    }
+    let json_output = args.json;
+    let open = args.open;
+    let output = todo_add::run(args).await?;
+    if open {
+        open_todo_in_browser(&[output.app_url.as_str()]);
+    }
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if receipt {
+        print_receipt(
+            "Created todo",
+            &[
+                ("To-do", output.content.clone()),
+                ("Project", output.project_name.clone()),
+                ("List", output.todolist_name.clone()),
+                ("Assignees", output.assignees.join(", ")),
+                ("Starts", output.starts_on.clone().unwrap_or_default()),
+                ("Due", output.due_on.clone().unwrap_or_default()),
+                ("Id", output.todo_id.to_string()),
+                ("URL", output.app_url.clone()),
+            ],
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in project \"{}\" / list \"{}\" {}.",
+        "Created todo".green(),
+        output.content,
+        output.project_name,
+        output.todolist_name,
+        format!("(id: {})", output.todo_id).bright_black()
+    );
+
+    Ok(())
+}
+
+// `--open`: opens the to-do when exactly one was affected. Failures only
+// warn, since the write itself already succeeded.
+fn open_todo_in_browser(urls: &[&str]) {
+    if dry_run::enabled() {
+        return;
+    }
+    match urls {
+        [url] => {
+            if let Err(err) = open_browser(url) {
+                eprintln!(
+                    "{}",
+                    format!("Could not open the browser ({err}); open {url} manually.").yellow()
+                );
+            }
+        }
+        [] => {}
+        _ => eprintln!(
+            "{}",
+            format!(
+                "Not opening {} to-dos in the browser; --open only opens a single to-do.",
+                urls.len()
+            )
+            .yellow()
+        ),
+    }
+}
+
+async fn handle_todo_position(args: TodoPositionArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_position::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" to position {} in \"{}\" {}.",
+        "Moved todo".green(),
+        output.content,
+        output.position,
+        output.todolist_name,
+        format!("(id: {})", output.todo_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_bulk_add(args: TodoAddArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = todo_bulk::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), "create");
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    println!(
+        "{} {} {} in project \"{}\" / list \"{}\":",
+        "Created".green(),
+        output.count,
+        todo_label,
+        output.project_name,
+        output.todolist_name
+    );
+    for item in &output.created {
+        let metadata = format!("(id: {}, line {})", item.todo_id, item.line);
+        let title = fit_width(&item.content, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.reference,
+            format!("(line {}: {})", item.line, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), "create")
+}
+
+async fn handle_todo_complete(
+    args: TodoCompleteArgs,
+    verbose: bool,
+    receipt: bool,
+) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let open = args.open;
+    let output = todo_complete::run(args).await?;
+    if open {
+        let urls: Vec<&str> = output
+            .completed
+            .iter()
+            .map(|item| item.app_url.as_str())
+            .collect();
+        open_todo_in_browser(&urls);
+    }
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), "complete");
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    if receipt {
+        let rows = output
+            .completed
+            .iter()
+            .map(|item| {
+                let title = item
+                    .content
+                    .clone()
+                    .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+                let project = item
+                    .project_name
+                    .clone()
+                    .unwrap_or_else(|| item.project_id.to_string());
+                (
+                    "To-do",
+                    format!("{title} (id: {}, project: {project})", item.todo_id),
+                )
+            })
+            .collect::<Vec<_>>();
+        print_receipt(&format!("Completed {} {todo_label}", output.count), &rows);
+    } else {
+        println!("{} {} {}:", "Completed".green(), output.count, todo_label);
+        for item in &output.completed {
+            let title = item
+                .content
+                .clone()
+                .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+            let metadata = match item.project_name.as_deref() {
+                Some(project_name) => {
+                    format!(
+                        "(id: {}, project: {} / {})",
+                        item.todo_id, project_name, item.project_id
+                    )
+                }
+                None => format!("(id: {}, project: {})", item.todo_id, item.project_id),
+            };
+            let title = fit_width(&title, metadata.chars().count() + 5);
+            println!("  - {} {}", title, metadata.bright_black());
+        }
+    }
+
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.reference,
+            format!("(line {}: {})", item.line, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), "complete")
+}
+
+async fn handle_todo_undo(args: TodoUndoArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_undo::run(args).await?;
+
+    // Undoing a completion re-opens, and the other way around.
+    let (verb, action) = match output.undid {
+        TodoBatchAction::Complete => ("Re-opened", "re-open"),
+        TodoBatchAction::ReOpen => ("Completed", "complete"),
+    };
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), action);
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    if receipt {
+        let rows = output
+            .todos
+            .iter()
+            .map(|item| {
+                let title = item
+                    .content
+                    .clone()
+                    .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+                (
+                    "To-do",
+                    format!(
+                        "{title} (id: {}, project: {})",
+                        item.todo_id, item.project_id
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        print_receipt(&format!("{verb} {} {todo_label}", output.count), &rows);
+    } else {
+        println!(
+            "{} {} {} {}:",
+            verb.green(),
+            output.count,
+            todo_label,
+            format!("(undo of {})", output.recorded_at).bright_black()
+        );
+        for item in &output.todos {
+            let title = item
+                .content
+                .clone()
+                .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+            let metadata = format!("(id: {}, project: {})", item.todo_id, item.project_id);
+            let title = fit_width(&title, metadata.chars().count() + 5);
+            println!("  - {} {}", title, metadata.bright_black());
+        }
+    }
+
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.reference,
+            format!("(line {}: {})", item.line, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), action)
+}
+
+async fn handle_todo_recur_add(args: TodoRecurAddArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_recur::run_add(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let schedule = &output.schedule;
+    let next = schedule
+        .next_due_on
+        .as_deref()
+        .map(|date| format!(", next: {date}"))
+        .unwrap_or_default();
+    println!(
+        "{} \"{}\" every {} in project \"{}\" / list \"{}\" {}.",
+        "Added recurring todo".green(),
+        schedule.content,
+        schedule.every,
+        schedule.project_name,
+        schedule.todolist_name,
+        format!("(id: {}{next})", schedule.id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_recur_list(args: TodoRecurListArgs) -> AppResult<()> {
+    let output = todo_recur::run_list().await?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.schedules.is_empty() {
+        println!("No recurring todos.");
+        return Ok(());
+    }
+
+    for schedule in &output.schedules {
+        let next = schedule
+            .next_due_on
+            .as_deref()
+            .map(|date| format!(", next: {date}"))
+            .unwrap_or_default();
+        let metadata = format!(
+            "(id: {}, every {}, {} / {}{next})",
+            schedule.id, schedule.every, schedule.project_name, schedule.todolist_name
+        );
+        let title = fit_width(&schedule.content, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+fn handle_todo_recur_remove(args: TodoRecurRemoveArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = todo_recur::run_remove(args)?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" {}.",
+        "Removed recurring todo".green(),
+        output.content,
+        format!("(id: {})", output.id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_recur_run(args: TodoRecurRunArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_recur::run_due(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), "create");
+    }
+
+    if output.count == 0 && output.failed.is_empty() {
+        println!("No recurring todos are due.");
+        return Ok(());
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    println!(
+        "{} {} recurring {}:",
+        "Created".green(),
+        output.count,
+        todo_label
+    );
+    for item in &output.created {
+        let metadata = format!(
+            "(id: {}, due: {}, project: {})",
+            item.todo_id, item.due_on, item.project_name
+        );
+        let title = fit_width(&item.content, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.content,
+            format!("(schedule {}: {})", item.schedule_id, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), "create")
+}
+
+async fn handle_todo_export(args: TodoExportArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let export = todo_export::run(args).await?;
+
+    let Some(path) = export.path else {
+        print!("{}", export.document);
+        return Ok(());
+    };
+
+    let todo_label = if export.output.count == 1 {
+        "todo"
+    } else {
+        "todos"
+    };
+    let source = match &export.output.todolist_name {
+        Some(todolist_name) => format!("{} / {todolist_name}", export.output.project_name),
+        None => export.output.project_name.clone(),
+    };
+    println!(
+        "{} {} {todo_label} from {source} to {path} {}.",
+        "Exported".green(),
+        export.output.count,
+        format!("(id: {})", export.output.project_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_import(args: TodoImportArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_import::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), "import");
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    let action = if output.dry_run {
+        "Would import"
+    } else {
+        "Imported"
+    };
+    println!(
+        "{} {} {todo_label} into {} {}:",
+        action.green(),
+        output.count,
+        output.project_name,
+        format!("(id: {}, format: {})", output.project_id, output.format).bright_black()
+    );
+    for list in &output.lists {
+        let metadata = match (list.todolist_id, list.created) {
+            (Some(todolist_id), true) => format!("(id: {todolist_id}, new list)"),
+            (Some(todolist_id), false) => format!("(id: {todolist_id})"),
+            (None, _) => "(new list)".to_string(),
+        };
+        println!("  {} {}", list.name.bold(), metadata.bright_black());
+        for todo in &list.todos {
+            let mut details = Vec::new();
+            if let Some(todo_id) = todo.todo_id {
+                details.push(format!("id: {todo_id}"));
+            }
+            if let Some(starts_on) = todo.starts_on.as_deref() {
+                details.push(format!("starts: {starts_on}"));
+            }
+            if let Some(due_on) = todo.due_on.as_deref() {
+                details.push(format!("due: {due_on}"));
+            }
+            if todo.completed {
+                details.push("completed".to_string());
+            }
+            let metadata = if details.is_empty() {
+                String::new()
+            } else {
+                format!("({})", details.join(", "))
+            };
+            let title = fit_width(&todo.content, metadata.chars().count() + 7);
+            println!("    - {} {}", title, metadata.bright_black());
+        }
+    }
+
+    for warning in &output.warnings {
+        eprintln!(
+            "{}",
+            format!("Line {}: {}", warning.line, warning.message).yellow()
+        );
+    }
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.reference,
+            format!("(line {}: {})", item.line, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), "import")
+}
+
+async fn handle_todo_stats(args: TodoStatsArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_stats::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {}, as of {})", output.project_id, output.today).bright_black()
+    );
+    let mut lists: Vec<(String, todo_stats::TodoCounts)> = output
+        .lists
+        .iter()
+        .map(|list| (list.name.clone(), list.counts))
+        .collect();
+    lists.push(("Total".to_string(), output.totals));
+    print_stats_table("List", &lists);
+
+    if !output.assignees.is_empty() {
+        println!();
+        let assignees: Vec<(String, todo_stats::TodoCounts)> = output
+            .assignees
+            .iter()
+            .map(|assignee| (assignee.name.clone(), assignee.counts))
+            .collect();
+        print_stats_table("Assignee", &assignees);
+    }
+
+    Ok(())
+}
+
+// Names are fitted to the output width; counts are right-aligned and a
+// non-zero overdue count is red.
+fn print_stats_table(heading: &str, rows: &[(String, todo_stats::TodoCounts)]) {
+    const COUNTS_WIDTH: usize = 2 + 6 + 2 + 6 + 2 + 7;
+    let names: Vec<String> = rows
+        .iter()
+        .map(|(name, _)| fit_width(name, COUNTS_WIDTH + 4))
+        .collect();
+    let name_width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .chain([heading.chars().count()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "  {}",
+        format!(
+            "{heading:<name_width$}  {:>6}  {:>6}  {:>7}",
+            "Open", "Done", "Overdue"
+        )
+        .bright_black()
+    );
+    for (name, (_, counts)) in names.iter().zip(rows) {
+        let overdue = format!("{:>7}", counts.overdue);
+        let overdue = if counts.overdue > 0 {
+            overdue.red().to_string()
+        } else {
+            overdue
+        };
+        println!(
+            "  {name:<name_width$}  {:>6}  {:>6}  {overdue}",
+            counts.open, counts.completed
+        );
+    }
+}
+
+async fn handle_todo_search(args: TodoSearchArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let id_only = args.id_only;
+    let output = todo_find::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    // One `project_id todo_id` pair per line; `todo complete --from-file -` reads it back.
+    if id_only {
+        for item in &output.matches {
+            println!("{} {}", item.project_id, item.todo_id);
+        }
+        return Ok(());
+    }
+
+    if output.matches.is_empty() {
+        println!("No to-dos matched \"{}\".", output.query);
+        return Ok(());
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    println!("{} {} {}:", "Found".green(), output.count, todo_label);
+    for item in &output.matches {
+        let metadata = format!(
+            "(id: {}, project: {} / {})",
+            item.todo_id, item.project_name, item.project_id
+        );
+        let title = fit_width(&item.content, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_mine(args: TodoMineArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_mine::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.count == 0 {
+        println!("No open to-dos are assigned to you.");
+        return Ok(());
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    println!("{} {} {}:", "Assigned".green(), output.count, todo_label);
+    for project in &output.projects {
+        println!(
+            "{} {}",
+            project.project_name.bold(),
+            format!("(id: {})", project.project_id).bright_black()
+        );
+        for todo in &project.todos {
+            let mut details = Vec::new();
+            if todo.priority {
+                details.push("priority".to_string());
+            }
+            if let Some(due_on) = todo.due_on.as_deref() {
+                details.push(format!("due: {due_on}"));
+            }
+            if let Some(todolist_name) = todo.todolist_name.as_deref() {
+                details.push(format!("list: {todolist_name}"));
+            }
+            details.push(format!("id: {}", todo.todo_id));
+            let metadata = format!("({})", details.join(", "));
+            let title = fit_width(&todo.content, metadata.chars().count() + 5);
+            println!("  - {} {}", title, metadata.bright_black());
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_overdue(args: TodoOverdueArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_report::run_overdue(args).await?;
+    print_todo_report(&output, json_output)
+}
+
+async fn handle_todo_due(args: TodoDueArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_report::run_due(args).await?;
+    print_todo_report(&output, json_output)
+}
+
+fn print_todo_report(output: &todo_report::TodoReportOutput, json_output: bool) -> AppResult<()> {
+    if json_output {
+        let rendered = serde_json::to_string_pretty(output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let heading = match output.until.as_deref() {
+        Some(until) => format!("Due from {} to {until}", output.today),
+        None => format!("Overdue as of {}", output.today),
+    };
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    println!(
+        "{} {}",
+        heading.green(),
+        format!("({} {todo_label})", output.count).bright_black()
+    );
+    if output.todos.is_empty() {
+        return Ok(());
+    }
+
+    let rows: Vec<[String; 4]> = output
+        .todos
+        .iter()
+        .map(|todo| {
+            let when = match todo.days_until_due {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                days if days < 0 => format!("{}d late", -days),
+                days => format!("in {days}d"),
+            };
+            let assignees = if todo.assignees.is_empty() {
+                "-".to_string()
+            } else {
+                todo.assignees.join(", ")
+            };
+            [
+                todo.due_on.clone(),
+                when,
+                truncate_column(&todo.project_name),
+                truncate_column(&assignees),
+            ]
+        })
+        .collect();
+    let headers = ["Due", "When", "Project", "Assignees"];
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([headers[column].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let reserved = widths.iter().map(|width| width + 2).sum::<usize>();
+
+    let header_line = headers
+        .iter()
+        .zip(&widths)
+        .map(|(header, width)| format!("{header:<width$}  "))
+        .collect::<String>();
+    println!("{}", format!("{header_line}To-do").bright_black());
+    for (row, todo) in rows.iter().zip(&output.todos) {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}  "))
+            .collect::<String>();
+        let cells = if todo.days_until_due < 0 {
+            cells.red().to_string()
+        } else {
+            cells
+        };
+        let metadata = format!("(id: {})", todo.todo_id);
+        let title = fit_width(&todo.content, reserved + metadata.chars().count() + 1);
+        println!("{cells}{title} {}", metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+// Report columns are capped so the to-do title keeps its room.
+fn truncate_column(text: &str) -> String {
+    const REPORT_COLUMN_MAX: usize = 24;
+    if text.chars().count() <= REPORT_COLUMN_MAX {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(REPORT_COLUMN_MAX - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+async fn handle_todo_edit(args: TodoEditArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let open = args.open;
+    let output = todo_edit::run(args).await?;
+    if open {
+        open_todo_in_browser(&[output.app_url.as_str()]);
+    }
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let project_label = output
+        .project_name
+        .as_deref()
+        .map(|project_name| format!("project \"{project_name}\""))
+        .unwrap_or_else(|| format!("project {}", output.project_id));
+
+    if receipt {
+        print_receipt(
+            "Edited todo",
+            &[
+                ("To-do", output.content.clone()),
+                (
+                    "Project",
+                    output
+                        .project_name
+                        .clone()
+                        .unwrap_or_else(|| output.project_id.to_string()),
+                ),
+                ("Assignees", output.assignees.join(", ")),
+                ("Notify", output.notify.join(", ")),
+                ("Starts", output.starts_on.clone().unwrap_or_default()),
+                ("Due", output.due_on.clone().unwrap_or_default()),
+                ("Id", output.todo_id.to_string()),
+                ("URL", output.app_url.clone()),
+            ],
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in {} {}.",
+        "Edited todo".green(),
+        output.content,
+        project_label,
+        format!("(id: {})", output.todo_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_assign(args: TodoAssignArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_assign::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if receipt {
+        print_receipt(
+            "Assigned todo",
+            &[
+                ("To-do", output.content.clone()),
+                (
+                    "Project",
+                    output
+                        .project_name
+                        .clone()
+                        .unwrap_or_else(|| output.project_id.to_string()),
+                ),
+                ("Assignees", output.assignees.join(", ")),
+                ("Id", output.todo_id.to_string()),
+                ("URL", output.app_url.clone().unwrap_or_default()),
+            ],
+        );
+        return Ok(());
+    }
+
+    if output.added == 0 {
+        println!(
+            "\"{}\" is already assigned to {} {}.",
+            output.content,
+            output.assignees.join(", "),
+            format!("(id: {})", output.todo_id).bright_black()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" to {} {}.",
+        "Assigned todo".green(),
+        output.content,
+        output.assignees.join(", "),
+        format!("(id: {})", output.todo_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_re_open(args: TodoReOpenArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_re_open::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return batch_failures_result(output.failed.len(), "re-open");
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    if receipt {
+        let rows = output
+            .reopened
+            .iter()
+            .map(|item| {
+                let title = item
+                    .content
+                    .clone()
+                    .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+                let project = item
+                    .project_name
+                    .clone()
+                    .unwrap_or_else(|| item.project_id.to_string());
+                (
+                    "To-do",
+                    format!("{title} (id: {}, project: {project})", item.todo_id),
+                )
+            })
+            .collect::<Vec<_>>();
+        print_receipt(&format!("Re-opened {} {todo_label}", output.count), &rows);
+    } else {
+        println!("{} {} {}:", "Re-opened".green(), output.count, todo_label);
+        for item in &output.reopened {
+            let title = item
+                .content
+                .clone()
+                .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+            let metadata = match item.project_name.as_deref() {
+                Some(project_name) => {
+                    format!(
+                        "(id: {}, project: {} / {})",
+                        item.todo_id, project_name, item.project_id
+                    )
+                }
+                None => format!("(id: {}, project: {})", item.todo_id, item.project_id),
+            };
+            let title = fit_width(&title, metadata.chars().count() + 5);
+            println!("  - {} {}", title, metadata.bright_black());
+        }
+    }
+
+    for item in &output.failed {
+        eprintln!(
+            "  {} {} {}",
+            "Failed".red(),
+            item.reference,
+            format!("(line {}: {})", item.line, item.error).bright_black()
+        );
+    }
+
+    batch_failures_result(output.failed.len(), "re-open")
+}
+
+async fn handle_todo_delete(args: TodoDeleteArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_delete::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let todo_label = if output.count == 1 { "todo" } else { "todos" };
+    if receipt {
+        let rows = output
+            .trashed
+            .iter()
+            .map(|item| {
+                let title = item
+                    .content
+                    .clone()
+                    .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+                let project = item
+                    .project_name
+                    .clone()
+                    .unwrap_or_else(|| item.project_id.to_string());
+                (
+                    "To-do",
+                    format!("{title} (id: {}, project: {project})", item.todo_id),
+                )
+            })
+            .collect::<Vec<_>>();
+        print_receipt(&format!("Trashed {} {todo_label}", output.count), &rows);
+        return Ok(());
+    }
+
+    println!("{} {} {}:", "Trashed".green(), output.count, todo_label);
+    for item in &output.trashed {
+        let title = item
+            .content
+            .clone()
+            .unwrap_or_else(|| format!("Todo {}", item.todo_id));
+        let metadata = match item.project_name.as_deref() {
+            Some(project_name) => format!(
+                "(id: {}, project: {} / {})",
+                item.todo_id, project_name, item.project_id
+            ),
+            None => format!("(id: {}, project: {})", item.todo_id, item.project_id),
+        };
+        let title = fit_width(&title, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_comment(args: TodoCommentArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_comment::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let todo_label = output
+        .todo_content
+        .as_deref()
+        .map(|content| format!("\"{content}\""))
+        .unwrap_or_else(|| format!("todo {}", output.todo_id));
+
+    if receipt {
+        print_receipt(
+            "Commented on todo",
+            &[
+                (
+                    "To-do",
+                    output
+                        .todo_content
+                        .clone()
+                        .unwrap_or_else(|| output.todo_id.to_string()),
+                ),
+                (
+                    "Project",
+                    output
+                        .project_name
+                        .clone()
+                        .unwrap_or_else(|| output.project_id.to_string()),
+                ),
+                ("Id", output.comment_id.to_string()),
+                ("URL", output.app_url.clone().unwrap_or_default()),
+            ],
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} {} {}.",
+        "Commented on".green(),
+        todo_label,
+        format!("(id: {}, todo: {})", output.comment_id, output.todo_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_todo_comments(args: TodoCommentsArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_comments::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let metadata = format!("(id: {}, project: {})", output.todo_id, output.project_id);
+    println!(
+        "{} {}",
+        fit_width(&output.todo_content, metadata.chars().count() + 1).green(),
+        metadata.bright_black()
+    );
+
+    if output.comments.is_empty() {
+        println!("No comments yet.");
+        return Ok(());
+    }
+
+    for comment in &output.comments {
+        let created_at = comment
+            .created_at
+            .as_deref()
+            .map(dates::format_timestamp)
+            .unwrap_or_default();
+        println!();
+        println!(
+            "{} {}",
+            comment.author.bold(),
+            format!("{created_at} (id: {})", comment.comment_id).bright_black()
+        );
+        for line in comment.content.lines() {
+            println!("  {line}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_show(args: TodoShowArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_show::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let todo = &output.todo;
+    let status = if todo.completed { "Completed" } else { "Open" };
+    let timestamp = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(dates::format_timestamp)
+            .unwrap_or_default()
+    };
+    let with_person = |person: &Option<String>, at: &Option<String>| match person {
+        Some(person) => format!("{person}, {}", timestamp(at)),
+        None => timestamp(at),
+    };
+    print_receipt(
+        status,
+        &[
+            ("To-do", todo.content.clone()),
+            (
+                "Project",
+                output
+                    .project_name
+                    .clone()
+                    .unwrap_or_else(|| output.project_id.to_string()),
+            ),
+            ("Assignees", todo.assignees.join(", ")),
+            ("Notify", todo.notify.join(", ")),
+            ("Starts", todo.starts_on.clone().unwrap_or_default()),
+            ("Due", todo.due_on.clone().unwrap_or_default()),
+            ("Created", with_person(&todo.created_by, &todo.created_at)),
+            ("Updated", timestamp(&todo.updated_at)),
+            (
+                "Completed",
+                with_person(&todo.completed_by, &todo.completed_at),
+            ),
+            ("Id", todo.todo_id.to_string()),
+            ("URL", todo.app_url.clone().unwrap_or_default()),
+        ],
+    );
+    for line in todo.notes.lines() {
+        println!("  {line}");
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_list(args: TodoListArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_list::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.todos.is_empty() {
+        println!("No to-dos in {}.", output.todolist_name);
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.todolist_name.bold(),
+        format!(
+            "(id: {}, project: {})",
+            output.todolist_id, output.project_name
+        )
+        .bright_black()
+    );
+    for todo in &output.todos {
+        let mut details = vec![format!("id: {}", todo.todo_id)];
+        if let Some(due_on) = todo.due_on.as_deref() {
+            details.push(format!("due: {due_on}"));
+        }
+        if todo.completed {
+            let by = todo.completed_by.as_deref().unwrap_or("unknown");
+            match todo.completed_at.as_deref() {
+                Some(at) => details.push(format!("done by {by} {}", dates::format_timestamp(at))),
+                None => details.push(format!("done by {by}")),
+            }
+        }
+        let metadata = format!("({})", details.join(", "));
+        let check = if todo.completed { "[x]" } else { "[ ]" };
+        let content = fit_width(&todo.content, metadata.chars().count() + 9);
+        println!("  {check} {content} {}", metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_todo_history(args: TodoHistoryArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todo_history::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let metadata = format!("(id: {}, project: {})", output.todo_id, output.project_id);
+    println!(
+        "{} {}",
+        fit_width(&output.todo_content, metadata.chars().count() + 1).green(),
+        metadata.bright_black()
+    );
+
+    if output.events.is_empty() {
+        println!("No recorded changes.");
+        return Ok(());
+    }
+
+    for event in &output.events {
+        let created_at = event
+            .created_at
+            .as_deref()
+            .map(dates::format_timestamp)
+            .unwrap_or_default();
+        println!(
+            "  {} {} {}",
+            created_at.bright_black(),
+            event.actor.bold(),
+            event.summary
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_message(args: MessageArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        MessageCommand::List(args) => handle_message_list(args).await,
+        MessageCommand::Show(args) => handle_message_show(args).await,
+        MessageCommand::Post(args) => handle_message_post(args).await,
+    }
+}
+
+async fn handle_message_list(args: MessageListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = message_board::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.messages.is_empty() {
+        println!("No messages in {}.", output.project_name);
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {})", output.project_id).bright_black()
+    );
+    for message in &output.messages {
+        let mut details = vec![format!("id: {}", message.message_id)];
+        if let Some(creator) = message.creator.as_deref() {
+            details.push(format!("by {creator}"));
+        }
+        if let Some(created_at) = message.created_at.as_deref() {
+            details.push(dates::format_timestamp(created_at));
+        }
+        if message.comments_count > 0 {
+            details.push(format!("{} comment(s)", message.comments_count));
+        }
+        let metadata = format!("({})", details.join(", "));
+        let subject = fit_width(&message.subject, metadata.chars().count() + 5);
+        println!("  - {} {}", subject, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_message_show(args: MessageShowArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = message_board::run_show(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let message = &output.message;
+    print_receipt(
+        "Message",
+        &[
+            ("Subject", message.subject.clone()),
+            ("By", message.creator.clone().unwrap_or_default()),
+            (
+                "Posted",
+                message
+                    .created_at
+                    .as_deref()
+                    .map(dates::format_timestamp)
+                    .unwrap_or_default(),
+            ),
+            ("Comments", message.comments_count.to_string()),
+            ("Id", message.message_id.to_string()),
+            ("URL", message.app_url.clone().unwrap_or_default()),
+        ],
+    );
+    for line in output.content.lines() {
+        println!("  {line}");
+    }
+
+    Ok(())
+}
+
+async fn handle_message_post(args: MessagePostArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let open = args.open;
+    let output = message_board::run_post(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+    } else {
+        println!(
+            "{} \"{}\" {}.",
+            "Posted message".green(),
+            output.message.subject,
+            format!("(id: {})", output.message.message_id).bright_black()
+        );
+    }
+
+    if open
+        && !dry_run::enabled()
+        && let Some(url) = output.message.app_url.as_deref()
+        && let Err(err) = open_browser(url)
+    {
+        eprintln!(
+            "{}",
+            format!("Could not open the browser ({err}); open {url} manually.").yellow()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_comment(args: CommentArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        CommentCommand::List(args) => handle_comment_list(args).await,
+        CommentCommand::Add(args) => handle_comment_add(args).await,
+    }
+}
+
+async fn handle_comment_list(args: CommentListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = recording_comments::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.comments.is_empty() {
+        println!("No comments yet.");
+        return Ok(());
+    }
+
+    for (index, comment) in output.comments.iter().enumerate() {
+        let created_at = comment
+            .created_at
+            .as_deref()
+            .map(dates::format_timestamp)
+            .unwrap_or_default();
+        if index > 0 {
+            println!();
+        }
+        println!(
+            "{} {}",
+            comment.author.bold(),
+            format!("{created_at} (id: {})", comment.comment_id).bright_black()
+        );
+        for line in comment.content.lines() {
+            println!("  {line}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_comment_add(args: CommentAddArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = recording_comments::run_add(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} {}.",
+        format!("Commented on {}", output.recording_id).green(),
+        format!("(id: {})", output.comment_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_campfire(args: CampfireArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        CampfireCommand::List(args) => handle_campfire_list(args).await,
+        CampfireCommand::Send(args) => handle_campfire_send(args).await,
+        CampfireCommand::Tail(args) => campfire_tail::run(args).await,
+    }
+}
+
+async fn handle_campfire_list(args: CampfireListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = campfire_chat::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.campfires.is_empty() {
+        println!("No campfires found.");
+        return Ok(());
+    }
+
+    for campfire in &output.campfires {
+        let mut details = vec![format!("id: {}", campfire.campfire_id)];
+        if let Some(project_id) = campfire.project_id {
+            details.push(format!("project: {project_id}"));
+        }
+        let label = match campfire.project_name.as_deref() {
+            Some(project_name) => format!("{project_name} / {}", campfire.title),
+            None => campfire.title.clone(),
+        };
+        println!(
+            "  - {} {}",
+            label,
+            format!("({})", details.join(", ")).bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_campfire_send(args: CampfireSendArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = campfire_chat::run_send(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" {}.",
+        "Sent to Campfire in".green(),
+        output.project_name,
+        format!("(id: {})", output.line_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_doc(args: DocArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        DocCommand::List(args) => handle_doc_list(args).await,
+        DocCommand::Show(args) => handle_doc_show(args).await,
+        DocCommand::Create(args) => handle_doc_create(args).await,
+        DocCommand::Edit(args) => handle_doc_edit(args).await,
+    }
+}
+
+async fn handle_doc_list(args: DocListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = doc_vault::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        format!("{} / {}", output.project_name, output.folder_path).bold(),
+        format!("(id: {})", output.folder_id).bright_black()
+    );
+    if output.folders.is_empty() && output.documents.is_empty() {
+        println!("  (empty)");
+        return Ok(());
+    }
+    for folder in &output.folders {
+        let metadata = format!(
+            "(id: {}, {} document(s), {} folder(s))",
+            folder.folder_id, folder.documents_count, folder.folders_count
+        );
+        let title = fit_width(&folder.title, metadata.chars().count() + 6);
+        println!("  + {}/ {}", title, metadata.bright_black());
+    }
+    for document in &output.documents {
+        let mut details = vec![format!("id: {}", document.document_id)];
+        if let Some(creator) = document.creator.as_deref() {
+            details.push(format!("by {creator}"));
+        }
+        if let Some(updated_at) = document.updated_at.as_deref() {
+            details.push(format!("updated {}", dates::format_timestamp(updated_at)));
+        }
+        let metadata = format!("({})", details.join(", "));
+        let title = fit_width(&document.title, metadata.chars().count() + 5);
+        println!("  - {} {}", title, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_doc_show(args: DocShowArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = doc_vault::run_show(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    // Plain markdown, so `doc show 123 > notes.md` round-trips.
+    println!("# {}", output.document.title);
+    if !output.content.is_empty() {
+        println!();
+        println!("{}", output.content);
+    }
+
+    Ok(())
+}
+
+async fn handle_doc_create(args: DocCreateArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let open = args.open;
+    let output = doc_vault::run_create(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+    } else {
+        println!(
+            "{} \"{}\" {}.",
+            "Created document".green(),
+            output.document.title,
+            format!("(id: {})", output.document.document_id).bright_black()
+        );
+    }
+
+    if open
+        && !dry_run::enabled()
+        && let Some(url) = output.document.app_url.as_deref()
+        && let Err(err) = open_browser(url)
+    {
+        eprintln!(
+            "{}",
+            format!("Could not open the browser ({err}); open {url} manually.").yellow()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_doc_edit(args: DocEditArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = doc_vault::run_edit(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" {}.",
+        "Updated document".green(),
+        output.document.title,
+        format!("(id: {})", output.document.document_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_upload(args: UploadArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = upload_file::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" to {} {}.",
+        "Uploaded".green(),
+        output.file_name,
+        output.folder_path,
+        format!("(id: {})", output.upload_id).bright_black()
+    );
+    println!("Attachable sgid: {}", output.attachable_sgid);
+
+    Ok(())
+}
+
+async fn handle_schedule(args: ScheduleArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        ScheduleCommand::List(args) => handle_schedule_list(args).await,
+        ScheduleCommand::Add(args) => handle_schedule_add(args).await,
+        ScheduleCommand::Export(args) => handle_schedule_export(args).await,
+    }
+}
+
+async fn handle_schedule_list(args: ScheduleListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = schedule_entries::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.entries.is_empty() {
+        println!(
+            "No schedule entries in {} from {}.",
+            output.project_name, output.from
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {})", output.project_id).bright_black()
+    );
+    for entry in &output.entries {
+        let when = if entry.all_day {
+            let starts_on = entry.starts_at.get(..10).unwrap_or_default();
+            match entry
+                .ends_at
+                .get(..10)
+                .filter(|ends_on| *ends_on != starts_on)
+            {
+                Some(ends_on) => format!("{starts_on} – {ends_on}"),
+                None => starts_on.to_string(),
+            }
+        } else {
+            format!(
+                "{} – {}",
+                dates::format_timestamp(&entry.starts_at),
+                dates::format_timestamp(&entry.ends_at)
+            )
+        };
+        let metadata = format!("(id: {})", entry.entry_id);
+        let summary = fit_width(
+            &entry.summary,
+            when.chars().count() + metadata.chars().count() + 6,
+        );
+        println!("  - {} {} {}", when, summary, metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_schedule_add(args: ScheduleAddArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = schedule_entries::run_add(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" {}.",
+        "Scheduled".green(),
+        output.entry.summary,
+        format!("(id: {})", output.entry.entry_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_schedule_export(args: ScheduleExportArgs) -> AppResult<()> {
+    let export = schedule_entries::run_export(args).await?;
+
+    let Some(path) = export.path else {
+        print!("{}", export.document);
+        return Ok(());
+    };
+
+    let entry_label = if export.count == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+    println!(
+        "{} {} schedule {entry_label} from {} to {path} {}.",
+        "Exported".green(),
+        export.count,
+        export.project_name,
+        format!("(id: {})", export.project_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_checkin(args: CheckinArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        CheckinCommand::List(args) => handle_checkin_list(args).await,
+        CheckinCommand::Answers(args) => handle_checkin_answers(args).await,
+        CheckinCommand::Answer(args) => handle_checkin_answer(args).await,
+    }
+}
+
+async fn handle_checkin_list(args: CheckinListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = checkin_questions::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.questions.is_empty() {
+        println!("No check-in questions in {}.", output.project_name);
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {})", output.project_id).bright_black()
+    );
+    for question in &output.questions {
+        let mut details = question.schedule.clone().unwrap_or_default();
+        if question.paused {
+            details.push_str(" (paused)");
+        }
+        println!(
+            "  {} {} {}",
+            question.title,
+            details.trim_start().bright_black(),
+            format!("(id: {})", question.question_id).bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_checkin_answers(args: CheckinAnswersArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = checkin_questions::run_answers(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.answers.is_empty() {
+        println!("No answers since {}.", output.since);
+        return Ok(());
+    }
+
+    for (index, answer) in output.answers.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!(
+            "{} {}",
+            answer.author.as_deref().unwrap_or("Someone").bold(),
+            format!(
+                "{} (id: {})",
+                answer.group_on.as_deref().unwrap_or_default(),
+                answer.answer_id
+            )
+            .bright_black()
+        );
+        for line in answer.content.lines() {
+            println!("  {line}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_checkin_answer(args: CheckinAnswerArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = checkin_questions::run_answer(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} the check-in for {} {}.",
+        "Answered".green(),
+        output.answer.group_on.as_deref().unwrap_or("today"),
+        format!("(id: {})", output.answer.answer_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_card(args: CardArgs, verbose: bool, receipt: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        CardCommand::Step(CardStepCommand::Add(args)) => handle_card_step_add(args).await,
+        CardCommand::Step(CardStepCommand::Complete(args)) => {
+            handle_card_step_complete(args, receipt).await
+        }
+    }
+}
+
+async fn handle_card_step_add(args: CardStepAddArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = card_steps::run_add(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} step \"{}\" to {} {}.",
+        "Added".green(),
+        output.step.title,
+        output.card_title,
+        format!("(id: {})", output.step.step_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_card_step_complete(args: CardStepCompleteArgs, receipt: bool) -> AppResult<()> {
+    let json_output = args.json;
+    let output = card_steps::run_complete(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let step_label = if output.count == 1 { "step" } else { "steps" };
+    let verb = if output.reopened {
+        "Re-opened"
+    } else {
+        "Completed"
+    };
+    if receipt {
+        let rows = output
+            .steps
+            .iter()
+            .map(|step| ("Step", format!("{} (id: {})", step.title, step.step_id)))
+            .collect::<Vec<_>>();
+        print_receipt(
+            &format!(
+                "{verb} {} {step_label} on {}",
+                output.count, output.card_title
+            ),
+            &rows,
+        );
+    } else {
+        println!(
+            "{} {} {step_label} on {}:",
+            verb.green(),
+            output.count,
+            output.card_title
+        );
+        for step in &output.steps {
+            println!(
+                "  - {} {}",
+                step.title,
+                format!("(id: {})", step.step_id).bright_black()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_people(args: PeopleArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        PeopleCommand::List(args) => handle_people_list(args).await,
+        PeopleCommand::Show(args) => handle_people_show(args).await,
+    }
+}
+
+async fn handle_people_list(args: PeopleListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = people_directory::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    match (output.project_id, output.project_name.as_deref()) {
+        (Some(project_id), Some(project_name)) => println!(
+            "{} {}",
+            project_name.bold(),
+            format!("(id: {project_id}, people: {})", output.count).bright_black()
+        ),
+        _ => println!(
+            "{} {}",
+            "Account".bold(),
+            format!("(people: {})", output.count).bright_black()
+        ),
+    }
+    for person in &output.people {
+        let email = person
+            .email_address
+            .as_deref()
+            .map(|email| format!(" <{email}>"))
+            .unwrap_or_default();
+        let title = person
+            .title
+            .as_deref()
+            .map(|title| format!(", {title}"))
+            .unwrap_or_default();
+        let mut metadata = format!("(id: {}", person.person_id);
+        for role in person_roles(person) {
+            metadata.push_str(&format!(", {role}"));
+        }
+        metadata.push(')');
+        println!(
+            "  - {}{email}{title} {}",
+            person.name,
+            metadata.bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_people_show(args: PeopleShowArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = people_directory::run_show(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let person = &output.person;
+    println!(
+        "{} {}",
+        person.name.bold(),
+        format!("(id: {})", person.person_id).bright_black()
+    );
+    if let Some(email) = person.email_address.as_deref() {
+        println!("  Email: {email}");
+    }
+    if let Some(title) = person.title.as_deref() {
+        println!("  Title: {title}");
+    }
+    let roles = person_roles(person);
+    if roles.is_empty() {
+        println!("  Role:  member");
+    } else {
+        println!("  Role:  {}", roles.join(", "));
+    }
+
+    Ok(())
+}
+
+// Owners are admins too; saying both adds nothing.
+fn person_roles(person: &people_directory::PersonDetails) -> Vec<&'static str> {
+    let mut roles = Vec::new();
+    if person.owner {
+        roles.push("owner");
+    } else if person.admin {
+        roles.push("admin");
+    }
+    if person.client {
+        roles.push("client");
+    }
+    roles
+}
+
+async fn handle_webhook(args: WebhookArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    match args.command {
+        WebhookCommand::List(args) => handle_webhook_list(args).await,
+        WebhookCommand::Create(args) => handle_webhook_create(args).await,
+        WebhookCommand::Delete(args) => handle_webhook_delete(args).await,
+    }
+}
+
+async fn handle_webhook_list(args: WebhookListArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = webhook_manage::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.webhooks.is_empty() {
+        println!("No webhooks in {}.", output.project_name);
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {}, webhooks: {})", output.project_id, output.count).bright_black()
+    );
+    for webhook in &output.webhooks {
+        let mut metadata = format!("(id: {}", webhook.webhook_id);
+        if !webhook.active {
+            metadata.push_str(", inactive");
+        }
+        metadata.push(')');
+        println!(
+            "  - {} {} {}",
+            webhook.payload_url,
+            webhook.types.join(",").bright_black(),
+            metadata.bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_webhook_create(args: WebhookCreateArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = webhook_manage::run_create(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let action = if output.created {
+        "Created".green()
+    } else {
+        "Already exists:".yellow()
+    };
+    println!(
+        "{action} webhook to {} in {} {}.",
+        output.webhook.payload_url,
+        output.project_name,
+        format!("(id: {})", output.webhook.webhook_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_webhook_delete(args: WebhookDeleteArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = webhook_manage::run_delete(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let webhook_label = if output.count == 1 {
+        "webhook"
+    } else {
+        "webhooks"
+    };
+    println!(
+        "{} {} {webhook_label} from {}:",
+        "Deleted".green(),
+        output.count,
+        output.project_name
+    );
+    for webhook in &output.deleted {
+        println!(
+            "  - {} {}",
+            webhook.payload_url,
+            format!("(id: {})", webhook.webhook_id).bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_daemon(args: DaemonArgs, verbose: bool) -> AppResult<()> {
+    match args.command {
+        Some(DaemonCommand::Status(status_args)) => handle_daemon_status(status_args).await,
+        None => {
+            print_secret_store_location_if_verbose(verbose)?;
+            daemon_run::run(args).await
+        }
+    }
+}
+
+async fn handle_daemon_status(args: DaemonStatusArgs) -> AppResult<()> {
+    let output = daemon_status::run().await?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let Some(status) = output.status.as_ref() else {
+        println!("Daemon is not running.");
+        return Ok(());
+    };
+
+    println!(
+        "{} {}",
+        "Daemon running".green(),
+        format!(
+            "(pid: {}, socket: {})",
+            status.pid,
+            output.socket.as_deref().unwrap_or_default()
+        )
+        .bright_black()
+    );
+    println!("Started: {}", dates::format_unix_utc(status.started_at));
+    println!("Interval: {}s", status.interval_seconds);
+    println!(
+        "Last warm: {} {}",
+        status
+            .last_warm_at
+            .map_or_else(|| "never".to_string(), dates::format_unix_utc),
+        format!("({} responses)", status.warm_responses).bright_black()
+    );
+    if let Some(refreshed_at) = status.last_token_refresh_at {
+        println!("Token refreshed: {}", dates::format_unix_utc(refreshed_at));
+    }
+    if let Some(error) = status.last_error.as_deref() {
+        eprintln!("{}", format!("Last error: {error}").yellow());
+    }
+
+    Ok(())
+}
+
+fn batch_failures_result(failed: usize, action: &str) -> AppResult<()> {
+    if failed == 0 {
+        return Ok(());
+    }
+
+    let label = if failed == 1 { "to-do" } else { "to-dos" };
+    Err(AppError::generic(format!(
+        "Failed to {action} {failed} {label}."
+    )))
+}
+
+fn handle_template(args: TemplateArgs) -> AppResult<()> {
+    match args.command {
+        TemplateCommand::Save(args) => handle_template_save(args),
+        TemplateCommand::List(args) => handle_template_list(args),
+        TemplateCommand::Delete(args) => handle_template_delete(args),
+    }
+}
+
+fn handle_template_save(args: TemplateSaveArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = todo_template::run_save(args)?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let action = if output.replaced {
+        "Replaced template"
+    } else {
+        "Saved template"
+    };
+    println!(
+        "{} \"{}\" {}.",
+        action.green(),
+        output.name,
+        format!("(use: todo add --template {})", output.name).bright_black()
+    );
+
+    Ok(())
+}
+
+fn handle_template_list(args: TemplateListArgs) -> AppResult<()> {
+    let output = todo_template::run_list()?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.templates.is_empty() {
+        println!("No to-do templates.");
+        return Ok(());
+    }
+
+    for summary in &output.templates {
+        let due = summary
+            .template
+            .due_in
+            .as_deref()
+            .map(|due_in| format!(" (due in {due_in})"))
+            .unwrap_or_default();
+        println!(
+            "  - {} {}{}",
+            summary.name,
+            format!("\"{}\"", summary.template.content).bright_black(),
+            due.bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+fn handle_template_delete(args: TemplateDeleteArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = todo_template::run_delete(args)?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!("{} \"{}\".", "Deleted template".green(), output.name);
+
+    Ok(())
+}
+
+async fn handle_list(args: ListArgs, verbose: bool) -> AppResult<()> {
+    match args.command {
+        ListCommand::List(args) => handle_list_list(args, verbose).await,
+        ListCommand::Create(args) => handle_list_create(args, verbose).await,
+        ListCommand::Rename(args) => handle_list_rename(args, verbose).await,
+        ListCommand::Archive(args) => handle_list_archive(args, verbose).await,
+        ListCommand::Group(args) => match args.command {
+            ListGroupCommand::List(args) => handle_list_group_list(args, verbose).await,
+            ListGroupCommand::Add(args) => handle_list_group_add(args, verbose).await,
+        },
+        ListCommand::Template(args) => match args.command {
+            ListTemplateCommand::Save(args) => handle_list_template_save(args, verbose).await,
+            ListTemplateCommand::Apply(args) => handle_list_template_apply(args, verbose).await,
+            ListTemplateCommand::List(args) => handle_list_template_list(args),
+        },
+    }
+}
+
+async fn handle_list_list(args: ListListArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_manage::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.todolists.is_empty() {
+        println!("No to-do lists in {}.", output.project_name);
+        return Ok(());
+    }
+
+    for todolist in &output.todolists {
+        let metadata = match &todolist.completed_ratio {
+            Some(ratio) => format!("(id: {}, done: {ratio})", todolist.todolist_id),
+            None => format!("(id: {})", todolist.todolist_id),
+        };
+        let name = fit_width(&todolist.name, metadata.chars().count() + 5);
+        println!("  - {name} {}", metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_list_create(args: ListCreateArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_manage::run_create(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in {} {}.",
+        "Created list".green(),
+        output.name,
+        output.project_name,
+        format!("(id: {})", output.todolist_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_list_rename(args: ListRenameArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_manage::run_rename(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" to \"{}\" {}.",
+        "Renamed list".green(),
+        output.previous_name.as_deref().unwrap_or_default(),
+        output.name,
+        format!("(id: {})", output.todolist_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_list_archive(args: ListArchiveArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_manage::run_archive(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in {} {}.",
+        "Archived list".green(),
+        output.name,
+        output.project_name,
+        format!("(id: {})", output.todolist_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_list_group_list(args: ListGroupListArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_groups::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.groups.is_empty() {
+        println!("No groups in {}.", output.todolist_name);
+        return Ok(());
+    }
+
+    for group in &output.groups {
+        let metadata = match &group.completed_ratio {
+            Some(ratio) => format!("(id: {}, done: {ratio})", group.group_id),
+            None => format!("(id: {})", group.group_id),
+        };
+        let name = fit_width(&group.name, metadata.chars().count() + 5);
+        println!("  - {name} {}", metadata.bright_black());
+    }
+
+    Ok(())
+}
+
+async fn handle_list_group_add(args: ListGroupAddArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = todolist_groups::run_add(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in {} {}.",
+        "Created group".green(),
+        output.name,
+        output.todolist_name,
+        format!("(id: {})", output.group_id).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_list_template_save(args: ListTemplateSaveArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = list_template::run_save(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    let action = if output.replaced {
+        "Replaced list template"
+    } else {
+        "Saved list template"
+    };
+    println!(
+        "{} \"{}\" from \"{}\" {}.",
+        action.green(),
+        output.name,
+        output.source_todolist_name,
+        format!("(groups: {}, to-dos: {})", output.groups, output.todos).bright_black()
+    );
+
+    Ok(())
+}
+
+async fn handle_list_template_apply(args: ListTemplateApplyArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = list_template::run_apply(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" in {} {}.",
+        "Created list".green(),
+        output.todolist_name,
+        output.project_name,
+        format!(
+            "(id: {}, groups: {}, to-dos: {}, start: {})",
+            output.todolist_id, output.groups_created, output.todos_created, output.start
+        )
+        .bright_black()
+    );
+
+    Ok(())
+}
+
+fn handle_list_template_list(args: ListTemplateListArgs) -> AppResult<()> {
+    let output = list_template::run_list()?;
+
+    if args.json {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    if output.templates.is_empty() {
+        println!("No list templates.");
+        return Ok(());
+    }
+
+    for summary in &output.templates {
+        println!(
+            "  - {} {}",
+            summary.name,
+            format!(
+                "(list: \"{}\", groups: {}, to-dos: {})",
+                summary.todolist_name, summary.groups, summary.todos
+            )
+            .bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_project(args: ProjectArgs, verbose: bool) -> AppResult<()> {
+    account_override::apply(args.account_id).await?;
+    match args.command {
+        ProjectCommand::Pin(args) => handle_project_pin(args, verbose).await,
+        ProjectCommand::Unpin(args) => handle_project_unpin(args),
+        ProjectCommand::Pins(args) => handle_project_pins(args),
+        ProjectCommand::Show(args) => handle_project_show(args, verbose).await,
+        ProjectCommand::Create(args) => handle_project_create(args, verbose).await,
+        ProjectCommand::Update(args) => handle_project_update(args, verbose).await,
+        ProjectCommand::Archive(args) => {
+            handle_project_lifecycle(ProjectLifecycle::Archive, args, verbose).await
+        }
+        ProjectCommand::Trash(args) => {
+            handle_project_lifecycle(ProjectLifecycle::Trash, args, verbose).await
+        }
+        ProjectCommand::Restore(args) => {
+            handle_project_lifecycle(ProjectLifecycle::Restore, args, verbose).await
+        }
+        ProjectCommand::People(args) => match args.command {
+            ProjectPeopleCommand::List(args) => handle_project_people_list(args, verbose).await,
+            ProjectPeopleCommand::Add(args) => {
+                handle_project_people_change(args, true, verbose).await
+            }
+            ProjectPeopleCommand::Remove(args) => {
+                handle_project_people_change(args, false, verbose).await
+            }
+        },
+    }
+}
+
+async fn handle_project_show(args: ProjectShowArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = project_show::run(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {})", output.project_id).bright_black()
+    );
+    for line in output.description.lines() {
+        println!("  {line}");
+    }
+    println!("People: {}", output.people_count);
+    if let Some(app_url) = output.app_url.as_deref() {
+        println!("URL: {app_url}");
+    }
+
+    if output.tools.is_empty() {
+        return Ok(());
+    }
+    println!("Tools:");
+    let name_width = output
+        .tools
+        .iter()
+        .map(|tool| tool.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for tool in &output.tools {
+        let mut metadata = format!("(id: {})", tool.id);
+        if !tool.enabled {
+            metadata.push_str(" disabled");
         }
-    };
+        println!(
+            "  {:<name_width$}  {} {}",
+            tool.name,
+            tool.title,
+            metadata.bright_black()
+        );
+    }
 
-    std::process::exit(exit_code);
+    Ok(())
 }
 
-async fn run() -> AppResult<()> {
-    configure_prompt_rendering();
-    let cli = Cli::parse();
-    let verbose = cli.verbose;
+async fn handle_project_create(args: ProjectCreateArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = project_manage::run_create(args).await?;
 
-    match cli.command {
-        Command::Integration(args) => handle_integration(args, verbose),
-        Command::Login(args) => handle_login(args).await,
-        Command::Logout(args) => handle_logout(args, verbose),
-        Command::Whoami(args) => handle_whoami(args, verbose).await,
-        Command::Todo(args) => handle_todo(args, verbose).await,
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
     }
+
+    let template = output
+        .template_id
+        .map(|template_id| format!(" from template {template_id}"))
+        .unwrap_or_default();
+    println!(
+        "{} \"{}\"{} {}.",
+        "Created project".green(),
+        output.project_name,
+        template,
+        format!("(id: {})", output.project_id).bright_black()
+    );
+
+    Ok(())
 }
 
-fn handle_integration(args: IntegrationArgs, verbose: bool) -> AppResult<()> {
-    match args.command {
-        IntegrationCommand::Set(args) => handle_integration_set(args),
-        IntegrationCommand::Show => handle_integration_show(verbose),
-        IntegrationCommand::Clear(args) => handle_integration_clear(args, verbose),
+async fn handle_project_update(args: ProjectUpdateArgs, verbose: bool) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
+    let json_output = args.json;
+    let output = project_manage::run_update(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    match output.previous_name.as_deref() {
+        Some(previous_name) if previous_name != output.project_name => println!(
+            "{} \"{}\" to \"{}\" {}.",
+            "Renamed project".green(),
+            previous_name,
+            output.project_name,
+            format!("(id: {})", output.project_id).bright_black()
+        ),
+        _ => println!(
+            "{} \"{}\" {}.",
+            "Updated project".green(),
+            output.project_name,
+            format!("(id: {})", output.project_id).bright_black()
+        ),
     }
-}
 
-fn handle_integration_set(args: IntegrationSetArgs) -> AppResult<()> {
-    let values = resolve_integration_set_values(args)?;
-    integration::set_integration(values.client_id, values.client_secret, values.redirect_uri)?;
-    println!("{}", "Integration credentials saved.".green());
-    integration::print_secret_store_location()?;
     Ok(())
 }
 
-fn handle_integration_show(verbose: bool) -> AppResult<()> {
+async fn handle_project_lifecycle(
+    action: ProjectLifecycle,
+    args: ProjectLifecycleArgs,
+    verbose: bool,
+) -> AppResult<()> {
     print_secret_store_location_if_verbose(verbose)?;
-    let status = integration::show_integration()?;
+    let json_output = args.json;
+    let output = project_lifecycle::run(action, args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
 
     println!(
-        "client_id: {}",
-        if status.has_client_id {
-            "configured"
-        } else {
-            "missing"
-        }
-    );
-    println!(
-        "client_secret: {}",
-        if status.has_client_secret {
-            "configured"
-        } else {
-            "missing"
-        }
-    );
-    println!(
-        "redirect_uri: {}",
-        if status.has_redirect_uri {
-            "configured"
-        } else {
-            "missing"
-        }
+        "{}",
+        format!("{} {} project(s):", action.past_tense(), output.count).green()
     );
-
-    if let Some(client_id) = status.client_id {
-        println!("client_id (redacted): {client_id}");
-    }
-    if let Some(redirect_uri) = status.redirect_uri {
-        println!("redirect_uri value: {redirect_uri}");
+    for project in &output.projects {
+        let metadata = format!("(id: {})", project.project_id);
+        let name = fit_width(&project.project_name, metadata.chars().count() + 5);
+        println!("  - {} {}", name, metadata.bright_black());
     }
 
     Ok(())
 }
 
-fn handle_integration_clear(args: IntegrationClearArgs, verbose: bool) -> AppResult<()> {
+async fn handle_project_people_list(args: ProjectPeopleListArgs, verbose: bool) -> AppResult<()> {
     print_secret_store_location_if_verbose(verbose)?;
-    if !args.force && !confirm("Clear integration credentials and local session? [y/N]")? {
-        println!("Cancelled.");
+    let json_output = args.json;
+    let output = project_people::run_list(args).await?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
         return Ok(());
     }
 
-    integration::clear_integration_and_session()?;
-    println!("{}", "Integration credentials and session cleared.".green());
+    println!(
+        "{} {}",
+        output.project_name.bold(),
+        format!("(id: {}, people: {})", output.project_id, output.count).bright_black()
+    );
+    print_project_people(&output.people, &[]);
+
     Ok(())
 }
 
-async fn handle_login(args: LoginArgs) -> AppResult<()> {
+async fn handle_project_people_change(
+    args: ProjectPeopleChangeArgs,
+    add: bool,
+    verbose: bool,
+) -> AppResult<()> {
+    print_secret_store_location_if_verbose(verbose)?;
     let json_output = args.json;
-    let output = login::run(args).await?;
+    let output = if add {
+        project_people::run_add(args).await?
+    } else {
+        project_people::run_remove(args).await?
+    };
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&output)
             .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
         println!("{rendered}");
-    } else {
-        println!(
-            "Logged in to Basecamp account \"{}\" ({}).",
-            output.account_name, output.account_id
-        );
+        return Ok(());
     }
 
-    integration::print_secret_store_location()?;
+    let (action, people) = if add {
+        ("Added", &output.granted)
+    } else {
+        ("Removed", &output.revoked)
+    };
+    let direction = if add { "to" } else { "from" };
+    println!(
+        "{} \"{}\" {}:",
+        format!("{action} {} person(s) {direction}", people.len()).green(),
+        output.project_name,
+        format!("(id: {})", output.project_id).bright_black()
+    );
+    print_project_people(people, &output.invited);
+
     Ok(())
 }
 
-fn handle_logout(args: LogoutArgs, verbose: bool) -> AppResult<()> {
+fn print_project_people(people: &[project_people::PersonSummary], invited: &[String]) {
+    for person in people {
+        let email = person
+            .email_address
+            .as_deref()
+            .map(|email| format!(" <{email}>"))
+            .unwrap_or_default();
+        let was_invited = person.email_address.as_deref().is_some_and(|email| {
+            invited
+                .iter()
+                .any(|invite| invite.eq_ignore_ascii_case(email))
+        });
+        let mut metadata = format!("(id: {})", person.person_id);
+        if was_invited {
+            metadata.push_str(" invited");
+        }
+        println!("  - {}{} {}", person.name, email, metadata.bright_black());
+    }
+}
+
+async fn handle_project_pin(args: ProjectPinArgs, verbose: bool) -> AppResult<()> {
     print_secret_store_location_if_verbose(verbose)?;
     let json_output = args.json;
-    let output = logout::run(args)?;
+    let output = project_pin::run_pin(args).await?;
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&output)
             .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
         println!("{rendered}");
-    } else {
-        println!("{}", "Logged out from local Basecamp session.".green());
+        return Ok(());
     }
 
+    let alias = output
+        .alias
+        .as_deref()
+        .map(|alias| format!(" as @{alias}"))
+        .unwrap_or_default();
+    println!(
+        "{} \"{}\"{} {}.",
+        "Pinned project".green(),
+        output.project_name,
+        alias,
+        format!("(id: {})", output.project_id).bright_black()
+    );
+
     Ok(())
 }
 
-async fn handle_whoami(args: WhoamiArgs, verbose: bool) -> AppResult<()> {
-    print_secret_store_location_if_verbose(verbose)?;
-    let output = whoami::run().await?;
+fn handle_project_unpin(args: ProjectUnpinArgs) -> AppResult<()> {
+    let json_output = args.json;
+    let output = project_pin::run_unpin(args)?;
+
+    if json_output {
+        let rendered = serde_json::to_string_pretty(&output)
+            .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
+        println!("{rendered}");
+        return Ok(());
+    }
+
+    println!(
+        "{} \"{}\" {}.",
+        "Unpinned project".green(),
+        output.project_name,
+        format!("(id: {})", output.project_id).bright_black()
+    );
+
+    Ok(())
+}
+
+fn handle_project_pins(args: ProjectPinsArgs) -> AppResult<()> {
+    let output = project_pin::run_pins()?;
 
     if args.json {
         let rendered = serde_json::to_string_pretty(&output)
@@ -164,40 +3594,40 @@ async fn handle_whoami(args: WhoamiArgs, verbose: bool) -> AppResult<()> {
         return Ok(());
     }
 
-    let email = output
-        .email_address
-        .as_deref()
-        .map(|value| format!(" <{value}>"))
-        .unwrap_or_default();
+    if output.pins.is_empty() {
+        println!("No pinned projects.");
+        return Ok(());
+    }
 
-    if let Some(account_name) = output.account_name.as_deref() {
-        println!(
-            "Current user: {}{} (person {}) on account \"{}\" ({}).",
-            output.name, email, output.id, account_name, output.account_id
-        );
-    } else {
-        println!(
-            "Current user: {}{} (person {}) on account {}.",
-            output.name, email, output.id, output.account_id
+    for pin in &output.pins {
+        let alias: String = pin
+            .aliases
+            .iter()
+            .map(|alias| format!("@{alias} "))
+            .collect();
+        let metadata = format!("(id: {})", pin.id);
+        let name = fit_width(
+            &pin.name,
+            alias.chars().count() + metadata.chars().count() + 5,
         );
+        println!("  - {}{} {}", alias, name, metadata.bright_black());
     }
 
     Ok(())
 }
 
-async fn handle_todo(args: TodoArgs, verbose: bool) -> AppResult<()> {
+async fn handle_alias(args: AliasArgs, verbose: bool) -> AppResult<()> {
     match args.command {
-        TodoCommand::Add(args) => handle_todo_add(args, verbose).await,
-        TodoCommand::Edit(args) => handle_todo_edit(args, verbose).await,
-        TodoCommand::Complete(args) => handle_todo_complete(args, verbose).await,
-        TodoCommand::ReOpen(args) => handle_todo_re_open(args, verbose).await,
+        AliasCommand::Set(args) => handle_alias_set(args, verbose).await,
+        AliasCommand::List(args) => handle_alias_list(args),
+        AliasCommand::Remove(args) => handle_alias_remove(args),
     }
 }
 
-async fn handle_todo_add(args: TodoAddArgs, verbose: bool) -> AppResult<()> {
+async fn handle_alias_set(args: AliasSetArgs, verbose: bool) -> AppResult<()> {
     print_secret_store_location_if_verbose(verbose)?;
     let json_output = args.json;
-    let output = todo_add::run(args).await?;
+    let output = alias_manage::run_set(args).await?;
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&output)
@@ -206,56 +3636,53 @@ async fn handle_todo_add(args: TodoAddArgs, verbose: bool) -> AppResult<()> {
         return Ok(());
     }
 
+    let action = if output.replaced {
+        "Replaced alias"
+    } else {
+        "Saved alias"
+    };
     println!(
-        "{} \"{}\" in project \"{}\" / list \"{}\" {}.",
-        "Created todo".green(),
-        output.content,
-        output.project_name,
-        output.todolist_name,
-        format!("(id: {})", output.todo_id).bright_black()
+        "{} @{} for {} {}.",
+        action.green(),
+        output.entry.alias,
+        alias_target_label(&output.entry),
+        alias_target_metadata(&output.entry).bright_black()
     );
 
     Ok(())
 }
 
-async fn handle_todo_complete(args: TodoCompleteArgs, verbose: bool) -> AppResult<()> {
-    print_secret_store_location_if_verbose(verbose)?;
-    let json_output = args.json;
-    let output = todo_complete::run(args).await?;
+fn handle_alias_list(args: AliasListArgs) -> AppResult<()> {
+    let output = alias_manage::run_list()?;
 
-    if json_output {
+    if args.json {
         let rendered = serde_json::to_string_pretty(&output)
             .map_err(|err| AppError::generic(format!("Failed to render JSON output: {err}")))?;
         println!("{rendered}");
         return Ok(());
     }
 
-    let todo_label = if output.count == 1 { "todo" } else { "todos" };
-    println!("{} {} {}:", "Completed".green(), output.count, todo_label);
-    for item in &output.completed {
-        let title = item
-            .content
-            .clone()
-            .unwrap_or_else(|| format!("Todo {}", item.todo_id));
-        let metadata = match item.project_name.as_deref() {
-            Some(project_name) => {
-                format!(
-                    "(id: {}, project: {} / {})",
-                    item.todo_id, project_name, item.project_id
-                )
-            }
-            None => format!("(id: {}, project: {})", item.todo_id, item.project_id),
-        };
-        println!("  - {} {}", title, metadata.bright_black());
+    if output.aliases.is_empty() {
+        println!("No aliases.");
+        return Ok(());
+    }
+
+    for entry in &output.aliases {
+        let alias = format!("@{} ", entry.alias);
+        let metadata = alias_target_metadata(entry);
+        let name = fit_width(
+            &alias_target_label(entry),
+            alias.chars().count() + metadata.chars().count() + 5,
+        );
+        println!("  - {}{} {}", alias, name, metadata.bright_black());
     }
 
     Ok(())
 }
 
-async fn handle_todo_edit(args: TodoEditArgs, verbose: bool) -> AppResult<()> {
-    print_secret_store_location_if_verbose(verbose)?;
+fn handle_alias_remove(args: AliasRemoveArgs) -> AppResult<()> {
     let json_output = args.json;
-    let output = todo_edit::run(args).await?;
+    let output = alias_manage::run_remove(args)?;
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&output)
@@ -264,27 +3691,35 @@ async fn handle_todo_edit(args: TodoEditArgs, verbose: bool) -> AppResult<()> {
         return Ok(());
     }
 
-    let project_label = output
-        .project_name
-        .as_deref()
-        .map(|project_name| format!("project \"{project_name}\""))
-        .unwrap_or_else(|| format!("project {}", output.project_id));
-
     println!(
-        "{} \"{}\" in {} {}.",
-        "Edited todo".green(),
-        output.content,
-        project_label,
-        format!("(id: {})", output.todo_id).bright_black()
+        "{} @{} for {} {}.",
+        "Removed alias".green(),
+        output.entry.alias,
+        alias_target_label(&output.entry),
+        alias_target_metadata(&output.entry).bright_black()
     );
 
     Ok(())
 }
 
-async fn handle_todo_re_open(args: TodoReOpenArgs, verbose: bool) -> AppResult<()> {
+fn alias_target_label(entry: &alias_manage::AliasEntry) -> String {
+    match &entry.todolist_name {
+        Some(todolist_name) => format!("{} / {todolist_name}", entry.project_name),
+        None => entry.project_name.clone(),
+    }
+}
+
+fn alias_target_metadata(entry: &alias_manage::AliasEntry) -> String {
+    match entry.todolist_id {
+        Some(todolist_id) => format!("(project: {}, list: {todolist_id})", entry.project_id),
+        None => format!("(id: {})", entry.project_id),
+    }
+}
+
+async fn handle_stats(args: StatsArgs, verbose: bool) -> AppResult<()> {
     print_secret_store_location_if_verbose(verbose)?;
     let json_output = args.json;
-    let output = todo_re_open::run(args).await?;
+    let output = stats_summary::run(args).await?;
 
     if json_output {
         let rendered = serde_json::to_string_pretty(&output)
@@ -293,23 +3728,35 @@ async fn handle_todo_re_open(args: TodoReOpenArgs, verbose: bool) -> AppResult<(
         return Ok(());
     }
 
-    let todo_label = if output.count == 1 { "todo" } else { "todos" };
-    println!("{} {} {}:", "Re-opened".green(), output.count, todo_label);
-    for item in &output.reopened {
-        let title = item
-            .content
-            .clone()
-            .unwrap_or_else(|| format!("Todo {}", item.todo_id));
-        let metadata = match item.project_name.as_deref() {
-            Some(project_name) => {
-                format!(
-                    "(id: {}, project: {} / {})",
-                    item.todo_id, project_name, item.project_id
-                )
-            }
-            None => format!("(id: {}, project: {})", item.todo_id, item.project_id),
-        };
-        println!("  - {} {}", title, metadata.bright_black());
+    println!(
+        "{} {}",
+        format!("Activity from {} to {}", output.since, output.until).green(),
+        format!(
+            "(account: {}, events: {})",
+            output.account_id, output.events_scanned
+        )
+        .bright_black()
+    );
+    let rows = [
+        ("To-dos created", output.todos_created),
+        ("To-dos completed", output.todos_completed),
+        ("Messages posted", output.messages_posted),
+        ("Comments posted", output.comments_posted),
+    ];
+    for (label, count) in rows {
+        println!("  {label:<18} {count:>6}");
+    }
+
+    if output.most_active_projects.is_empty() {
+        println!("No project activity in this period.");
+        return Ok(());
+    }
+
+    println!("Most active projects:");
+    for (index, project) in output.most_active_projects.iter().enumerate() {
+        let metadata = format!("(id: {}, events: {})", project.project_id, project.events);
+        let name = fit_width(&project.project_name, metadata.chars().count() + 6);
+        println!("  {}. {} {}", index + 1, name, metadata.bright_black());
     }
 
     Ok(())
@@ -341,10 +3788,22 @@ struct IntegrationSetValues {
     redirect_uri: String,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntegrationSetDocument {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    redirect_uri: Option<String>,
+}
+
 fn resolve_integration_set_values(args: IntegrationSetArgs) -> AppResult<IntegrationSetValues> {
-    let mut client_id = normalize_optional(args.client_id);
-    let mut client_secret = normalize_optional(args.client_secret);
-    let mut redirect_uri = normalize_optional(args.redirect_uri);
+    let document = match args.from_json.as_deref() {
+        Some(path) => read_integration_set_document(path)?,
+        None => IntegrationSetDocument::default(),
+    };
+    let mut client_id = normalize_optional(args.client_id.or(document.client_id));
+    let mut client_secret = normalize_optional(args.client_secret.or(document.client_secret));
+    let mut redirect_uri = normalize_optional(args.redirect_uri.or(document.redirect_uri));
 
     let mut missing_flags = Vec::new();
     if client_id.is_none() {
@@ -358,7 +3817,7 @@ fn resolve_integration_set_values(args: IntegrationSetArgs) -> AppResult<Integra
     }
 
     let needs_prompt = !missing_flags.is_empty();
-    if needs_prompt && !is_interactive_terminal() {
+    if needs_prompt && !can_prompt() {
         return Err(AppError::invalid_input(format!(
             "Missing required arguments: {}. Provide all flags in non-interactive mode.",
             missing_flags.join(", ")
@@ -399,6 +3858,30 @@ fn resolve_integration_set_values(args: IntegrationSetArgs) -> AppResult<Integra
     })
 }
 
+// Errors never echo the document, since it carries the client secret.
+fn read_integration_set_document(path: &Path) -> AppResult<IntegrationSetDocument> {
+    let (source, raw) = if path.as_os_str() == "-" {
+        let mut raw = String::new();
+        io::stdin()
+            .read_to_string(&mut raw)
+            .map_err(|err| AppError::generic(format!("Failed to read stdin: {err}")))?;
+        ("stdin".to_string(), raw)
+    } else {
+        let raw = fs::read_to_string(path).map_err(|err| {
+            AppError::invalid_input(format!("Failed to read {}: {err}", path.display()))
+        })?;
+        (path.display().to_string(), raw)
+    };
+
+    serde_json::from_str(&raw).map_err(|err| {
+        AppError::invalid_input(format!(
+            "Invalid integration JSON from {source} at line {}, column {}. Expected an object with only client_id, client_secret, and redirect_uri strings.",
+            err.line(),
+            err.column()
+        ))
+    })
+}
+
 fn prompt_visible_input(prompt: &str, default: Option<&str>) -> AppResult<String> {
     let required_message = format!("{prompt} is required.");
     let input = match default {
@@ -447,7 +3930,3 @@ fn normalize_optional(value: Option<String>) -> Option<String> {
         }
     })
 }
-
-fn is_interactive_terminal() -> bool {
-    io::stdin().is_terminal() && io::stderr().is_terminal()
-}
@@ -0,0 +1,528 @@
+//! Conversions between terminal text and Basecamp rich text (HTML).
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+/// Turns typed text into rich text: markup characters are escaped and every
+/// line becomes a `<div>`, which is how Basecamp's editor stores paragraphs.
+pub fn plain_to_html(text: &str) -> String {
+    text.trim()
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                "<div><br></div>".to_string()
+            } else {
+                format!("<div>{}</div>", escape_html(line))
+            }
+        })
+        .collect()
+}
+
+/// Turns markdown into rich text. Covers what Basecamp's editor can store:
+/// `**bold**`, `*italic*`, `~~strike~~`, `[links](url)`, `#` headings, `>`
+/// quotes, `-`/`1.` lists (nested by indentation), and fenced code. Anything
+/// else, raw HTML included, is escaped and kept as text.
+pub fn markdown_to_html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len() * 2);
+    // Open lists as (ordered, indentation), innermost last.
+    let mut lists: Vec<(bool, usize)> = Vec::new();
+    let mut fence: Option<Vec<&str>> = None;
+    let mut quote: Vec<&str> = Vec::new();
+
+    for line in text.trim_matches('\n').lines() {
+        if let Some(code) = fence.as_mut() {
+            if line.trim_start().starts_with("```") {
+                html.push_str(&format!("<pre>{}</pre>", escape_html(&code.join("\n"))));
+                fence = None;
+            } else {
+                code.push(line);
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(quoted) = trimmed.strip_prefix('>') {
+            close_lists(&mut html, &mut lists, 0);
+            quote.push(quoted.strip_prefix(' ').unwrap_or(quoted));
+            continue;
+        }
+        flush_quote(&mut html, &mut quote);
+
+        if trimmed.starts_with("```") {
+            close_lists(&mut html, &mut lists, 0);
+            fence = Some(Vec::new());
+            continue;
+        }
+
+        if let Some((ordered, item)) = list_item(trimmed) {
+            let indent = line.len() - trimmed.len();
+            close_lists(&mut html, &mut lists, indent + 1);
+            // A deeper item opens a list inside the previous item, which
+            // therefore stays open until its siblings or parent close it.
+            if lists.last() == Some(&(ordered, indent)) {
+                html.push_str("</li>");
+            } else {
+                close_lists(&mut html, &mut lists, indent);
+                html.push_str(if ordered { "<ol>" } else { "<ul>" });
+                lists.push((ordered, indent));
+            }
+            html.push_str(&format!("<li>{}", inline_markdown(item)));
+            continue;
+        }
+        close_lists(&mut html, &mut lists, 0);
+
+        if trimmed.is_empty() {
+            html.push_str("<div><br></div>");
+        } else if let Some(heading) = heading(trimmed) {
+            html.push_str(&format!("<h1>{}</h1>", inline_markdown(heading)));
+        } else {
+            html.push_str(&format!("<div>{}</div>", inline_markdown(line.trim_end())));
+        }
+    }
+
+    flush_quote(&mut html, &mut quote);
+    close_lists(&mut html, &mut lists, 0);
+    if let Some(code) = fence {
+        html.push_str(&format!("<pre>{}</pre>", escape_html(&code.join("\n"))));
+    }
+    html
+}
+
+/// Renders rich text for the terminal as plain text with light markdown:
+/// paragraphs and line breaks become newlines, list items get `-` or `1.`
+/// markers, bold/italic become `**`/`*`, and links keep their URL.
+pub fn html_to_text(html: &str) -> String {
+    render_html(html, false)
+}
+
+/// Renders rich text as markdown that `markdown_to_html` reads back: like
+/// `html_to_text`, but links become `[text](url)`, headings `#`, quotes `>`,
+/// and code blocks are fenced.
+pub fn html_to_markdown(html: &str) -> String {
+    render_html(html, true)
+}
+
+/// A title as one line of plain text: tags are dropped, entities decoded,
+/// and whitespace runs collapsed. A `<` that does not start a tag (`a < b`)
+/// is kept.
+pub fn plain_title(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        stripped.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let starts_tag = after
+            .chars()
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '/' || ch == '!');
+        match after.find('>').filter(|_| starts_tag) {
+            Some(end) => rest = &after[end + 1..],
+            None => {
+                stripped.push('<');
+                rest = after;
+            }
+        }
+    }
+    stripped.push_str(rest);
+
+    decode_entities(&stripped)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn render_html(html: &str, markdown: bool) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut lists: Vec<Option<usize>> = Vec::new();
+    let mut links: Vec<(usize, Option<String>)> = Vec::new();
+    let mut quotes: Vec<usize> = Vec::new();
+    let mut preformatted = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut output, rest, preformatted);
+            break;
+        };
+        push_text(&mut output, &rest[..tag_start], preformatted);
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            // A stray `<` that never closes is text, not markup.
+            push_text(&mut output, &rest[tag_start..], preformatted);
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|ch: char| ch.is_whitespace() || ch == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("br", _) => output.push('\n'),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) if markdown => {
+                end_line(&mut output);
+                output.push_str("# ");
+            }
+            ("blockquote", false) if markdown => {
+                end_line(&mut output);
+                quotes.push(output.len());
+            }
+            ("blockquote", true) if markdown => {
+                end_line(&mut output);
+                if let Some(start) = quotes.pop() {
+                    let quoted: String = output[start..]
+                        .lines()
+                        .map(|line| format!("> {line}\n"))
+                        .collect();
+                    output.truncate(start);
+                    output.push_str(&quoted);
+                }
+            }
+            ("div" | "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "blockquote", _) => {
+                end_line(&mut output);
+            }
+            ("pre", closing) => {
+                end_line(&mut output);
+                if markdown {
+                    output.push_str("```\n");
+                }
+                preformatted = !closing;
+            }
+            ("ul", false) => lists.push(None),
+            ("ol", false) => lists.push(Some(0)),
+            ("ul" | "ol", true) => {
+                lists.pop();
+                end_line(&mut output);
+            }
+            ("li", false) => {
+                end_line(&mut output);
+                output.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        output.push_str(&format!("{number}. "));
+                    }
+                    _ => output.push_str("- "),
+                }
+            }
+            ("li", true) => end_line(&mut output),
+            ("strong" | "b", _) => output.push_str("**"),
+            ("em" | "i", _) => output.push('*'),
+            ("del" | "s" | "strike", _) => output.push_str("~~"),
+            ("a", false) => links.push((output.len(), attribute(tag, "href"))),
+            ("a", true) => match links.pop() {
+                Some((start, Some(href))) if markdown => {
+                    output.insert(start, '[');
+                    output.push_str(&format!("]({href})"));
+                }
+                Some((start, Some(href))) if output[start..].trim() != href => {
+                    output.push_str(&format!(" ({href})"));
+                }
+                _ => {}
+            },
+            ("bc-attachment", false) => {
+                if let Some(filename) = attribute(tag, "filename") {
+                    output.push_str(&format!("[{filename}]"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut text = String::with_capacity(output.len());
+    let mut blank_lines = 0;
+    for line in output.trim().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(line);
+    }
+    text
+}
+
+fn close_lists(html: &mut String, lists: &mut Vec<(bool, usize)>, below_indent: usize) {
+    while let Some((ordered, indent)) = lists.last().copied() {
+        if indent < below_indent {
+            break;
+        }
+        html.push_str(if ordered { "</li></ol>" } else { "</li></ul>" });
+        lists.pop();
+    }
+}
+
+fn flush_quote(html: &mut String, quote: &mut Vec<&str>) {
+    if quote.is_empty() {
+        return;
+    }
+    let lines: Vec<String> = quote.drain(..).map(inline_markdown).collect();
+    html.push_str(&format!("<blockquote>{}</blockquote>", lines.join("<br>")));
+}
+
+/// `- item`, `* item`, `+ item`, or `1. item`, as (ordered, text).
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(marker) {
+            return Some((false, item.trim()));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let item = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then(|| (true, item.trim()))
+}
+
+fn heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|ch| *ch == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..].strip_prefix(' ').map(str::trim)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Emphasis {
+    Strong,
+    Em,
+    Del,
+}
+
+enum Inline {
+    Text(String),
+    Marker(Emphasis, &'static str),
+    Html(String),
+}
+
+/// Converts `**`, `*`, `_`, `~~`, `` ` `` and `[text](url)` inside one line.
+/// Markers without a partner stay literal text.
+fn inline_markdown(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut pieces: Vec<Inline> = Vec::new();
+    let mut text = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let next = chars.get(index + 1).copied();
+        let previous = index.checked_sub(1).map(|previous| chars[previous]);
+        let marker = match (ch, next) {
+            ('\\', Some(escaped)) if escaped.is_ascii_punctuation() => {
+                text.push(escaped);
+                index += 2;
+                continue;
+            }
+            ('`', _) => {
+                if let Some(len) = chars[index + 1..].iter().position(|ch| *ch == '`') {
+                    let code: String = chars[index..index + len + 2].iter().collect();
+                    text.push_str(&code);
+                    index += len + 2;
+                    continue;
+                }
+                None
+            }
+            ('[', _) => {
+                if let Some((label, href, len)) = markdown_link(&chars[index..]) {
+                    flush_text(&mut pieces, &mut text);
+                    pieces.push(Inline::Html(format!(
+                        "<a href=\"{}\">{}</a>",
+                        escape_html(&href),
+                        inline_markdown(&label)
+                    )));
+                    index += len;
+                    continue;
+                }
+                None
+            }
+            ('*', Some('*')) => Some((Emphasis::Strong, "**")),
+            ('_', Some('_')) => Some((Emphasis::Strong, "__")),
+            ('~', Some('~')) => Some((Emphasis::Del, "~~")),
+            ('*', _) => Some((Emphasis::Em, "*")),
+            // `snake_case` words are not emphasis.
+            ('_', _)
+                if !previous.is_some_and(char::is_alphanumeric)
+                    || !next.is_some_and(char::is_alphanumeric) =>
+            {
+                Some((Emphasis::Em, "_"))
+            }
+            _ => None,
+        };
+
+        match marker {
+            Some((emphasis, literal)) => {
+                flush_text(&mut pieces, &mut text);
+                pieces.push(Inline::Marker(emphasis, literal));
+                index += literal.chars().count();
+            }
+            None => {
+                text.push(ch);
+                index += 1;
+            }
+        }
+    }
+    flush_text(&mut pieces, &mut text);
+
+    // Pair markers like brackets; an inner marker left open when its outer
+    // one closes is literal.
+    let mut open: Vec<usize> = Vec::new();
+    let mut paired = vec![None; pieces.len()];
+    for (position, piece) in pieces.iter().enumerate() {
+        let Inline::Marker(emphasis, literal) = piece else {
+            continue;
+        };
+        let partner = open.iter().rposition(|opened| {
+            matches!(pieces[*opened], Inline::Marker(open_emphasis, open_literal)
+                if open_emphasis == *emphasis && open_literal == *literal)
+        });
+        match partner {
+            Some(depth) => {
+                let opened = open[depth];
+                open.truncate(depth);
+                paired[opened] = Some(true);
+                paired[position] = Some(false);
+            }
+            None => open.push(position),
+        }
+    }
+
+    let mut html = String::with_capacity(line.len());
+    for (piece, paired) in pieces.iter().zip(paired) {
+        match (piece, paired) {
+            (Inline::Text(text), _) => html.push_str(&escape_html(text)),
+            (Inline::Html(markup), _) => html.push_str(markup),
+            (Inline::Marker(emphasis, _), Some(opening)) => {
+                let tag = match emphasis {
+                    Emphasis::Strong => "strong",
+                    Emphasis::Em => "em",
+                    Emphasis::Del => "del",
+                };
+                html.push_str(&if opening {
+                    format!("<{tag}>")
+                } else {
+                    format!("</{tag}>")
+                });
+            }
+            (Inline::Marker(_, literal), None) => html.push_str(literal),
+        }
+    }
+    html
+}
+
+fn flush_text(pieces: &mut Vec<Inline>, text: &mut String) {
+    if !text.is_empty() {
+        pieces.push(Inline::Text(std::mem::take(text)));
+    }
+}
+
+/// Parses `[label](href)` at the start of `chars`, returning the label, the
+/// href, and how many chars it spans.
+fn markdown_link(chars: &[char]) -> Option<(String, String, usize)> {
+    let label_end = chars.iter().position(|ch| *ch == ']')?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let href_len = chars[label_end + 2..].iter().position(|ch| *ch == ')')?;
+    let label: String = chars[1..label_end].iter().collect();
+    let href: String = chars[label_end + 2..label_end + 2 + href_len]
+        .iter()
+        .collect();
+    let href = href.trim();
+    if label.trim().is_empty() || href.is_empty() || href.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((label, href.to_string(), label_end + 3 + href_len))
+}
+
+fn push_text(output: &mut String, raw: &str, preformatted: bool) {
+    let decoded = decode_entities(raw);
+    if preformatted {
+        output.push_str(&decoded);
+        return;
+    }
+
+    for ch in decoded.chars() {
+        let ch = if ch == '\n' || ch == '\r' { ' ' } else { ch };
+        // Source indentation and line wrapping are not content.
+        if ch == ' ' && (output.is_empty() || output.ends_with([' ', '\n'])) {
+            continue;
+        }
+        output.push(ch);
+    }
+}
+
+fn end_line(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+        match entity {
+            Some((ch, end)) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
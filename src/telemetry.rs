@@ -0,0 +1,62 @@
+use serde::Serialize;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const TELEMETRY_ENV: &str = "BASECAMP_CLI_TELEMETRY";
+
+static API_CALLS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Serialize)]
+struct CommandEvent<'a> {
+    event: &'static str,
+    command: &'a str,
+    version: &'static str,
+    timestamp: u64,
+    duration_ms: u128,
+    exit_code: i32,
+    api_calls: u64,
+}
+
+pub fn record_api_call() {
+    API_CALLS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Appends one JSON line describing the finished command to the target in
+/// `BASECAMP_CLI_TELEMETRY` (a file path, or `fd:N` for an inherited
+/// descriptor). Telemetry is opt-in and never changes the command outcome.
+pub fn emit(command: &str, duration: Duration, exit_code: i32) {
+    let Ok(target) = env::var(TELEMETRY_ENV) else {
+        return;
+    };
+    let target = target.trim();
+    if target.is_empty() {
+        return;
+    }
+
+    let event = CommandEvent {
+        event: "command",
+        command,
+        version: env!("CARGO_PKG_VERSION"),
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs()),
+        duration_ms: duration.as_millis(),
+        exit_code,
+        api_calls: API_CALLS.load(Ordering::Relaxed),
+    };
+    let Ok(mut line) = serde_json::to_vec(&event) else {
+        return;
+    };
+    line.push(b'\n');
+
+    let path = match target.strip_prefix("fd:") {
+        Some(fd) if fd.parse::<u32>().is_ok() => format!("/dev/fd/{fd}"),
+        _ => target.to_string(),
+    };
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = file.write_all(&line);
+    }
+}
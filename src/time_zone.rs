@@ -0,0 +1,56 @@
+//! "Today" and wall-clock offsets for commands that resolve dates. Zone
+//! rules need a time zone database, which waits on an approved dependency
+//! (see `docs/agent/features/dates/code.md`); until then both are UTC, and a
+//! Basecamp profile in another zone gets a warning.
+
+use crate::basecamp::client::BasecampClient;
+use crate::dates::Date;
+use crate::error::AppResult;
+use colored::Colorize;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static PROFILE_ZONE_CHECKED: OnceLock<()> = OnceLock::new();
+
+/// Today's date in UTC. Warns once per run when the Basecamp profile's
+/// `time_zone` is another zone, since dates may then be a day off.
+pub async fn profile_today(client: &BasecampClient) -> AppResult<Date> {
+    warn_if_profile_zone_differs(client).await?;
+    Ok(Date::from_unix(unix_now(), 0))
+}
+
+/// Seconds east of UTC for a wall-clock time (`local` is Unix seconds as if
+/// the wall clock were UTC). Always `0` for now, with the same warning as
+/// [`profile_today`].
+pub async fn profile_offset_at_local(client: &BasecampClient, _local: i64) -> AppResult<i64> {
+    warn_if_profile_zone_differs(client).await?;
+    Ok(0)
+}
+
+async fn warn_if_profile_zone_differs(client: &BasecampClient) -> AppResult<()> {
+    if PROFILE_ZONE_CHECKED.get().is_some() {
+        return Ok(());
+    }
+    let profile = client.fetch_my_profile().await?;
+    if PROFILE_ZONE_CHECKED.set(()).is_err() {
+        return Ok(());
+    }
+    if let Some(zone) = profile.time_zone.as_deref()
+        && !matches!(zone, "UTC" | "Etc/UTC")
+    {
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: your Basecamp profile's time zone is \"{zone}\", but time zones are not supported yet; dates like `today` and `fri` use UTC."
+            )
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
@@ -1,7 +1,23 @@
 use crate::error::AppError;
+use colored::Colorize;
 use inquire::error::InquireError;
 use inquire::ui::{Color, RenderConfig, StyleSheet};
 use std::io::{self, IsTerminal, Write};
+use std::process::Command;
+use std::sync::OnceLock;
+
+// Narrowest budget left for listing text, so titles stay recognizable.
+const MIN_FIT_WIDTH: usize = 12;
+
+/// Columns taken by prompt cursors and checkboxes ahead of an option label.
+pub const PROMPT_MARGIN: usize = 6;
+
+/// Key help under `Select` prompts.
+pub const SELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Enter to select";
+/// Key help under `MultiSelect` prompts.
+pub const MULTISELECT_HELP_MESSAGE: &str = "Type to filter, use Up/Down to move, Space to select one, Right to all, Left to none, Enter to confirm";
+
+static OUTPUT_WIDTH: OnceLock<Option<usize>> = OnceLock::new();
 
 pub fn configure_prompt_rendering() {
     let render_config = RenderConfig {
@@ -14,6 +30,52 @@ pub fn configure_prompt_rendering() {
     inquire::set_global_render_config(render_config);
 }
 
+/// Prompts render on stderr and read keys from the controlling terminal, so
+/// they work with piped stdin as long as a terminal is attached.
+pub fn can_prompt() -> bool {
+    if !io::stderr().is_terminal() {
+        return false;
+    }
+
+    io::stdin().is_terminal() || controlling_terminal_available()
+}
+
+#[cfg(unix)]
+fn controlling_terminal_available() -> bool {
+    std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .is_ok()
+}
+
+#[cfg(not(unix))]
+fn controlling_terminal_available() -> bool {
+    false
+}
+
+/// Sets the listing width used by [`fit_width`]; `None` disables truncation.
+pub fn set_output_width(width: Option<usize>) {
+    let _ = OUTPUT_WIDTH.set(width);
+}
+
+/// Shortens `text` with a trailing `…` so a line holding `reserved` other
+/// columns stays within the configured output width.
+pub fn fit_width(text: &str, reserved: usize) -> String {
+    let Some(width) = OUTPUT_WIDTH.get().copied().flatten() else {
+        return text.to_string();
+    };
+
+    let budget = width.saturating_sub(reserved).max(MIN_FIT_WIDTH);
+    if text.chars().count() <= budget {
+        return text.to_string();
+    }
+
+    let mut fitted: String = text.chars().take(budget - 1).collect();
+    fitted.push('…');
+    fitted
+}
+
 pub fn prompt_error(action: &str, err: InquireError) -> AppError {
     match err {
         InquireError::OperationCanceled | InquireError::OperationInterrupted => {
@@ -24,6 +86,72 @@ pub fn prompt_error(action: &str, err: InquireError) -> AppError {
     }
 }
 
+/// An answer from one step of a multi-prompt flow, or Esc to revisit the
+/// previous step.
+pub enum Step<T> {
+    Next(T),
+    Back,
+}
+
+impl<T> Step<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Step<U> {
+        match self {
+            Self::Next(value) => Step::Next(f(value)),
+            Self::Back => Step::Back,
+        }
+    }
+
+    /// For prompts outside a step flow, where Esc still cancels.
+    pub fn or_cancel(self, action: &str) -> Result<T, AppError> {
+        match self {
+            Self::Next(value) => Ok(value),
+            Self::Back => Err(AppError::invalid_input(format!("{action} cancelled."))),
+        }
+    }
+}
+
+/// Like [`prompt_error`], but Esc becomes [`Step::Back`]; Ctrl-C still cancels.
+pub fn prompt_step<T>(result: Result<T, InquireError>, action: &str) -> Result<Step<T>, AppError> {
+    match result {
+        Ok(value) => Ok(Step::Next(value)),
+        Err(InquireError::OperationCanceled) => {
+            clear_active_terminal_line();
+            Ok(Step::Back)
+        }
+        Err(err) => Err(prompt_error(action, err)),
+    }
+}
+
+/// Prints a boxed summary of a mutation; rows with empty values are skipped.
+pub fn print_receipt(title: &str, rows: &[(&str, String)]) {
+    let rows = rows
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .collect::<Vec<_>>();
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let content_width = rows
+        .iter()
+        .map(|(_, value)| label_width + 2 + value.chars().count())
+        .chain([title.chars().count() + 1])
+        .max()
+        .unwrap_or(0);
+
+    let title_rule = "─".repeat(content_width - title.chars().count() - 1);
+    println!("┌─ {} {title_rule}┐", title.green());
+    for (label, value) in rows {
+        let padding = " ".repeat(content_width - label_width - 2 - value.chars().count());
+        println!(
+            "│ {}  {value}{padding} │",
+            format!("{label:<label_width$}").bright_black()
+        );
+    }
+    println!("└{}┘", "─".repeat(content_width + 2));
+}
+
 fn clear_active_terminal_line() {
     if !io::stderr().is_terminal() {
         return;
@@ -33,3 +161,85 @@ fn clear_active_terminal_line() {
     let _ = stderr.write_all(b"\r\x1b[2K");
     let _ = stderr.flush();
 }
+
+/// Opens `url` in the default browser.
+pub fn open_browser(url: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let status = Command::new("open")
+            .arg(url)
+            .status()
+            .map_err(|err| err.to_string())?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("open exited with status {status}"))
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let status = Command::new("xdg-open")
+            .arg(url)
+            .status()
+            .map_err(|err| err.to_string())?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("xdg-open exited with status {status}"))
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let status = Command::new("cmd")
+            .args(["/C", "start", "", url])
+            .status()
+            .map_err(|err| err.to_string())?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("cmd start exited with status {status}"))
+        }
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        let _ = url;
+        Err("Unsupported platform for automatic browser launch.".to_string())
+    }
+}
+
+/// Opens `$VISUAL` (or `$EDITOR`, else `vi`) on a scratch `.md` file holding
+/// `initial`, and returns what was saved. The editor value may carry
+/// arguments, e.g. `code --wait`.
+pub fn edit_text(initial: &str) -> Result<String, String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        });
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos());
+    let path = std::env::temp_dir().join(format!("basecamp-cli-{}-{nanos}.md", std::process::id()));
+    std::fs::write(&path, initial).map_err(|err| err.to_string())?;
+
+    let status = Command::new(program).args(parts).arg(&path).status();
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+    let status = status.map_err(|err| format!("could not start {program}: {err}"))?;
+    if !status.success() {
+        return Err(format!("{program} exited with status {status}"));
+    }
+    edited.map_err(|err| err.to_string())
+}